@@ -41,16 +41,57 @@ export interface Unit09Instruction {
   name:
     | "initialize"
     | "setConfig"
+    | "collectFee"
+    | "setLifecycle"
+    | "addBlockedHost"
+    | "removeBlockedHost"
+    | "addCoAdmin"
+    | "removeCoAdmin"
     | "registerRepo"
+    | "reserveRepo"
+    | "finalizeRepo"
     | "updateRepo"
+    | "addMaintainer"
+    | "removeMaintainer"
+    | "setRepoObservable"
+    | "setPrimaryModule"
+    | "setRepoFeeOverride"
+    | "renameTag"
     | "registerModule"
     | "updateModule"
     | "linkModuleToRepo"
     | "createFork"
+    | "cloneFork"
     | "updateForkState"
+    | "adminReassignFork"
+    | "pruneForks"
     | "recordObservation"
+    | "recordObservationMulti"
+    | "topUpCredits"
     | "recordMetrics"
-    | "setMetadata";
+    | "recomputeMetrics"
+    | "takeMetricsSnapshot"
+    | "restoreMetrics"
+    | "ackIndexed"
+    | "setMetadata"
+    | "estimateRents"
+    | "getCuEstimates"
+    | "getPermissions"
+    | "getAuthorityPermissions"
+    | "getStaleRepos"
+    | "getVersionCount"
+    | "compareSnapshots"
+    | "getRepoScore"
+    | "getRepo"
+    | "summarizeRepoTags"
+    | "repoHasTag"
+    | "checkForkDescendant"
+    | "getDescendantCount"
+    | "refreshBumps"
+    | "migrateRepo"
+    | "migrateDeployment"
+    | "migrateModule"
+    | "migrateFork";
   docs?: string[];
   accounts: Unit09InstructionAccount[];
   args: Unit09InstructionArg[];
@@ -111,7 +152,9 @@ export interface Unit09AccountDescription {
     | "Fork"
     | "ModuleRepoLink"
     | "GlobalMetadata"
-    | "Authority";
+    | "Authority"
+    | "ObserverCredits"
+    | "OwnerForkIndex";
   docs?: string[];
   type: Unit09StructType;
 }
@@ -166,16 +209,38 @@ export interface Unit09TypeDescription {
   name:
     | "InitializeArgs"
     | "SetConfigArgs"
+    | "CollectFeeArgs"
+    | "SetLifecycleArgs"
+    | "AddBlockedHostArgs"
+    | "RemoveBlockedHostArgs"
+    | "AddCoAdminArgs"
     | "RegisterRepoArgs"
+    | "ReserveRepoArgs"
+    | "FinalizeRepoArgs"
     | "UpdateRepoArgs"
+    | "AddMaintainerArgs"
+    | "RemoveMaintainerArgs"
+    | "SetRepoObservableArgs"
+    | "SetPrimaryModuleArgs"
+    | "SetRepoFeeOverrideArgs"
+    | "RenameTagArgs"
     | "RegisterModuleArgs"
     | "UpdateModuleArgs"
     | "LinkModuleToRepoArgs"
     | "CreateForkArgs"
+    | "CloneForkArgs"
     | "UpdateForkStateArgs"
+    | "AdminReassignForkArgs"
+    | "PruneForksArgs"
+    | "CheckForkDescendantArgs"
     | "RecordObservationArgs"
+    | "RecordObservationMultiArgs"
+    | "TopUpCreditsArgs"
     | "RecordMetricsArgs"
-    | "SetMetadataArgs";
+    | "RecomputeMetricsArgs"
+    | "AckIndexedArgs"
+    | "SetMetadataArgs"
+    | "RepoHasTagArgs";
   type: Unit09StructType;
 }
 
@@ -185,16 +250,33 @@ export interface Unit09TypeDescription {
 export interface Unit09EventDescription {
   name:
     | "ConfigUpdated"
+    | "BlockedHostsChanged"
+    | "AuthorityRoleAssigned"
+    | "AuthorityRoleRevoked"
     | "RepoRegistered"
     | "RepoUpdated"
     | "RepoActivationChanged"
+    | "RepoObservabilityChanged"
+    | "PrimaryModuleSet"
+    | "RepoFeeOverrideSet"
+    | "TagRenamed"
+    | "RepoMigrated"
+    | "DeploymentMigrated"
+    | "ModuleMigrated"
+    | "ForkMigrated"
+    | "RepoObservationCapReached"
     | "ModuleRegistered"
     | "ModuleVersionRegistered"
     | "ModuleLinkedToRepo"
     | "ForkCreated"
+    | "ForkCloned"
     | "ForkUpdated"
     | "ObservationRecorded"
+    | "BatchPartiallyApplied"
+    | "CreditsToppedUp"
     | "MetricsReconciled"
+    | "MetricsRestored"
+    | "MetricsRecomputed"
     | "GlobalMetadataUpdated";
   fields: Unit09EventField[];
 }
@@ -222,20 +304,65 @@ export interface Unit09EventField {
 export interface Unit09ErrorDescription {
   code: number;
   name:
-    | "InvalidAdmin"
-    | "InvalidAuthority"
-    | "InvalidForkOwner"
-    | "InvalidPda"
     | "InternalError"
+    | "CounterOverflow"
+    | "ValidationFailed"
+    | "InvalidAdmin"
+    | "UnauthorizedAdminAction"
     | "InvalidFeeBps"
-    | "ValueOutOfRange"
-    | "StringEmpty"
+    | "UnsupportedConfigVersion"
+    | "InvalidAuthority"
+    | "MissingAuthority"
+    | "AuthorityRoleNotAllowed"
     | "StringTooLong"
+    | "StringEmpty"
+    | "ValueOutOfRange"
+    | "InvalidUrl"
+    | "RepoInactive"
+    | "RepoModuleLimitReached"
+    | "RepoObservationLimitReached"
+    | "RepoKeyMismatch"
+    | "ModuleInactive"
+    | "ModuleVersionNotFound"
+    | "ModuleVersionAlreadyExists"
+    | "ModuleRepoMismatch"
+    | "ModuleImmutable"
+    | "ForkInactive"
+    | "InvalidForkParent"
+    | "ForkLimitReached"
+    | "InvalidForkOwner"
     | "ObservationDataTooLarge"
+    | "ObservationNotAllowed"
+    | "MetricsInconsistent"
+    | "MetadataTooLong"
+    | "MetadataMissing"
     | "MetadataInvalid"
-    | "DeploymentInactive"
-    | "TimestampInFuture"
-    | "InvalidTimeRange";
+    | "InvalidLifecycleState"
+    | "MigrationRequired"
+    | "MigrationAlreadyApplied"
+    | "MissingRequiredAccount"
+    | "InvalidAccountOwner"
+    | "InvalidAccountDiscriminator"
+    | "ExpectedSystemAccount"
+    | "ExpectedSigner"
+    | "ExpectedWritableAccount"
+    | "CooldownActive"
+    | "RateLimitReached"
+    | "InvalidFeeRecipient"
+    | "MetricsRecomputeNotStarted"
+    | "InsufficientCredits"
+    | "ForkLabelTaken"
+    | "NoChangeRequested"
+    | "RepoNotReserved"
+    | "MaintainerAlreadyAdded"
+    | "MaintainerNotFound"
+    | "ForkNotPrunable"
+    | "InsufficientObserverBalance"
+    | "RepoNameTaken"
+    | "RepoBlocked"
+    | "AdminQuorumUnreachable"
+    | "InvalidTimeRange"
+    | "TimestampInFuture";
   msg: string;
 }
 
@@ -252,6 +379,7 @@ export interface InitializeArgs {
   maxModulesPerRepo: number; // u32
   policyRef: Uint8Array; // [u8; 32]
   lifecycleNoteRef: Uint8Array; // [u8; 32]
+  requireAll: boolean;
 }
 
 /**
@@ -262,6 +390,78 @@ export interface SetConfigArgs {
   maxModulesPerRepo: number | null; // option<u32>
   isActive: boolean | null; // option<bool>
   policyRef: Uint8Array | null; // option<[u8; 32]>
+  autoFreezeOnInconsistency: boolean | null; // option<bool>
+  feeRecipient: string | null; // option<publicKey>
+  enforceUrlKeyed: boolean | null; // option<bool>
+  maxObservationsPerWindow: bigint | null; // option<u64>
+  minLocPerObservation: bigint | null; // option<u64>, at least 1
+  requiredAdminSigs: number | null; // option<u8>, 1..=min(MAX_REQUIRED_ADMIN_SIGS, 1 + coAdminCount)
+  quietWhenFrozen: boolean | null; // option<bool>
+  creditsPerObservation: bigint | null; // option<u64>, 0 disables metering
+  stalenessThresholdSecs: bigint | null; // option<i64>, 0 disables staleness
+  maxNameLenOverride: number | null; // option<u16>, 1..=MAX_NAME_LEN, MAX_NAME_LEN clears
+  requireAttestor: boolean | null;
+  eventVerbosity: number | null; // option<u8>, 0 critical, 1 normal, 2 debug
+  reviewGated: boolean | null; // new repos start inactive until an admin activates them
+  labelPrefixRequired: string | null; // option<string>, "" clears
+  cooldowns: CooldownConfig | null; // replaces all three windows
+  minObserverLamports: bigint | null; // option<u64>, 0 disables the check
+  enforceUniqueRepoName: boolean | null;
+}
+
+/**
+ * Per-instruction cooldown windows in seconds; 0 disables a window.
+ */
+export interface CooldownConfig {
+  observationSecs: number; // u32, per repository
+  forkCreationSecs: number; // u32, deployment-wide
+  moduleTouchSecs: number; // u32, per module
+}
+
+/**
+ * Args for `renameTag`. Repos to migrate go in `remainingAccounts`.
+ */
+export interface RenameTagArgs {
+  old: string;
+  new: string;
+}
+
+/**
+ * Args for `collectFee`.
+ */
+export interface CollectFeeArgs {
+  amount: bigint; // u64
+}
+
+/**
+ * Args for `setLifecycle`.
+ */
+export interface SetLifecycleArgs {
+  phase: number; // u8, LifecyclePhase
+  globalFreeze: boolean | null; // option<bool>
+  freezeReason: string;
+}
+
+/**
+ * Args for `addBlockedHost`.
+ */
+export interface AddBlockedHostArgs {
+  host: string; // bare host or any URL on it
+}
+
+/**
+ * Args for `removeBlockedHost`.
+ */
+export interface RemoveBlockedHostArgs {
+  host: string; // bare host or any URL on it
+}
+
+/**
+ * Args for `addCoAdmin`. Co-signers go in `remainingAccounts` as
+ * `[signer, authorityPda]` pairs, like `setConfig`.
+ */
+export interface AddCoAdminArgs {
+  coAdmin: string; // publicKey
 }
 
 /**
@@ -272,6 +472,25 @@ export interface RegisterRepoArgs {
   name: string;
   url: string;
   tags: string;
+  metadataUri: string;
+  allowObservation: boolean;
+}
+
+/**
+ * Args for `reserveRepo`.
+ */
+export interface ReserveRepoArgs {
+  repoKey: string; // publicKey
+}
+
+/**
+ * Args for `finalizeRepo` (the `repoKey` is fixed by `reserveRepo`).
+ */
+export interface FinalizeRepoArgs {
+  name: string;
+  url: string;
+  tags: string;
+  metadataUri: string;
   allowObservation: boolean;
 }
 
@@ -282,8 +501,48 @@ export interface UpdateRepoArgs {
   name: string | null;
   url: string | null;
   tags: string | null;
+  metadataUri: string | null;
   isActive: boolean | null;
   allowObservation: boolean | null;
+  allowedObservers: string[] | null; // publicKey[]
+  tagBits: bigint | null; // option<u64>, TAG_VOCABULARY bitmask
+  enforceUniqueModuleUri: boolean | null;
+  requireAccessProof: boolean | null;
+}
+
+/**
+ * Args for `addMaintainer`.
+ */
+export interface AddMaintainerArgs {
+  maintainer: string; // publicKey
+}
+
+/**
+ * Args for `removeMaintainer`.
+ */
+export interface RemoveMaintainerArgs {
+  maintainer: string; // publicKey
+}
+
+/**
+ * Args for `setRepoObservable`.
+ */
+export interface SetRepoObservableArgs {
+  allowObservation: boolean;
+}
+
+/**
+ * Args for `setPrimaryModule`.
+ */
+export interface SetPrimaryModuleArgs {
+  module: string | null; // option<publicKey>, module PDA
+}
+
+/**
+ * Args for `setRepoFeeOverride` (deployment admin only).
+ */
+export interface SetRepoFeeOverrideArgs {
+  feeBpsOverride: number | null; // option<u16>, at most MAX_FEE_BPS; null clears
 }
 
 /**
@@ -305,6 +564,8 @@ export interface RegisterModuleArgs {
   changelogUri: string;
   isStable: boolean;
   createInitialVersionSnapshot: boolean;
+  inheritRepoTags: boolean;
+  immutableUntil: bigint; // i64, 0 for no immutability window
 }
 
 /**
@@ -315,6 +576,7 @@ export interface UpdateModuleArgs {
   metadataUri: string | null;
   category: string | null;
   tags: string | null;
+  tagBits: bigint | null; // option<u64>, TAG_VOCABULARY bitmask
   isActive: boolean | null;
   createVersionSnapshot: boolean;
   newVersion: SemanticVersionTuple | null;
@@ -342,6 +604,15 @@ export interface CreateForkArgs {
   tags: string;
   isRoot: boolean;
   depth: number | null; // option<u16>
+  deriveKey: boolean;
+}
+
+/**
+ * Args for `cloneFork`.
+ */
+export interface CloneForkArgs {
+  forkKey: string; // publicKey
+  labelSuffix: string;
 }
 
 /**
@@ -354,6 +625,31 @@ export interface UpdateForkStateArgs {
   isActive: boolean | null;
 }
 
+/**
+ * Args for `adminReassignFork`.
+ */
+export interface AdminReassignForkArgs {
+  newOwner: string; // publicKey
+}
+
+/**
+ * Args for `pruneForks`.
+ *
+ * Forks to close are passed as writable remaining accounts, followed by
+ * `ancestorCount` ancestors whose descendant counts are decremented.
+ */
+export interface PruneForksArgs {
+  atomic: boolean;
+  ancestorCount: number; // u8
+}
+
+/**
+ * Args for `checkForkDescendant`.
+ */
+export interface CheckForkDescendantArgs {
+  ancestor: string; // publicKey (fork key)
+}
+
 /**
  * Args for `recordObservation`.
  */
@@ -363,6 +659,44 @@ export interface RecordObservationArgs {
   modulesTouched: number; // u32
   revision: string;
   note: string;
+  module: string | null; // option<publicKey>, module PDA
+  observedAtOverride: bigint | null; // option<i64>, admin-only backfill
+  idempotencyKey: number[] | null; // option<[u8; 16]>, retry de-duplication
+  sourceRegionHash: number[] | null; // option<[u8; 8]>, hashed worker region, non-zero
+  accessProof: number[] | null; // option<[u8; 32]>, required when repo.requireAccessProof
+  kind: number; // u8, ObservationKind (0 Full, 1 MetadataOnly)
+}
+
+/**
+ * Per-repo share of a `recordObservationMulti` scan.
+ */
+export interface ObservationSplit {
+  repoIndex: number; // u8, index into remaining accounts
+  repoKey: string; // publicKey, expected Repo::repo_key at repoIndex
+  linesOfCode: bigint;
+  filesProcessed: number;
+  modulesTouched: number;
+}
+
+/**
+ * Args for `recordObservationMulti`.
+ */
+export interface RecordObservationMultiArgs {
+  linesOfCode: bigint;
+  filesProcessed: number;
+  modulesTouched: number;
+  splits: ObservationSplit[];
+  revision: string;
+  note: string;
+  atomic: boolean; // false: skip failing splits, report BatchPartiallyApplied
+}
+
+/**
+ * Args for `topUpCredits`.
+ */
+export interface TopUpCreditsArgs {
+  observer: string; // publicKey
+  amount: bigint; // u64, lamports paid and credits added
 }
 
 /**
@@ -377,6 +711,21 @@ export interface RecordMetricsArgs {
   totalFilesProcessed: bigint | null;
 }
 
+/**
+ * Args for `recomputeMetrics`.
+ */
+export interface RecomputeMetricsArgs {
+  reset: boolean;
+  finalize: boolean;
+}
+
+/**
+ * Args for `ackIndexed`.
+ */
+export interface AckIndexedArgs {
+  count: bigint; // u64
+}
+
 /**
  * Args for `setMetadata`.
  */
@@ -390,6 +739,66 @@ export interface SetMetadataArgs {
   extraJson: string | null;
 }
 
+/**
+ * Args for `repoHasTag`.
+ */
+export interface RepoHasTagArgs {
+  tag: string;
+}
+
+/**
+ * Return data of `estimateRents` (lamports per account type).
+ */
+export interface RentEstimates {
+  config: bigint; // u64
+  metrics: bigint; // u64
+  repo: bigint; // u64
+  module: bigint; // u64
+  fork: bigint; // u64
+}
+
+/**
+ * One entry of the return data of `getCuEstimates` (a `Vec<CuEstimate>`).
+ */
+export interface CuEstimate {
+  instruction: string; // snake_case instruction name
+  units: number; // u32, approximate compute units
+}
+
+/**
+ * Return data of `getRepo`, independent of the `Repo` account layout.
+ */
+export interface RepoView {
+  schemaVersion: number; // u8, currently 1
+  repoKey: string; // publicKey
+  authority: string; // publicKey
+  name: string;
+  url: string;
+  host: number; // u8, RepoHost
+  tags: string;
+  tagBits: bigint; // u64
+  metadataUri: string;
+  isActive: boolean;
+  allowObservation: boolean;
+  isReserved: boolean;
+  requireAccessProof: boolean;
+  maintainerCount: number; // u8
+  allowedObserverCount: number; // u8
+  moduleCount: number; // u32
+  primaryModule: string | null; // option<publicKey>
+  feeBpsOverride: number | null; // option<u16>
+  observationCount: bigint;
+  totalLinesOfCode: bigint;
+  totalFilesProcessed: bigint;
+  score: bigint;
+  lastObserver: string; // publicKey
+  firstObservedAt: bigint; // i64
+  lastObservedAt: bigint; // i64
+  lastRevision: string;
+  createdAt: bigint; // i64
+  updatedAt: bigint; // i64
+}
+
 // ==========================================================================
 // STRONGLY TYPED ACCOUNTS
 // ==========================================================================
@@ -403,10 +812,30 @@ export interface ConfigAccount {
   maxModulesPerRepo: number; // u32
   isActive: boolean;
   policyRef: Uint8Array; // [u8; 32]
+  autoFreezeOnInconsistency: boolean;
+  feeRecipient: string; // publicKey
+  enforceUrlKeyed: boolean;
+  maxObservationsPerWindow: bigint; // u64
+  minLocPerObservation: bigint; // u64
+  requiredAdminSigs: number; // u8
+  quietWhenFrozen: boolean;
+  creditsPerObservation: bigint; // u64
+  stalenessThresholdSecs: bigint; // i64
+  maxNameLenOverride: number | null; // option<u16>
+  requireAttestor: boolean;
+  policyVersion: number; // u32
+  eventVerbosity: number; // u8, 0 critical, 1 normal, 2 debug
+  labelPrefixRequired: string | null; // option<string>
+  cooldowns: CooldownConfig;
+  minObserverLamports: bigint; // u64
+  enforceUniqueRepoName: boolean;
+  blockedHosts: number[][]; // vec<[u8; 32]>, sha256 of normalized hosts
+  coAdminCount: number; // u8, global Authority entries with the admin role
+  reviewGated: boolean;
   bump: number; // u8
   createdAt: bigint; // i64
   updatedAt: bigint; // i64
-  reserved: Uint8Array; // [u8; 64]
+  reserved: Uint8Array; // [u8; 63]
 }
 
 /**
@@ -420,11 +849,35 @@ export interface MetricsAccount {
   totalObservations: bigint;
   totalLinesOfCode: bigint;
   totalFilesProcessed: bigint;
+  activeRepos: bigint;
+  activeModules: bigint;
+  activeForks: bigint;
+  eventSeq: bigint;
+  pendingUnindexed: bigint;
+  observationsThisWindow: bigint;
+  windowStart: bigint;
   createdAt: bigint;
   updatedAt: bigint;
+  recomputeInProgress: boolean;
+  recomputeStartedAt: bigint;
+  recomputeBaseline: RepoTotals;
+  snapshotCount: number; // u32, next MetricsSnapshot index
+  lastForkCreatedAt: bigint; // i64, 0 before the first fork
   reserved: Uint8Array;
 }
 
+/**
+ * Repository-derived totals snapshotted by `recomputeMetrics`.
+ */
+export interface RepoTotals {
+  totalRepos: bigint;
+  activeRepos: bigint;
+  totalModules: bigint;
+  totalObservations: bigint;
+  totalLinesOfCode: bigint;
+  totalFilesProcessed: bigint;
+}
+
 /**
  * Lifecycle and write-lock account.
  */
@@ -447,15 +900,36 @@ export interface RepoAccount {
   authority: string; // publicKey
   name: string;
   url: string;
+  host: number; // u8, RepoHost (0 Other, 1 GitHub, 2 GitLab, 3 Bitbucket, 4 SelfHosted)
   tags: string;
+  tagBits: bigint; // u64, TAG_VOCABULARY bitmask
+  metadataUri: string;
   isActive: boolean;
   allowObservation: boolean;
+  allowedObservers: string[]; // publicKey[]
+  maintainers: string[]; // publicKey[], at most 4
   totalObservations: bigint;
   totalLinesOfCode: bigint;
   totalFilesProcessed: bigint;
+  score: bigint; // weighted: loc / 1000 + files + modules * 10
   totalModules: number; // u32
+  enforceUniqueModuleUri: boolean;
+  recentModuleUris: bigint[]; // [u64; 4], URI fingerprints (0 = empty)
+  recentModuleUriCursor: number; // u8
+  primaryModule: string | null; // option<publicKey>
+  feeBpsOverride: number | null; // option<u16>
+  firstObservedAt: bigint;
   lastObservedAt: bigint;
+  lastObservedSlot: bigint; // u64
   lastObserver: string; // publicKey
+  lastAttestor: string | null; // option<publicKey>
+  dailyObservations: number[]; // [u32; 7], most recent day first
+  lastIdempotencyKey: number[]; // [u8; 16]
+  lastIdempotencyAt: bigint; // i64
+  lastSourceRegionHash: number[]; // [u8; 8], all zero until reported
+  requireAccessProof: boolean;
+  lastAccessProof: number[]; // [u8; 32], all zero until reported
+  isReserved: boolean; // awaiting finalizeRepo
   createdAt: bigint;
   updatedAt: bigint;
   reserved: Uint8Array; // [u8; 64]
@@ -471,12 +945,18 @@ export interface ModuleAccount {
   authority: string; // publicKey
   name: string;
   metadataUri: string;
+  changelogUri: string; // empty if none
   category: string;
   tags: string;
+  tagBits: bigint; // u64, TAG_VOCABULARY bitmask
   isActive: boolean;
   majorVersion: number; // u16
   minorVersion: number; // u16
   patchVersion: number; // u16
+  observationCount: bigint;
+  lastObservedAt: bigint;
+  versionCount: number; // u32, ModuleVersion snapshots created
+  immutableUntil: bigint; // i64, 0 if never immutable
   createdAt: bigint;
   updatedAt: bigint;
   reserved: Uint8Array; // [u8; 64]
@@ -496,6 +976,7 @@ export interface ModuleVersionAccount {
   changelogUri: string;
   label: string;
   isStable: boolean;
+  index: number; // u32, position in 0..Module.versionCount
   createdAt: bigint;
   updatedAt: bigint;
   reserved: Uint8Array; // [u8; 32]
@@ -515,6 +996,8 @@ export interface ForkAccount {
   isRoot: boolean;
   isActive: boolean;
   depth: number; // u16
+  descendantCount: bigint; // u64, estimate
+  children: string[]; // publicKey[] (fork keys), at most MAX_CHILDREN_PER_FORK
   createdAt: bigint;
   updatedAt: bigint;
   reserved: Uint8Array; // [u8; 64]
@@ -565,6 +1048,86 @@ export interface AuthorityAccount {
   reserved: Uint8Array; // [u8; 64]
 }
 
+/**
+ * Prepaid observation credits of one observer.
+ */
+export interface ObserverCreditsAccount {
+  observer: string; // publicKey
+  balance: bigint; // u64
+  totalToppedUp: bigint; // u64
+  totalSpent: bigint; // u64
+  createdAt: bigint;
+  updatedAt: bigint;
+  schemaVersion: number; // u8
+  bump: number;
+  reserved: Uint8Array; // [u8; 32]
+}
+
+/**
+ * Recent fork labels of one owner.
+ */
+export interface OwnerForkIndexAccount {
+  owner: string; // publicKey
+  recentLabels: number[][]; // [[u8; 32]; 8], label hashes
+  labelCursor: number; // u8
+  labelCount: bigint; // u64
+  createdAt: bigint;
+  updatedAt: bigint;
+  schemaVersion: number; // u8
+  bump: number;
+  reserved: Uint8Array; // [u8; 32]
+}
+
+/**
+ * Reservation of a repository name, claimed by `registerRepo`.
+ */
+export interface RepoNameIndexAccount {
+  repo: string; // publicKey
+  nameHash: number[]; // [u8; 32], sha256 of the name
+  createdAt: bigint; // i64
+  schemaVersion: number; // u8
+  bump: number;
+  reserved: Uint8Array; // [u8; 32]
+}
+
+/**
+ * Immutable copy of the metrics counters, taken by `takeMetricsSnapshot`.
+ */
+export interface MetricsSnapshotAccount {
+  index: number; // u32
+  totalRepos: bigint;
+  totalModules: bigint;
+  totalForks: bigint;
+  totalObservations: bigint;
+  totalLinesOfCode: bigint;
+  totalFilesProcessed: bigint;
+  activeRepos: bigint;
+  activeModules: bigint;
+  activeForks: bigint;
+  takenAt: bigint; // i64
+  takenSlot: bigint; // u64
+  bump: number;
+  reserved: Uint8Array; // [u8; 16]
+}
+
+/**
+ * Return data of `compareSnapshots` (Borsh-encoded); deltas are `to - from`.
+ */
+export interface SnapshotComparison {
+  fromIndex: number; // u32
+  toIndex: number; // u32
+  timeSpanSecs: bigint; // i64
+  totalRepos: bigint; // i128
+  totalModules: bigint; // i128
+  totalForks: bigint; // i128
+  totalObservations: bigint; // i128
+  totalLinesOfCode: bigint; // i128
+  totalFilesProcessed: bigint; // i128
+  activeRepos: bigint; // i128
+  activeModules: bigint; // i128
+  activeForks: bigint; // i128
+}
+
 // ==========================================================================
 // STRONGLY TYPED EVENTS
 // ==========================================================================
@@ -573,17 +1136,50 @@ export interface ConfigUpdatedEvent {
   admin: string;
   feeBps: number;
   maxModulesPerRepo: number;
+  policyVersion: number;
+}
+
+export interface BlockedHostsChangedEvent {
+  admin: string;
+  hostHash: number[]; // [u8; 32]
+  blocked: boolean;
+  blockedCount: number;
+  eventSeq: bigint;
+}
+
+export interface AuthorityRoleAssignedEvent {
+  authority: string;
+  role: string;
+  assignedAt: bigint;
+  eventSeq: bigint;
+}
+
+export interface AuthorityRoleRevokedEvent {
+  authority: string;
+  role: string;
+  revokedAt: bigint;
+  eventSeq: bigint;
 }
 
 export interface RepoRegisteredEvent {
   repo: string;
   owner: string;
   url: string;
+  host: number; // u8, RepoHost
 }
 
 export interface RepoUpdatedEvent {
   repo: string;
   url: string;
+  host: number; // u8, RepoHost
+}
+
+export interface RepoMaintainersChangedEvent {
+  repo: string;
+  maintainer: string;
+  added: boolean;
+  maintainerCount: number;
+  eventSeq: bigint;
 }
 
 export interface RepoActivationChangedEvent {
@@ -592,6 +1188,75 @@ export interface RepoActivationChangedEvent {
   updatedAt: bigint;
 }
 
+export interface PrimaryModuleSetEvent {
+  repo: string;
+  primaryModule: string | null;
+  previousPrimaryModule: string | null;
+  updatedAt: bigint;
+  eventSeq: bigint;
+}
+
+export interface RepoFeeOverrideSetEvent {
+  repo: string;
+  feeBpsOverride: number | null; // option<u16>
+  previousFeeBpsOverride: number | null; // option<u16>
+  updatedAt: bigint;
+  eventSeq: bigint;
+}
+
+export interface RepoObservabilityChangedEvent {
+  repo: string;
+  allowObservation: boolean;
+  updatedAt: bigint;
+  eventSeq: bigint;
+}
+
+export interface RepoObservationCapReachedEvent {
+  repo: string;
+  observationCount: bigint;
+  softCap: bigint;
+  hardCap: bigint;
+  reachedAt: bigint;
+  eventSeq: bigint;
+}
+
+export interface RepoMigratedEvent {
+  repo: string;
+  fromVersion: number; // u8
+  toVersion: number; // u8
+  migratedBy: string;
+  migratedAt: bigint;
+  eventSeq: bigint;
+}
+
+export interface DeploymentMigratedEvent {
+  config: string;
+  metrics: string;
+  fromVersion: number; // u8
+  toVersion: number; // u8
+  migratedBy: string;
+  migratedAt: bigint;
+  eventSeq: bigint;
+}
+
+export interface ModuleMigratedEvent {
+  module: string;
+  fromVersion: number; // u8
+  toVersion: number; // u8
+  migratedBy: string;
+  migratedAt: bigint;
+  eventSeq: bigint;
+}
+
+export interface ForkMigratedEvent {
+  fork: string;
+  fromVersion: number; // u8
+  toVersion: number; // u8
+  migratedBy: string;
+  migratedAt: bigint;
+  eventSeq: bigint;
+}
+
 export interface ModuleRegisteredEvent {
   module: string;
   repo: string;
@@ -600,6 +1265,7 @@ export interface ModuleRegisteredEvent {
   majorVersion: number;
   minorVersion: number;
   patchVersion: number;
+  changelogUri: string;
 }
 
 export interface ModuleVersionRegisteredEvent {
@@ -627,6 +1293,16 @@ export interface ForkCreatedEvent {
   createdAt: bigint;
 }
 
+export interface ForkClonedEvent {
+  fork: string;
+  source: string;
+  owner: string;
+  label: string;
+  depth: number;
+  createdAt: bigint;
+  eventSeq: bigint;
+}
+
 export interface ForkUpdatedEvent {
   fork: string;
   owner: string;
@@ -635,15 +1311,61 @@ export interface ForkUpdatedEvent {
   updatedAt: bigint;
 }
 
+export interface ForkPrunedEvent {
+  fork: string;
+  forkKey: string;
+  refundRecipient: string;
+  lamports: bigint;
+  eventSeq: bigint;
+}
+
+export interface ForkOwnerReassignedEvent {
+  fork: string;
+  admin: string;
+  oldOwner: string;
+  newOwner: string;
+  reassignedAt: bigint;
+  eventSeq: bigint;
+}
+
 export interface ObservationRecordedEvent {
   repo: string;
   observer: string;
+  attestor: string | null;
   linesOfCode: bigint;
   filesProcessed: number;
   modulesTouched: number;
   revision: string;
   note: string;
+  sourceRegionHash: number[] | null; // [u8; 8]
   observedAt: bigint;
+  firstObservedAt: bigint;
+  isStale: boolean; // always false; the observation refreshed the repo
+  score: bigint;
+}
+
+export interface TagRenamedEvent {
+  repo: string;
+  oldTag: string;
+  newTag: string;
+  tags: string;
+  eventSeq: bigint;
+}
+
+export interface BatchPartiallyAppliedEvent {
+  instruction: string;
+  signer: string;
+  applied: number;
+  skipped: number;
+  eventSeq: bigint;
+}
+
+export interface CreditsToppedUpEvent {
+  observer: string;
+  payer: string;
+  amount: bigint;
+  balance: bigint;
+  eventSeq: bigint;
 }
 
 export interface MetricsReconciledEvent {
@@ -657,6 +1379,49 @@ export interface MetricsReconciledEvent {
   updatedAt: bigint;
 }
 
+export interface MetricsRestoredEvent {
+  admin: string;
+  snapshot: string; // MetricsSnapshot PDA
+  snapshotIndex: number; // u32
+  snapshotTakenAt: bigint;
+  restoredAt: bigint;
+  eventSeq: bigint;
+}
+
+export interface MetricsRecomputedEvent {
+  admin: string;
+  totalRepos: bigint;
+  activeRepos: bigint;
+  totalModules: bigint;
+  totalObservations: bigint;
+  totalLinesOfCode: bigint;
+  totalFilesProcessed: bigint;
+  startedAt: bigint;
+  updatedAt: bigint;
+  eventSeq: bigint;
+}
+
+export interface MetricsDriftReportedEvent {
+  admin: string;
+  totalReposDelta: bigint; // i128
+  activeReposDelta: bigint; // i128
+  totalModulesDelta: bigint; // i128
+  totalObservationsDelta: bigint; // i128
+  totalLinesOfCodeDelta: bigint; // i128
+  totalFilesProcessedDelta: bigint; // i128
+  eventSeq: bigint;
+}
+
+/**
+ * Debug-tier log event, only emitted at `eventVerbosity` 2.
+ */
+export interface Unit09LogEvent {
+  category: string;
+  message: string;
+  loggedAt: bigint;
+  eventSeq: bigint;
+}
+
 export interface GlobalMetadataUpdatedEvent {
   admin: string;
   description: string;
@@ -672,20 +1437,65 @@ export interface GlobalMetadataUpdatedEvent {
 // ==========================================================================
 
 export type Unit09ErrorName =
-  | "InvalidAdmin"
-  | "InvalidAuthority"
-  | "InvalidForkOwner"
-  | "InvalidPda"
   | "InternalError"
+  | "CounterOverflow"
+  | "ValidationFailed"
+  | "InvalidAdmin"
+  | "UnauthorizedAdminAction"
   | "InvalidFeeBps"
-  | "ValueOutOfRange"
-  | "StringEmpty"
+  | "UnsupportedConfigVersion"
+  | "InvalidAuthority"
+  | "MissingAuthority"
+  | "AuthorityRoleNotAllowed"
   | "StringTooLong"
+  | "StringEmpty"
+  | "ValueOutOfRange"
+  | "InvalidUrl"
+  | "RepoInactive"
+  | "RepoModuleLimitReached"
+  | "RepoObservationLimitReached"
+  | "RepoKeyMismatch"
+  | "ModuleInactive"
+  | "ModuleVersionNotFound"
+  | "ModuleVersionAlreadyExists"
+  | "ModuleRepoMismatch"
+  | "ModuleImmutable"
+  | "ForkInactive"
+  | "InvalidForkParent"
+  | "ForkLimitReached"
+  | "InvalidForkOwner"
   | "ObservationDataTooLarge"
+  | "ObservationNotAllowed"
+  | "MetricsInconsistent"
+  | "MetadataTooLong"
+  | "MetadataMissing"
   | "MetadataInvalid"
-  | "DeploymentInactive"
-  | "TimestampInFuture"
-  | "InvalidTimeRange";
+  | "InvalidLifecycleState"
+  | "MigrationRequired"
+  | "MigrationAlreadyApplied"
+  | "MissingRequiredAccount"
+  | "InvalidAccountOwner"
+  | "InvalidAccountDiscriminator"
+  | "ExpectedSystemAccount"
+  | "ExpectedSigner"
+  | "ExpectedWritableAccount"
+  | "CooldownActive"
+  | "RateLimitReached"
+  | "InvalidFeeRecipient"
+  | "MetricsRecomputeNotStarted"
+  | "InsufficientCredits"
+  | "ForkLabelTaken"
+  | "NoChangeRequested"
+  | "RepoNotReserved"
+  | "MaintainerAlreadyAdded"
+  | "MaintainerNotFound"
+  | "ForkNotPrunable"
+  | "InsufficientObserverBalance"
+  | "RepoNameTaken"
+  | "RepoBlocked"
+  | "AdminQuorumUnreachable"
+  | "InvalidTimeRange"
+  | "TimestampInFuture";
 
 export type Unit09ErrorCode =
   | 6000
@@ -701,7 +1511,52 @@ export type Unit09ErrorCode =
   | 6010
   | 6011
   | 6012
-  | 6013;
+  | 6013
+  | 6014
+  | 6015
+  | 6016
+  | 6017
+  | 6018
+  | 6019
+  | 6020
+  | 6021
+  | 6022
+  | 6023
+  | 6024
+  | 6025
+  | 6026
+  | 6027
+  | 6028
+  | 6029
+  | 6030
+  | 6031
+  | 6032
+  | 6033
+  | 6034
+  | 6035
+  | 6036
+  | 6037
+  | 6038
+  | 6039
+  | 6040
+  | 6041
+  | 6042
+  | 6043
+  | 6044
+  | 6045
+  | 6046
+  | 6047
+  | 6048
+  | 6049
+  | 6050
+  | 6051
+  | 6052
+  | 6053
+  | 6054
+  | 6055
+  | 6056
+  | 6057
+  | 6058;
 
 // ==========================================================================
 // OPTIONAL IDL CONSTANT
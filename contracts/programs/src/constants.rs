@@ -25,7 +25,12 @@ use anchor_lang::prelude::*;
 /// Bumping this value should be done whenever a breaking change is introduced
 /// to the on-chain data layout. Off-chain indexers and dashboards can use
 /// this to detect incompatible states.
-pub const CURRENT_SCHEMA_VERSION: u8 = 1;
+///
+/// Version 2 appends every field introduced after version 1 behind the
+/// version 1 fields of each account, grows the account `LEN`s and restores
+/// `reserved` at the end. Version 1 accounts are upgraded with the
+/// `migrate_*` instructions.
+pub const CURRENT_SCHEMA_VERSION: u8 = 2;
 
 /// Maximum allowed fee in basis points (50%).
 ///
@@ -37,6 +42,9 @@ pub const MAX_FEE_BPS: u16 = 5_000;
 /// Basis point denominator (100%).
 pub const BPS_DENOMINATOR: u16 = 10_000;
 
+/// Upper bound for `Config::required_admin_sigs`.
+pub const MAX_REQUIRED_ADMIN_SIGS: u8 = 5;
+
 // ---------------------------------------------------------------------------
 // PDA Seeds
 // ---------------------------------------------------------------------------
@@ -69,6 +77,18 @@ pub const AUTHORITY_SEED: &str = "authority";
 /// Seed for lifecycle tracking PDA, if used by the deployment.
 pub const LIFECYCLE_SEED: &str = "lifecycle";
 
+/// Seed used for per-observer prepaid credit PDAs.
+pub const OBSERVER_CREDITS_SEED: &str = "observer_credits";
+
+/// Seed used for per-owner fork label index PDAs.
+pub const OWNER_FORK_INDEX_SEED: &str = "owner_fork_index";
+
+/// Seed used for point-in-time metrics snapshot PDAs.
+pub const METRICS_SNAPSHOT_SEED: &str = "metrics_snapshot";
+
+/// Seed used for repository name reservation PDAs.
+pub const REPO_NAME_INDEX_SEED: &str = "repo_name_index";
+
 // ---------------------------------------------------------------------------
 // String Length Limits
 // ---------------------------------------------------------------------------
@@ -81,6 +101,12 @@ pub const LIFECYCLE_SEED: &str = "lifecycle";
 /// - `Fork::label`
 pub const MAX_NAME_LEN: usize = 64;
 
+/// Maximum length of `Config::label_prefix_required`.
+pub const MAX_LABEL_PREFIX_LEN: usize = 32;
+
+/// Maximum number of entries in `Config::blocked_hosts`.
+pub const MAX_BLOCKED_HOSTS: usize = 8;
+
 /// Maximum length for repository URLs.
 ///
 /// Example: GitHub / GitLab / self-hosted git URLs.
@@ -101,12 +127,48 @@ pub const MAX_DESCRIPTION_LEN: usize = 512;
 /// Example: `"solana,anchor,unit09,modules"`.
 pub const MAX_TAGS_LEN: usize = 128;
 
+/// Fixed tag vocabulary backing the `tag_bits` bitmask on `Repo` and
+/// `Module`.
+///
+/// Entry `i` maps to bit `1 << i`. Entries are normalized (lowercase, no
+/// commas). Append only: reordering or removing an entry changes the meaning
+/// of stored bitmasks. At most 64 entries.
+pub const TAG_VOCABULARY: &[&str] = &[
+    "solana",
+    "anchor",
+    "protocol",
+    "defi",
+    "nft",
+    "token",
+    "governance",
+    "oracle",
+    "bridge",
+    "indexer",
+    "router",
+    "worker",
+    "sdk",
+    "cli",
+    "library",
+    "unit09",
+];
+
 /// Optional: maximum length for repository tags or classification labels.
 pub const MAX_REPO_TAGS_LEN: usize = 128;
 
+/// Number of tags returned by `summarize_repo_tags`.
+pub const REPO_TAG_SUMMARY_LEN: usize = 5;
+
 /// Optional: maximum length for a module category field.
 pub const MAX_MODULE_CATEGORY_LEN: usize = 64;
 
+/// Maximum length for an observation revision identifier.
+///
+/// Example: `"9f2a1c7"` or `"main@2025-01-01T12:00:00Z"`.
+pub const MAX_REVISION_LEN: usize = 64;
+
+/// Maximum length for a short observation note.
+pub const MAX_OBSERVATION_NOTE_LEN: usize = 128;
+
 // ---------------------------------------------------------------------------
 // Numeric Limits and Safety Bounds
 // ---------------------------------------------------------------------------
@@ -118,14 +180,26 @@ pub const DEFAULT_MAX_MODULES_PER_REPO: u32 = 1_000;
 /// Soft limit for how many forks can be created per deployment before
 /// external tooling is expected to archive or prune inactive forks.
 ///
-/// This is not enforced in the current on-chain logic by default, but is
-/// provided as a guideline and can be used in future instructions.
+/// This is not enforced on-chain; the admin keeps the deployment below it
+/// by closing inactive forks with `prune_forks`.
 pub const SOFT_MAX_FORKS: u32 = 10_000;
 
 /// Default maximum observation count for a single repository before
 /// dashboards may decide to roll over or aggregate historical data off-chain.
+///
+/// Reaching it is only signalled (`RepoObservationCapReached`); observations
+/// keep being accepted up to `MAX_OBSERVATIONS_HARD_CAP`.
 pub const SOFT_MAX_OBSERVATIONS_PER_REPO: u64 = 1_000_000;
 
+/// Hard maximum observation count for a single repository.
+///
+/// `record_observation` rejects further observations with
+/// `RepoObservationLimitReached` once a repository holds this many.
+pub const MAX_OBSERVATIONS_HARD_CAP: u64 = 10_000_000;
+
+/// Default `Config::min_loc_per_observation`: only empty scans are rejected.
+pub const DEFAULT_MIN_LOC_PER_OBSERVATION: u64 = 1;
+
 /// Maximum lines of code that a single observation is expected to report.
 ///
 /// This is a safety bound that can be used in validation logic if desired.
@@ -134,6 +208,60 @@ pub const MAX_LOC_PER_OBSERVATION: u64 = 10_000_000;
 /// Maximum file count that a single observation is expected to report.
 pub const MAX_FILES_PER_OBSERVATION: u32 = 100_000;
 
+/// Maximum number of modules a single observation may report as touched.
+pub const MAX_MODULES_PER_OBSERVATION: u32 = DEFAULT_MAX_MODULES_PER_REPO;
+
+/// Maximum number of per-repo splits accepted by `record_observation_multi`.
+pub const MAX_OBSERVATION_SPLITS: usize = 16;
+
+/// Lines of code worth one point of `Repo::score`.
+pub const SCORE_LOC_DIVISOR: u64 = 1_000;
+
+/// Points of `Repo::score` per processed file.
+pub const SCORE_WEIGHT_PER_FILE: u64 = 1;
+
+/// Points of `Repo::score` per touched module.
+pub const SCORE_WEIGHT_PER_MODULE: u64 = 10;
+
+/// Length of the client-chosen `idempotency_key` of `record_observation`.
+pub const IDEMPOTENCY_KEY_LEN: usize = 16;
+
+/// Length of the hashed worker region reported by `record_observation`
+/// (`source_region_hash`).
+pub const SOURCE_REGION_HASH_LEN: usize = 8;
+
+/// Maximum number of entries in a repository's observer allow-list.
+pub const MAX_ALLOWED_OBSERVERS: usize = 8;
+
+/// Maximum number of maintainers per repository (see `Repo::maintainers`).
+pub const MAX_REPO_MAINTAINERS: usize = 4;
+
+/// Maximum number of ancestors walked by `check_fork_descendant`.
+pub const MAX_FORK_DEPTH: usize = 16;
+
+/// Maximum number of direct children tracked in `Fork::children`.
+pub const MAX_CHILDREN_PER_FORK: usize = 8;
+
+/// Number of daily buckets kept in `Repo::daily_observations`.
+pub const OBSERVATION_HISTOGRAM_DAYS: usize = 7;
+
+/// Number of module metadata URIs remembered in `Repo::recent_module_uris`.
+pub const RECENT_MODULE_URI_SLOTS: usize = 4;
+
+/// Number of fork labels remembered in `OwnerForkIndex::recent_labels`.
+pub const RECENT_FORK_LABEL_SLOTS: usize = 8;
+
+/// Largest decrease (in basis points of the current value) that a single
+/// `record_metrics` reconciliation may apply to any total before it is
+/// treated as suspicious by the circuit breaker (10%).
+pub const CIRCUIT_BREAKER_MAX_DECREASE_BPS: u16 = 1_000;
+
+/// Headroom below `u64::MAX` that the counters touched by an observation
+/// must keep; room for 1,000 maximal observations. Closer counters make
+/// `record_observation` stop gracefully (see
+/// `Metrics::find_counter_near_overflow`).
+pub const METRICS_OVERFLOW_MARGIN: u64 = 1_000 * MAX_LOC_PER_OBSERVATION;
+
 // ---------------------------------------------------------------------------
 // Time and Slot Related Defaults
 // ---------------------------------------------------------------------------
@@ -147,6 +275,101 @@ pub const SECONDS_PER_DAY: i64 = 86_400;
 /// Convenience constant: number of slots per day (approximate).
 pub const SLOTS_PER_DAY_APPROX: u64 = (SECONDS_PER_DAY as u64 * 1_000) / APPROX_SLOT_DURATION_MS;
 
+/// Length (seconds) of the deployment-wide observation throttle window
+/// (see `Config::max_observations_per_window`).
+pub const OBSERVATION_WINDOW_SECS: i64 = 60;
+
+/// Length (seconds) during which a retried `record_observation` carrying
+/// `Repo::last_idempotency_key` is treated as already applied.
+pub const IDEMPOTENCY_WINDOW_SECS: i64 = 3_600;
+
+/// Longest immutability window (seconds from registration) accepted for
+/// `Module::immutable_until`.
+pub const MAX_MODULE_IMMUTABILITY_SECS: i64 = 90 * SECONDS_PER_DAY;
+
+// ---------------------------------------------------------------------------
+// Event Verbosity
+// ---------------------------------------------------------------------------
+//
+// Tiers for `Config::event_verbosity`. An event or log of a given tier is
+// emitted when the configured level is at least that tier.
+//
+// - Critical: every plain `emit!` (config, lifecycle, registrations,
+//   observations, circuit breaker, ...). Always emitted.
+// - Normal: optional telemetry emitted through `emit_if_active!`
+//   (`MetricsReconciled`, `MetricsRecomputed`, `MetricsDriftReported`).
+// - Debug: `Unit09Log` events and diagnostic `msg!` logs (`debug_msg!`).
+
+/// Only critical events.
+pub const EVENT_VERBOSITY_CRITICAL: u8 = 0;
+
+/// Critical events plus optional telemetry (default for new deployments).
+pub const EVENT_VERBOSITY_NORMAL: u8 = 1;
+
+/// Everything, including debug events and logs.
+pub const EVENT_VERBOSITY_DEBUG: u8 = 2;
+
+// ---------------------------------------------------------------------------
+// Compute Budget Estimates
+// ---------------------------------------------------------------------------
+
+/// Approximate compute units consumed by each write instruction with
+/// typical inputs, reported by `get_cu_estimates` for capacity planning.
+///
+/// These are reference values, not limits: actual usage grows with string
+/// lengths, optional accounts, and `remaining_accounts` (the batch
+/// instructions are listed for a single entry). Keep this table in sync
+/// when handlers change materially.
+pub const CU_ESTIMATES: &[(&str, u32)] = &[
+    ("initialize", 45_000),
+    ("set_config", 12_000),
+    ("collect_fee", 8_000),
+    ("set_lifecycle", 10_000),
+    ("add_blocked_host", 9_000),
+    ("remove_blocked_host", 9_000),
+    ("add_co_admin", 14_000),
+    ("remove_co_admin", 10_000),
+    ("register_repo", 35_000),
+    ("reserve_repo", 20_000),
+    ("finalize_repo", 24_000),
+    ("update_repo", 18_000),
+    ("add_maintainer", 9_000),
+    ("remove_maintainer", 9_000),
+    ("set_repo_observable", 9_000),
+    ("set_primary_module", 11_000),
+    ("set_repo_fee_override", 10_000),
+    ("rename_tag", 20_000),
+    ("register_module", 55_000),
+    ("update_module", 25_000),
+    ("link_module_to_repo", 30_000),
+    ("init_owner_fork_index", 12_000),
+    ("create_fork", 30_000),
+    ("clone_fork", 32_000),
+    ("update_fork_state", 14_000),
+    ("admin_reassign_fork", 10_000),
+    ("prune_forks", 30_000),
+    ("record_observation", 22_000),
+    ("record_observation_multi", 28_000),
+    ("top_up_credits", 14_000),
+    ("record_metrics", 12_000),
+    ("recompute_metrics", 15_000),
+    ("take_metrics_snapshot", 14_000),
+    ("restore_metrics", 16_000),
+    ("compare_snapshots", 6_000),
+    ("get_repo_score", 3_000),
+    ("get_repo", 6_000),
+    ("summarize_repo_tags", 20_000),
+    ("repo_has_tag", 4_000),
+    ("get_authority_permissions", 3_000),
+    ("ack_indexed", 7_000),
+    ("set_metadata", 20_000),
+    ("refresh_bumps", 16_000),
+    ("migrate_repo", 12_000),
+    ("migrate_deployment", 18_000),
+    ("migrate_module", 12_000),
+    ("migrate_fork", 12_000),
+];
+
 // ---------------------------------------------------------------------------
 // Unit09-Specific Flavor Constants (purely semantic, not enforced on-chain)
 // ---------------------------------------------------------------------------
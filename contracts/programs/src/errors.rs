@@ -134,13 +134,15 @@ pub enum Unit09Error {
     #[msg("Repository reached maximum allowed modules.")]
     RepoModuleLimitReached,
 
-    /// Observation counts for this repository reached a configured soft or
-    /// hard limit.
+    /// Observation counts for this repository reached
+    /// `MAX_OBSERVATIONS_HARD_CAP`. The soft limit only emits
+    /// `RepoObservationCapReached` and does not fail.
     #[msg("Repository reached maximum observation count.")]
     RepoObservationLimitReached,
 
     /// The repository key used for PDA derivation does not match the provided
-    /// repository account.
+    /// repository account, or (with `Config::enforce_url_keyed`) is not the
+    /// hash of the repository URL.
     #[msg("Repository PDA does not match the provided repository key.")]
     RepoKeyMismatch,
 
@@ -283,6 +285,130 @@ pub enum Unit09Error {
     /// A soft rate limit for a specific caller or resource has been reached.
     #[msg("Rate limit reached for this caller or resource.")]
     RateLimitReached,
+
+    // -----------------------------------------------------------------------
+    // Fees
+    // -----------------------------------------------------------------------
+
+    /// The fee recipient is unset (default pubkey) or does not match the
+    /// recipient stored in `Config`.
+    #[msg("Invalid fee recipient.")]
+    InvalidFeeRecipient,
+
+    // -----------------------------------------------------------------------
+    // Metrics Recomputation
+    // -----------------------------------------------------------------------
+
+    /// A `recompute_metrics` batch without `reset` was submitted while no
+    /// recomputation was in progress.
+    #[msg("No metrics recomputation is in progress.")]
+    MetricsRecomputeNotStarted,
+
+    // -----------------------------------------------------------------------
+    // Metering
+    // -----------------------------------------------------------------------
+
+    /// The observer's prepaid `ObserverCredits` balance (or missing account)
+    /// cannot cover `Config::credits_per_observation`.
+    #[msg("Insufficient prepaid credits for this observation.")]
+    InsufficientCredits,
+
+    // -----------------------------------------------------------------------
+    // Fork Labels
+    // -----------------------------------------------------------------------
+
+    /// The owner already used this fork label recently (see
+    /// `OwnerForkIndex`).
+    #[msg("Fork label is already used by this owner.")]
+    ForkLabelTaken,
+
+    // -----------------------------------------------------------------------
+    // Transfers
+    // -----------------------------------------------------------------------
+
+    /// A transfer named the current holder as its target (see
+    /// `utils::validators::assert_distinct`), or a host was blocked twice
+    /// or unblocked while not listed.
+    #[msg("The requested change would have no effect.")]
+    NoChangeRequested,
+
+    // -----------------------------------------------------------------------
+    // Reservations
+    // -----------------------------------------------------------------------
+
+    /// `finalize_repo` was called on a repository that was not created by
+    /// `reserve_repo` or was already finalized.
+    #[msg("Repository is not reserved.")]
+    RepoNotReserved,
+
+    // -----------------------------------------------------------------------
+    // Maintainers
+    // -----------------------------------------------------------------------
+
+    /// The key is already a maintainer (or the authority) of the repository.
+    #[msg("Key is already a maintainer of this repository.")]
+    MaintainerAlreadyAdded,
+
+    /// The key is not a maintainer of the repository.
+    #[msg("Key is not a maintainer of this repository.")]
+    MaintainerNotFound,
+
+    // -----------------------------------------------------------------------
+    // Fork Pruning
+    // -----------------------------------------------------------------------
+
+    /// The fork is still active or has children, so it cannot be closed.
+    #[msg("Fork must be inactive and have no children to be pruned.")]
+    ForkNotPrunable,
+
+    // -----------------------------------------------------------------------
+    // Observer Balance
+    // -----------------------------------------------------------------------
+
+    /// The observer's wallet holds fewer lamports than
+    /// `Config::min_observer_lamports`.
+    #[msg("Observer balance is below the required minimum.")]
+    InsufficientObserverBalance,
+
+    // -----------------------------------------------------------------------
+    // Repo Name Index
+    // -----------------------------------------------------------------------
+
+    /// Another repository already claimed this name while
+    /// `Config::enforce_unique_repo_name` is set.
+    #[msg("Repository name is already taken.")]
+    RepoNameTaken,
+
+    // -----------------------------------------------------------------------
+    // Blocked Hosts
+    // -----------------------------------------------------------------------
+
+    /// The repository URL is on a host listed in `Config::blocked_hosts`.
+    #[msg("Repository host is blocked.")]
+    RepoBlocked,
+
+    // -----------------------------------------------------------------------
+    // Admin Quorum
+    // -----------------------------------------------------------------------
+
+    /// `Config::required_admin_sigs` would exceed the number of admins
+    /// (`Config::admin` plus `Config::co_admin_count`).
+    #[msg("Required admin signatures exceed the number of admins.")]
+    AdminQuorumUnreachable,
+
+    // -----------------------------------------------------------------------
+    // Time
+    // -----------------------------------------------------------------------
+
+    /// A timestamp range is out of order, e.g. a clock that is behind the
+    /// `updated_at` already stored on the account being written.
+    #[msg("Invalid time range.")]
+    InvalidTimeRange,
+
+    /// A timestamp lies further in the future than the allowed drift, e.g.
+    /// a `Module::immutable_until` beyond `MAX_MODULE_IMMUTABILITY_SECS`.
+    #[msg("Timestamp is too far in the future.")]
+    TimestampInFuture,
 }
 
 /// Optional helper functions for constructing common errors programmatically.
@@ -309,4 +435,106 @@ impl Unit09Error {
     pub fn module_inactive() -> Error {
         Unit09Error::ModuleInactive.into()
     }
+
+    /// Numeric code Anchor reports for this variant
+    /// (`ERROR_CODE_OFFSET` + declaration index).
+    pub const fn code(self) -> u32 {
+        self as u32 + anchor_lang::error::ERROR_CODE_OFFSET
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Documented Error Codes
+// ---------------------------------------------------------------------------
+
+/// Canonical variant-to-code table for off-chain tooling.
+///
+/// Codes are derived from declaration order, so existing variants must never
+/// be reordered or removed; new variants go at the end of the enum. The test
+/// below fails if any entry here drifts from what the program reports.
+pub const ERROR_CODES: &[(Unit09Error, u32)] = &[
+    (Unit09Error::InternalError, 6000),
+    (Unit09Error::CounterOverflow, 6001),
+    (Unit09Error::ValidationFailed, 6002),
+    (Unit09Error::InvalidAdmin, 6003),
+    (Unit09Error::UnauthorizedAdminAction, 6004),
+    (Unit09Error::InvalidFeeBps, 6005),
+    (Unit09Error::UnsupportedConfigVersion, 6006),
+    (Unit09Error::InvalidAuthority, 6007),
+    (Unit09Error::MissingAuthority, 6008),
+    (Unit09Error::AuthorityRoleNotAllowed, 6009),
+    (Unit09Error::StringTooLong, 6010),
+    (Unit09Error::StringEmpty, 6011),
+    (Unit09Error::ValueOutOfRange, 6012),
+    (Unit09Error::InvalidUrl, 6013),
+    (Unit09Error::RepoInactive, 6014),
+    (Unit09Error::RepoModuleLimitReached, 6015),
+    (Unit09Error::RepoObservationLimitReached, 6016),
+    (Unit09Error::RepoKeyMismatch, 6017),
+    (Unit09Error::ModuleInactive, 6018),
+    (Unit09Error::ModuleVersionNotFound, 6019),
+    (Unit09Error::ModuleVersionAlreadyExists, 6020),
+    (Unit09Error::ModuleRepoMismatch, 6021),
+    (Unit09Error::ModuleImmutable, 6022),
+    (Unit09Error::ForkInactive, 6023),
+    (Unit09Error::InvalidForkParent, 6024),
+    (Unit09Error::ForkLimitReached, 6025),
+    (Unit09Error::InvalidForkOwner, 6026),
+    (Unit09Error::ObservationDataTooLarge, 6027),
+    (Unit09Error::ObservationNotAllowed, 6028),
+    (Unit09Error::MetricsInconsistent, 6029),
+    (Unit09Error::MetadataTooLong, 6030),
+    (Unit09Error::MetadataMissing, 6031),
+    (Unit09Error::MetadataInvalid, 6032),
+    (Unit09Error::InvalidLifecycleState, 6033),
+    (Unit09Error::MigrationRequired, 6034),
+    (Unit09Error::MigrationAlreadyApplied, 6035),
+    (Unit09Error::MissingRequiredAccount, 6036),
+    (Unit09Error::InvalidAccountOwner, 6037),
+    (Unit09Error::InvalidAccountDiscriminator, 6038),
+    (Unit09Error::ExpectedSystemAccount, 6039),
+    (Unit09Error::ExpectedSigner, 6040),
+    (Unit09Error::ExpectedWritableAccount, 6041),
+    (Unit09Error::CooldownActive, 6042),
+    (Unit09Error::RateLimitReached, 6043),
+    (Unit09Error::InvalidFeeRecipient, 6044),
+    (Unit09Error::MetricsRecomputeNotStarted, 6045),
+    (Unit09Error::InsufficientCredits, 6046),
+    (Unit09Error::ForkLabelTaken, 6047),
+    (Unit09Error::NoChangeRequested, 6048),
+    (Unit09Error::RepoNotReserved, 6049),
+    (Unit09Error::MaintainerAlreadyAdded, 6050),
+    (Unit09Error::MaintainerNotFound, 6051),
+    (Unit09Error::ForkNotPrunable, 6052),
+    (Unit09Error::InsufficientObserverBalance, 6053),
+    (Unit09Error::RepoNameTaken, 6054),
+    (Unit09Error::RepoBlocked, 6055),
+    (Unit09Error::AdminQuorumUnreachable, 6056),
+    (Unit09Error::InvalidTimeRange, 6057),
+    (Unit09Error::TimestampInFuture, 6058),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn documented_codes_match_declaration_order() {
+        for (variant, code) in ERROR_CODES {
+            assert_eq!(variant.code(), *code, "{:?} code drifted", variant);
+            assert_eq!(u32::from(*variant), *code, "{:?} code drifted", variant);
+        }
+    }
+
+    #[test]
+    fn documented_codes_are_contiguous_from_offset() {
+        for (index, (variant, code)) in ERROR_CODES.iter().enumerate() {
+            assert_eq!(
+                *code,
+                anchor_lang::error::ERROR_CODE_OFFSET + index as u32,
+                "{:?} is out of order in ERROR_CODES",
+                variant
+            );
+        }
+    }
 }
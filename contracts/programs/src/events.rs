@@ -13,6 +13,8 @@
 //!     * observation runs and aggregate metrics
 //!     * global metadata evolution
 //! - Events are stable and versioned through schema evolution
+//! - Every emitted event carries a global `event_seq` taken from
+//!   `Metrics::next_event_seq`, so consumers can detect dropped events
 //!
 //! Notes:
 //! - The core events (ConfigUpdated, RepoRegistered, RepoUpdated,
@@ -29,6 +31,8 @@
 
 use anchor_lang::prelude::*;
 
+use crate::constants::{OBSERVATION_HISTOGRAM_DAYS, SOURCE_REGION_HASH_LEN};
+
 // ---------------------------------------------------------------------------
 // Core Configuration Events
 // ---------------------------------------------------------------------------
@@ -46,6 +50,10 @@ pub struct ConfigUpdated {
     pub fee_bps: u16,
     /// Maximum number of modules allowed per repository.
     pub max_modules_per_repo: u32,
+    /// Current `Config::policy_version`.
+    pub policy_version: u32,
+    /// Global event sequence number (see `Metrics::event_seq`).
+    pub event_seq: u64,
 }
 
 /// Emitted when a new configuration admin is explicitly rotated.
@@ -62,6 +70,22 @@ pub struct AdminRotated {
     pub rotated_at: i64,
 }
 
+/// Emitted when `add_blocked_host` or `remove_blocked_host` changes
+/// `Config::blocked_hosts`.
+#[event]
+pub struct BlockedHostsChanged {
+    /// Admin that made the change.
+    pub admin: Pubkey,
+    /// `Config::host_hash` of the host that was blocked or unblocked.
+    pub host_hash: [u8; 32],
+    /// Whether the host was blocked (`true`) or unblocked (`false`).
+    pub blocked: bool,
+    /// Number of blocked hosts after the change.
+    pub blocked_count: u8,
+    /// Global event sequence number (see `Metrics::event_seq`).
+    pub event_seq: u64,
+}
+
 // ---------------------------------------------------------------------------
 // Repository Events
 // ---------------------------------------------------------------------------
@@ -78,19 +102,80 @@ pub struct RepoRegistered {
     pub owner: Pubkey,
     /// URL where the repository can be accessed (GitHub, GitLab, etc.).
     pub url: String,
+    /// `RepoHost` classification of `url` as a raw `u8`.
+    pub host: u8,
+    /// Optional repository metadata URI (empty when unset).
+    pub metadata_uri: String,
+    /// Global event sequence number (see `Metrics::event_seq`).
+    pub event_seq: u64,
+}
+
+/// Bit flags describing which fields an `update_repo` call changed.
+///
+/// Used in `RepoUpdated::changed_fields`. A flag is only set when the stored
+/// value actually differs after the update, not merely when it was provided.
+pub mod repo_update_fields {
+    pub const NAME: u16 = 1 << 0;
+    pub const URL: u16 = 1 << 1;
+    pub const TAGS: u16 = 1 << 2;
+    pub const METADATA_URI: u16 = 1 << 3;
+    pub const IS_ACTIVE: u16 = 1 << 4;
+    pub const ALLOW_OBSERVATION: u16 = 1 << 5;
+    pub const ALLOWED_OBSERVERS: u16 = 1 << 6;
+    pub const ENFORCE_UNIQUE_MODULE_URI: u16 = 1 << 7;
+    pub const REQUIRE_ACCESS_PROOF: u16 = 1 << 8;
 }
 
 /// Emitted when repository metadata is updated.
 ///
-/// The base implementation uses this event when the URL is changed. You can
-/// extend the instruction handler to emit this for other metadata updates
-/// as well.
+/// Emitted on every `update_repo` call. `changed_fields` tells indexers
+/// which fields actually changed so they can apply partial updates.
 #[event]
 pub struct RepoUpdated {
     /// PDA of the repository account.
     pub repo: Pubkey,
+    /// Bitmask of `repo_update_fields` flags that changed in this update.
+    pub changed_fields: u16,
     /// New URL after the update.
     pub url: String,
+    /// `RepoHost` classification of `url` as a raw `u8`.
+    pub host: u8,
+    /// Metadata URI after the update (empty when unset).
+    pub metadata_uri: String,
+    /// Global event sequence number (see `Metrics::event_seq`).
+    pub event_seq: u64,
+}
+
+/// Emitted when `set_primary_module` sets or clears a repository's
+/// primary module.
+#[event]
+pub struct PrimaryModuleSet {
+    /// PDA of the repository account.
+    pub repo: Pubkey,
+    /// New primary module PDA (`None` when cleared).
+    pub primary_module: Option<Pubkey>,
+    /// Previous primary module PDA, if any.
+    pub previous_primary_module: Option<Pubkey>,
+    /// Unix timestamp of the change.
+    pub updated_at: i64,
+    /// Global event sequence number (see `Metrics::event_seq`).
+    pub event_seq: u64,
+}
+
+/// Emitted when `set_repo_fee_override` sets or clears a repository's fee
+/// rate override.
+#[event]
+pub struct RepoFeeOverrideSet {
+    /// PDA of the repository account.
+    pub repo: Pubkey,
+    /// New override in basis points (`None` when cleared).
+    pub fee_bps_override: Option<u16>,
+    /// Previous override, if any.
+    pub previous_fee_bps_override: Option<u16>,
+    /// Unix timestamp of the change.
+    pub updated_at: i64,
+    /// Global event sequence number (see `Metrics::event_seq`).
+    pub event_seq: u64,
 }
 
 /// Emitted when a repository is activated or deactivated.
@@ -105,6 +190,143 @@ pub struct RepoActivationChanged {
     pub is_active: bool,
     /// Unix timestamp of the change.
     pub updated_at: i64,
+    /// Global event sequence number (see `Metrics::event_seq`).
+    pub event_seq: u64,
+}
+
+/// Emitted when `add_maintainer` or `remove_maintainer` changes a
+/// repository's maintainer list.
+#[event]
+pub struct RepoMaintainersChanged {
+    /// PDA of the repository account.
+    pub repo: Pubkey,
+    /// Maintainer that was added or removed.
+    pub maintainer: Pubkey,
+    /// Whether `maintainer` was added (`true`) or removed (`false`).
+    pub added: bool,
+    /// Number of maintainers after the change.
+    pub maintainer_count: u8,
+    /// Global event sequence number (see `Metrics::event_seq`).
+    pub event_seq: u64,
+}
+
+/// Emitted when automated observation is enabled or disabled for a
+/// repository via `set_repo_observable`.
+#[event]
+pub struct RepoObservabilityChanged {
+    /// PDA of the repository account.
+    pub repo: Pubkey,
+    /// Whether automated observation is now allowed.
+    pub allow_observation: bool,
+    /// Unix timestamp of the change.
+    pub updated_at: i64,
+    /// Global event sequence number (see `Metrics::event_seq`).
+    pub event_seq: u64,
+}
+
+/// Emitted by `rename_tag` for each repository whose tags were rewritten.
+#[event]
+pub struct TagRenamed {
+    /// PDA of the repository account.
+    pub repo: Pubkey,
+    /// Normalized tag that was replaced.
+    pub old_tag: String,
+    /// Normalized tag that replaced it.
+    pub new_tag: String,
+    /// Normalized tag string after the rename.
+    pub tags: String,
+    /// Global event sequence number (see `Metrics::event_seq`).
+    pub event_seq: u64,
+}
+
+/// Emitted when `migrate_repo` upgrades a repository account layout.
+#[event]
+pub struct RepoMigrated {
+    /// PDA of the repository account.
+    pub repo: Pubkey,
+    /// `schema_version` before the migration.
+    pub from_version: u8,
+    /// `schema_version` after the migration.
+    pub to_version: u8,
+    /// Admin that ran the migration.
+    pub migrated_by: Pubkey,
+    /// Unix timestamp of the migration.
+    pub migrated_at: i64,
+    /// Global event sequence number (see `Metrics::event_seq`).
+    pub event_seq: u64,
+}
+
+/// Emitted when `migrate_deployment` upgrades the `Config` and `Metrics`
+/// account layouts.
+#[event]
+pub struct DeploymentMigrated {
+    /// PDA of the configuration account.
+    pub config: Pubkey,
+    /// PDA of the metrics account.
+    pub metrics: Pubkey,
+    /// `schema_version` before the migration.
+    pub from_version: u8,
+    /// `schema_version` after the migration.
+    pub to_version: u8,
+    /// Admin that ran the migration.
+    pub migrated_by: Pubkey,
+    /// Unix timestamp of the migration.
+    pub migrated_at: i64,
+    /// Global event sequence number (see `Metrics::event_seq`).
+    pub event_seq: u64,
+}
+
+/// Emitted when `migrate_module` upgrades a module account layout.
+#[event]
+pub struct ModuleMigrated {
+    /// PDA of the module account.
+    pub module: Pubkey,
+    /// `schema_version` before the migration.
+    pub from_version: u8,
+    /// `schema_version` after the migration.
+    pub to_version: u8,
+    /// Admin that ran the migration.
+    pub migrated_by: Pubkey,
+    /// Unix timestamp of the migration.
+    pub migrated_at: i64,
+    /// Global event sequence number (see `Metrics::event_seq`).
+    pub event_seq: u64,
+}
+
+/// Emitted when `migrate_fork` upgrades a fork account layout.
+#[event]
+pub struct ForkMigrated {
+    /// PDA of the fork account.
+    pub fork: Pubkey,
+    /// `schema_version` before the migration.
+    pub from_version: u8,
+    /// `schema_version` after the migration.
+    pub to_version: u8,
+    /// Admin that ran the migration.
+    pub migrated_by: Pubkey,
+    /// Unix timestamp of the migration.
+    pub migrated_at: i64,
+    /// Global event sequence number (see `Metrics::event_seq`).
+    pub event_seq: u64,
+}
+
+/// Emitted the first time a repository reaches
+/// `SOFT_MAX_OBSERVATIONS_PER_REPO`, so off-chain tooling can prune or roll
+/// over history before `MAX_OBSERVATIONS_HARD_CAP` starts rejecting.
+#[event]
+pub struct RepoObservationCapReached {
+    /// PDA of the repository account.
+    pub repo: Pubkey,
+    /// Observation count after the observation that reached the soft cap.
+    pub observation_count: u64,
+    /// Soft cap that was reached.
+    pub soft_cap: u64,
+    /// Hard cap at which observations will be rejected.
+    pub hard_cap: u64,
+    /// Unix timestamp of the observation.
+    pub reached_at: i64,
+    /// Global event sequence number (see `Metrics::event_seq`).
+    pub event_seq: u64,
 }
 
 // ---------------------------------------------------------------------------
@@ -122,11 +344,19 @@ pub struct ModuleRegistered {
     /// PDA of the repository this module belongs to.
     pub repo: Pubkey,
     /// Authority (owner) that controls this module.
-    pub authority: Pubkey,
-    /// Human-readable name of the module.
-    pub name: String,
-    /// Version number assigned at registration time.
-    pub version: u32,
+    pub owner: Pubkey,
+    /// Category label of the module.
+    pub category: String,
+    /// Major version assigned at registration time.
+    pub major_version: u16,
+    /// Minor version assigned at registration time.
+    pub minor_version: u16,
+    /// Patch version assigned at registration time.
+    pub patch_version: u16,
+    /// Module changelog URI (empty if none).
+    pub changelog_uri: String,
+    /// Global event sequence number (see `Metrics::event_seq`).
+    pub event_seq: u64,
 }
 
 /// Emitted when a module is updated.
@@ -139,8 +369,30 @@ pub struct ModuleRegistered {
 pub struct ModuleUpdated {
     /// PDA of the module account.
     pub module: Pubkey,
-    /// New version number after the update.
-    pub version: u32,
+    /// PDA of the repository this module belongs to.
+    pub repo: Pubkey,
+    /// Major version before the update.
+    pub previous_major_version: u16,
+    /// Minor version before the update.
+    pub previous_minor_version: u16,
+    /// Patch version before the update.
+    pub previous_patch_version: u16,
+    /// Major version after the update.
+    pub new_major_version: u16,
+    /// Minor version after the update.
+    pub new_minor_version: u16,
+    /// Patch version after the update.
+    pub new_patch_version: u16,
+    /// Whether the module was active before the update.
+    pub previous_is_active: bool,
+    /// Whether the module is active after the update.
+    pub new_is_active: bool,
+    /// Unix timestamp of the update.
+    pub updated_at: i64,
+    /// Module changelog URI after the update (empty if none).
+    pub changelog_uri: String,
+    /// Global event sequence number (see `Metrics::event_seq`).
+    pub event_seq: u64,
 }
 
 /// Emitted when a module is linked to a repository or relinked from one
@@ -151,8 +403,14 @@ pub struct ModuleLinkedToRepo {
     pub module: Pubkey,
     /// PDA of the repository the module is linked to.
     pub repo: Pubkey,
+    /// Signer that created or refreshed the link.
+    pub linked_by: Pubkey,
+    /// Whether this link is the primary association for the module.
+    pub is_primary: bool,
     /// Unix timestamp of the link operation.
-    pub linked_at: i64,
+    pub updated_at: i64,
+    /// Global event sequence number (see `Metrics::event_seq`).
+    pub event_seq: u64,
 }
 
 /// Emitted when a module is explicitly marked as active or inactive.
@@ -189,6 +447,24 @@ pub struct ModuleVersionCreated {
     pub created_at: i64,
 }
 
+/// Emitted when `register_module` or `update_module` snapshots a version
+/// into a `ModuleVersion` account.
+#[event]
+pub struct ModuleVersionRegistered {
+    /// PDA of the parent module.
+    pub module: Pubkey,
+    /// Major version of the snapshot.
+    pub major_version: u16,
+    /// Minor version of the snapshot.
+    pub minor_version: u16,
+    /// Patch version of the snapshot.
+    pub patch_version: u16,
+    /// Whether the snapshot is marked stable.
+    pub is_stable: bool,
+    /// Global event sequence number (see `Metrics::event_seq`).
+    pub event_seq: u64,
+}
+
 // ---------------------------------------------------------------------------
 // Fork Events (Unit09 Variants)
 // ---------------------------------------------------------------------------
@@ -201,12 +477,37 @@ pub struct ModuleVersionCreated {
 pub struct ForkCreated {
     /// PDA of the fork account.
     pub fork: Pubkey,
+    /// Owner authority controlling this fork.
+    pub owner: Pubkey,
     /// Parent reference (often another fork or a root identity).
     pub parent: Pubkey,
-    /// Owner authority controlling this fork.
+    /// Whether the fork is a root-level branch.
+    pub is_root: bool,
+    /// Depth of the fork in the fork tree.
+    pub depth: u16,
+    /// Unix timestamp of the creation time.
+    pub created_at: i64,
+    /// Global event sequence number (see `Metrics::event_seq`).
+    pub event_seq: u64,
+}
+
+/// Emitted when `clone_fork` creates a fork from a template fork.
+#[event]
+pub struct ForkCloned {
+    /// PDA of the new fork account.
+    pub fork: Pubkey,
+    /// PDA of the template fork it was cloned from.
+    pub source: Pubkey,
+    /// Owner authority of the new fork.
     pub owner: Pubkey,
-    /// Human-readable label describing the fork.
+    /// Label of the new fork.
     pub label: String,
+    /// Depth of the new fork (`source.depth + 1`).
+    pub depth: u16,
+    /// Unix timestamp of the creation time.
+    pub created_at: i64,
+    /// Global event sequence number (see `Metrics::event_seq`).
+    pub event_seq: u64,
 }
 
 /// Emitted when the active state of a fork is toggled or when important
@@ -219,6 +520,23 @@ pub struct ForkStateUpdated {
     pub active: bool,
 }
 
+/// Emitted when `update_fork_state` updates a fork's metadata or flags.
+#[event]
+pub struct ForkUpdated {
+    /// PDA of the fork account.
+    pub fork: Pubkey,
+    /// Owner authority controlling this fork.
+    pub owner: Pubkey,
+    /// Whether the fork was active before the update.
+    pub previous_is_active: bool,
+    /// Whether the fork is active after the update.
+    pub new_is_active: bool,
+    /// Unix timestamp of the update.
+    pub updated_at: i64,
+    /// Global event sequence number (see `Metrics::event_seq`).
+    pub event_seq: u64,
+}
+
 /// Emitted when the owner of a fork is rotated.
 ///
 /// This is useful when transferring control of a fork to a new operator.
@@ -234,6 +552,42 @@ pub struct ForkOwnerChanged {
     pub changed_at: i64,
 }
 
+/// Emitted by `prune_forks` for every fork account it closes.
+#[event]
+pub struct ForkPruned {
+    /// PDA of the closed fork account.
+    pub fork: Pubkey,
+    /// Logical fork key of the closed fork.
+    pub fork_key: Pubkey,
+    /// Account that received the fork's lamports.
+    pub refund_recipient: Pubkey,
+    /// Lamports refunded from the closed account.
+    pub lamports: u64,
+    /// Global event sequence number (see `Metrics::event_seq`).
+    pub event_seq: u64,
+}
+
+/// Emitted when the admin forcibly reassigns a fork with
+/// `admin_reassign_fork`, bypassing the owner's signature.
+///
+/// Kept separate from `ForkOwnerChanged` so recovery actions stand out in
+/// audit trails.
+#[event]
+pub struct ForkOwnerReassigned {
+    /// PDA of the fork account.
+    pub fork: Pubkey,
+    /// Config admin that performed the reassignment.
+    pub admin: Pubkey,
+    /// Owner before the reassignment.
+    pub old_owner: Pubkey,
+    /// Owner after the reassignment.
+    pub new_owner: Pubkey,
+    /// Unix timestamp of the reassignment.
+    pub reassigned_at: i64,
+    /// Global event sequence number (see `Metrics::event_seq`).
+    pub event_seq: u64,
+}
+
 // ---------------------------------------------------------------------------
 // Observation and Metrics Events
 // ---------------------------------------------------------------------------
@@ -247,12 +601,69 @@ pub struct ForkOwnerChanged {
 pub struct ObservationRecorded {
     /// PDA of the repository that was observed.
     pub repo: Pubkey,
-    /// Slot at which the observation was recorded.
-    pub slot: u64,
+    /// Signer that performed the observation.
+    pub observer: Pubkey,
+    /// Attestor that co-signed the observation, if any.
+    pub attestor: Option<Pubkey>,
     /// Number of lines of code processed in this observation run.
     pub lines_of_code: u64,
     /// Number of files processed in this observation run.
     pub files_processed: u32,
+    /// Number of modules touched in this observation run.
+    pub modules_touched: u32,
+    /// Commit or revision identifier for this observation.
+    pub revision: String,
+    /// Short note describing the observation.
+    pub note: String,
+    /// Module PDA the observation was attributed to, if any.
+    pub module: Option<Pubkey>,
+    /// Hashed worker region reported with the observation, if any.
+    pub source_region_hash: Option<[u8; SOURCE_REGION_HASH_LEN]>,
+    /// Unix timestamp of the observation.
+    pub observed_at: i64,
+    /// Unix timestamp of the repository's first observation.
+    pub first_observed_at: i64,
+    /// Repository daily histogram after this observation (see
+    /// `Repo::daily_observations`).
+    pub daily_observations: [u32; OBSERVATION_HISTOGRAM_DAYS],
+    /// Whether the repository is stale (see `Repo::is_stale`). Always false
+    /// here, since the observation just refreshed it; lets indexers reset a
+    /// stale flag derived from earlier reads.
+    pub is_stale: bool,
+    /// Repository activity score after this observation (see `Repo::score`).
+    pub score: u64,
+    /// Global event sequence number (see `Metrics::event_seq`).
+    pub event_seq: u64,
+}
+
+/// Emitted when a non-atomic batch skipped some of its items.
+#[event]
+pub struct BatchPartiallyApplied {
+    /// Snake-case name of the batch instruction.
+    pub instruction: String,
+    /// Signer that submitted the batch.
+    pub signer: Pubkey,
+    /// Number of items applied.
+    pub applied: u32,
+    /// Number of items skipped because they failed validation.
+    pub skipped: u32,
+    /// Global event sequence number (see `Metrics::event_seq`).
+    pub event_seq: u64,
+}
+
+/// Emitted when `top_up_credits` adds prepaid credits for an observer.
+#[event]
+pub struct CreditsToppedUp {
+    /// Observer whose balance was topped up.
+    pub observer: Pubkey,
+    /// Signer that paid for the credits.
+    pub payer: Pubkey,
+    /// Credits added (equal to the lamports paid).
+    pub amount: u64,
+    /// Balance after the top-up.
+    pub balance: u64,
+    /// Global event sequence number (see `Metrics::event_seq`).
+    pub event_seq: u64,
 }
 
 /// Emitted when aggregate metrics are updated in bulk.
@@ -271,6 +682,130 @@ pub struct MetricsUpdated {
     pub total_observations: u64,
 }
 
+/// Emitted when an admin reconciles the global metrics via `record_metrics`.
+///
+/// Carries the full post-reconciliation snapshot, including the active
+/// counters, so indexers can resynchronize without an extra account fetch.
+#[event]
+pub struct MetricsReconciled {
+    /// Admin that performed the reconciliation.
+    pub admin: Pubkey,
+    /// Total repositories tracked by this deployment.
+    pub total_repos: u64,
+    /// Total modules registered across all repositories.
+    pub total_modules: u64,
+    /// Total forks created.
+    pub total_forks: u64,
+    /// Total observation runs recorded.
+    pub total_observations: u64,
+    /// Aggregate lines of code processed.
+    pub total_lines_of_code: u64,
+    /// Aggregate files processed.
+    pub total_files_processed: u64,
+    /// Repositories currently marked active.
+    pub active_repos: u64,
+    /// Modules currently marked active.
+    pub active_modules: u64,
+    /// Forks currently marked active.
+    pub active_forks: u64,
+    /// Unix timestamp of the reconciliation.
+    pub updated_at: i64,
+    /// Global event sequence number (see `Metrics::event_seq`).
+    pub event_seq: u64,
+}
+
+/// Emitted when `restore_metrics` rolls the counters back to a
+/// `MetricsSnapshot`.
+#[event]
+pub struct MetricsRestored {
+    /// Admin that performed the restore.
+    pub admin: Pubkey,
+    /// PDA of the snapshot the counters were restored from.
+    pub snapshot: Pubkey,
+    /// Index of that snapshot (see `MetricsSnapshot::index`).
+    pub snapshot_index: u32,
+    /// Unix timestamp at which the snapshot was taken.
+    pub snapshot_taken_at: i64,
+    /// Unix timestamp of the restore.
+    pub restored_at: i64,
+    /// Global event sequence number (see `Metrics::event_seq`).
+    pub event_seq: u64,
+}
+
+/// Emitted when `recompute_metrics` finalizes totals re-derived from
+/// `Repo` accounts.
+#[event]
+pub struct MetricsRecomputed {
+    /// Admin that performed the recomputation.
+    pub admin: Pubkey,
+    /// Repositories summed (equals the recomputed `total_repos`).
+    pub total_repos: u64,
+    /// Repositories marked active.
+    pub active_repos: u64,
+    /// Sum of `Repo::module_count`.
+    pub total_modules: u64,
+    /// Sum of `Repo::observation_count`.
+    pub total_observations: u64,
+    /// Sum of `Repo::total_lines_of_code`.
+    pub total_lines_of_code: u64,
+    /// Sum of `Repo::total_files_processed`.
+    pub total_files_processed: u64,
+    /// Unix timestamp at which the recomputation started.
+    pub started_at: i64,
+    /// Unix timestamp at which it was finalized.
+    pub updated_at: i64,
+    /// Global event sequence number (see `Metrics::event_seq`).
+    pub event_seq: u64,
+}
+
+/// Emitted alongside `MetricsRecomputed` with the correction it applied:
+/// recomputed value minus the value before the run, per counter.
+#[event]
+pub struct MetricsDriftReported {
+    /// Admin that performed the recomputation.
+    pub admin: Pubkey,
+    /// Drift of `total_repos`.
+    pub total_repos_delta: i128,
+    /// Drift of `active_repos`.
+    pub active_repos_delta: i128,
+    /// Drift of `total_modules`.
+    pub total_modules_delta: i128,
+    /// Drift of `total_observations`.
+    pub total_observations_delta: i128,
+    /// Drift of `total_lines_of_code`.
+    pub total_lines_of_code_delta: i128,
+    /// Drift of `total_files_processed`.
+    pub total_files_processed_delta: i128,
+    /// Global event sequence number (see `Metrics::event_seq`).
+    pub event_seq: u64,
+}
+
+/// Emitted when `record_metrics` refuses a reconciliation that looks
+/// inconsistent, or `record_observation` finds a counter near overflow, and
+/// the deployment is frozen instead.
+///
+/// Only emitted when `Config::auto_freeze_on_inconsistency` is enabled.
+#[event]
+pub struct CircuitBreakerTripped {
+    /// Admin submitting the rejected reconciliation; `None` when an
+    /// observation tripped the breaker.
+    pub admin: Option<Pubkey>,
+    /// Signer whose call tripped the breaker: the admin for a
+    /// reconciliation, the observer for a counter near overflow.
+    pub triggered_by: Pubkey,
+    /// Name of the first counter that failed the consistency check.
+    pub counter: String,
+    /// Value of the counter before the rejected reconciliation.
+    pub current_value: u64,
+    /// Value the reconciliation attempted to set (`u64::MAX` for a counter
+    /// near overflow).
+    pub proposed_value: u64,
+    /// Unix timestamp when the breaker tripped.
+    pub tripped_at: i64,
+    /// Global event sequence number (see `Metrics::event_seq`).
+    pub event_seq: u64,
+}
+
 /// Emitted when a soft or hard limit for metrics has been reached
 /// and certain actions may be throttled or restricted off-chain.
 #[event]
@@ -297,12 +832,20 @@ pub struct MetricsLimitReached {
 pub struct GlobalMetadataUpdated {
     /// Admin that performed this update.
     pub admin: Pubkey,
-    /// Short description string summary (truncated for event payload).
-    pub description_preview: String,
-    /// Tags string summary (truncated for event payload).
-    pub tags_preview: String,
+    /// Deployment description.
+    pub description: String,
+    /// Canonical website URL.
+    pub website_url: String,
+    /// Documentation URL.
+    pub docs_url: String,
+    /// Dashboard URL.
+    pub dashboard_url: String,
+    /// Icon or logo URI.
+    pub icon_uri: String,
     /// Unix timestamp of the update.
     pub updated_at: i64,
+    /// Global event sequence number (see `Metrics::event_seq`).
+    pub event_seq: u64,
 }
 
 /// Emitted when the lifecycle state of the deployment changes.
@@ -322,6 +865,26 @@ pub struct LifecycleStateChanged {
     pub note_ref: String,
 }
 
+/// Emitted by `set_lifecycle` when an admin changes the lifecycle phase or
+/// the global freeze flag.
+#[event]
+pub struct LifecyclePhaseChanged {
+    /// Admin that performed the change.
+    pub admin: Pubkey,
+    /// Phase before the change (raw `LifecyclePhase` value).
+    pub previous_phase: u8,
+    /// Phase after the change (raw `LifecyclePhase` value).
+    pub new_phase: u8,
+    /// Global freeze flag after the change.
+    pub global_freeze: bool,
+    /// Reason for the freeze; empty when the deployment is not frozen.
+    pub freeze_reason: String,
+    /// Unix timestamp of the change.
+    pub changed_at: i64,
+    /// Global event sequence number (see `Metrics::event_seq`).
+    pub event_seq: u64,
+}
+
 // ---------------------------------------------------------------------------
 // Authority / Role Events (optional)
 // ---------------------------------------------------------------------------
@@ -339,6 +902,8 @@ pub struct AuthorityRoleAssigned {
     pub role: String,
     /// Unix timestamp of the assignment.
     pub assigned_at: i64,
+    /// Global event sequence number (see `Metrics::event_seq`).
+    pub event_seq: u64,
 }
 
 /// Emitted when a role is revoked from an authority account.
@@ -350,6 +915,8 @@ pub struct AuthorityRoleRevoked {
     pub role: String,
     /// Unix timestamp of the revocation.
     pub revoked_at: i64,
+    /// Global event sequence number (see `Metrics::event_seq`).
+    pub event_seq: u64,
 }
 
 // ---------------------------------------------------------------------------
@@ -360,7 +927,7 @@ pub struct AuthorityRoleRevoked {
 /// application-level signals without requiring a dedicated event type.
 ///
 /// This is optional and can be removed if you prefer having only
-/// strongly-typed events.
+/// strongly-typed events. Only emitted at `EVENT_VERBOSITY_DEBUG`.
 #[event]
 pub struct Unit09Log {
     /// Free-form category string for the log message.
@@ -369,4 +936,328 @@ pub struct Unit09Log {
     pub message: String,
     /// Unix timestamp when the log was emitted.
     pub logged_at: i64,
+    /// Global event sequence number (see `Metrics::event_seq`).
+    pub event_seq: u64,
+}
+
+// ---------------------------------------------------------------------------
+// Optional Telemetry
+// ---------------------------------------------------------------------------
+
+/// Emit an optional telemetry event unless the deployment is quiet.
+///
+/// `$event` is only evaluated when the event is emitted, so an `event_seq`
+/// taken inside it (`metrics.next_event_seq()?`) is not consumed for a
+/// suppressed event and the sequence stays gap-free.
+///
+/// ```ignore
+/// emit_if_active!(config, lifecycle, MetricsReconciled {
+///     ...,
+///     event_seq: metrics.next_event_seq()?,
+/// });
+/// ```
+///
+/// Events are in the `EVENT_VERBOSITY_NORMAL` tier unless a tier is given
+/// before the event (`emit_if_active!(config, lifecycle, EVENT_VERBOSITY_DEBUG,
+/// Unit09Log { .. })`); see `Config::emits_at`.
+///
+/// Only use this for events indexers can live without. State transitions
+/// that consumers must see (config, lifecycle, circuit breaker) keep using
+/// `emit!`.
+macro_rules! emit_if_active {
+    ($config:expr, $lifecycle:expr, $event:expr) => {
+        emit_if_active!(
+            $config,
+            $lifecycle,
+            $crate::constants::EVENT_VERBOSITY_NORMAL,
+            $event
+        )
+    };
+    ($config:expr, $lifecycle:expr, $tier:expr, $event:expr) => {
+        if $config.emits_at($tier) && $lifecycle.emits_telemetry($config.quiet_when_frozen) {
+            emit!($event);
+        }
+    };
+}
+
+pub(crate) use emit_if_active;
+
+/// `msg!` a diagnostic log only at `EVENT_VERBOSITY_DEBUG`.
+///
+/// ```ignore
+/// debug_msg!(config, "accumulated {} repos", count);
+/// ```
+macro_rules! debug_msg {
+    ($config:expr, $($arg:tt)+) => {
+        if $config.emits_at($crate::constants::EVENT_VERBOSITY_DEBUG) {
+            msg!($($arg)+);
+        }
+    };
+}
+
+pub(crate) use debug_msg;
+
+// ---------------------------------------------------------------------------
+// Typed Event Enum
+// ---------------------------------------------------------------------------
+
+/// Declare `Unit09Event` with one variant per event struct, plus
+/// `event_kind` and `From` conversions, from a single list so the three
+/// cannot drift apart.
+macro_rules! unit09_events {
+    ($($name:ident),* $(,)?) => {
+        /// Any event emitted by the Unit09 program.
+        ///
+        /// Off-chain consumers can decode into the matching struct and wrap
+        /// it here to get a single type to match on.
+        pub enum Unit09Event {
+            $($name($name),)*
+        }
+
+        impl Unit09Event {
+            /// Stable name of the wrapped event, for logging and telemetry.
+            ///
+            /// Always equal to the event struct's name.
+            pub fn event_kind(&self) -> &'static str {
+                match self {
+                    $(Unit09Event::$name(_) => stringify!($name),)*
+                }
+            }
+        }
+
+        $(
+            impl From<$name> for Unit09Event {
+                fn from(event: $name) -> Self {
+                    Unit09Event::$name(event)
+                }
+            }
+        )*
+    };
+}
+
+unit09_events! {
+    ConfigUpdated,
+    AdminRotated,
+    BlockedHostsChanged,
+    RepoRegistered,
+    RepoUpdated,
+    PrimaryModuleSet,
+    RepoFeeOverrideSet,
+    RepoActivationChanged,
+    RepoMaintainersChanged,
+    RepoObservabilityChanged,
+    TagRenamed,
+    RepoMigrated,
+    DeploymentMigrated,
+    ModuleMigrated,
+    ForkMigrated,
+    RepoObservationCapReached,
+    ModuleRegistered,
+    ModuleUpdated,
+    ModuleLinkedToRepo,
+    ModuleActivationChanged,
+    ModuleVersionCreated,
+    ModuleVersionRegistered,
+    ForkCreated,
+    ForkCloned,
+    ForkStateUpdated,
+    ForkUpdated,
+    ForkOwnerChanged,
+    ForkOwnerReassigned,
+    ForkPruned,
+    ObservationRecorded,
+    BatchPartiallyApplied,
+    CreditsToppedUp,
+    MetricsUpdated,
+    MetricsReconciled,
+    MetricsRestored,
+    MetricsRecomputed,
+    MetricsDriftReported,
+    CircuitBreakerTripped,
+    MetricsLimitReached,
+    GlobalMetadataUpdated,
+    LifecycleStateChanged,
+    LifecyclePhaseChanged,
+    AuthorityRoleAssigned,
+    AuthorityRoleRevoked,
+    Unit09Log,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Decode an event from zeroed bytes: empty strings, `None`, zero
+    /// numbers. Good enough to construct every variant.
+    fn zeroed<T: AnchorDeserialize>() -> T {
+        T::deserialize(&mut &[0u8; 512][..]).unwrap()
+    }
+
+    #[test]
+    fn each_variant_reports_its_kind() {
+        assert_eq!(
+            Unit09Event::from(zeroed::<ConfigUpdated>()).event_kind(),
+            "ConfigUpdated"
+        );
+        assert_eq!(
+            Unit09Event::from(zeroed::<AdminRotated>()).event_kind(),
+            "AdminRotated"
+        );
+        assert_eq!(
+            Unit09Event::from(zeroed::<BlockedHostsChanged>()).event_kind(),
+            "BlockedHostsChanged"
+        );
+        assert_eq!(
+            Unit09Event::from(zeroed::<RepoRegistered>()).event_kind(),
+            "RepoRegistered"
+        );
+        assert_eq!(
+            Unit09Event::from(zeroed::<RepoUpdated>()).event_kind(),
+            "RepoUpdated"
+        );
+        assert_eq!(
+            Unit09Event::from(zeroed::<PrimaryModuleSet>()).event_kind(),
+            "PrimaryModuleSet"
+        );
+        assert_eq!(
+            Unit09Event::from(zeroed::<RepoActivationChanged>()).event_kind(),
+            "RepoActivationChanged"
+        );
+        assert_eq!(
+            Unit09Event::from(zeroed::<RepoMaintainersChanged>()).event_kind(),
+            "RepoMaintainersChanged"
+        );
+        assert_eq!(
+            Unit09Event::from(zeroed::<RepoObservabilityChanged>()).event_kind(),
+            "RepoObservabilityChanged"
+        );
+        assert_eq!(
+            Unit09Event::from(zeroed::<TagRenamed>()).event_kind(),
+            "TagRenamed"
+        );
+        assert_eq!(
+            Unit09Event::from(zeroed::<RepoMigrated>()).event_kind(),
+            "RepoMigrated"
+        );
+        assert_eq!(
+            Unit09Event::from(zeroed::<RepoObservationCapReached>()).event_kind(),
+            "RepoObservationCapReached"
+        );
+        assert_eq!(
+            Unit09Event::from(zeroed::<ModuleRegistered>()).event_kind(),
+            "ModuleRegistered"
+        );
+        assert_eq!(
+            Unit09Event::from(zeroed::<ModuleUpdated>()).event_kind(),
+            "ModuleUpdated"
+        );
+        assert_eq!(
+            Unit09Event::from(zeroed::<ModuleLinkedToRepo>()).event_kind(),
+            "ModuleLinkedToRepo"
+        );
+        assert_eq!(
+            Unit09Event::from(zeroed::<ModuleActivationChanged>()).event_kind(),
+            "ModuleActivationChanged"
+        );
+        assert_eq!(
+            Unit09Event::from(zeroed::<ModuleVersionCreated>()).event_kind(),
+            "ModuleVersionCreated"
+        );
+        assert_eq!(
+            Unit09Event::from(zeroed::<ModuleVersionRegistered>()).event_kind(),
+            "ModuleVersionRegistered"
+        );
+        assert_eq!(
+            Unit09Event::from(zeroed::<ForkCreated>()).event_kind(),
+            "ForkCreated"
+        );
+        assert_eq!(
+            Unit09Event::from(zeroed::<ForkCloned>()).event_kind(),
+            "ForkCloned"
+        );
+        assert_eq!(
+            Unit09Event::from(zeroed::<ForkStateUpdated>()).event_kind(),
+            "ForkStateUpdated"
+        );
+        assert_eq!(
+            Unit09Event::from(zeroed::<ForkUpdated>()).event_kind(),
+            "ForkUpdated"
+        );
+        assert_eq!(
+            Unit09Event::from(zeroed::<ForkOwnerChanged>()).event_kind(),
+            "ForkOwnerChanged"
+        );
+        assert_eq!(
+            Unit09Event::from(zeroed::<ForkOwnerReassigned>()).event_kind(),
+            "ForkOwnerReassigned"
+        );
+        assert_eq!(
+            Unit09Event::from(zeroed::<ForkPruned>()).event_kind(),
+            "ForkPruned"
+        );
+        assert_eq!(
+            Unit09Event::from(zeroed::<ObservationRecorded>()).event_kind(),
+            "ObservationRecorded"
+        );
+        assert_eq!(
+            Unit09Event::from(zeroed::<BatchPartiallyApplied>()).event_kind(),
+            "BatchPartiallyApplied"
+        );
+        assert_eq!(
+            Unit09Event::from(zeroed::<CreditsToppedUp>()).event_kind(),
+            "CreditsToppedUp"
+        );
+        assert_eq!(
+            Unit09Event::from(zeroed::<MetricsUpdated>()).event_kind(),
+            "MetricsUpdated"
+        );
+        assert_eq!(
+            Unit09Event::from(zeroed::<MetricsReconciled>()).event_kind(),
+            "MetricsReconciled"
+        );
+        assert_eq!(
+            Unit09Event::from(zeroed::<MetricsRestored>()).event_kind(),
+            "MetricsRestored"
+        );
+        assert_eq!(
+            Unit09Event::from(zeroed::<MetricsRecomputed>()).event_kind(),
+            "MetricsRecomputed"
+        );
+        assert_eq!(
+            Unit09Event::from(zeroed::<MetricsDriftReported>()).event_kind(),
+            "MetricsDriftReported"
+        );
+        assert_eq!(
+            Unit09Event::from(zeroed::<CircuitBreakerTripped>()).event_kind(),
+            "CircuitBreakerTripped"
+        );
+        assert_eq!(
+            Unit09Event::from(zeroed::<MetricsLimitReached>()).event_kind(),
+            "MetricsLimitReached"
+        );
+        assert_eq!(
+            Unit09Event::from(zeroed::<GlobalMetadataUpdated>()).event_kind(),
+            "GlobalMetadataUpdated"
+        );
+        assert_eq!(
+            Unit09Event::from(zeroed::<LifecycleStateChanged>()).event_kind(),
+            "LifecycleStateChanged"
+        );
+        assert_eq!(
+            Unit09Event::from(zeroed::<LifecyclePhaseChanged>()).event_kind(),
+            "LifecyclePhaseChanged"
+        );
+        assert_eq!(
+            Unit09Event::from(zeroed::<AuthorityRoleAssigned>()).event_kind(),
+            "AuthorityRoleAssigned"
+        );
+        assert_eq!(
+            Unit09Event::from(zeroed::<AuthorityRoleRevoked>()).event_kind(),
+            "AuthorityRoleRevoked"
+        );
+        assert_eq!(
+            Unit09Event::from(zeroed::<Unit09Log>()).event_kind(),
+            "Unit09Log"
+        );
+    }
 }
@@ -0,0 +1,102 @@
+//! ===========================================================================
+//! Unit09 – Ack Indexed Instruction
+//! Path: contracts/unit09-program/programs/unit09_program/src/instructions/ack_indexed.rs
+//!
+//! This instruction lets the admin (typically an off-chain indexer service
+//! operating with the admin key) acknowledge that a batch of observations
+//! has been processed.
+//!
+//! Every `record_observation` increments `Metrics::pending_unindexed`; this
+//! instruction decrements it by `count`, saturating at zero. Dashboards can
+//! alert when the pending counter keeps growing, which signals that
+//! observers are outpacing the indexer.
+//!
+//! Notes:
+//! - Only the current `Config::admin` is allowed to call this instruction.
+//! - Acknowledging is a wind-down operation and stays available during the
+//!   `Sunset` phase so the queue can be drained.
+//!
+//! ===========================================================================
+
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::state::{Config, Lifecycle, Metrics};
+use crate::utils::time::current_checked;
+
+/// Arguments for the `ack_indexed` instruction.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct AckIndexedArgs {
+    /// Number of observations the indexer has processed since the last ack.
+    pub count: u64,
+}
+
+/// Accounts required for the `ack_indexed` instruction.
+#[derive(Accounts)]
+pub struct AckIndexed<'info> {
+    /// Admin signer; must match `config.admin`.
+    pub admin: Signer<'info>,
+
+    /// Global configuration account.
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Lifecycle account controlling global write permissions.
+    #[account(
+        seeds = [LIFECYCLE_SEED.as_bytes()],
+        bump = lifecycle.bump,
+    )]
+    pub lifecycle: Account<'info, Lifecycle>,
+
+    /// Global metrics account holding `pending_unindexed`.
+    #[account(
+        mut,
+        seeds = [METRICS_SEED.as_bytes()],
+        bump = metrics.bump,
+    )]
+    pub metrics: Account<'info, Metrics>,
+
+    /// Clock sysvar used for timestamps.
+    pub clock: Sysvar<'info, Clock>,
+}
+
+// ---------------------------------------------------------------------------
+// Handler
+// ---------------------------------------------------------------------------
+
+/// Entry point for the `ack_indexed` instruction.
+///
+/// Steps:
+/// 1. Ensure lifecycle allows wind-down writes.
+/// 2. Verify `admin` matches `config.admin`.
+/// 3. Decrement `metrics.pending_unindexed` by `count` (saturating).
+pub fn handle(ctx: Context<AckIndexed>, args: AckIndexedArgs) -> Result<()> {
+    let AckIndexed {
+        admin,
+        config,
+        lifecycle,
+        metrics,
+        clock,
+    } = ctx.accounts;
+
+    let clock_ref: &Clock = clock;
+    current_checked(clock_ref)?;
+
+    // -----------------------------------------------------------------------
+    // Lifecycle and admin guards
+    // -----------------------------------------------------------------------
+
+    lifecycle.assert_wind_down_allowed()?;
+    config.assert_admin(admin)?;
+
+    // -----------------------------------------------------------------------
+    // Acknowledge processed observations
+    // -----------------------------------------------------------------------
+
+    metrics.ack_indexed(args.count, clock_ref);
+
+    Ok(())
+}
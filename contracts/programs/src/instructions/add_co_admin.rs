@@ -0,0 +1,152 @@
+//! ===========================================================================
+//! Unit09 – Add Co-Admin Instruction
+//! Path: contracts/unit09-program/programs/unit09_program/src/instructions/add_co_admin.rs
+//!
+//! This instruction registers a co-admin: a key that can co-sign admin
+//! actions gated by `Config::required_admin_sigs` (see
+//! `Config::assert_admin_quorum`).
+//!
+//! On success this instruction:
+//! - creates the global `Authority` PDA of `co_admin` with the admin role
+//! - increments `Config::co_admin_count`
+//! - emits `AuthorityRoleAssigned`
+//!
+//! Notes:
+//! - `Config::admin` must sign, together with enough existing co-admins to
+//!   reach `required_admin_sigs` (same `remaining_accounts` layout as
+//!   `set_config`).
+//! - `Config::admin` itself cannot be added (`ValidationFailed`); it always
+//!   counts as one signature.
+//! - A key that already has an `Authority` entry cannot be added again, since
+//!   the PDA already exists.
+//!
+//! ===========================================================================
+
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::Unit09Error;
+use crate::events::AuthorityRoleAssigned;
+use crate::state::{role_flags, Authority, Config, Metrics};
+use crate::utils::time::current_checked;
+
+/// Arguments for the `add_co_admin` instruction.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct AddCoAdminArgs {
+    /// Key to register as a co-admin.
+    pub co_admin: Pubkey,
+}
+
+/// Accounts required for the `add_co_admin` instruction.
+#[derive(Accounts)]
+#[instruction(args: AddCoAdminArgs)]
+pub struct AddCoAdmin<'info> {
+    /// Admin signer; must match `config.admin`. Pays for the `Authority`.
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// Global configuration account holding `co_admin_count`.
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Global metrics account, used for event sequencing.
+    #[account(
+        mut,
+        seeds = [METRICS_SEED.as_bytes()],
+        bump = metrics.bump,
+    )]
+    pub metrics: Account<'info, Metrics>,
+
+    /// `Authority` entry created for the co-admin.
+    ///
+    /// PDA:
+    ///   seeds = [AUTHORITY_SEED.as_bytes(), args.co_admin.as_ref()]
+    ///   bump  = authority.bump
+    #[account(
+        init,
+        payer = admin,
+        space = Authority::LEN,
+        seeds = [
+            AUTHORITY_SEED.as_bytes(),
+            args.co_admin.as_ref(),
+        ],
+        bump,
+    )]
+    pub authority: Account<'info, Authority>,
+
+    /// System program.
+    pub system_program: Program<'info, System>,
+
+    /// Clock sysvar used for timestamps.
+    pub clock: Sysvar<'info, Clock>,
+}
+
+// ---------------------------------------------------------------------------
+// Handler
+// ---------------------------------------------------------------------------
+
+/// Entry point for the `add_co_admin` instruction.
+///
+/// Steps:
+/// 1. Verify the admin quorum and that `co_admin` is not `Config::admin`.
+/// 2. Initialize the `Authority` entry as a global admin.
+/// 3. Increment `Config::co_admin_count`.
+/// 4. Emit `AuthorityRoleAssigned`.
+pub fn handle(ctx: Context<AddCoAdmin>, args: AddCoAdminArgs) -> Result<()> {
+    let co_signers = ctx.remaining_accounts;
+    let authority_bump = *ctx.bumps.get("authority").ok_or(Unit09Error::InternalError)?;
+    let AddCoAdmin {
+        admin,
+        config,
+        metrics,
+        authority,
+        system_program: _,
+        clock,
+    } = ctx.accounts;
+
+    let clock_ref: &Clock = clock;
+    current_checked(clock_ref)?;
+
+    // -----------------------------------------------------------------------
+    // Admin authority check
+    // -----------------------------------------------------------------------
+
+    config.assert_admin_quorum(admin, co_signers)?;
+
+    if args.co_admin == config.admin {
+        return err!(Unit09Error::ValidationFailed);
+    }
+
+    // -----------------------------------------------------------------------
+    // Register co-admin
+    // -----------------------------------------------------------------------
+
+    authority.init(
+        args.co_admin,
+        role_flags::ADMIN,
+        true,
+        Pubkey::default(),
+        authority_bump,
+        clock_ref,
+    )?;
+    config.add_co_admin()?;
+    config.updated_at = clock_ref.unix_timestamp;
+
+    // -----------------------------------------------------------------------
+    // Emit event
+    // -----------------------------------------------------------------------
+
+    let event_seq = metrics.next_event_seq()?;
+    emit!(AuthorityRoleAssigned {
+        authority: args.co_admin,
+        role: "admin".to_string(),
+        assigned_at: clock_ref.unix_timestamp,
+        event_seq,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,145 @@
+//! ===========================================================================
+//! Unit09 – Admin Reassign Fork Instruction
+//! Path: contracts/unit09-program/programs/unit09_program/src/instructions/admin_reassign_fork.rs
+//!
+//! Recovery instruction that lets the config admin hand a fork over to a new
+//! owner without the current owner's signature, e.g. after the owner's key
+//! was lost and the fork can no longer be managed.
+//!
+//! Guards:
+//! - lifecycle must allow writes (`Lifecycle::assert_writes_allowed`)
+//! - only `Config::admin` may call this instruction
+//! - the new owner must not be the default key (`InvalidForkOwner`) or the
+//!   current owner (`NoChangeRequested`)
+//!
+//! Every reassignment emits `ForkOwnerReassigned` (rather than the regular
+//! `ForkOwnerChanged`) so admin overrides are easy to audit.
+//!
+//! ===========================================================================
+
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::Unit09Error;
+use crate::events::ForkOwnerReassigned;
+use crate::state::{Config, Fork, Lifecycle, Metrics};
+use crate::utils::time::current_checked;
+use crate::utils::validators::Validate;
+
+/// Arguments for the `admin_reassign_fork` instruction.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct AdminReassignForkArgs {
+    /// Key that becomes the fork owner.
+    pub new_owner: Pubkey,
+}
+
+impl Validate for AdminReassignForkArgs {
+    /// Non-default new owner (`InvalidForkOwner`).
+    fn validate(&self) -> Result<()> {
+        require_keys_neq!(
+            self.new_owner,
+            Pubkey::default(),
+            Unit09Error::InvalidForkOwner
+        );
+        Ok(())
+    }
+}
+
+/// Accounts required for the `admin_reassign_fork` instruction.
+#[derive(Accounts)]
+pub struct AdminReassignFork<'info> {
+    /// Admin signer; must match `config.admin`.
+    pub admin: Signer<'info>,
+
+    /// Global configuration account.
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Lifecycle account controlling global write permissions.
+    #[account(
+        seeds = [LIFECYCLE_SEED.as_bytes()],
+        bump = lifecycle.bump,
+    )]
+    pub lifecycle: Account<'info, Lifecycle>,
+
+    /// Global metrics account, used for event sequencing.
+    #[account(
+        mut,
+        seeds = [METRICS_SEED.as_bytes()],
+        bump = metrics.bump,
+    )]
+    pub metrics: Account<'info, Metrics>,
+
+    /// Fork whose owner is reassigned.
+    #[account(
+        mut,
+        seeds = [
+            FORK_SEED.as_bytes(),
+            fork.fork_key.as_ref(),
+        ],
+        bump = fork.bump,
+    )]
+    pub fork: Account<'info, Fork>,
+
+    /// Clock sysvar used for timestamps.
+    pub clock: Sysvar<'info, Clock>,
+}
+
+// ---------------------------------------------------------------------------
+// Handler
+// ---------------------------------------------------------------------------
+
+/// Entry point for the `admin_reassign_fork` instruction.
+///
+/// Steps:
+/// 1. Ensure lifecycle allows writes and the caller is admin.
+/// 2. Validate arguments (`args.validate()`).
+/// 3. Move ownership with `Fork::reassign_owner`.
+/// 4. Emit `ForkOwnerReassigned`.
+pub fn handle(ctx: Context<AdminReassignFork>, args: AdminReassignForkArgs) -> Result<()> {
+    let AdminReassignFork {
+        admin,
+        config,
+        lifecycle,
+        metrics,
+        fork,
+        clock,
+    } = ctx.accounts;
+
+    let clock_ref: &Clock = clock;
+    current_checked(clock_ref)?;
+
+    // -----------------------------------------------------------------------
+    // Lifecycle and admin guards
+    // -----------------------------------------------------------------------
+
+    lifecycle.assert_writes_allowed()?;
+    config.assert_admin(admin)?;
+    args.validate()?;
+
+    // -----------------------------------------------------------------------
+    // Reassign owner
+    // -----------------------------------------------------------------------
+
+    let old_owner = fork.owner;
+    fork.reassign_owner(args.new_owner, clock_ref)?;
+
+    // -----------------------------------------------------------------------
+    // Emit ForkOwnerReassigned event
+    // -----------------------------------------------------------------------
+
+    let event_seq = metrics.next_event_seq()?;
+    emit!(ForkOwnerReassigned {
+        fork: fork.key(),
+        admin: admin.key(),
+        old_owner,
+        new_owner: fork.owner,
+        reassigned_at: clock_ref.unix_timestamp,
+        event_seq,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,123 @@
+//! ===========================================================================
+//! Unit09 – Check Fork Descendant Instruction
+//! Path: contracts/unit09-program/programs/unit09_program/src/instructions/check_fork_descendant.rs
+//!
+//! Read-only lineage query: is `ancestor` somewhere in the parent chain of a
+//! candidate fork?
+//!
+//! The caller passes the candidate fork and then the parent chain as
+//! remaining accounts, nearest parent first:
+//!
+//!     remaining_accounts = [parent, grandparent, ...]
+//!
+//! The walk stops as soon as `ancestor` is found, a root fork is reached, or
+//! `MAX_FORK_DEPTH` parents have been visited. The result is written with
+//! `set_return_data` as a Borsh-encoded `bool`.
+//!
+//! Notes:
+//! - `ancestor` and `Fork::parent` are fork keys, not PDAs.
+//! - Each chain entry must be a `Fork` owned by this program whose
+//!   `fork_key` equals the previous fork's `parent`; a missing, foreign, or
+//!   mismatched entry fails with `InvalidForkParent`.
+//! - Extra trailing accounts are ignored.
+//!
+//! ===========================================================================
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::set_return_data;
+
+use crate::constants::*;
+use crate::errors::Unit09Error;
+use crate::state::Fork;
+
+/// Arguments for the `check_fork_descendant` instruction.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct CheckForkDescendantArgs {
+    /// Fork key to look for in the candidate's parent chain.
+    pub ancestor: Pubkey,
+}
+
+/// Accounts required for the `check_fork_descendant` instruction.
+///
+/// The parent chain is passed via `remaining_accounts`.
+#[derive(Accounts)]
+pub struct CheckForkDescendant<'info> {
+    /// Fork whose lineage is checked.
+    ///
+    /// PDA:
+    ///   seeds = [
+    ///       FORK_SEED.as_bytes(),
+    ///       candidate.fork_key.as_ref(),
+    ///   ]
+    ///   bump = candidate.bump
+    #[account(
+        seeds = [
+            FORK_SEED.as_bytes(),
+            candidate.fork_key.as_ref(),
+        ],
+        bump = candidate.bump,
+    )]
+    pub candidate: Account<'info, Fork>,
+}
+
+// ---------------------------------------------------------------------------
+// Handler
+// ---------------------------------------------------------------------------
+
+/// Entry point for the `check_fork_descendant` instruction.
+///
+/// Steps:
+/// 1. Start from the candidate fork.
+/// 2. While the current fork has a parent, compare it with `ancestor`; on a
+///    miss, load the next chain entry and verify it is that parent.
+/// 3. Stop after `MAX_FORK_DEPTH` parents.
+/// 4. Return the result via `set_return_data`.
+pub fn handle(ctx: Context<CheckForkDescendant>, args: CheckForkDescendantArgs) -> Result<()> {
+    let candidate = &ctx.accounts.candidate;
+    let mut chain = ctx.remaining_accounts.iter();
+
+    // -----------------------------------------------------------------------
+    // Walk the parent chain
+    // -----------------------------------------------------------------------
+
+    let mut parent_key = candidate.parent;
+    let mut has_parent = candidate.has_parent();
+    let mut is_descendant = false;
+
+    for _ in 0..MAX_FORK_DEPTH {
+        if !has_parent {
+            break;
+        }
+        if parent_key == args.ancestor {
+            is_descendant = true;
+            break;
+        }
+
+        let account_info = chain.next().ok_or(Unit09Error::InvalidForkParent)?;
+        let parent = load_parent(account_info)?;
+        if parent.fork_key != parent_key {
+            return err!(Unit09Error::InvalidForkParent);
+        }
+
+        parent_key = parent.parent;
+        has_parent = parent.has_parent();
+    }
+
+    // -----------------------------------------------------------------------
+    // Return result
+    // -----------------------------------------------------------------------
+
+    set_return_data(&is_descendant.try_to_vec()?);
+
+    Ok(())
+}
+
+/// Deserialize a chain entry as a `Fork` owned by this program.
+fn load_parent(account_info: &AccountInfo) -> Result<Fork> {
+    if account_info.owner != &crate::ID {
+        return err!(Unit09Error::InvalidForkParent);
+    }
+
+    let data = account_info.try_borrow_data()?;
+    Fork::try_deserialize(&mut &data[..]).map_err(|_| error!(Unit09Error::InvalidForkParent))
+}
@@ -0,0 +1,228 @@
+//! ===========================================================================
+//! Unit09 – Clone Fork Instruction
+//! Path: contracts/unit09-program/programs/unit09_program/src/instructions/clone_fork.rs
+//!
+//! This instruction creates a new `Fork` from an existing template fork.
+//!
+//! Narrative deployments often spin up many similar forks. Instead of
+//! resending every field, the caller names a source fork and supplies only
+//! the new `fork_key`, owner, and a label suffix.
+//!
+//! The clone:
+//! - uses `"<source.label>-<label_suffix>"` as its label
+//! - copies `metadata_uri` and `tags` from the source
+//! - sets `parent = source.fork_key` and `depth = source.depth + 1`
+//!   (`Fork::parent` holds fork keys, like in `create_fork`)
+//! - is never a root fork
+//!
+//! On success this instruction:
+//! - initializes the new `Fork` PDA
+//! - increments `descendant_count` on the source fork and appends the new
+//!   fork key to its `children` (`ForkLimitReached` once it is full)
+//! - increments `Metrics::total_forks` and `Metrics::active_forks`
+//! - emits `ForkCloned`
+//!
+//! Guards:
+//! - lifecycle must allow writes (`Lifecycle::assert_writes_allowed`)
+//! - global config must be active (`Config::assert_active`)
+//! - the source fork must be active (`ForkInactive`)
+//! - the new depth must not exceed `MAX_FORK_DEPTH` (`ValueOutOfRange`)
+//! - the cloned label must fit `Config::max_name_len` (`StringTooLong`)
+//!   and start with `Config::label_prefix_required`, when set
+//!   (`ValidationFailed`); a clone of a source created before the prefix
+//!   was required therefore fails
+//! - the fork creation cooldown must have elapsed since the last fork
+//!   (`CooldownActive`, see `CooldownConfig`)
+//!
+//! ===========================================================================
+
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::Unit09Error;
+use crate::events::ForkCloned;
+use crate::state::{Config, Fork, Lifecycle, Metrics};
+use crate::utils::time::current_checked;
+
+/// Arguments for the `clone_fork` instruction.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct CloneForkArgs {
+    /// Arbitrary key used with `FORK_SEED` to derive the new `Fork` PDA.
+    pub fork_key: Pubkey,
+
+    /// Suffix appended to the source label, separated by `-`.
+    ///
+    /// Example: source "unit09-lab" + suffix "eu" => "unit09-lab-eu"
+    pub label_suffix: String,
+}
+
+/// Accounts required for the `clone_fork` instruction.
+#[derive(Accounts)]
+#[instruction(args: CloneForkArgs)]
+pub struct CloneFork<'info> {
+    /// Payer for the newly created `Fork` account.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Owner of the new fork.
+    pub owner: Signer<'info>,
+
+    /// Global configuration account.
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Lifecycle account controlling global phases and freezes.
+    #[account(
+        seeds = [LIFECYCLE_SEED.as_bytes()],
+        bump = lifecycle.bump,
+    )]
+    pub lifecycle: Account<'info, Lifecycle>,
+
+    /// Global metrics account that aggregates deployment-wide counters.
+    #[account(
+        mut,
+        seeds = [METRICS_SEED.as_bytes()],
+        bump = metrics.bump,
+    )]
+    pub metrics: Account<'info, Metrics>,
+
+    /// Template fork to clone from.
+    ///
+    /// PDA:
+    ///   seeds = [FORK_SEED.as_bytes(), source.fork_key.as_ref()]
+    ///   bump  = source.bump
+    #[account(
+        mut,
+        seeds = [
+            FORK_SEED.as_bytes(),
+            source.fork_key.as_ref(),
+        ],
+        bump = source.bump,
+    )]
+    pub source: Account<'info, Fork>,
+
+    /// Fork account to be created.
+    ///
+    /// PDA:
+    ///   seeds = [FORK_SEED.as_bytes(), args.fork_key.as_ref()]
+    ///   bump  = fork.bump
+    #[account(
+        init,
+        payer = payer,
+        space = Fork::LEN,
+        seeds = [
+            FORK_SEED.as_bytes(),
+            args.fork_key.as_ref(),
+        ],
+        bump,
+    )]
+    pub fork: Account<'info, Fork>,
+
+    /// System program.
+    pub system_program: Program<'info, System>,
+
+    /// Clock sysvar for timestamps.
+    pub clock: Sysvar<'info, Clock>,
+}
+
+// ---------------------------------------------------------------------------
+// Handler
+// ---------------------------------------------------------------------------
+
+/// Entry point for the `clone_fork` instruction.
+///
+/// Steps:
+/// 1. Ensure lifecycle allows writes, config is active, and the source fork
+///    is active.
+/// 2. Derive the new label and depth from the source.
+/// 3. Initialize the new `Fork` via `Fork::init`.
+/// 4. Count the clone as a descendant and child of the source.
+/// 5. Increment global fork counters in `Metrics`.
+/// 6. Emit `ForkCloned`.
+pub fn handle(ctx: Context<CloneFork>, args: CloneForkArgs) -> Result<()> {
+    let fork_bump = *ctx.bumps.get("fork").ok_or(Unit09Error::InternalError)?;
+
+    let CloneFork {
+        payer: _,
+        owner,
+        config,
+        lifecycle,
+        metrics,
+        source,
+        fork,
+        system_program: _,
+        clock,
+    } = ctx.accounts;
+
+    let clock_ref: &Clock = clock;
+    current_checked(clock_ref)?;
+
+    // -----------------------------------------------------------------------
+    // Lifecycle, configuration, and source guards
+    // -----------------------------------------------------------------------
+
+    lifecycle.assert_writes_allowed()?;
+    config.assert_active()?;
+    config
+        .cooldowns
+        .assert_fork_creation_elapsed(metrics.last_fork_created_at, clock_ref)?;
+    source.assert_active()?;
+
+    // -----------------------------------------------------------------------
+    // Derive label and depth from the source
+    // -----------------------------------------------------------------------
+
+    let label = source.cloned_label(&args.label_suffix)?;
+    config.assert_name_len(&label)?;
+    config.assert_label_prefix(&label)?;
+    let depth = source.child_depth()?;
+
+    // -----------------------------------------------------------------------
+    // Initialize Fork account
+    // -----------------------------------------------------------------------
+
+    fork.init(
+        args.fork_key,
+        source.fork_key,
+        owner.key(),
+        label,
+        source.metadata_uri.clone(),
+        source.tags.clone(),
+        false,
+        depth,
+        fork_bump,
+        clock_ref,
+    )?;
+
+    source.increment_descendants()?;
+    source.add_child(args.fork_key)?;
+
+    // -----------------------------------------------------------------------
+    // Update global metrics
+    // -----------------------------------------------------------------------
+
+    metrics.increment_forks()?;
+    metrics.increment_active_forks()?;
+    metrics.last_fork_created_at = clock_ref.unix_timestamp;
+    metrics.updated_at = clock_ref.unix_timestamp;
+
+    // -----------------------------------------------------------------------
+    // Emit ForkCloned event
+    // -----------------------------------------------------------------------
+
+    let event_seq = metrics.next_event_seq()?;
+    emit!(ForkCloned {
+        fork: fork.key(),
+        source: source.key(),
+        owner: fork.owner,
+        label: fork.label.clone(),
+        depth: fork.depth,
+        created_at: fork.created_at,
+        event_seq,
+    });
+
+    Ok(())
+}
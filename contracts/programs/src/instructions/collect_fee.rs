@@ -0,0 +1,130 @@
+//! ===========================================================================
+//! Unit09 – Collect Fee Instruction
+//! Path: contracts/unit09-program/programs/unit09_program/src/instructions/collect_fee.rs
+//!
+//! This instruction charges the protocol fee on a lamport amount and moves
+//! it from a payer to `Config::fee_recipient`.
+//!
+//! It is the extension point for fee-bearing flows:
+//! - callers (or a wrapping program via CPI) pass the gross `amount` they
+//!   are paying for
+//! - the fee is scoped to the `repo` it is paid for, and computed with
+//!   `utils::fees::apply_fee_bps` using `Repo::fee_bps_override` when set,
+//!   otherwise `Config::fee_bps`, rounded down
+//! - the fee is transferred with a System Program CPI
+//!
+//! Notes:
+//! - Only the fee is moved; the remainder of `amount` is untouched.
+//! - A zero fee (zero amount or zero `fee_bps`) is a no-op.
+//! - The provided `fee_recipient` must match `Config::fee_recipient`, and
+//!   the configured recipient must not be the default pubkey.
+//! - `payer` must be the repository authority (`InvalidAuthority`), so a
+//!   payer cannot pick another repository's override.
+//!
+//! ===========================================================================
+
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+
+use crate::constants::*;
+use crate::errors::Unit09Error;
+use crate::state::{Config, Repo};
+use crate::utils::fees::apply_fee_bps;
+
+/// Arguments for the `collect_fee` instruction.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct CollectFeeArgs {
+    /// Gross amount (in lamports) that the fee is charged on.
+    pub amount: u64,
+}
+
+/// Accounts required for the `collect_fee` instruction.
+#[derive(Accounts)]
+pub struct CollectFee<'info> {
+    /// Account paying the fee; must match `repo.authority`.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Global configuration account.
+    ///
+    /// PDA:
+    ///   seeds = [CONFIG_SEED.as_bytes()]
+    ///   bump = config.bump
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Fee recipient; must match `config.fee_recipient`.
+    #[account(
+        mut,
+        address = config.fee_recipient @ Unit09Error::InvalidFeeRecipient,
+    )]
+    pub fee_recipient: SystemAccount<'info>,
+
+    /// Repository the fee is charged for, owned by `payer`.
+    ///
+    /// PDA:
+    ///   seeds = [REPO_SEED.as_bytes(), repo.repo_key.as_ref()]
+    ///   bump  = repo.bump
+    #[account(
+        seeds = [
+            REPO_SEED.as_bytes(),
+            repo.repo_key.as_ref(),
+        ],
+        bump = repo.bump,
+        constraint = repo.authority == payer.key() @ Unit09Error::InvalidAuthority,
+    )]
+    pub repo: Account<'info, Repo>,
+
+    /// System program used for the lamport transfer.
+    pub system_program: Program<'info, System>,
+}
+
+// ---------------------------------------------------------------------------
+// Handler
+// ---------------------------------------------------------------------------
+
+/// Entry point for the `collect_fee` instruction.
+///
+/// Steps:
+/// 1. Ensure the deployment is active and a fee recipient is configured.
+/// 2. Compute the fee on `args.amount` using the repository's override, or
+///    `Config::fee_bps` when none is set.
+/// 3. Transfer the fee from `payer` to `fee_recipient`.
+pub fn handle(ctx: Context<CollectFee>, args: CollectFeeArgs) -> Result<()> {
+    let config = &ctx.accounts.config;
+
+    // -----------------------------------------------------------------------
+    // Config checks
+    // -----------------------------------------------------------------------
+
+    config.assert_active()?;
+    config.assert_fee_recipient_set()?;
+
+    // -----------------------------------------------------------------------
+    // Compute fee
+    // -----------------------------------------------------------------------
+
+    let fee_bps = ctx.accounts.repo.effective_fee_bps(config.fee_bps);
+    let fee = apply_fee_bps(args.amount, fee_bps)?;
+    if fee == 0 {
+        return Ok(());
+    }
+
+    // -----------------------------------------------------------------------
+    // Transfer fee to recipient
+    // -----------------------------------------------------------------------
+
+    let cpi_ctx = CpiContext::new(
+        ctx.accounts.system_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.payer.to_account_info(),
+            to: ctx.accounts.fee_recipient.to_account_info(),
+        },
+    );
+    system_program::transfer(cpi_ctx, fee)?;
+
+    Ok(())
+}
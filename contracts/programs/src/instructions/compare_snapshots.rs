@@ -0,0 +1,68 @@
+//! ===========================================================================
+//! Unit09 – Compare Snapshots Instruction
+//! Path: contracts/unit09-program/programs/unit09_program/src/instructions/compare_snapshots.rs
+//!
+//! Read-only query returning the change between two `MetricsSnapshot`
+//! accounts, so dashboards need not fetch and diff both client-side.
+//!
+//! The result is a Borsh-encoded `SnapshotComparison` written with
+//! `set_return_data`: both indices, the time span in seconds, and one
+//! signed `i128` delta per counter (`to` minus `from`).
+//!
+//! Accounts:
+//! - `from` and `to` are loaded without seed constraints; each must be a
+//!   `MetricsSnapshot` owned by this program (`InvalidAccountOwner` /
+//!   `InvalidAccountDiscriminator`)
+//!
+//! ===========================================================================
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::set_return_data;
+
+use crate::errors::Unit09Error;
+use crate::state::MetricsSnapshot;
+
+/// Accounts required for the `compare_snapshots` instruction.
+#[derive(Accounts)]
+pub struct CompareSnapshots<'info> {
+    /// Baseline snapshot.
+    ///
+    /// CHECK: owner and discriminator are validated by `load_snapshot`.
+    pub from: UncheckedAccount<'info>,
+
+    /// Snapshot compared against `from`.
+    ///
+    /// CHECK: owner and discriminator are validated by `load_snapshot`.
+    pub to: UncheckedAccount<'info>,
+}
+
+// ---------------------------------------------------------------------------
+// Handler
+// ---------------------------------------------------------------------------
+
+/// Entry point for the `compare_snapshots` instruction.
+///
+/// Steps:
+/// 1. Load both accounts as `MetricsSnapshot`.
+/// 2. Compute `from.compare(&to)`.
+/// 3. Return it via `set_return_data`.
+pub fn handle(ctx: Context<CompareSnapshots>) -> Result<()> {
+    let from = load_snapshot(&ctx.accounts.from)?;
+    let to = load_snapshot(&ctx.accounts.to)?;
+
+    let comparison = from.compare(&to);
+
+    set_return_data(&comparison.try_to_vec()?);
+
+    Ok(())
+}
+
+/// Deserialize an account expected to hold a `MetricsSnapshot`.
+fn load_snapshot(account_info: &AccountInfo) -> Result<MetricsSnapshot> {
+    if account_info.owner != &crate::ID {
+        return err!(Unit09Error::InvalidAccountOwner);
+    }
+    let data = account_info.try_borrow_data()?;
+    MetricsSnapshot::try_deserialize(&mut &data[..])
+        .map_err(|_| error!(Unit09Error::InvalidAccountDiscriminator))
+}
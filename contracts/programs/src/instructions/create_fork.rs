@@ -14,18 +14,49 @@
 //! - initializes a `Fork` PDA
 //! - sets parent, depth, label, metadata URI, tags
 //! - marks the fork as active
+//! - increments `Metrics::total_forks` and `Metrics::active_forks`
+//! - increments `Fork::descendant_count` on every ancestor passed in
+//!   `remaining_accounts`
+//! - appends the new fork key to the parent's `Fork::children` when the
+//!   parent is passed (`ForkLimitReached` once it is full)
+//! - records the label in the owner's `OwnerForkIndex` when it is passed
 //! - emits `ForkCreated` event
 //!
 //! Guards:
 //! - lifecycle must allow writes (`Lifecycle::assert_writes_allowed`)
 //! - global config must be active (`Config::assert_active`)
 //! - any signer can become a fork owner by calling this instruction
+//! - when `owner_fork_index` is passed, the label must not be one of the
+//!   owner's recent labels (`ForkLabelTaken`); without it no label
+//!   uniqueness is enforced
+//! - when `Config::label_prefix_required` is set, the label must start with
+//!   it (`ValidationFailed`)
+//! - the fork creation cooldown must have elapsed since the last fork
+//!   (`CooldownActive`, see `CooldownConfig`)
 //!
 //! PDA layout:
 //! - Fork:
-//!     seeds = [FORK_SEED.as_bytes(), args.fork_key.as_ref()]
+//!     seeds = [FORK_SEED.as_bytes(), fork_key.as_ref()]
 //!     bump  = fork.bump
 //!
+//!   where `fork_key` is `args.fork_key`, or with `args.derive_key` the
+//!   deterministic `Fork::derived_key(owner, label)`
+//!   (see `CreateForkArgs::resolved_fork_key`)
+//!
+//! Derived keys:
+//! - the same owner and label always give the same fork key, so an owner
+//!   can hold only one derived fork per label; creating it again fails
+//!   because the PDA already exists
+//! - clients can recompute the key (and the PDA) from owner and label alone
+//!
+//! Ancestors:
+//!     remaining_accounts = [parent, grandparent, ...] (writable)
+//!
+//! The list may be cut short (or empty); ancestors that are not passed are
+//! simply not counted, which makes `descendant_count` an estimate. Every
+//! entry that is passed must be the previous fork's parent, otherwise the
+//! instruction fails with `InvalidForkParent`.
+//!
 //! ===========================================================================
 
 use anchor_lang::prelude::*;
@@ -33,11 +64,14 @@ use anchor_lang::prelude::*;
 use crate::constants::*;
 use crate::errors::Unit09Error;
 use crate::events::ForkCreated;
-use crate::state::{Config, Fork, Lifecycle};
+use crate::state::{Config, Fork, Lifecycle, Metrics, OwnerForkIndex};
+use crate::utils::time::current_checked;
+use crate::utils::validators::{assert_max_len, assert_non_empty_str, Validate};
 
 /// Arguments for the `create_fork` instruction.
 ///
-/// The caller chooses a `fork_key` used to derive the PDA.
+/// The caller chooses a `fork_key` used to derive the PDA, or lets the
+/// program derive it from owner and label (`derive_key`).
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
 pub struct CreateForkArgs {
     /// Arbitrary key used with `FORK_SEED` to derive the `Fork` PDA.
@@ -46,6 +80,8 @@ pub struct CreateForkArgs {
     /// - a random key generated locally
     /// - hash of a configuration manifest
     /// - hash of a narrative / storyline ID
+    ///
+    /// Ignored when `derive_key` is set.
     pub fork_key: Pubkey,
 
     /// Optional parent fork or root identity.
@@ -82,10 +118,43 @@ pub struct CreateForkArgs {
     /// - depth defaults to 0 when `is_root == true`
     /// - depth defaults to 1 when `is_root == false`
     pub depth: Option<u16>,
+
+    /// Whether to derive the fork key on-chain as
+    /// `Fork::derived_key(owner, label)` instead of using `fork_key`.
+    ///
+    /// Derived keys are reproducible: the same owner and label always map
+    /// to the same fork, so a second fork with both unchanged cannot be
+    /// created.
+    pub derive_key: bool,
+}
+
+impl CreateForkArgs {
+    /// Fork key used for the PDA and stored as `Fork::fork_key`.
+    pub fn resolved_fork_key(&self, owner: &Pubkey) -> Pubkey {
+        if self.derive_key {
+            Fork::derived_key(owner, &self.label)
+        } else {
+            self.fork_key
+        }
+    }
+}
+
+impl Validate for CreateForkArgs {
+    /// Non-empty label and metadata URI, and metadata URI and tags within
+    /// the `Fork` limits. The label length limit and required prefix come
+    /// from `Config` and are checked by the handler.
+    fn validate(&self) -> Result<()> {
+        assert_non_empty_str(&self.label)?;
+        assert_non_empty_str(&self.metadata_uri)?;
+        assert_max_len(&self.metadata_uri, Fork::MAX_METADATA_URI_LEN)?;
+        // Tags are optional and may be empty.
+        assert_max_len(&self.tags, Fork::MAX_TAGS_LEN)
+    }
 }
 
 /// Accounts required for the `create_fork` instruction.
 #[derive(Accounts)]
+#[instruction(args: CreateForkArgs)]
 pub struct CreateFork<'info> {
     /// Payer for the newly created `Fork` account.
     #[account(mut)]
@@ -114,12 +183,20 @@ pub struct CreateFork<'info> {
     )]
     pub lifecycle: Account<'info, Lifecycle>,
 
+    /// Global metrics account that aggregates deployment-wide counters.
+    #[account(
+        mut,
+        seeds = [METRICS_SEED.as_bytes()],
+        bump = metrics.bump,
+    )]
+    pub metrics: Account<'info, Metrics>,
+
     /// Fork account to be created.
     ///
     /// PDA:
     ///   seeds = [
     ///       FORK_SEED.as_bytes(),
-    ///       args.fork_key.as_ref(),
+    ///       args.resolved_fork_key(&owner.key()).as_ref(),
     ///   ]
     ///   bump  = fork.bump
     #[account(
@@ -128,12 +205,27 @@ pub struct CreateFork<'info> {
         space = Fork::LEN,
         seeds = [
             FORK_SEED.as_bytes(),
-            args.fork_key.as_ref(),
+            args.resolved_fork_key(&owner.key()).as_ref(),
         ],
         bump,
     )]
     pub fork: Account<'info, Fork>,
 
+    /// Optional label index of `owner`, created by `init_owner_fork_index`.
+    ///
+    /// PDA:
+    ///   seeds = [OWNER_FORK_INDEX_SEED.as_bytes(), owner.key().as_ref()]
+    ///   bump  = owner_fork_index.bump
+    #[account(
+        mut,
+        seeds = [
+            OWNER_FORK_INDEX_SEED.as_bytes(),
+            owner.key().as_ref(),
+        ],
+        bump = owner_fork_index.bump,
+    )]
+    pub owner_fork_index: Option<Account<'info, OwnerForkIndex>>,
+
     /// System program.
     pub system_program: Program<'info, System>,
 
@@ -152,54 +244,54 @@ pub struct CreateFork<'info> {
 ///
 /// Steps:
 /// 1. Ensure lifecycle allows writes and config is active.
-/// 2. Validate label, metadata URI, and tags length.
+/// 2. Validate label (length and required prefix), metadata URI, and tags
+///    length, and reject a label already in the owner's `OwnerForkIndex`
+///    when it is passed.
 /// 3. Derive parent and depth values.
 /// 4. Initialize `Fork` account via `Fork::init`.
-/// 5. Emit `ForkCreated` event.
+/// 5. Increment `descendant_count` on the ancestors passed in
+///    `remaining_accounts`.
+/// 6. Increment global fork counters in `Metrics`.
+/// 7. Emit `ForkCreated` event.
 pub fn handle(ctx: Context<CreateFork>, args: CreateForkArgs) -> Result<()> {
     let CreateFork {
         payer: _,
         owner,
         mut config,
         mut lifecycle,
+        mut metrics,
         mut fork,
+        owner_fork_index,
         system_program: _,
         rent: _,
         clock,
     } = ctx.accounts;
 
     let clock_ref: &Clock = clock;
+    current_checked(clock_ref)?;
 
     // -----------------------------------------------------------------------
     // Lifecycle and configuration guards
     // -----------------------------------------------------------------------
 
+    // New activity; hard-blocked during `Sunset` (`InvalidLifecycleState`).
     lifecycle.assert_writes_allowed()?;
     config.assert_active()?;
+    config
+        .cooldowns
+        .assert_fork_creation_elapsed(metrics.last_fork_created_at, clock_ref)?;
 
     // -----------------------------------------------------------------------
     // Early validation
     // -----------------------------------------------------------------------
 
-    // Label
-    if args.label.is_empty() {
-        return err!(Unit09Error::StringEmpty);
-    }
-    if args.label.len() > Fork::MAX_LABEL_LEN {
-        return err!(Unit09Error::StringTooLong);
-    }
+    args.validate()?;
+    config.assert_name_len(&args.label)?;
+    config.assert_label_prefix(&args.label)?;
 
-    // Metadata URI
-    if args.metadata_uri.is_empty() {
-        return err!(Unit09Error::StringEmpty);
-    }
-    if args.metadata_uri.len() > Fork::MAX_METADATA_URI_LEN {
-        return err!(Unit09Error::StringTooLong);
-    }
-
-    // Tags (optional, can be empty)
-    if args.tags.len() > Fork::MAX_TAGS_LEN {
-        return err!(Unit09Error::StringTooLong);
+    // Label uniqueness per owner, only when the index is provided.
+    if let Some(index) = owner_fork_index.as_deref_mut() {
+        index.remember_label(&args.label, clock_ref)?;
     }
 
     // -----------------------------------------------------------------------
@@ -213,6 +305,7 @@ pub fn handle(ctx: Context<CreateFork>, args: CreateForkArgs) -> Result<()> {
     // -----------------------------------------------------------------------
 
     let parent = args.parent.unwrap_or_else(Pubkey::default);
+    let fork_key = args.resolved_fork_key(&owner.key());
 
     // If depth is not provided:
     // - for root forks: depth = 0
@@ -233,7 +326,7 @@ pub fn handle(ctx: Context<CreateFork>, args: CreateForkArgs) -> Result<()> {
     // -----------------------------------------------------------------------
 
     fork.init(
-        args.fork_key,
+        fork_key,
         parent,
         owner.key(),
         args.label,
@@ -245,10 +338,38 @@ pub fn handle(ctx: Context<CreateFork>, args: CreateForkArgs) -> Result<()> {
         clock_ref,
     )?;
 
+    // -----------------------------------------------------------------------
+    // Update ancestor subtree counts
+    // -----------------------------------------------------------------------
+
+    let mut parent_key = fork.parent;
+    let mut has_parent = fork.has_parent();
+    // Only the first entry (the direct parent) lists the new fork as a child.
+    let mut new_child = Some(fork.fork_key);
+
+    for account_info in ctx.remaining_accounts.iter().take(MAX_FORK_DEPTH) {
+        if !has_parent {
+            break;
+        }
+        let ancestor = increment_ancestor(account_info, &parent_key, new_child.take())?;
+        parent_key = ancestor.parent;
+        has_parent = ancestor.has_parent();
+    }
+
+    // -----------------------------------------------------------------------
+    // Update global metrics
+    // -----------------------------------------------------------------------
+
+    metrics.increment_forks()?;
+    metrics.increment_active_forks()?;
+    metrics.last_fork_created_at = clock_ref.unix_timestamp;
+    metrics.updated_at = clock_ref.unix_timestamp;
+
     // -----------------------------------------------------------------------
     // Emit ForkCreated event
     // -----------------------------------------------------------------------
 
+    let event_seq = metrics.next_event_seq()?;
     emit!(ForkCreated {
         fork: fork.key(),
         owner: fork.owner,
@@ -256,7 +377,42 @@ pub fn handle(ctx: Context<CreateFork>, args: CreateForkArgs) -> Result<()> {
         is_root: fork.is_root,
         depth: fork.depth,
         created_at: fork.created_at,
+        event_seq,
     });
 
     Ok(())
 }
+
+/// Increment `descendant_count` on an ancestor passed in `remaining_accounts`
+/// and, for the direct parent, append `child_key` to its children.
+///
+/// The entry must be a writable `Fork` owned by this program whose
+/// `fork_key` equals `expected_key`. Returns the updated ancestor so the
+/// caller can continue the walk.
+fn increment_ancestor(
+    account_info: &AccountInfo,
+    expected_key: &Pubkey,
+    child_key: Option<Pubkey>,
+) -> Result<Fork> {
+    if account_info.owner != &crate::ID {
+        return err!(Unit09Error::InvalidForkParent);
+    }
+    if !account_info.is_writable {
+        return err!(Unit09Error::ExpectedWritableAccount);
+    }
+
+    let mut data = account_info.try_borrow_mut_data()?;
+    let mut ancestor =
+        Fork::try_deserialize(&mut &data[..]).map_err(|_| error!(Unit09Error::InvalidForkParent))?;
+    if ancestor.fork_key != *expected_key {
+        return err!(Unit09Error::InvalidForkParent);
+    }
+
+    ancestor.increment_descendants()?;
+    if let Some(child_key) = child_key {
+        ancestor.add_child(child_key)?;
+    }
+    ancestor.try_serialize(&mut &mut data[..])?;
+
+    Ok(ancestor)
+}
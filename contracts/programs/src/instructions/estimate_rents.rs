@@ -0,0 +1,65 @@
+//! ===========================================================================
+//! Unit09 – Estimate Rents Instruction
+//! Path: contracts/unit09-program/programs/unit09_program/src/instructions/estimate_rents.rs
+//!
+//! Read-only instruction that reports the rent-exempt minimum balance for
+//! each core Unit09 account type.
+//!
+//! The result is written with `set_return_data` as a Borsh-encoded
+//! `RentEstimates` struct. Clients typically call this through a simulated
+//! transaction and decode the return data, so no state is mutated and no
+//! signer other than the fee payer is required.
+//!
+//! ===========================================================================
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::set_return_data;
+
+use crate::utils::rent::{rent_exempt_minimum, AccountKind};
+
+/// Rent-exempt minimums (in lamports) returned by `estimate_rents`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct RentEstimates {
+    /// Minimum balance for the `Config` account.
+    pub config: u64,
+
+    /// Minimum balance for the `Metrics` account.
+    pub metrics: u64,
+
+    /// Minimum balance for a `Repo` account.
+    pub repo: u64,
+
+    /// Minimum balance for a `Module` account.
+    pub module: u64,
+
+    /// Minimum balance for a `Fork` account.
+    pub fork: u64,
+}
+
+/// Accounts required for the `estimate_rents` instruction.
+#[derive(Accounts)]
+pub struct EstimateRents<'info> {
+    /// Rent sysvar used to compute minimum balances.
+    pub rent: Sysvar<'info, Rent>,
+}
+
+// ---------------------------------------------------------------------------
+// Handler
+// ---------------------------------------------------------------------------
+
+/// Entry point for the `estimate_rents` instruction.
+pub fn handle(ctx: Context<EstimateRents>) -> Result<()> {
+    let rent: &Rent = &ctx.accounts.rent;
+
+    let estimates = RentEstimates {
+        config: rent_exempt_minimum(rent, AccountKind::Config),
+        metrics: rent_exempt_minimum(rent, AccountKind::Metrics),
+        repo: rent_exempt_minimum(rent, AccountKind::Repo),
+        module: rent_exempt_minimum(rent, AccountKind::Module),
+        fork: rent_exempt_minimum(rent, AccountKind::Fork),
+    };
+
+    set_return_data(&estimates.try_to_vec()?);
+
+    Ok(())
+}
@@ -0,0 +1,219 @@
+//! ===========================================================================
+//! Unit09 – Finalize Repo Instruction
+//! Path: contracts/unit09-program/programs/unit09_program/src/instructions/finalize_repo.rs
+//!
+//! This instruction completes a repository pre-allocated by `reserve_repo`.
+//!
+//! On success this instruction:
+//! - fills in the name, URL, tags and metadata URI (validated like
+//!   `register_repo`) and activates the repository unless
+//!   `Config::review_gated` is set
+//! - increments the global `Metrics::total_repos` counter, and
+//!   `active_repos` for an active repository
+//! - emits a `RepoRegistered` event, exactly as a direct registration would
+//!
+//! Guards:
+//! - lifecycle must allow writes (`Lifecycle::assert_writes_allowed`)
+//! - global config must be active (`Config::assert_active`)
+//! - signer must be the authority that reserved the repository
+//! - the repository must still be reserved (`RepoNotReserved` otherwise)
+//! - when `Config::enforce_url_keyed` is set, the reserved `repo_key` must
+//!   equal `Repo::key_for_url(url)`
+//! - when `Config::enforce_unique_repo_name` is set, the `RepoNameIndex`
+//!   for `args.name` is required and claimed (`RepoNameTaken` if another
+//!   repository holds the name), as in `register_repo`
+//! - the URL's host must not be in `Config::blocked_hosts` (`RepoBlocked`)
+//!
+//! PDA layout:
+//! - Repo:
+//!     seeds = [REPO_SEED.as_bytes(), repo.repo_key.as_ref()]
+//!     bump  = repo.bump
+//!
+//! ===========================================================================
+
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::Unit09Error;
+use crate::events::RepoRegistered;
+use crate::state::{Config, Lifecycle, Metrics, Repo, RepoNameIndex};
+use crate::utils::time::current_checked;
+
+/// Arguments for the `finalize_repo` instruction.
+///
+/// The fields match `RegisterRepoArgs`, minus the `repo_key` fixed by
+/// `reserve_repo`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct FinalizeRepoArgs {
+    /// Human-readable repository name.
+    pub name: String,
+
+    /// URL to the codebase.
+    pub url: String,
+
+    /// Optional tags for search and discovery.
+    pub tags: String,
+
+    /// Optional metadata URI for a richer repository manifest.
+    pub metadata_uri: String,
+
+    /// Whether automated observation is allowed for this repository.
+    pub allow_observation: bool,
+}
+
+/// Accounts required for the `finalize_repo` instruction.
+#[derive(Accounts)]
+#[instruction(args: FinalizeRepoArgs)]
+pub struct FinalizeRepo<'info> {
+    /// Authority that reserved the repository; pays for the name index.
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// Global configuration account.
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Lifecycle account controlling global write permissions.
+    #[account(
+        seeds = [LIFECYCLE_SEED.as_bytes()],
+        bump = lifecycle.bump,
+    )]
+    pub lifecycle: Account<'info, Lifecycle>,
+
+    /// Global metrics account that aggregates deployment-wide counters.
+    #[account(
+        mut,
+        seeds = [METRICS_SEED.as_bytes()],
+        bump = metrics.bump,
+    )]
+    pub metrics: Account<'info, Metrics>,
+
+    /// The reserved repository account.
+    #[account(
+        mut,
+        seeds = [
+            REPO_SEED.as_bytes(),
+            repo.repo_key.as_ref(),
+        ],
+        bump = repo.bump,
+    )]
+    pub repo: Account<'info, Repo>,
+
+    /// Name reservation for `args.name`; see `RegisterRepo::repo_name_index`.
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = RepoNameIndex::LEN,
+        seeds = [
+            REPO_NAME_INDEX_SEED.as_bytes(),
+            &RepoNameIndex::name_hash(&args.name),
+        ],
+        bump,
+    )]
+    pub repo_name_index: Option<Account<'info, RepoNameIndex>>,
+
+    /// System program, for creating the name index.
+    pub system_program: Program<'info, System>,
+
+    /// Clock sysvar used for timestamps.
+    pub clock: Sysvar<'info, Clock>,
+}
+
+// ---------------------------------------------------------------------------
+// Handler
+// ---------------------------------------------------------------------------
+
+/// Entry point for the `finalize_repo` instruction.
+///
+/// Steps:
+/// 1. Enforce lifecycle, config and authority guards.
+/// 2. Apply the configured name limit and URL keying rule, and claim the
+///    name's `RepoNameIndex` when passed (required for unique names).
+/// 3. Fill in the repository (`Repo::finalize`), leaving it inactive in
+///    review-gated mode.
+/// 4. Increment global repository counters in `Metrics`.
+/// 5. Emit `RepoRegistered`.
+pub fn handle(ctx: Context<FinalizeRepo>, args: FinalizeRepoArgs) -> Result<()> {
+    let FinalizeRepo {
+        authority,
+        config,
+        lifecycle,
+        metrics,
+        repo,
+        repo_name_index,
+        system_program: _,
+        clock,
+    } = ctx.accounts;
+
+    let clock_ref: &Clock = clock;
+    current_checked(clock_ref)?;
+
+    // -----------------------------------------------------------------------
+    // Lifecycle, configuration and authority guards
+    // -----------------------------------------------------------------------
+
+    lifecycle.assert_writes_allowed()?;
+    config.assert_active()?;
+    repo.assert_authority(authority)?;
+
+    config.assert_name_len(&args.name)?;
+    config.assert_repo_key_allowed(&repo.repo_key, &args.url)?;
+    config.assert_host_allowed(&args.url)?;
+
+    if config.enforce_unique_repo_name && repo_name_index.is_none() {
+        return err!(Unit09Error::MissingRequiredAccount);
+    }
+    if let Some(repo_name_index) = repo_name_index.as_mut() {
+        let index_bump = *ctx
+            .bumps
+            .get("repo_name_index")
+            .ok_or(Unit09Error::InternalError)?;
+        repo_name_index.claim(repo.key(), &args.name, index_bump, clock_ref)?;
+    }
+
+    // -----------------------------------------------------------------------
+    // Finalize Repo account
+    // -----------------------------------------------------------------------
+
+    repo.finalize(
+        args.name,
+        args.url,
+        args.tags,
+        args.metadata_uri,
+        args.allow_observation,
+        clock_ref,
+    )?;
+
+    // As in `register_repo`, review-gated deployments leave the repository
+    // inactive until an admin activates it through `update_repo`.
+    repo.is_active = !config.review_gated;
+
+    // -----------------------------------------------------------------------
+    // Update global metrics
+    // -----------------------------------------------------------------------
+
+    metrics.increment_repos()?;
+    if repo.is_active {
+        metrics.increment_active_repos()?;
+    }
+    metrics.updated_at = clock_ref.unix_timestamp;
+
+    // -----------------------------------------------------------------------
+    // Emit RepoRegistered event
+    // -----------------------------------------------------------------------
+
+    let event_seq = metrics.next_event_seq()?;
+    emit!(RepoRegistered {
+        repo: repo.key(),
+        owner: repo.authority,
+        url: repo.url.clone(),
+        host: repo.host,
+        metadata_uri: repo.metadata_uri.clone(),
+        event_seq,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,46 @@
+//! ===========================================================================
+//! Unit09 – Get Authority Permissions Instruction
+//! Path: contracts/unit09-program/programs/unit09_program/src/instructions/get_authority_permissions.rs
+//!
+//! Read-only query reporting which groups of instructions an `Authority`
+//! entry may call according to its roles.
+//!
+//! The result is `Authority::permissions`, written with `set_return_data`
+//! as a Borsh-encoded `u32`. Bits are the same `permission_bits` used by
+//! `get_permissions`, so clients can AND both masks to learn what the
+//! authority may call right now.
+//!
+//! Notes:
+//! - Only roles are considered. The resource scope and the deployment-wide
+//!   guards are not; use `get_permissions` for the latter.
+//!
+//! ===========================================================================
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::set_return_data;
+
+use crate::state::Authority;
+
+/// Accounts required for the `get_authority_permissions` instruction.
+#[derive(Accounts)]
+pub struct GetAuthorityPermissions<'info> {
+    /// Authority entry whose roles are mapped to permissions.
+    pub authority: Account<'info, Authority>,
+}
+
+// ---------------------------------------------------------------------------
+// Handler
+// ---------------------------------------------------------------------------
+
+/// Entry point for the `get_authority_permissions` instruction.
+///
+/// Steps:
+/// 1. Map the authority's roles to a `permission_bits` mask.
+/// 2. Return it via `set_return_data`.
+pub fn handle(ctx: Context<GetAuthorityPermissions>) -> Result<()> {
+    let mask = ctx.accounts.authority.permissions();
+
+    set_return_data(&mask.try_to_vec()?);
+
+    Ok(())
+}
@@ -0,0 +1,53 @@
+//! ===========================================================================
+//! Unit09 – Get CU Estimates Instruction
+//! Path: contracts/unit09-program/programs/unit09_program/src/instructions/get_cu_estimates.rs
+//!
+//! Read-only instruction that reports approximate compute-unit costs for
+//! each write instruction, taken from `CU_ESTIMATES`.
+//!
+//! The result is written with `set_return_data` as a Borsh-encoded
+//! `Vec<CuEstimate>` in `CU_ESTIMATES` order. The values are static
+//! reference numbers for capacity planning and compute-budget requests;
+//! they are not measured at runtime.
+//!
+//! ===========================================================================
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::set_return_data;
+
+use crate::constants::CU_ESTIMATES;
+
+/// Approximate compute-unit cost of one instruction.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct CuEstimate {
+    /// Instruction name in snake_case (for example "record_observation").
+    pub instruction: String,
+
+    /// Approximate compute units consumed with typical inputs.
+    pub units: u32,
+}
+
+/// Accounts required for the `get_cu_estimates` instruction.
+///
+/// No accounts are needed; the estimates are compiled into the program.
+#[derive(Accounts)]
+pub struct GetCuEstimates {}
+
+// ---------------------------------------------------------------------------
+// Handler
+// ---------------------------------------------------------------------------
+
+/// Entry point for the `get_cu_estimates` instruction.
+pub fn handle(_ctx: Context<GetCuEstimates>) -> Result<()> {
+    let estimates: Vec<CuEstimate> = CU_ESTIMATES
+        .iter()
+        .map(|(instruction, units)| CuEstimate {
+            instruction: instruction.to_string(),
+            units: *units,
+        })
+        .collect();
+
+    set_return_data(&estimates.try_to_vec()?);
+
+    Ok(())
+}
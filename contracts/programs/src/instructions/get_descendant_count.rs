@@ -0,0 +1,61 @@
+//! ===========================================================================
+//! Unit09 – Get Descendant Count Instruction
+//! Path: contracts/unit09-program/programs/unit09_program/src/instructions/get_descendant_count.rs
+//!
+//! Read-only query returning the estimated size of a fork's subtree.
+//!
+//! The value is `Fork::descendant_count`, written with `set_return_data` as a
+//! Borsh-encoded `u64`. Dashboards can use it instead of enumerating every
+//! fork below a node.
+//!
+//! Notes:
+//! - The count is an estimate. `create_fork` only increments the ancestors
+//!   that its caller passes in `remaining_accounts`, so omitted ancestors
+//!   under-count. `prune_forks` likewise only decrements the ancestors it is
+//!   given, so ancestors omitted there over-count.
+//!
+//! ===========================================================================
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::set_return_data;
+
+use crate::constants::*;
+use crate::state::Fork;
+
+/// Accounts required for the `get_descendant_count` instruction.
+#[derive(Accounts)]
+pub struct GetDescendantCount<'info> {
+    /// Fork whose subtree size is returned.
+    ///
+    /// PDA:
+    ///   seeds = [
+    ///       FORK_SEED.as_bytes(),
+    ///       fork.fork_key.as_ref(),
+    ///   ]
+    ///   bump = fork.bump
+    #[account(
+        seeds = [
+            FORK_SEED.as_bytes(),
+            fork.fork_key.as_ref(),
+        ],
+        bump = fork.bump,
+    )]
+    pub fork: Account<'info, Fork>,
+}
+
+// ---------------------------------------------------------------------------
+// Handler
+// ---------------------------------------------------------------------------
+
+/// Entry point for the `get_descendant_count` instruction.
+///
+/// Steps:
+/// 1. Read `Fork::descendant_count`.
+/// 2. Return it via `set_return_data`.
+pub fn handle(ctx: Context<GetDescendantCount>) -> Result<()> {
+    let descendant_count = ctx.accounts.fork.descendant_count;
+
+    set_return_data(&descendant_count.try_to_vec()?);
+
+    Ok(())
+}
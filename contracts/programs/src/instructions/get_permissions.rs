@@ -0,0 +1,61 @@
+//! ===========================================================================
+//! Unit09 – Get Permissions Instruction
+//! Path: contracts/unit09-program/programs/unit09_program/src/instructions/get_permissions.rs
+//!
+//! Read-only query reporting which groups of instructions are currently
+//! permitted by the deployment-wide guards.
+//!
+//! The result is `Lifecycle::permissions(config.is_active)`, written with
+//! `set_return_data` as a Borsh-encoded `u32`. Bit meanings are defined in
+//! `permission_bits`. UIs can use it to disable actions up front instead of
+//! waiting for a transaction to fail.
+//!
+//! Notes:
+//! - Only the lifecycle phase, the global freeze, pending migrations, and
+//!   `Config::is_active` are considered. Per-account guards (authority,
+//!   repo activity, limits) are not.
+//!
+//! ===========================================================================
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::set_return_data;
+
+use crate::constants::*;
+use crate::state::{Config, Lifecycle};
+
+/// Accounts required for the `get_permissions` instruction.
+#[derive(Accounts)]
+pub struct GetPermissions<'info> {
+    /// Global configuration account.
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Lifecycle account controlling global write permissions.
+    #[account(
+        seeds = [LIFECYCLE_SEED.as_bytes()],
+        bump = lifecycle.bump,
+    )]
+    pub lifecycle: Account<'info, Lifecycle>,
+}
+
+// ---------------------------------------------------------------------------
+// Handler
+// ---------------------------------------------------------------------------
+
+/// Entry point for the `get_permissions` instruction.
+///
+/// Steps:
+/// 1. Compute the `permission_bits` mask from the lifecycle and config.
+/// 2. Return it via `set_return_data`.
+pub fn handle(ctx: Context<GetPermissions>) -> Result<()> {
+    let GetPermissions { config, lifecycle } = ctx.accounts;
+
+    let mask = lifecycle.permissions(config.is_active);
+
+    set_return_data(&mask.try_to_vec()?);
+
+    Ok(())
+}
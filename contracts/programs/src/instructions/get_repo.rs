@@ -0,0 +1,186 @@
+//! ===========================================================================
+//! Unit09 – Get Repo Instruction
+//! Path: contracts/unit09-program/programs/unit09_program/src/instructions/get_repo.rs
+//!
+//! Read-only query returning a stable snapshot of a repository for client
+//! caching.
+//!
+//! The result is a `RepoView`, written with `set_return_data` as Borsh.
+//! Its layout is independent of the `Repo` account layout: fields added
+//! to `Repo` or moved by `migrate_repo` do not change it. Any change to
+//! the view itself bumps `RepoView::SCHEMA_VERSION`, which is the first
+//! byte of the return data, so clients can check it before decoding.
+//!
+//! Notes:
+//! - Return data is limited to `MAX_RETURN_DATA` bytes, so bulky or
+//!   internal fields are summarized or left out: maintainers and allowed
+//!   observers are reported as counts, and the last note, daily histogram,
+//!   idempotency, region and access proof fields are omitted.
+//!
+//! ===========================================================================
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::{set_return_data, MAX_RETURN_DATA};
+
+use crate::constants::*;
+use crate::state::Repo;
+
+/// Client-facing snapshot of a `Repo`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct RepoView {
+    /// Layout version of this view (`RepoView::SCHEMA_VERSION`).
+    pub schema_version: u8,
+    /// Key the repository PDA is derived from.
+    pub repo_key: Pubkey,
+    /// Repository authority.
+    pub authority: Pubkey,
+    /// Human-readable name.
+    pub name: String,
+    /// URL of the codebase.
+    pub url: String,
+    /// `RepoHost` as `u8`.
+    pub host: u8,
+    /// Normalized tags.
+    pub tags: String,
+    /// Vocabulary tag bitmask.
+    pub tag_bits: u64,
+    /// Metadata URI; empty when unset.
+    pub metadata_uri: String,
+    /// Whether the repository is active.
+    pub is_active: bool,
+    /// Whether automated observation is allowed.
+    pub allow_observation: bool,
+    /// Whether the repository is still reserved (see `reserve_repo`).
+    pub is_reserved: bool,
+    /// Whether observations must carry an access proof.
+    pub require_access_proof: bool,
+    /// Number of entries in `Repo::maintainers`.
+    pub maintainer_count: u8,
+    /// Number of entries in `Repo::allowed_observers`; 0 means anyone.
+    pub allowed_observer_count: u8,
+    /// Number of modules registered under the repository.
+    pub module_count: u32,
+    /// Primary module, if set.
+    pub primary_module: Option<Pubkey>,
+    /// Per-repository fee override, if set.
+    pub fee_bps_override: Option<u16>,
+    /// Number of recorded observations.
+    pub observation_count: u64,
+    /// Aggregated lines of code over all observations.
+    pub total_lines_of_code: u64,
+    /// Aggregated files processed over all observations.
+    pub total_files_processed: u64,
+    /// Weighted activity score.
+    pub score: u64,
+    /// Observer of the last observation.
+    pub last_observer: Pubkey,
+    /// Unix timestamp of the first observation; 0 if never observed.
+    pub first_observed_at: i64,
+    /// Unix timestamp of the last observation; 0 if never observed.
+    pub last_observed_at: i64,
+    /// Revision of the last observation.
+    pub last_revision: String,
+    /// Unix timestamp when the repository was created.
+    pub created_at: i64,
+    /// Unix timestamp of the last update.
+    pub updated_at: i64,
+}
+
+impl RepoView {
+    /// Current layout version; bump it on any change to the fields above.
+    pub const SCHEMA_VERSION: u8 = 1;
+
+    /// Largest Borsh-encoded size of a view.
+    pub const MAX_LEN: usize = 1 // schema_version: u8
+        + 32 // repo_key: Pubkey
+        + 32 // authority: Pubkey
+        + 4 + Repo::MAX_NAME_LEN // name: String
+        + 4 + Repo::MAX_URL_LEN  // url: String
+        + 1  // host: u8
+        + 4 + Repo::MAX_TAGS_LEN // tags: String
+        + 8  // tag_bits: u64
+        + 4 + Repo::MAX_METADATA_URI_LEN // metadata_uri: String
+        + 4  // is_active, allow_observation, is_reserved, require_access_proof
+        + 2  // maintainer_count, allowed_observer_count: u8
+        + 4  // module_count: u32
+        + 1 + 32 // primary_module: Option<Pubkey>
+        + 1 + 2  // fee_bps_override: Option<u16>
+        + 8 * 4  // observation_count, total_lines_of_code, total_files_processed, score
+        + 32 // last_observer: Pubkey
+        + 8 * 2  // first_observed_at, last_observed_at: i64
+        + 4 + Repo::MAX_REVISION_LEN // last_revision: String
+        + 8 * 2; // created_at, updated_at: i64
+
+    /// Build the view of `repo`.
+    pub fn from_repo(repo: &Repo) -> Self {
+        Self {
+            schema_version: Self::SCHEMA_VERSION,
+            repo_key: repo.repo_key,
+            authority: repo.authority,
+            name: repo.name.clone(),
+            url: repo.url.clone(),
+            host: repo.host,
+            tags: repo.tags.clone(),
+            tag_bits: repo.tag_bits,
+            metadata_uri: repo.metadata_uri.clone(),
+            is_active: repo.is_active,
+            allow_observation: repo.allow_observation,
+            is_reserved: repo.is_reserved,
+            require_access_proof: repo.require_access_proof,
+            maintainer_count: repo.maintainers.len() as u8,
+            allowed_observer_count: repo.allowed_observers.len() as u8,
+            module_count: repo.module_count,
+            primary_module: repo.primary_module,
+            fee_bps_override: repo.fee_bps_override,
+            observation_count: repo.observation_count,
+            total_lines_of_code: repo.total_lines_of_code,
+            total_files_processed: repo.total_files_processed,
+            score: repo.score,
+            last_observer: repo.last_observer,
+            first_observed_at: repo.first_observed_at,
+            last_observed_at: repo.last_observed_at,
+            last_revision: repo.last_revision.clone(),
+            created_at: repo.created_at,
+            updated_at: repo.updated_at,
+        }
+    }
+}
+
+// A full view must always fit into the return data.
+const _: () = assert!(RepoView::MAX_LEN <= MAX_RETURN_DATA);
+
+/// Accounts required for the `get_repo` instruction.
+#[derive(Accounts)]
+pub struct GetRepo<'info> {
+    /// Repository whose view is returned.
+    ///
+    /// PDA:
+    ///   seeds = [REPO_SEED.as_bytes(), repo.repo_key.as_ref()]
+    ///   bump  = repo.bump
+    #[account(
+        seeds = [
+            REPO_SEED.as_bytes(),
+            repo.repo_key.as_ref(),
+        ],
+        bump = repo.bump,
+    )]
+    pub repo: Account<'info, Repo>,
+}
+
+// ---------------------------------------------------------------------------
+// Handler
+// ---------------------------------------------------------------------------
+
+/// Entry point for the `get_repo` instruction.
+///
+/// Steps:
+/// 1. Build the `RepoView` of `repo`.
+/// 2. Return it via `set_return_data`.
+pub fn handle(ctx: Context<GetRepo>) -> Result<()> {
+    let GetRepo { repo } = ctx.accounts;
+
+    let view = RepoView::from_repo(repo);
+    set_return_data(&view.try_to_vec()?);
+
+    Ok(())
+}
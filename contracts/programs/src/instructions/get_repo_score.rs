@@ -0,0 +1,52 @@
+//! ===========================================================================
+//! Unit09 – Get Repo Score Instruction
+//! Path: contracts/unit09-program/programs/unit09_program/src/instructions/get_repo_score.rs
+//!
+//! Read-only query returning `Repo::score`, the weighted activity score a
+//! repository accumulated over its observations.
+//!
+//! The result is written with `set_return_data` as a Borsh-encoded `u64`.
+//! Scores use the same weights for every repository (see
+//! `Repo::observation_score`), so they can be compared directly.
+//!
+//! ===========================================================================
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::set_return_data;
+
+use crate::constants::*;
+use crate::state::Repo;
+
+/// Accounts required for the `get_repo_score` instruction.
+#[derive(Accounts)]
+pub struct GetRepoScore<'info> {
+    /// Repository whose score is returned.
+    ///
+    /// PDA:
+    ///   seeds = [REPO_SEED.as_bytes(), repo.repo_key.as_ref()]
+    ///   bump  = repo.bump
+    #[account(
+        seeds = [
+            REPO_SEED.as_bytes(),
+            repo.repo_key.as_ref(),
+        ],
+        bump = repo.bump,
+    )]
+    pub repo: Account<'info, Repo>,
+}
+
+// ---------------------------------------------------------------------------
+// Handler
+// ---------------------------------------------------------------------------
+
+/// Entry point for the `get_repo_score` instruction.
+///
+/// Steps:
+/// 1. Return `repo.score` via `set_return_data`.
+pub fn handle(ctx: Context<GetRepoScore>) -> Result<()> {
+    let GetRepoScore { repo } = ctx.accounts;
+
+    set_return_data(&repo.score.try_to_vec()?);
+
+    Ok(())
+}
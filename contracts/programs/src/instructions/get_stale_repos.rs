@@ -0,0 +1,76 @@
+//! ===========================================================================
+//! Unit09 – Get Stale Repos Instruction
+//! Path: contracts/unit09-program/programs/unit09_program/src/instructions/get_stale_repos.rs
+//!
+//! Read-only query reporting which of the given repositories hold stale
+//! observation data.
+//!
+//! Batching:
+//!     remaining_accounts = [repo, repo, ...]
+//!
+//! A repository is stale when its last observation is older than
+//! `Config::staleness_threshold_secs` (see `Repo::is_stale`). The PDAs of
+//! the stale repositories, in input order, are written with
+//! `set_return_data` as a Borsh-encoded `Vec<Pubkey>`. With a threshold of
+//! 0 the result is always empty.
+//!
+//! Guards:
+//! - every remaining account must be a `Repo` owned by this program
+//! - no account may be passed twice in `remaining_accounts`
+//!   (`ValidationFailed`)
+//!
+//! ===========================================================================
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::set_return_data;
+
+use crate::constants::*;
+use crate::state::{Config, Repo};
+use crate::utils::validators::assert_unique_keys;
+
+/// Accounts required for the `get_stale_repos` instruction.
+///
+/// The `Repo` accounts to check are passed as `remaining_accounts`.
+#[derive(Accounts)]
+pub struct GetStaleRepos<'info> {
+    /// Global configuration account.
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Clock sysvar used as "now".
+    pub clock: Sysvar<'info, Clock>,
+}
+
+// ---------------------------------------------------------------------------
+// Handler
+// ---------------------------------------------------------------------------
+
+/// Entry point for the `get_stale_repos` instruction.
+///
+/// Steps:
+/// 1. Check every `Repo` in `remaining_accounts` against
+///    `Config::staleness_threshold_secs`.
+/// 2. Return the stale repository PDAs via `set_return_data`.
+pub fn handle<'info>(ctx: Context<'_, '_, 'info, 'info, GetStaleRepos<'info>>) -> Result<()> {
+    let remaining_accounts = ctx.remaining_accounts;
+    let GetStaleRepos { config, clock } = ctx.accounts;
+
+    let clock_ref: &Clock = clock;
+
+    assert_unique_keys(remaining_accounts.iter().map(|account_info| account_info.key))?;
+
+    let mut stale: Vec<Pubkey> = Vec::new();
+    for account_info in remaining_accounts.iter() {
+        let repo: Account<'info, Repo> = Account::try_from(account_info)?;
+        if repo.is_stale(clock_ref, config.staleness_threshold_secs) {
+            stale.push(repo.key());
+        }
+    }
+
+    set_return_data(&stale.try_to_vec()?);
+
+    Ok(())
+}
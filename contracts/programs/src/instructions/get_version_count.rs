@@ -0,0 +1,60 @@
+//! ===========================================================================
+//! Unit09 – Get Version Count Instruction
+//! Path: contracts/unit09-program/programs/unit09_program/src/instructions/get_version_count.rs
+//!
+//! Read-only query returning `Module::version_count`, the number of
+//! `ModuleVersion` snapshots created for a module.
+//!
+//! The result is written with `set_return_data` as a Borsh-encoded `u32`.
+//! Snapshots are seeded by their position (`ModuleVersion::index`), so a
+//! client can derive and fetch every version `0..version_count` (see
+//! `utils::seeds::module_version_pda`). On a module migrated from schema 1
+//! together with its initial snapshot, index 0 is that snapshot at its
+//! semver-seeded address (`utils::seeds::legacy_module_version_pda`).
+//!
+//! ===========================================================================
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::set_return_data;
+
+use crate::constants::*;
+use crate::state::Module;
+
+/// Accounts required for the `get_version_count` instruction.
+#[derive(Accounts)]
+pub struct GetVersionCount<'info> {
+    /// Module whose versions are counted.
+    ///
+    /// PDA:
+    ///   seeds = [
+    ///       MODULE_SEED.as_bytes(),
+    ///       module.repo.as_ref(),
+    ///       module.module_key.as_ref(),
+    ///   ]
+    ///   bump  = module.bump
+    #[account(
+        seeds = [
+            MODULE_SEED.as_bytes(),
+            module.repo.as_ref(),
+            module.module_key.as_ref(),
+        ],
+        bump = module.bump,
+    )]
+    pub module: Account<'info, Module>,
+}
+
+// ---------------------------------------------------------------------------
+// Handler
+// ---------------------------------------------------------------------------
+
+/// Entry point for the `get_version_count` instruction.
+///
+/// Steps:
+/// 1. Return `module.version_count` via `set_return_data`.
+pub fn handle(ctx: Context<GetVersionCount>) -> Result<()> {
+    let GetVersionCount { module } = ctx.accounts;
+
+    set_return_data(&module.version_count.try_to_vec()?);
+
+    Ok(())
+}
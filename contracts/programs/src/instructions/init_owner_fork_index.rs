@@ -0,0 +1,114 @@
+//! ===========================================================================
+//! Unit09 – Init Owner Fork Index Instruction
+//! Path: contracts/unit09-program/programs/unit09_program/src/instructions/init_owner_fork_index.rs
+//!
+//! This instruction creates the `OwnerForkIndex` of the signing owner.
+//!
+//! Once it exists, passing it to `create_fork` makes that instruction
+//! reject a label the owner used recently (`ForkLabelTaken`). Owners that
+//! never create an index get no label uniqueness checks.
+//!
+//! Guards:
+//! - lifecycle must allow writes (`Lifecycle::assert_writes_allowed`)
+//! - global config must be active (`Config::assert_active`)
+//!
+//! PDA layout:
+//! - OwnerForkIndex:
+//!     seeds = [OWNER_FORK_INDEX_SEED.as_bytes(), owner.key().as_ref()]
+//!     bump  = owner_fork_index.bump
+//!
+//! ===========================================================================
+
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::Unit09Error;
+use crate::state::{Config, Lifecycle, OwnerForkIndex};
+use crate::utils::time::current_checked;
+
+/// Accounts required for the `init_owner_fork_index` instruction.
+#[derive(Accounts)]
+pub struct InitOwnerForkIndex<'info> {
+    /// Payer for the index account.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Fork owner the index belongs to.
+    pub owner: Signer<'info>,
+
+    /// Global configuration account.
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Lifecycle account controlling global write permissions.
+    #[account(
+        seeds = [LIFECYCLE_SEED.as_bytes()],
+        bump = lifecycle.bump,
+    )]
+    pub lifecycle: Account<'info, Lifecycle>,
+
+    /// Index account to be created.
+    ///
+    /// PDA:
+    ///   seeds = [OWNER_FORK_INDEX_SEED.as_bytes(), owner.key().as_ref()]
+    ///   bump  = owner_fork_index.bump
+    #[account(
+        init,
+        payer = payer,
+        space = OwnerForkIndex::LEN,
+        seeds = [
+            OWNER_FORK_INDEX_SEED.as_bytes(),
+            owner.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub owner_fork_index: Account<'info, OwnerForkIndex>,
+
+    /// System program.
+    pub system_program: Program<'info, System>,
+
+    /// Clock sysvar used for timestamps.
+    pub clock: Sysvar<'info, Clock>,
+}
+
+// ---------------------------------------------------------------------------
+// Handler
+// ---------------------------------------------------------------------------
+
+/// Entry point for the `init_owner_fork_index` instruction.
+///
+/// Steps:
+/// 1. Enforce lifecycle and config guards.
+/// 2. Initialize an empty `OwnerForkIndex` for `owner`.
+pub fn handle(ctx: Context<InitOwnerForkIndex>) -> Result<()> {
+    let index_bump = *ctx.bumps.get("owner_fork_index").ok_or(Unit09Error::InternalError)?;
+
+    let InitOwnerForkIndex {
+        payer: _,
+        owner,
+        config,
+        lifecycle,
+        owner_fork_index,
+        system_program: _,
+        clock,
+    } = ctx.accounts;
+
+    // -----------------------------------------------------------------------
+    // Lifecycle and configuration guards
+    // -----------------------------------------------------------------------
+
+    lifecycle.assert_writes_allowed()?;
+    config.assert_active()?;
+    current_checked(clock)?;
+
+    // -----------------------------------------------------------------------
+    // Initialize index
+    // -----------------------------------------------------------------------
+
+    owner_fork_index.init(owner.key(), index_bump, clock);
+
+    Ok(())
+}
@@ -8,6 +8,8 @@
 //! - `Config`   : global configuration (admin, fees, limits, flags)
 //! - `Metrics`  : global aggregate counters
 //! - `Lifecycle`: high-level lifecycle and global freeze flags
+//! - `GlobalMetadata`: descriptive metadata, created empty when the
+//!   optional account is passed (required with `require_all`)
 //!
 //! Design goals:
 //! - Single entry point for first-time deployment
@@ -15,6 +17,12 @@
 //! - Clear separation between configuration, metrics, and lifecycle
 //! - Easy to extend in future migrations
 //!
+//! Atomicity:
+//! - every account is created and initialized inside this one instruction,
+//!   so a failing step reverts all of them
+//! - with `require_all = true`, a missing `global_metadata` account fails
+//!   the call instead of leaving metadata to a later `set_metadata`
+//!
 //! The `Initialize` instruction is expected to be called exactly once per
 //! deployment. Subsequent configuration changes should go through
 //! `set_config` and other admin instructions.
@@ -33,7 +41,9 @@ use anchor_lang::prelude::*;
 
 use crate::constants::*;
 use crate::errors::Unit09Error;
-use crate::state::{Config, Lifecycle, Metrics};
+use crate::state::{Config, GlobalMetadata, Lifecycle, Metrics};
+use crate::utils::time::current_checked;
+use crate::utils::validators::{assert_fee_bps_in_range, assert_non_zero_u32, Validate};
 
 /// Arguments for the `initialize` instruction.
 ///
@@ -65,6 +75,21 @@ pub struct InitializeArgs {
     ///
     /// If not needed, pass `[0u8; 32]`.
     pub lifecycle_note_ref: [u8; 32],
+
+    /// Whether `GlobalMetadata` must be created together with the other
+    /// singletons. When `true`, omitting `global_metadata` fails the call
+    /// with `MissingRequiredAccount` and nothing is created.
+    pub require_all: bool,
+}
+
+impl Validate for InitializeArgs {
+    /// Fee within `MAX_FEE_BPS` and a non-zero module cap. Both are checked
+    /// again in `Config::init`; this fails fast before any account is
+    /// initialized.
+    fn validate(&self) -> Result<()> {
+        assert_fee_bps_in_range(self.fee_bps)?;
+        assert_non_zero_u32(self.max_modules_per_repo)
+    }
 }
 
 /// Accounts required for the `initialize` instruction.
@@ -121,6 +146,21 @@ pub struct Initialize<'info> {
     )]
     pub lifecycle: Account<'info, Lifecycle>,
 
+    /// Global metadata account (singleton), created with empty fields.
+    ///
+    /// Optional unless `args.require_all` is set; when omitted it is created
+    /// later by the first `set_metadata`.
+    ///
+    /// PDA: seeds = [GLOBAL_METADATA_SEED], bump
+    #[account(
+        init,
+        payer = payer,
+        space = GlobalMetadata::LEN,
+        seeds = [GLOBAL_METADATA_SEED.as_bytes()],
+        bump,
+    )]
+    pub global_metadata: Option<Account<'info, GlobalMetadata>>,
+
     /// System program.
     pub system_program: Program<'info, System>,
 
@@ -142,6 +182,9 @@ pub struct Initialize<'info> {
 /// - validates arguments
 /// - derives bumps from account seeds
 /// - calls domain-specific `init` methods on `Config`, `Metrics`, `Lifecycle`
+///   and, when passed, `GlobalMetadata`
+///
+/// Any error reverts every account created by this instruction.
 pub fn handle(ctx: Context<Initialize>, args: InitializeArgs) -> Result<()> {
     let Initialize {
         payer: _,
@@ -149,12 +192,14 @@ pub fn handle(ctx: Context<Initialize>, args: InitializeArgs) -> Result<()> {
         mut config,
         mut metrics,
         mut lifecycle,
+        global_metadata,
         system_program: _,
         rent: _,
         clock,
     } = ctx.accounts;
 
     let clock_ref: &Clock = clock;
+    current_checked(clock_ref)?;
 
     // -----------------------------------------------------------------------
     // Basic argument validation
@@ -165,13 +210,12 @@ pub fn handle(ctx: Context<Initialize>, args: InitializeArgs) -> Result<()> {
         return err!(Unit09Error::InvalidAdmin);
     }
 
-    // Fee bounds and max_modules bounds are validated again in Config::init,
-    // but we perform a quick early check here to fail fast.
-    if args.fee_bps > MAX_FEE_BPS {
-        return err!(Unit09Error::InvalidFeeBps);
-    }
-    if args.max_modules_per_repo == 0 {
-        return err!(Unit09Error::ValueOutOfRange);
+    args.validate()?;
+
+    // Checked before any init so an all-or-nothing deployment never starts
+    // without its metadata account.
+    if args.require_all && global_metadata.is_none() {
+        return err!(Unit09Error::MissingRequiredAccount);
     }
 
     // -----------------------------------------------------------------------
@@ -216,5 +260,28 @@ pub fn handle(ctx: Context<Initialize>, args: InitializeArgs) -> Result<()> {
 
     lifecycle.init(lifecycle_bump, clock_ref, args.lifecycle_note_ref)?;
 
+    // -----------------------------------------------------------------------
+    // Initialize GlobalMetadata (optional)
+    // -----------------------------------------------------------------------
+
+    if let Some(global_metadata) = global_metadata {
+        let metadata_bump = *ctx
+            .bumps
+            .get("global_metadata")
+            .ok_or(Unit09Error::InternalError)?;
+
+        global_metadata.init(
+            String::new(),
+            String::new(),
+            String::new(),
+            String::new(),
+            String::new(),
+            String::new(),
+            String::new(),
+            metadata_bump,
+            clock_ref,
+        )?;
+    }
+
     Ok(())
 }
@@ -46,8 +46,14 @@
 //!   association for discovery and analytics.
 //! - Authorization is granted to:
 //!     * the module authority, OR
-//!     * the repo authority
+//!     * the repo authority, OR
+//!     * the admin
 //!   so that either side can manage their own linkage graph.
+//! - Linking a module into a repo other than the one it was registered
+//!   under (`module.repo`) additionally requires both repos to share the
+//!   same authority, unless the admin signs. Otherwise the authority of one
+//!   repo could attach modules it controls to repos it does not. The source
+//!   repo must then be passed as `source_repo`.
 //!
 //! ===========================================================================
 
@@ -56,7 +62,9 @@ use anchor_lang::prelude::*;
 use crate::constants::*;
 use crate::errors::Unit09Error;
 use crate::events::ModuleLinkedToRepo;
-use crate::state::{Config, Lifecycle, Module, ModuleRepoLink, Repo};
+use crate::state::{Config, Lifecycle, Metrics, Module, ModuleRepoLink, Repo};
+use crate::utils::time::current_checked;
+use crate::utils::validators::{assert_max_len, Validate};
 
 /// Arguments for the `link_module_to_repo` instruction.
 ///
@@ -80,6 +88,14 @@ pub struct LinkModuleToRepoArgs {
     pub notes: String,
 }
 
+impl Validate for LinkModuleToRepoArgs {
+    /// Notes within `ModuleRepoLink::MAX_NOTES_LEN`; empty notes are
+    /// allowed.
+    fn validate(&self) -> Result<()> {
+        assert_max_len(&self.notes, ModuleRepoLink::MAX_NOTES_LEN)
+    }
+}
+
 /// Accounts required for the `link_module_to_repo` instruction.
 #[derive(Accounts)]
 pub struct LinkModuleToRepo<'info> {
@@ -91,7 +107,8 @@ pub struct LinkModuleToRepo<'info> {
     ///
     /// This must be either:
     /// - the module authority, OR
-    /// - the repo authority
+    /// - the repo authority, OR
+    /// - the admin
     #[account(mut)]
     pub authority: Signer<'info>,
 
@@ -111,6 +128,14 @@ pub struct LinkModuleToRepo<'info> {
     )]
     pub lifecycle: Account<'info, Lifecycle>,
 
+    /// Global metrics account, used for event sequencing.
+    #[account(
+        mut,
+        seeds = [METRICS_SEED.as_bytes()],
+        bump = metrics.bump,
+    )]
+    pub metrics: Account<'info, Metrics>,
+
     /// Repository to which the module is being linked.
     ///
     /// PDA:
@@ -131,23 +156,32 @@ pub struct LinkModuleToRepo<'info> {
     /// PDA:
     ///   seeds = [
     ///       MODULE_SEED.as_bytes(),
+    ///       module.repo.as_ref(),
     ///       module.module_key.as_ref(),
     ///   ]
     ///   bump  = module.bump
     ///
     /// Note:
-    /// - The module PDA is derived only from `MODULE_SEED` and `module_key`
-    ///   so that it can be associated with multiple repos via link accounts.
+    /// - The module PDA stays derived from the repo it was registered under;
+    ///   further repos are associated with it via link accounts.
     #[account(
         mut,
         seeds = [
             MODULE_SEED.as_bytes(),
+            module.repo.as_ref(),
             module.module_key.as_ref(),
         ],
         bump = module.bump,
     )]
     pub module: Account<'info, Module>,
 
+    /// Repository the module was registered under (`module.repo`).
+    ///
+    /// Required when `repo` is a different repository and the signer is
+    /// not the admin, so both repo authorities can be compared.
+    #[account(address = module.repo @ Unit09Error::ModuleRepoMismatch)]
+    pub source_repo: Option<Account<'info, Repo>>,
+
     /// Link account between the module and the repo.
     ///
     /// PDA:
@@ -192,18 +226,23 @@ pub struct LinkModuleToRepo<'info> {
 /// Steps:
 /// 1. Enforce lifecycle and config guards.
 /// 2. Enforce repo activity.
-/// 3. Enforce that the signer is either module or repo authority.
-/// 4. Validate notes length.
-/// 5. Initialize or update `ModuleRepoLink`.
-/// 6. Emit `ModuleLinkedToRepo` event.
+/// 3. Enforce that the signer is the module authority, repo authority or
+///    admin.
+/// 4. For cross-repo links, enforce that both repos share an authority
+///    unless the signer is admin.
+/// 5. Validate notes length.
+/// 6. Initialize or update `ModuleRepoLink`.
+/// 7. Emit `ModuleLinkedToRepo` event.
 pub fn handle(ctx: Context<LinkModuleToRepo>, args: LinkModuleToRepoArgs) -> Result<()> {
     let LinkModuleToRepo {
         payer: _,
         authority,
         mut config,
         mut lifecycle,
+        mut metrics,
         mut repo,
         mut module,
+        source_repo,
         mut link,
         system_program: _,
         rent: _,
@@ -211,6 +250,7 @@ pub fn handle(ctx: Context<LinkModuleToRepo>, args: LinkModuleToRepoArgs) -> Res
     } = ctx.accounts;
 
     let clock_ref: &Clock = clock;
+    current_checked(clock_ref)?;
 
     // -----------------------------------------------------------------------
     // Lifecycle and configuration guards
@@ -221,26 +261,38 @@ pub fn handle(ctx: Context<LinkModuleToRepo>, args: LinkModuleToRepoArgs) -> Res
     repo.assert_active()?;
 
     // -----------------------------------------------------------------------
-    // Authorization: signer must be module or repo authority
+    // Authorization: signer must be module authority, repo authority or admin
     // -----------------------------------------------------------------------
 
     let signer_key = authority.key();
 
     let is_module_authority = signer_key == module.authority;
     let is_repo_authority = signer_key == repo.authority;
+    let is_admin = signer_key == config.admin;
 
-    if !is_module_authority && !is_repo_authority {
+    if !is_module_authority && !is_repo_authority && !is_admin {
         return err!(Unit09Error::InvalidAuthority);
     }
 
     // -----------------------------------------------------------------------
-    // Basic validation for notes
+    // Cross-repo links: source and destination must share an authority
     // -----------------------------------------------------------------------
 
-    if args.notes.len() > ModuleRepoLink::MAX_NOTES_LEN {
-        return err!(Unit09Error::StringTooLong);
+    if module.repo != repo.key() && !is_admin {
+        let source_repo = source_repo
+            .as_ref()
+            .ok_or(Unit09Error::MissingRequiredAccount)?;
+        if source_repo.authority != repo.authority {
+            return err!(Unit09Error::InvalidAuthority);
+        }
     }
 
+    // -----------------------------------------------------------------------
+    // Basic validation for notes
+    // -----------------------------------------------------------------------
+
+    args.validate()?;
+
     // -----------------------------------------------------------------------
     // Derive bump from Anchor context
     // -----------------------------------------------------------------------
@@ -280,12 +332,14 @@ pub fn handle(ctx: Context<LinkModuleToRepo>, args: LinkModuleToRepoArgs) -> Res
     // Emit ModuleLinkedToRepo event
     // -----------------------------------------------------------------------
 
+    let event_seq = metrics.next_event_seq()?;
     emit!(ModuleLinkedToRepo {
         module: module.key(),
         repo: repo.key(),
         linked_by: signer_key,
         is_primary: link.is_primary,
         updated_at: link.updated_at,
+        event_seq,
     });
 
     Ok(())
@@ -0,0 +1,142 @@
+//! ===========================================================================
+//! Unit09 – Migrate Deployment Instruction
+//! Path: contracts/unit09-program/programs/unit09_program/src/instructions/migrate_deployment.rs
+//!
+//! Admin-only instruction that upgrades the global `Config` and `Metrics`
+//! accounts from an older `schema_version` to `CURRENT_SCHEMA_VERSION`.
+//!
+//! Fields added since schema version 1 are appended behind the version 1
+//! fields, so older accounts are too short to decode as the current layout
+//! and every instruction that loads them fails until they are upgraded. Both
+//! accounts are grown to their current `LEN` (the admin pays the extra rent)
+//! and then upgraded by `Config::migrate` and `Metrics::migrate`. They are
+//! migrated together because each is needed to upgrade the other: `Config`
+//! holds the admin and `Metrics` sequences the emitted event.
+//!
+//! This must run before `migrate_repo`, `migrate_module` and `migrate_fork`.
+//!
+//! On success this instruction:
+//! - grows `Config` and `Metrics` and upgrades them in place
+//! - emits `DeploymentMigrated`
+//!
+//! Guards:
+//! - caller must be `Config::admin` (`InvalidAdmin`)
+//! - both accounts must be owned by this program (`InvalidAccountOwner`)
+//!   and be their canonical PDAs (`ConstraintSeeds`)
+//! - a deployment already at the current version fails with
+//!   `MigrationAlreadyApplied`
+//! - lifecycle is intentionally NOT checked, so migrations can run while
+//!   the deployment is frozen or in the `Migration` phase
+//!
+//! ===========================================================================
+
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::events::DeploymentMigrated;
+use crate::state::{Config, Metrics};
+use crate::utils::migration::{assert_migrated_pda, load_for_migration};
+use crate::utils::time::current_checked;
+
+/// Accounts required for the `migrate_deployment` instruction.
+#[derive(Accounts)]
+pub struct MigrateDeployment<'info> {
+    /// Admin signer; must match `config.admin`. Pays for the extra space.
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// Global configuration account.
+    ///
+    /// CHECK: an older `Config` does not decode as the current layout until
+    /// it has been grown; ownership and the PDA `[CONFIG_SEED.as_bytes()]`
+    /// are verified in the handler after loading.
+    #[account(mut)]
+    pub config: UncheckedAccount<'info>,
+
+    /// Global metrics account.
+    ///
+    /// CHECK: an older `Metrics` does not decode as the current layout until
+    /// it has been grown; ownership and the PDA `[METRICS_SEED.as_bytes()]`
+    /// are verified in the handler after loading.
+    #[account(mut)]
+    pub metrics: UncheckedAccount<'info>,
+
+    /// System program, used to top up rent for the grown accounts.
+    pub system_program: Program<'info, System>,
+
+    /// Clock sysvar used for timestamps.
+    pub clock: Sysvar<'info, Clock>,
+}
+
+// ---------------------------------------------------------------------------
+// Handler
+// ---------------------------------------------------------------------------
+
+/// Entry point for the `migrate_deployment` instruction.
+///
+/// Steps:
+/// 1. Grow `Config` to `Config::LEN`, verify its PDA and that `admin`
+///    matches `config.admin`.
+/// 2. Grow `Metrics` to `Metrics::LEN` and verify its PDA.
+/// 3. Upgrade both accounts and write them back.
+/// 4. Emit `DeploymentMigrated`.
+pub fn handle(ctx: Context<MigrateDeployment>) -> Result<()> {
+    let MigrateDeployment {
+        admin,
+        config,
+        metrics,
+        system_program,
+        clock,
+    } = ctx.accounts;
+
+    let clock_ref: &Clock = clock;
+    current_checked(clock_ref)?;
+
+    // -----------------------------------------------------------------------
+    // Load and verify accounts
+    // -----------------------------------------------------------------------
+
+    let mut config_account: Account<Config> =
+        load_for_migration(&config.to_account_info(), Config::LEN, admin, system_program)?;
+    assert_migrated_pda(
+        &config_account.key(),
+        &[CONFIG_SEED.as_bytes()],
+        config_account.bump,
+    )?;
+    config_account.assert_admin(admin)?;
+
+    let mut metrics_account: Account<Metrics> =
+        load_for_migration(&metrics.to_account_info(), Metrics::LEN, admin, system_program)?;
+    assert_migrated_pda(
+        &metrics_account.key(),
+        &[METRICS_SEED.as_bytes()],
+        metrics_account.bump,
+    )?;
+
+    // -----------------------------------------------------------------------
+    // Migrate
+    // -----------------------------------------------------------------------
+
+    let from_version = config_account.migrate(clock_ref)?;
+    metrics_account.migrate(clock_ref)?;
+
+    // -----------------------------------------------------------------------
+    // Emit event
+    // -----------------------------------------------------------------------
+
+    let event_seq = metrics_account.next_event_seq()?;
+    emit!(DeploymentMigrated {
+        config: config_account.key(),
+        metrics: metrics_account.key(),
+        from_version,
+        to_version: config_account.schema_version,
+        migrated_by: admin.key(),
+        migrated_at: clock_ref.unix_timestamp,
+        event_seq,
+    });
+
+    config_account.exit(&crate::ID)?;
+    metrics_account.exit(&crate::ID)?;
+
+    Ok(())
+}
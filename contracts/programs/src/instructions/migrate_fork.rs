@@ -0,0 +1,136 @@
+//! ===========================================================================
+//! Unit09 – Migrate Fork Instruction
+//! Path: contracts/unit09-program/programs/unit09_program/src/instructions/migrate_fork.rs
+//!
+//! Admin-only instruction that upgrades a `Fork` account from an older
+//! `schema_version` to `CURRENT_SCHEMA_VERSION`.
+//!
+//! Fields added since schema version 1 are appended behind the version 1
+//! fields, so an older account is too short to decode as the current `Fork`.
+//! The account is first grown to `Fork::LEN` (the admin pays the extra
+//! rent), after which the appended fields decode from zeros.
+//!
+//! Requires `Config` and `Metrics` to be upgraded first (`migrate_deployment`).
+//!
+//! On success this instruction:
+//! - grows the `Fork` account and upgrades it in place
+//! - emits `ForkMigrated`
+//!
+//! Guards:
+//! - caller must be `Config::admin` (`InvalidAdmin`)
+//! - `fork` must be owned by this program (`InvalidAccountOwner`) and be
+//!   the PDA of its own `fork_key` (`ConstraintSeeds`)
+//! - a fork already at the current version fails with
+//!   `MigrationAlreadyApplied`
+//! - lifecycle is intentionally NOT checked, so migrations can run while
+//!   the deployment is frozen or in the `Migration` phase
+//!
+//! ===========================================================================
+
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::events::ForkMigrated;
+use crate::state::{Config, Fork, Metrics};
+use crate::utils::migration::{assert_migrated_pda, load_for_migration};
+use crate::utils::time::current_checked;
+
+/// Accounts required for the `migrate_fork` instruction.
+#[derive(Accounts)]
+pub struct MigrateFork<'info> {
+    /// Admin signer; must match `config.admin`. Pays for the extra space.
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// Global configuration account.
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Global metrics account, used for event sequencing.
+    #[account(
+        mut,
+        seeds = [METRICS_SEED.as_bytes()],
+        bump = metrics.bump,
+    )]
+    pub metrics: Account<'info, Metrics>,
+
+    /// Fork to upgrade.
+    ///
+    /// CHECK: an older `Fork` does not decode as the current layout until
+    /// it has been grown; ownership and the PDA
+    /// `[FORK_SEED.as_bytes(), fork.fork_key.as_ref()]` are verified in the
+    /// handler after loading.
+    #[account(mut)]
+    pub fork: UncheckedAccount<'info>,
+
+    /// System program, used to top up rent for the grown account.
+    pub system_program: Program<'info, System>,
+
+    /// Clock sysvar used for timestamps.
+    pub clock: Sysvar<'info, Clock>,
+}
+
+// ---------------------------------------------------------------------------
+// Handler
+// ---------------------------------------------------------------------------
+
+/// Entry point for the `migrate_fork` instruction.
+///
+/// Steps:
+/// 1. Verify `admin` matches `config.admin`.
+/// 2. Grow the fork to `Fork::LEN` and verify its PDA.
+/// 3. Upgrade the fork via `Fork::migrate` and write it back.
+/// 4. Emit `ForkMigrated`.
+pub fn handle(ctx: Context<MigrateFork>) -> Result<()> {
+    let MigrateFork {
+        admin,
+        config,
+        metrics,
+        fork,
+        system_program,
+        clock,
+    } = ctx.accounts;
+
+    let clock_ref: &Clock = clock;
+    current_checked(clock_ref)?;
+
+    // -----------------------------------------------------------------------
+    // Admin authority check
+    // -----------------------------------------------------------------------
+
+    config.assert_admin(admin)?;
+
+    // -----------------------------------------------------------------------
+    // Migrate
+    // -----------------------------------------------------------------------
+
+    let mut fork_account: Account<Fork> =
+        load_for_migration(&fork.to_account_info(), Fork::LEN, admin, system_program)?;
+    assert_migrated_pda(
+        &fork_account.key(),
+        &[FORK_SEED.as_bytes(), fork_account.fork_key.as_ref()],
+        fork_account.bump,
+    )?;
+
+    let from_version = fork_account.migrate(clock_ref)?;
+    fork_account.exit(&crate::ID)?;
+
+    // -----------------------------------------------------------------------
+    // Emit event
+    // -----------------------------------------------------------------------
+
+    let event_seq = metrics.next_event_seq()?;
+    emit!(ForkMigrated {
+        fork: fork_account.key(),
+        from_version,
+        to_version: fork_account.schema_version,
+        migrated_by: admin.key(),
+        migrated_at: clock_ref.unix_timestamp,
+        event_seq,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,185 @@
+//! ===========================================================================
+//! Unit09 – Migrate Module Instruction
+//! Path: contracts/unit09-program/programs/unit09_program/src/instructions/migrate_module.rs
+//!
+//! Admin-only instruction that upgrades a `Module` account from an older
+//! `schema_version` to `CURRENT_SCHEMA_VERSION`.
+//!
+//! Fields added since schema version 1 are appended behind the version 1
+//! fields, so an older account is too short to decode as the current
+//! `Module`. The account is first grown to `Module::LEN` (the admin pays the
+//! extra rent), after which the appended fields decode from zeros and
+//! `Module::migrate` fills in the ones derived from existing data.
+//!
+//! `ModuleVersion` snapshots are now seeded by index. A schema 1 module may
+//! own an initial snapshot seeded by its semantic version (see
+//! `utils::seeds::legacy_module_version_pda`); passing it as
+//! `legacy_version` counts it as index 0, so `Module::version_count` starts
+//! at 1 and later snapshots are created from index 1. The account itself
+//! keeps its address; its `index` already decodes as 0.
+//!
+//! Requires `Config` and `Metrics` to be upgraded first (`migrate_deployment`).
+//!
+//! On success this instruction:
+//! - grows the `Module` account and upgrades it in place
+//! - emits `ModuleMigrated`
+//!
+//! Guards:
+//! - caller must be `Config::admin` (`InvalidAdmin`)
+//! - `module` must be owned by this program (`InvalidAccountOwner`) and be
+//!   the PDA of its own `repo` and `module_key` (`ConstraintSeeds`)
+//! - a module already at the current version fails with
+//!   `MigrationAlreadyApplied`
+//! - `legacy_version`, if given, must belong to `module`
+//!   (`ValidationFailed`) and sit at its semver-seeded PDA
+//!   (`ConstraintSeeds`)
+//! - lifecycle is intentionally NOT checked, so migrations can run while
+//!   the deployment is frozen or in the `Migration` phase
+//!
+//! ===========================================================================
+
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::Unit09Error;
+use crate::events::ModuleMigrated;
+use crate::state::{Config, Metrics, Module, ModuleVersion};
+use crate::utils::migration::{assert_migrated_pda, load_for_migration};
+use crate::utils::time::current_checked;
+
+/// Accounts required for the `migrate_module` instruction.
+#[derive(Accounts)]
+pub struct MigrateModule<'info> {
+    /// Admin signer; must match `config.admin`. Pays for the extra space.
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// Global configuration account.
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Global metrics account, used for event sequencing.
+    #[account(
+        mut,
+        seeds = [METRICS_SEED.as_bytes()],
+        bump = metrics.bump,
+    )]
+    pub metrics: Account<'info, Metrics>,
+
+    /// Module to upgrade.
+    ///
+    /// CHECK: an older `Module` does not decode as the current layout until
+    /// it has been grown; ownership and the PDA
+    /// `[MODULE_SEED.as_bytes(), module.repo.as_ref(), module.module_key.as_ref()]`
+    /// are verified in the handler after loading.
+    #[account(mut)]
+    pub module: UncheckedAccount<'info>,
+
+    /// Optional initial snapshot created by a schema 1 `register_module`.
+    ///
+    /// PDA (verified in the handler):
+    ///   seeds = [
+    ///       MODULE_VERSION_SEED.as_bytes(),
+    ///       module.key().as_ref(),
+    ///       &legacy_version.major_version.to_le_bytes(),
+    ///       &legacy_version.minor_version.to_le_bytes(),
+    ///       &legacy_version.patch_version.to_le_bytes(),
+    ///   ]
+    pub legacy_version: Option<Account<'info, ModuleVersion>>,
+
+    /// System program, used to top up rent for the grown account.
+    pub system_program: Program<'info, System>,
+
+    /// Clock sysvar used for timestamps.
+    pub clock: Sysvar<'info, Clock>,
+}
+
+// ---------------------------------------------------------------------------
+// Handler
+// ---------------------------------------------------------------------------
+
+/// Entry point for the `migrate_module` instruction.
+///
+/// Steps:
+/// 1. Verify `admin` matches `config.admin`.
+/// 2. Grow the module to `Module::LEN` and verify its PDA.
+/// 3. Verify `legacy_version`, if given, against the module.
+/// 4. Upgrade the module via `Module::migrate` and write it back.
+/// 5. Emit `ModuleMigrated`.
+pub fn handle(ctx: Context<MigrateModule>) -> Result<()> {
+    let MigrateModule {
+        admin,
+        config,
+        metrics,
+        module,
+        legacy_version,
+        system_program,
+        clock,
+    } = ctx.accounts;
+
+    let clock_ref: &Clock = clock;
+    current_checked(clock_ref)?;
+
+    // -----------------------------------------------------------------------
+    // Admin authority check
+    // -----------------------------------------------------------------------
+
+    config.assert_admin(admin)?;
+
+    // -----------------------------------------------------------------------
+    // Migrate
+    // -----------------------------------------------------------------------
+
+    let mut module_account: Account<Module> =
+        load_for_migration(&module.to_account_info(), Module::LEN, admin, system_program)?;
+    assert_migrated_pda(
+        &module_account.key(),
+        &[
+            MODULE_SEED.as_bytes(),
+            module_account.repo.as_ref(),
+            module_account.module_key.as_ref(),
+        ],
+        module_account.bump,
+    )?;
+
+    if let Some(legacy_version) = legacy_version.as_ref() {
+        require_keys_eq!(
+            legacy_version.module,
+            module_account.key(),
+            Unit09Error::ValidationFailed
+        );
+        assert_migrated_pda(
+            &legacy_version.key(),
+            &[
+                MODULE_VERSION_SEED.as_bytes(),
+                module_account.key().as_ref(),
+                &legacy_version.major_version.to_le_bytes(),
+                &legacy_version.minor_version.to_le_bytes(),
+                &legacy_version.patch_version.to_le_bytes(),
+            ],
+            legacy_version.bump,
+        )?;
+    }
+
+    let from_version = module_account.migrate(legacy_version.is_some(), clock_ref)?;
+    module_account.exit(&crate::ID)?;
+
+    // -----------------------------------------------------------------------
+    // Emit event
+    // -----------------------------------------------------------------------
+
+    let event_seq = metrics.next_event_seq()?;
+    emit!(ModuleMigrated {
+        module: module_account.key(),
+        from_version,
+        to_version: module_account.schema_version,
+        migrated_by: admin.key(),
+        migrated_at: clock_ref.unix_timestamp,
+        event_seq,
+    });
+
+    Ok(())
+}
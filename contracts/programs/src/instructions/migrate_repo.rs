@@ -0,0 +1,137 @@
+//! ===========================================================================
+//! Unit09 – Migrate Repo Instruction
+//! Path: contracts/unit09-program/programs/unit09_program/src/instructions/migrate_repo.rs
+//!
+//! Admin-only instruction that upgrades a `Repo` account from an older
+//! `schema_version` to `CURRENT_SCHEMA_VERSION`.
+//!
+//! Fields added since schema version 1 are appended behind the version 1
+//! fields, so an older account is too short to decode as the current `Repo`.
+//! The account is first grown to `Repo::LEN` (the admin pays the extra
+//! rent), after which the appended fields decode from zeros and
+//! `Repo::migrate` fills in the ones derived from existing data.
+//!
+//! Requires `Config` and `Metrics` to be upgraded first (`migrate_deployment`).
+//!
+//! On success this instruction:
+//! - grows the `Repo` account and upgrades it in place
+//! - emits `RepoMigrated`
+//!
+//! Guards:
+//! - caller must be `Config::admin` (`InvalidAdmin`)
+//! - `repo` must be owned by this program (`InvalidAccountOwner`) and be
+//!   the PDA of its own `repo_key` (`ConstraintSeeds`)
+//! - a repository already at the current version fails with
+//!   `MigrationAlreadyApplied`
+//! - lifecycle is intentionally NOT checked, so migrations can run while
+//!   the deployment is frozen or in the `Migration` phase
+//!
+//! ===========================================================================
+
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::events::RepoMigrated;
+use crate::state::{Config, Metrics, Repo};
+use crate::utils::migration::{assert_migrated_pda, load_for_migration};
+use crate::utils::time::current_checked;
+
+/// Accounts required for the `migrate_repo` instruction.
+#[derive(Accounts)]
+pub struct MigrateRepo<'info> {
+    /// Admin signer; must match `config.admin`. Pays for the extra space.
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// Global configuration account.
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Global metrics account, used for event sequencing.
+    #[account(
+        mut,
+        seeds = [METRICS_SEED.as_bytes()],
+        bump = metrics.bump,
+    )]
+    pub metrics: Account<'info, Metrics>,
+
+    /// Repository to upgrade.
+    ///
+    /// CHECK: an older `Repo` does not decode as the current layout until
+    /// it has been grown; ownership and the PDA
+    /// `[REPO_SEED.as_bytes(), repo.repo_key.as_ref()]` are verified in the
+    /// handler after loading.
+    #[account(mut)]
+    pub repo: UncheckedAccount<'info>,
+
+    /// System program, used to top up rent for the grown account.
+    pub system_program: Program<'info, System>,
+
+    /// Clock sysvar used for timestamps.
+    pub clock: Sysvar<'info, Clock>,
+}
+
+// ---------------------------------------------------------------------------
+// Handler
+// ---------------------------------------------------------------------------
+
+/// Entry point for the `migrate_repo` instruction.
+///
+/// Steps:
+/// 1. Verify `admin` matches `config.admin`.
+/// 2. Grow the repository to `Repo::LEN` and verify its PDA.
+/// 3. Upgrade the repository via `Repo::migrate` and write it back.
+/// 4. Emit `RepoMigrated`.
+pub fn handle(ctx: Context<MigrateRepo>) -> Result<()> {
+    let MigrateRepo {
+        admin,
+        config,
+        metrics,
+        repo,
+        system_program,
+        clock,
+    } = ctx.accounts;
+
+    let clock_ref: &Clock = clock;
+    current_checked(clock_ref)?;
+
+    // -----------------------------------------------------------------------
+    // Admin authority check
+    // -----------------------------------------------------------------------
+
+    config.assert_admin(admin)?;
+
+    // -----------------------------------------------------------------------
+    // Migrate
+    // -----------------------------------------------------------------------
+
+    let mut repo_account: Account<Repo> =
+        load_for_migration(&repo.to_account_info(), Repo::LEN, admin, system_program)?;
+    assert_migrated_pda(
+        &repo_account.key(),
+        &[REPO_SEED.as_bytes(), repo_account.repo_key.as_ref()],
+        repo_account.bump,
+    )?;
+
+    let from_version = repo_account.migrate(clock_ref)?;
+    repo_account.exit(&crate::ID)?;
+
+    // -----------------------------------------------------------------------
+    // Emit event
+    // -----------------------------------------------------------------------
+
+    let event_seq = metrics.next_event_seq()?;
+    emit!(RepoMigrated {
+        repo: repo_account.key(),
+        from_version,
+        to_version: repo_account.schema_version,
+        migrated_by: admin.key(),
+        migrated_at: clock_ref.unix_timestamp,
+        event_seq,
+    });
+
+    Ok(())
+}
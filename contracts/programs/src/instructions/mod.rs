@@ -52,6 +52,48 @@ pub mod update_fork_state;
 pub mod record_observation;
 pub mod record_metrics;
 pub mod set_metadata;
+pub mod estimate_rents;
+pub mod collect_fee;
+pub mod ack_indexed;
+pub mod record_observation_multi;
+pub mod refresh_bumps;
+pub mod set_lifecycle;
+pub mod check_fork_descendant;
+pub mod set_repo_observable;
+pub mod get_descendant_count;
+pub mod migrate_repo;
+pub mod clone_fork;
+pub mod recompute_metrics;
+pub mod set_primary_module;
+pub mod get_cu_estimates;
+pub mod get_permissions;
+pub mod top_up_credits;
+pub mod rename_tag;
+pub mod get_stale_repos;
+pub mod get_version_count;
+pub mod init_owner_fork_index;
+pub mod reserve_repo;
+pub mod finalize_repo;
+pub mod take_metrics_snapshot;
+pub mod compare_snapshots;
+pub mod add_maintainer;
+pub mod remove_maintainer;
+pub mod admin_reassign_fork;
+pub mod prune_forks;
+pub mod get_repo_score;
+pub mod summarize_repo_tags;
+pub mod repo_has_tag;
+pub mod restore_metrics;
+pub mod get_authority_permissions;
+pub mod add_blocked_host;
+pub mod remove_blocked_host;
+pub mod get_repo;
+pub mod set_repo_fee_override;
+pub mod migrate_deployment;
+pub mod migrate_module;
+pub mod migrate_fork;
+pub mod add_co_admin;
+pub mod remove_co_admin;
 
 // ---------------------------------------------------------------------------
 // Public Re-exports
@@ -62,10 +104,24 @@ pub use initialize::{Initialize, InitializeArgs};
 
 // Config
 pub use set_config::{SetConfig, SetConfigArgs};
+pub use collect_fee::{CollectFee, CollectFeeArgs};
+pub use set_lifecycle::{SetLifecycle, SetLifecycleArgs};
+pub use add_blocked_host::{AddBlockedHost, AddBlockedHostArgs};
+pub use remove_blocked_host::{RemoveBlockedHost, RemoveBlockedHostArgs};
+pub use add_co_admin::{AddCoAdmin, AddCoAdminArgs};
+pub use remove_co_admin::RemoveCoAdmin;
 
 // Repositories
 pub use register_repo::{RegisterRepo, RegisterRepoArgs};
 pub use update_repo::{UpdateRepo, UpdateRepoArgs};
+pub use set_repo_observable::{SetRepoObservable, SetRepoObservableArgs};
+pub use set_primary_module::{SetPrimaryModule, SetPrimaryModuleArgs};
+pub use rename_tag::{RenameTag, RenameTagArgs};
+pub use reserve_repo::{ReserveRepo, ReserveRepoArgs};
+pub use finalize_repo::{FinalizeRepo, FinalizeRepoArgs};
+pub use add_maintainer::{AddMaintainer, AddMaintainerArgs};
+pub use remove_maintainer::{RemoveMaintainer, RemoveMaintainerArgs};
+pub use set_repo_fee_override::{SetRepoFeeOverride, SetRepoFeeOverrideArgs};
 
 // Modules
 pub use register_module::{RegisterModule, RegisterModuleArgs};
@@ -75,14 +131,46 @@ pub use link_module_to_repo::{LinkModuleToRepo, LinkModuleToRepoArgs};
 // Forks
 pub use create_fork::{CreateFork, CreateForkArgs};
 pub use update_fork_state::{UpdateForkState, UpdateForkStateArgs};
+pub use clone_fork::{CloneFork, CloneForkArgs};
+pub use init_owner_fork_index::InitOwnerForkIndex;
+pub use admin_reassign_fork::{AdminReassignFork, AdminReassignForkArgs};
+pub use prune_forks::{PruneForks, PruneForksArgs};
 
 // Observations / Metrics
 pub use record_observation::{RecordObservation, RecordObservationArgs};
 pub use record_metrics::{RecordMetrics, RecordMetricsArgs};
+pub use ack_indexed::{AckIndexed, AckIndexedArgs};
+pub use record_observation_multi::{RecordObservationMulti, RecordObservationMultiArgs, ObservationSplit};
+pub use recompute_metrics::{RecomputeMetrics, RecomputeMetricsArgs};
+pub use top_up_credits::{TopUpCredits, TopUpCreditsArgs};
+pub use take_metrics_snapshot::TakeMetricsSnapshot;
+pub use restore_metrics::RestoreMetrics;
 
 // Metadata
 pub use set_metadata::{SetMetadata, SetMetadataArgs};
 
+// Queries
+pub use estimate_rents::{EstimateRents, RentEstimates};
+pub use check_fork_descendant::{CheckForkDescendant, CheckForkDescendantArgs};
+pub use get_descendant_count::GetDescendantCount;
+pub use get_cu_estimates::{GetCuEstimates, CuEstimate};
+pub use get_permissions::GetPermissions;
+pub use get_stale_repos::GetStaleRepos;
+pub use get_version_count::GetVersionCount;
+pub use compare_snapshots::CompareSnapshots;
+pub use get_repo_score::GetRepoScore;
+pub use summarize_repo_tags::{SummarizeRepoTags, TagCount};
+pub use repo_has_tag::{RepoHasTag, RepoHasTagArgs};
+pub use get_authority_permissions::GetAuthorityPermissions;
+pub use get_repo::{GetRepo, RepoView};
+
+// Maintenance
+pub use refresh_bumps::RefreshBumps;
+pub use migrate_repo::MigrateRepo;
+pub use migrate_deployment::MigrateDeployment;
+pub use migrate_module::MigrateModule;
+pub use migrate_fork::MigrateFork;
+
 // ---------------------------------------------------------------------------
 // Instruction Routing Wrappers
 // ---------------------------------------------------------------------------
@@ -99,8 +187,8 @@ pub use set_metadata::{SetMetadata, SetMetadataArgs};
 
 /// Initialize the Unit09 deployment:
 /// - create and configure `Config`
-/// - create `Metrics`
-/// - optionally create `Lifecycle` and `GlobalMetadata` accounts
+/// - create `Metrics` and `Lifecycle`
+/// - optionally create `GlobalMetadata` (required with `require_all`)
 pub fn initialize(ctx: Context<Initialize>, args: InitializeArgs) -> Result<()> {
     initialize::handle(ctx, args)
 }
@@ -205,3 +293,262 @@ pub fn record_metrics(ctx: Context<RecordMetrics>, args: RecordMetricsArgs) -> R
 pub fn set_metadata(ctx: Context<SetMetadata>, args: SetMetadataArgs) -> Result<()> {
     set_metadata::handle(ctx, args)
 }
+
+/// Report rent-exempt minimums for core account types:
+/// - Config, Metrics, Repo, Module, Fork
+/// - returned via `set_return_data` as `RentEstimates`
+pub fn estimate_rents(ctx: Context<EstimateRents>) -> Result<()> {
+    estimate_rents::handle(ctx)
+}
+
+/// Route for the `collect_fee` instruction.
+pub fn collect_fee(ctx: Context<CollectFee>, args: CollectFeeArgs) -> Result<()> {
+    collect_fee::handle(ctx, args)
+}
+
+/// Route for the `ack_indexed` instruction.
+pub fn ack_indexed(ctx: Context<AckIndexed>, args: AckIndexedArgs) -> Result<()> {
+    ack_indexed::handle(ctx, args)
+}
+
+/// Route for the `record_observation_multi` instruction.
+pub fn record_observation_multi<'info>(
+    ctx: Context<'_, '_, 'info, 'info, RecordObservationMulti<'info>>,
+    args: RecordObservationMultiArgs,
+) -> Result<()> {
+    record_observation_multi::handle(ctx, args)
+}
+
+/// Route for the `refresh_bumps` instruction.
+pub fn refresh_bumps(ctx: Context<RefreshBumps>) -> Result<()> {
+    refresh_bumps::handle(ctx)
+}
+
+/// Route for the `set_lifecycle` instruction.
+pub fn set_lifecycle(ctx: Context<SetLifecycle>, args: SetLifecycleArgs) -> Result<()> {
+    set_lifecycle::handle(ctx, args)
+}
+
+/// Check fork lineage:
+/// - walks the candidate's parent chain (remaining accounts)
+/// - returned via `set_return_data` as a Borsh `bool`
+pub fn check_fork_descendant(
+    ctx: Context<CheckForkDescendant>,
+    args: CheckForkDescendantArgs,
+) -> Result<()> {
+    check_fork_descendant::handle(ctx, args)
+}
+
+/// Set only a repository's `allow_observation` flag.
+pub fn set_repo_observable(
+    ctx: Context<SetRepoObservable>,
+    args: SetRepoObservableArgs,
+) -> Result<()> {
+    set_repo_observable::handle(ctx, args)
+}
+
+/// Return a fork's estimated subtree size.
+pub fn get_descendant_count(ctx: Context<GetDescendantCount>) -> Result<()> {
+    get_descendant_count::handle(ctx)
+}
+
+/// Upgrade a repository account to the current schema version.
+pub fn migrate_repo(ctx: Context<MigrateRepo>) -> Result<()> {
+    migrate_repo::handle(ctx)
+}
+
+/// Create a new `Fork` from a template fork, inheriting its label
+/// prefix, metadata URI, and tags.
+pub fn clone_fork(ctx: Context<CloneFork>, args: CloneForkArgs) -> Result<()> {
+    clone_fork::handle(ctx, args)
+}
+
+/// Re-derive the repository-based `Metrics` totals from `Repo` accounts
+/// passed in `remaining_accounts`, optionally across several batches.
+pub fn recompute_metrics(
+    ctx: Context<RecomputeMetrics>,
+    args: RecomputeMetricsArgs,
+) -> Result<()> {
+    recompute_metrics::handle(ctx, args)
+}
+
+/// Set or clear a repository's primary module (repo authority only).
+pub fn set_primary_module(
+    ctx: Context<SetPrimaryModule>,
+    args: SetPrimaryModuleArgs,
+) -> Result<()> {
+    set_primary_module::handle(ctx, args)
+}
+
+/// Report approximate compute-unit costs for each write instruction.
+pub fn get_cu_estimates(ctx: Context<GetCuEstimates>) -> Result<()> {
+    get_cu_estimates::handle(ctx)
+}
+
+/// Report the `permission_bits` mask allowed by the current lifecycle and config.
+pub fn get_permissions(ctx: Context<GetPermissions>) -> Result<()> {
+    get_permissions::handle(ctx)
+}
+
+/// Route for the `top_up_credits` instruction.
+pub fn top_up_credits(ctx: Context<TopUpCredits>, args: TopUpCreditsArgs) -> Result<()> {
+    top_up_credits::handle(ctx, args)
+}
+
+/// Route for the `rename_tag` instruction.
+pub fn rename_tag<'info>(
+    ctx: Context<'_, '_, 'info, 'info, RenameTag<'info>>,
+    args: RenameTagArgs,
+) -> Result<()> {
+    rename_tag::handle(ctx, args)
+}
+
+/// Route for the `get_stale_repos` instruction.
+pub fn get_stale_repos<'info>(
+    ctx: Context<'_, '_, 'info, 'info, GetStaleRepos<'info>>,
+) -> Result<()> {
+    get_stale_repos::handle(ctx)
+}
+
+/// Route for the `get_version_count` instruction.
+pub fn get_version_count(ctx: Context<GetVersionCount>) -> Result<()> {
+    get_version_count::handle(ctx)
+}
+
+/// Route for the `init_owner_fork_index` instruction.
+pub fn init_owner_fork_index(ctx: Context<InitOwnerForkIndex>) -> Result<()> {
+    init_owner_fork_index::handle(ctx)
+}
+
+/// Pre-allocate an inactive repository to be completed by `finalize_repo`.
+pub fn reserve_repo(ctx: Context<ReserveRepo>, args: ReserveRepoArgs) -> Result<()> {
+    reserve_repo::handle(ctx, args)
+}
+
+/// Fill in and activate a repository reserved by `reserve_repo`.
+pub fn finalize_repo(ctx: Context<FinalizeRepo>, args: FinalizeRepoArgs) -> Result<()> {
+    finalize_repo::handle(ctx, args)
+}
+
+/// Copy the current metrics counters into a new `MetricsSnapshot`.
+pub fn take_metrics_snapshot(ctx: Context<TakeMetricsSnapshot>) -> Result<()> {
+    take_metrics_snapshot::handle(ctx)
+}
+
+/// Return the deltas between two metrics snapshots.
+pub fn compare_snapshots(ctx: Context<CompareSnapshots>) -> Result<()> {
+    compare_snapshots::handle(ctx)
+}
+
+/// Add a maintainer allowed to update repository metadata.
+pub fn add_maintainer(
+    ctx: Context<AddMaintainer>,
+    args: AddMaintainerArgs,
+) -> Result<()> {
+    add_maintainer::handle(ctx, args)
+}
+
+/// Remove a repository maintainer.
+pub fn remove_maintainer(
+    ctx: Context<RemoveMaintainer>,
+    args: RemoveMaintainerArgs,
+) -> Result<()> {
+    remove_maintainer::handle(ctx, args)
+}
+
+/// Reassign a fork to a new owner (admin recovery).
+pub fn admin_reassign_fork(
+    ctx: Context<AdminReassignFork>,
+    args: AdminReassignForkArgs,
+) -> Result<()> {
+    admin_reassign_fork::handle(ctx, args)
+}
+
+/// Close inactive, childless forks passed in `remaining_accounts`.
+pub fn prune_forks<'info>(
+    ctx: Context<'_, '_, 'info, 'info, PruneForks<'info>>,
+    args: PruneForksArgs,
+) -> Result<()> {
+    prune_forks::handle(ctx, args)
+}
+
+/// Route for the `get_repo_score` instruction.
+pub fn get_repo_score(ctx: Context<GetRepoScore>) -> Result<()> {
+    get_repo_score::handle(ctx)
+}
+
+/// Route for the `summarize_repo_tags` instruction.
+pub fn summarize_repo_tags<'info>(
+    ctx: Context<'_, '_, 'info, 'info, SummarizeRepoTags<'info>>,
+) -> Result<()> {
+    summarize_repo_tags::handle(ctx)
+}
+
+/// Route for the `repo_has_tag` instruction.
+pub fn repo_has_tag(ctx: Context<RepoHasTag>, args: RepoHasTagArgs) -> Result<()> {
+    repo_has_tag::handle(ctx, args)
+}
+
+/// Roll the `Metrics` counters back to a `MetricsSnapshot` (full admin quorum).
+pub fn restore_metrics(ctx: Context<RestoreMetrics>) -> Result<()> {
+    restore_metrics::handle(ctx)
+}
+
+/// Report the `permission_bits` mask granted by an `Authority` entry's roles.
+pub fn get_authority_permissions(ctx: Context<GetAuthorityPermissions>) -> Result<()> {
+    get_authority_permissions::handle(ctx)
+}
+
+/// Block a repository host (admin only).
+pub fn add_blocked_host(
+    ctx: Context<AddBlockedHost>,
+    args: AddBlockedHostArgs,
+) -> Result<()> {
+    add_blocked_host::handle(ctx, args)
+}
+
+/// Unblock a repository host (admin only).
+pub fn remove_blocked_host(
+    ctx: Context<RemoveBlockedHost>,
+    args: RemoveBlockedHostArgs,
+) -> Result<()> {
+    remove_blocked_host::handle(ctx, args)
+}
+
+/// Route for the `get_repo` instruction.
+pub fn get_repo(ctx: Context<GetRepo>) -> Result<()> {
+    get_repo::handle(ctx)
+}
+
+/// Set or clear a repository's fee rate override (deployment admin only).
+pub fn set_repo_fee_override(
+    ctx: Context<SetRepoFeeOverride>,
+    args: SetRepoFeeOverrideArgs,
+) -> Result<()> {
+    set_repo_fee_override::handle(ctx, args)
+}
+
+/// Upgrade the global `Config` and `Metrics` accounts to the current schema version.
+pub fn migrate_deployment(ctx: Context<MigrateDeployment>) -> Result<()> {
+    migrate_deployment::handle(ctx)
+}
+
+/// Upgrade a module account to the current schema version.
+pub fn migrate_module(ctx: Context<MigrateModule>) -> Result<()> {
+    migrate_module::handle(ctx)
+}
+
+/// Upgrade a fork account to the current schema version.
+pub fn migrate_fork(ctx: Context<MigrateFork>) -> Result<()> {
+    migrate_fork::handle(ctx)
+}
+
+/// Register a co-admin that can co-sign quorum-gated admin actions.
+pub fn add_co_admin(ctx: Context<AddCoAdmin>, args: AddCoAdminArgs) -> Result<()> {
+    add_co_admin::handle(ctx, args)
+}
+
+/// Remove a co-admin and close its `Authority` entry.
+pub fn remove_co_admin(ctx: Context<RemoveCoAdmin>) -> Result<()> {
+    remove_co_admin::handle(ctx)
+}
@@ -0,0 +1,307 @@
+//! ===========================================================================
+//! Unit09 – Prune Forks Instruction
+//! Path: contracts/unit09-program/programs/unit09_program/src/instructions/prune_forks.rs
+//!
+//! Admin-only instruction that closes inactive, childless `Fork` accounts so
+//! deployments can stay below `SOFT_MAX_FORKS`.
+//!
+//! Batching:
+//!     remaining_accounts = [fork, fork, ..., ancestor, ancestor, ...]   (writable)
+//!
+//! The last `args.ancestor_count` entries are ancestors of the pruned forks.
+//! They are never pruned themselves; they only have their
+//! `descendant_count` brought back down.
+//!
+//! A fork with a parent (`Fork::has_parent`) is only pruned together with
+//! that parent: either the parent `Fork` is in the batch, as a fork or as
+//! an ancestor (it is then detached from the parent), or the parent's fork
+//! PDA (`[FORK_SEED, parent]`) is passed as an empty, system-owned account,
+//! proving the parent is a root identity rather than a fork.
+//!
+//! Every pruned fork is closed to `refund_recipient`, counted out of
+//! `Metrics::total_forks` and reported with a `ForkPruned` event.
+//!
+//! Guards:
+//! - lifecycle must allow wind-down writes (permitted during `Sunset`)
+//! - only `Config::admin` may call this instruction
+//! - every remaining account must be a writable `Fork` owned by this program,
+//!   or an empty parent PDA as described above
+//! - no account may be passed twice in `remaining_accounts`
+//!   (`ValidationFailed`)
+//! - a fork must be inactive and list no children (`Fork::assert_prunable`)
+//! - a fork whose parent is not accounted for in the batch is not pruned
+//!   (`MissingRequiredAccount`), so no parent keeps a closed child listed
+//!
+//! Atomicity:
+//! - with `atomic = true`, a fork that is not prunable fails the whole call
+//! - with `atomic = false`, such forks are left untouched and
+//!   `BatchPartiallyApplied` reports how many were pruned and skipped
+//!
+//! Design notes:
+//! - The pruned fork is removed from its parent's `children` (and
+//!   `descendant_count`). A parent that is not prunable itself (e.g. still
+//!   active) is only updated and persisted.
+//! - Like `create_fork`, the `descendant_count` walk continues up
+//!   `Fork::parent` through every ancestor in the batch and stops at the
+//!   first one that is not passed, so omitted ancestors keep over-counting.
+//! - Forks are processed in order, so passing children before their parents
+//!   prunes a whole inactive subtree in one call.
+//!
+//! ===========================================================================
+
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::Unit09Error;
+use crate::events::{BatchPartiallyApplied, ForkPruned};
+use crate::state::{Config, Fork, Lifecycle, Metrics};
+use crate::utils::time::current_checked;
+use crate::utils::validators::assert_unique_keys;
+
+/// Arguments for the `prune_forks` instruction.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct PruneForksArgs {
+    /// Whether a fork that cannot be pruned reverts the whole call (`true`)
+    /// or is skipped and counted (`false`).
+    pub atomic: bool,
+
+    /// Number of trailing `remaining_accounts` that are ancestors whose
+    /// `descendant_count` is decremented rather than forks to prune.
+    pub ancestor_count: u8,
+}
+
+/// Accounts required for the `prune_forks` instruction.
+///
+/// The `Fork` accounts to close are passed as writable `remaining_accounts`.
+#[derive(Accounts)]
+pub struct PruneForks<'info> {
+    /// Admin signer; must match `config.admin`.
+    pub admin: Signer<'info>,
+
+    /// Global configuration account.
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Lifecycle account controlling global write permissions.
+    #[account(
+        seeds = [LIFECYCLE_SEED.as_bytes()],
+        bump = lifecycle.bump,
+    )]
+    pub lifecycle: Account<'info, Lifecycle>,
+
+    /// Global metrics account; `total_forks` is decremented per pruned fork.
+    #[account(
+        mut,
+        seeds = [METRICS_SEED.as_bytes()],
+        bump = metrics.bump,
+    )]
+    pub metrics: Account<'info, Metrics>,
+
+    /// Account receiving the lamports of every closed fork.
+    #[account(mut)]
+    pub refund_recipient: SystemAccount<'info>,
+
+    /// Clock sysvar used for timestamps.
+    pub clock: Sysvar<'info, Clock>,
+}
+
+// ---------------------------------------------------------------------------
+// Handler
+// ---------------------------------------------------------------------------
+
+/// Entry point for the `prune_forks` instruction.
+///
+/// Steps:
+/// 1. Ensure lifecycle allows wind-down writes and the caller is admin.
+/// 2. Load every fork and ancestor from `remaining_accounts`.
+/// 3. Close each prunable fork to `refund_recipient`, detach it from its
+///    parent in the batch, decrement the ancestors above it and emit
+///    `ForkPruned`; in non-atomic mode, skip forks that are not prunable or
+///    whose parent is missing.
+/// 4. Emit `BatchPartiallyApplied` if any fork was skipped.
+/// 5. Persist the forks and ancestors that were kept.
+pub fn handle<'info>(
+    ctx: Context<'_, '_, 'info, 'info, PruneForks<'info>>,
+    args: PruneForksArgs,
+) -> Result<()> {
+    let remaining_accounts = ctx.remaining_accounts;
+    let PruneForks {
+        admin,
+        config,
+        lifecycle,
+        metrics,
+        refund_recipient,
+        clock,
+    } = ctx.accounts;
+
+    // -----------------------------------------------------------------------
+    // Lifecycle and admin guards
+    // -----------------------------------------------------------------------
+
+    lifecycle.assert_wind_down_allowed()?;
+    config.assert_admin(admin)?;
+    let now = current_checked(clock)?;
+
+    // -----------------------------------------------------------------------
+    // Load forks
+    // -----------------------------------------------------------------------
+
+    assert_unique_keys(remaining_accounts.iter().map(|account_info| account_info.key))?;
+
+    let prune_len = remaining_accounts
+        .len()
+        .checked_sub(args.ancestor_count as usize)
+        .ok_or(Unit09Error::MissingRequiredAccount)?;
+
+    // Forks to prune come first in `forks`, followed by the ancestors.
+    let mut forks: Vec<Account<'info, Fork>> = Vec::with_capacity(remaining_accounts.len());
+    let mut absent_parents: Vec<Pubkey> = Vec::new();
+    let mut candidate_count: usize = 0;
+    for (position, account_info) in remaining_accounts.iter().enumerate() {
+        let is_ancestor = position >= prune_len;
+        // An empty parent PDA stands for a parent that is not a fork.
+        if !is_ancestor && account_info.owner == &System::id() && account_info.data_is_empty() {
+            absent_parents.push(account_info.key());
+            continue;
+        }
+        if !account_info.is_writable {
+            return err!(Unit09Error::ExpectedWritableAccount);
+        }
+        forks.push(Account::try_from(account_info)?);
+        if !is_ancestor {
+            candidate_count += 1;
+        }
+    }
+
+    // -----------------------------------------------------------------------
+    // Prune
+    // -----------------------------------------------------------------------
+
+    let mut closed: Vec<Pubkey> = Vec::with_capacity(forks.len());
+    let mut skipped: u32 = 0;
+
+    for index in 0..candidate_count {
+        let prunable = forks[index].assert_prunable().and_then(|()| {
+            find_parent(&forks, &absent_parents, &forks[index])
+        });
+        let parent_index = match prunable {
+            Ok(parent_index) => parent_index,
+            Err(err) => {
+                if args.atomic {
+                    return Err(err);
+                }
+                skipped += 1;
+                continue;
+            }
+        };
+
+        if let Some(parent_index) = parent_index {
+            let child_key = forks[index].fork_key;
+            let parent = &mut forks[parent_index];
+            if parent.remove_child(&child_key) {
+                parent.decrement_descendants();
+                parent.updated_at = now;
+            }
+            decrement_ancestors(&mut forks, parent_index, now);
+        }
+
+        let fork = &forks[index];
+        let lamports = fork.to_account_info().lamports();
+        fork.close(refund_recipient.to_account_info())?;
+        closed.push(fork.key());
+
+        metrics.decrement_forks()?;
+
+        let event_seq = metrics.next_event_seq()?;
+        emit!(ForkPruned {
+            fork: fork.key(),
+            fork_key: fork.fork_key,
+            refund_recipient: refund_recipient.key(),
+            lamports,
+            event_seq,
+        });
+    }
+
+    metrics.updated_at = now;
+
+    if skipped > 0 {
+        let event_seq = metrics.next_event_seq()?;
+        emit!(BatchPartiallyApplied {
+            instruction: "prune_forks".to_string(),
+            signer: admin.key(),
+            applied: closed.len() as u32,
+            skipped,
+            event_seq,
+        });
+    }
+
+    // -----------------------------------------------------------------------
+    // Persist kept forks and ancestors
+    // -----------------------------------------------------------------------
+
+    for fork in forks.iter() {
+        if !closed.contains(&fork.key()) {
+            fork.exit(&crate::ID)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Locate the parent of `fork` for pruning.
+///
+/// Returns the index of the parent in `forks`, or `None` when the fork has
+/// no parent or its parent's fork PDA is listed in `absent_parents`. Fails
+/// with `MissingRequiredAccount` when the parent is not accounted for.
+fn find_parent(
+    forks: &[Account<Fork>],
+    absent_parents: &[Pubkey],
+    fork: &Fork,
+) -> Result<Option<usize>> {
+    if !fork.has_parent() {
+        return Ok(None);
+    }
+
+    if let Some(parent_index) = forks
+        .iter()
+        .position(|candidate| candidate.fork_key == fork.parent)
+    {
+        return Ok(Some(parent_index));
+    }
+
+    let (parent_pda, _) =
+        Pubkey::find_program_address(&[FORK_SEED.as_bytes(), fork.parent.as_ref()], &crate::ID);
+    if absent_parents.contains(&parent_pda) {
+        return Ok(None);
+    }
+
+    err!(Unit09Error::MissingRequiredAccount)
+}
+
+/// Decrement `descendant_count` on every ancestor above `forks[index]` that
+/// is passed in the batch, following `Fork::parent` like `create_fork` does.
+///
+/// The walk stops at the first ancestor that is not in `forks`, and after
+/// `MAX_FORK_DEPTH` steps.
+fn decrement_ancestors(forks: &mut [Account<Fork>], mut index: usize, now: i64) {
+    for _ in 0..MAX_FORK_DEPTH {
+        if !forks[index].has_parent() {
+            return;
+        }
+        let parent_key = forks[index].parent;
+        let parent_index = match forks
+            .iter()
+            .position(|candidate| candidate.fork_key == parent_key)
+        {
+            Some(parent_index) => parent_index,
+            None => return,
+        };
+        let ancestor = &mut forks[parent_index];
+        ancestor.decrement_descendants();
+        ancestor.updated_at = now;
+        index = parent_index;
+    }
+}
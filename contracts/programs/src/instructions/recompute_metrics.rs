@@ -0,0 +1,221 @@
+//! ===========================================================================
+//! Unit09 – Recompute Metrics Instruction
+//! Path: contracts/unit09-program/programs/unit09_program/src/instructions/recompute_metrics.rs
+//!
+//! This instruction lets the admin re-derive the repository-based totals in
+//! `Metrics` from the `Repo` accounts themselves, instead of supplying them
+//! by hand through `record_metrics`.
+//!
+//! Recomputed totals:
+//! - `total_repos` / `active_repos`
+//! - `total_modules`            (sum of `Repo::module_count`)
+//! - `total_observations`       (sum of `Repo::observation_count`)
+//! - `total_lines_of_code`      (sum of `Repo::total_lines_of_code`)
+//! - `total_files_processed`    (sum of `Repo::total_files_processed`)
+//!
+//! Fork counters and `active_modules` cannot be derived from `Repo` accounts
+//! and are left untouched. Repositories still reserved by `reserve_repo` are
+//! skipped, as they are not counted until `finalize_repo`.
+//!
+//! Batching:
+//!     remaining_accounts = [repo, repo, ...]
+//!
+//! Large deployments cannot pass every repository in one transaction, so
+//! the work can be split across batches. The totals themselves act as the
+//! running accumulator:
+//! - the first batch sets `reset`, which zeroes the totals and marks
+//!   `Metrics::recompute_in_progress`
+//! - every batch adds its repositories to the totals
+//! - the last batch sets `finalize`, which clears the in-progress flag and
+//!   emits `MetricsRecomputed` and `MetricsDriftReported`
+//!
+//! `MetricsDriftReported` carries the signed difference between each
+//! recomputed total and its value when `reset` was applied, quantifying how
+//! far the live counters had drifted.
+//!
+//! A single call with both flags set recomputes everything at once. Each
+//! repository must be passed exactly once across all batches, and the
+//! deployment should be quiet (for example in `Maintenance`) while a
+//! multi-batch run is in progress; observations recorded in between are
+//! otherwise counted twice or not at all.
+//!
+//! Guards:
+//! - lifecycle must allow wind-down writes (permitted during `Sunset`)
+//! - only `Config::admin` may call this instruction
+//! - every remaining account must be a `Repo` owned by this program
+//!   (`InvalidAccountOwner` / `InvalidAccountDiscriminator`)
+//! - no account may be passed twice in `remaining_accounts`
+//!   (`ValidationFailed`)
+//! - a batch without `reset` requires a recomputation in progress
+//!   (`MetricsRecomputeNotStarted`)
+//!
+//! ===========================================================================
+
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::Unit09Error;
+use crate::events::{emit_if_active, MetricsDriftReported, MetricsRecomputed, Unit09Log};
+use crate::state::{Config, Lifecycle, Metrics, Repo};
+use crate::utils::time::current_checked;
+use crate::utils::validators::assert_unique_keys;
+
+/// Arguments for the `recompute_metrics` instruction.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct RecomputeMetricsArgs {
+    /// Zero the recomputed totals before summing this batch.
+    pub reset: bool,
+
+    /// Mark the recomputation as complete after summing this batch.
+    pub finalize: bool,
+}
+
+/// Accounts required for the `recompute_metrics` instruction.
+#[derive(Accounts)]
+pub struct RecomputeMetrics<'info> {
+    /// Admin signer; must match `config.admin`.
+    pub admin: Signer<'info>,
+
+    /// Global configuration account.
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Lifecycle account controlling global write permissions.
+    #[account(
+        seeds = [LIFECYCLE_SEED.as_bytes()],
+        bump = lifecycle.bump,
+    )]
+    pub lifecycle: Account<'info, Lifecycle>,
+
+    /// Global metrics account.
+    #[account(
+        mut,
+        seeds = [METRICS_SEED.as_bytes()],
+        bump = metrics.bump,
+    )]
+    pub metrics: Account<'info, Metrics>,
+
+    /// Clock sysvar used for timestamps.
+    pub clock: Sysvar<'info, Clock>,
+}
+
+// ---------------------------------------------------------------------------
+// Handler
+// ---------------------------------------------------------------------------
+
+/// Entry point for the `recompute_metrics` instruction.
+///
+/// Steps:
+/// 1. Ensure lifecycle allows wind-down writes and the caller is admin.
+/// 2. Start a new recomputation when `reset` is set.
+/// 3. Add every non-reserved `Repo` in `remaining_accounts` to the running
+///    totals and log the batch size (`Unit09Log`, debug tier).
+/// 4. When `finalize` is set, complete the run and emit `MetricsRecomputed`
+///    and `MetricsDriftReported`.
+pub fn handle(ctx: Context<RecomputeMetrics>, args: RecomputeMetricsArgs) -> Result<()> {
+    let RecomputeMetrics {
+        admin,
+        config,
+        lifecycle,
+        metrics,
+        clock,
+    } = ctx.accounts;
+
+    let clock_ref: &Clock = clock;
+    current_checked(clock_ref)?;
+
+    // -----------------------------------------------------------------------
+    // Lifecycle and admin guards
+    // -----------------------------------------------------------------------
+
+    lifecycle.assert_wind_down_allowed()?;
+    config.assert_admin(admin)?;
+
+    // -----------------------------------------------------------------------
+    // Accumulate repository counters
+    // -----------------------------------------------------------------------
+
+    if args.reset {
+        metrics.begin_recompute(clock_ref);
+    }
+
+    assert_unique_keys(ctx.remaining_accounts.iter().map(|account_info| account_info.key))?;
+
+    let mut accumulated: u32 = 0;
+    for account_info in ctx.remaining_accounts.iter() {
+        let repo = load_repo(account_info)?;
+        if repo.is_reserved {
+            continue;
+        }
+        accumulated += 1;
+        metrics.accumulate_repo(
+            repo.is_active,
+            repo.module_count,
+            repo.observation_count,
+            repo.total_lines_of_code,
+            repo.total_files_processed,
+        )?;
+    }
+
+    emit_if_active!(config, lifecycle, EVENT_VERBOSITY_DEBUG, Unit09Log {
+        category: "recompute_metrics".to_string(),
+        message: format!("accumulated {} repos", accumulated),
+        logged_at: clock_ref.unix_timestamp,
+        event_seq: metrics.next_event_seq()?,
+    });
+
+    if !args.finalize {
+        // An empty non-reset batch must still belong to a running recompute.
+        if !metrics.recompute_in_progress {
+            return err!(Unit09Error::MetricsRecomputeNotStarted);
+        }
+        return Ok(());
+    }
+
+    // -----------------------------------------------------------------------
+    // Finalize and emit MetricsRecomputed / MetricsDriftReported
+    // (optional telemetry)
+    // -----------------------------------------------------------------------
+
+    metrics.finish_recompute(clock_ref)?;
+
+    emit_if_active!(config, lifecycle, MetricsRecomputed {
+        admin: admin.key(),
+        total_repos: metrics.total_repos,
+        active_repos: metrics.active_repos,
+        total_modules: metrics.total_modules,
+        total_observations: metrics.total_observations,
+        total_lines_of_code: metrics.total_lines_of_code,
+        total_files_processed: metrics.total_files_processed,
+        started_at: metrics.recompute_started_at,
+        updated_at: metrics.updated_at,
+        event_seq: metrics.next_event_seq()?,
+    });
+
+    let drift = metrics.recompute_drift();
+    emit_if_active!(config, lifecycle, MetricsDriftReported {
+        admin: admin.key(),
+        total_repos_delta: drift.total_repos,
+        active_repos_delta: drift.active_repos,
+        total_modules_delta: drift.total_modules,
+        total_observations_delta: drift.total_observations,
+        total_lines_of_code_delta: drift.total_lines_of_code,
+        total_files_processed_delta: drift.total_files_processed,
+        event_seq: metrics.next_event_seq()?,
+    });
+
+    Ok(())
+}
+
+/// Deserialize a `Repo` passed in `remaining_accounts`.
+fn load_repo(account_info: &AccountInfo) -> Result<Repo> {
+    if account_info.owner != &crate::ID {
+        return err!(Unit09Error::InvalidAccountOwner);
+    }
+    let data = account_info.try_borrow_data()?;
+    Repo::try_deserialize(&mut &data[..])
+        .map_err(|_| error!(Unit09Error::InvalidAccountDiscriminator))
+}
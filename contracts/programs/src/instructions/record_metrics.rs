@@ -15,11 +15,21 @@
 //!   It only updates the global `Metrics` aggregates.
 //! - Only the current `Config::admin` is allowed to call this instruction.
 //! - All fields in `RecordMetricsArgs` are optional. `None` means
-//!   "do not change this value".
+//!   "do not change this value". At least one field must be `Some`; an
+//!   all-`None` call is rejected with `ValidationFailed` so that empty
+//!   reconciliations never reach the event history.
+//!
+//! Circuit breaker:
+//! - When `Config::auto_freeze_on_inconsistency` is set and a provided value
+//!   would shrink a total by more than `CIRCUIT_BREAKER_MAX_DECREASE_BPS`,
+//!   the reconciliation is NOT applied. Instead the lifecycle is moved to
+//!   `Frozen` and a `CircuitBreakerTripped` event is emitted. The
+//!   instruction still succeeds so that the freeze is persisted.
 //!
 //! On success this instruction:
 //! - calls `Metrics::adjust_aggregate` with the provided values
-//! - updates `metrics.updated_at` using the current clock
+//! - updates `metrics.updated_at` using the current clock, rejecting a
+//!   clock behind the stored value with `InvalidTimeRange`
 //! - emits a `MetricsReconciled` event for indexers and dashboards
 //!
 //! ===========================================================================
@@ -27,15 +37,17 @@
 use anchor_lang::prelude::*;
 
 use crate::errors::Unit09Error;
-use crate::events::MetricsReconciled;
-use crate::state::{Config, Lifecycle, Metrics};
+use crate::events::{emit_if_active, CircuitBreakerTripped, MetricsReconciled};
+use crate::state::{Config, Lifecycle, LifecyclePhase, Metrics};
+use crate::utils::time::current_checked;
+use crate::utils::validators::Validate;
 
 /// Arguments for the `record_metrics` instruction.
 ///
 /// Each field is optional. When a value is `Some`, it replaces the existing
 /// value on the `Metrics` account. When a value is `None`, the existing value
 /// is kept as-is.
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default)]
 pub struct RecordMetricsArgs {
     /// Optional new total number of repositories.
     pub total_repos: Option<u64>,
@@ -56,6 +68,42 @@ pub struct RecordMetricsArgs {
     pub total_files_processed: Option<u64>,
 }
 
+impl RecordMetricsArgs {
+    /// Returns true if no field is set, i.e. the call would change nothing.
+    pub fn is_empty(&self) -> bool {
+        self.total_repos.is_none()
+            && self.total_modules.is_none()
+            && self.total_forks.is_none()
+            && self.total_observations.is_none()
+            && self.total_lines_of_code.is_none()
+            && self.total_files_processed.is_none()
+    }
+}
+
+impl Validate for RecordMetricsArgs {
+    /// At least one field set (`ValidationFailed`) and none equal to
+    /// `u64::MAX` (`ValueOutOfRange`). More complex consistency rules are
+    /// left to off-chain tooling and the circuit breaker.
+    fn validate(&self) -> Result<()> {
+        if self.is_empty() {
+            return err!(Unit09Error::ValidationFailed);
+        }
+
+        let values = [
+            self.total_repos,
+            self.total_modules,
+            self.total_forks,
+            self.total_observations,
+            self.total_lines_of_code,
+            self.total_files_processed,
+        ];
+        if values.iter().flatten().any(|v| *v == u64::MAX) {
+            return err!(Unit09Error::ValueOutOfRange);
+        }
+        Ok(())
+    }
+}
+
 /// Accounts required for the `record_metrics` instruction.
 #[derive(Accounts)]
 pub struct RecordMetrics<'info> {
@@ -106,12 +154,15 @@ pub struct RecordMetrics<'info> {
 /// Entry point for the `record_metrics` instruction.
 ///
 /// Steps:
-/// 1. Ensure lifecycle allows writes.
+/// 1. Ensure lifecycle allows wind-down writes (permitted during `Sunset`).
 /// 2. Verify `admin` matches `config.admin`.
 /// 3. Optionally ensure config is active.
-/// 4. Perform light validation on provided values.
-/// 5. Call `Metrics::adjust_aggregate`.
-/// 6. Emit `MetricsReconciled` event.
+/// 4. Reject an all-`None` args set, then perform light validation on
+///    provided values.
+/// 5. Trip the circuit breaker on suspicious values, if enabled.
+/// 6. Call `Metrics::adjust_aggregate`.
+/// 7. Emit `MetricsReconciled` event, unless suppressed by
+///    `Config::quiet_when_frozen`.
 pub fn handle(ctx: Context<RecordMetrics>, args: RecordMetricsArgs) -> Result<()> {
     let RecordMetrics {
         admin,
@@ -123,13 +174,15 @@ pub fn handle(ctx: Context<RecordMetrics>, args: RecordMetricsArgs) -> Result<()
     } = ctx.accounts;
 
     let clock_ref: &Clock = clock;
+    current_checked(clock_ref)?;
 
     // -----------------------------------------------------------------------
     // Lifecycle and config guards
     // -----------------------------------------------------------------------
 
-    // Ensure writes are allowed at the lifecycle level.
-    lifecycle.assert_writes_allowed()?;
+    // Metrics reconciliation is a wind-down operation and stays available
+    // during the `Sunset` phase.
+    lifecycle.assert_wind_down_allowed()?;
 
     // Ensure the caller is the current admin.
     config.assert_admin(admin)?;
@@ -146,39 +199,37 @@ pub fn handle(ctx: Context<RecordMetrics>, args: RecordMetricsArgs) -> Result<()
     // (such as u64::MAX). More complex consistency rules should be enforced
     // by off-chain tooling before calling this instruction.
 
-    if let Some(v) = args.total_repos {
-        if v == u64::MAX {
-            return err!(Unit09Error::ValueOutOfRange);
-        }
-    }
-
-    if let Some(v) = args.total_modules {
-        if v == u64::MAX {
-            return err!(Unit09Error::ValueOutOfRange);
-        }
-    }
-
-    if let Some(v) = args.total_forks {
-        if v == u64::MAX {
-            return err!(Unit09Error::ValueOutOfRange);
-        }
-    }
-
-    if let Some(v) = args.total_observations {
-        if v == u64::MAX {
-            return err!(Unit09Error::ValueOutOfRange);
-        }
-    }
+    args.validate()?;
 
-    if let Some(v) = args.total_lines_of_code {
-        if v == u64::MAX {
-            return err!(Unit09Error::ValueOutOfRange);
-        }
-    }
+    // -----------------------------------------------------------------------
+    // Circuit breaker
+    // -----------------------------------------------------------------------
 
-    if let Some(v) = args.total_files_processed {
-        if v == u64::MAX {
-            return err!(Unit09Error::ValueOutOfRange);
+    if config.auto_freeze_on_inconsistency {
+        if let Some(suspicious) = metrics.find_suspicious_adjustment(
+            args.total_repos,
+            args.total_modules,
+            args.total_forks,
+            args.total_observations,
+            args.total_lines_of_code,
+            args.total_files_processed,
+        ) {
+            lifecycle.set_phase(LifecyclePhase::Frozen, clock_ref)?;
+            lifecycle.update_freeze_reason(format!("circuit breaker: {}", suspicious.counter))?;
+
+            let event_seq = metrics.next_event_seq()?;
+            emit!(CircuitBreakerTripped {
+                admin: Some(admin.key()),
+                triggered_by: admin.key(),
+                counter: suspicious.counter.to_string(),
+                current_value: suspicious.current_value,
+                proposed_value: suspicious.proposed_value,
+                tripped_at: clock_ref.unix_timestamp,
+                event_seq,
+            });
+
+            // Return Ok so the freeze is committed; the values are discarded.
+            return Ok(());
         }
     }
 
@@ -201,10 +252,10 @@ pub fn handle(ctx: Context<RecordMetrics>, args: RecordMetricsArgs) -> Result<()
     metrics.updated_at = clock_ref.unix_timestamp;
 
     // -----------------------------------------------------------------------
-    // Emit MetricsReconciled event
+    // Emit MetricsReconciled event (optional telemetry)
     // -----------------------------------------------------------------------
 
-    emit!(MetricsReconciled {
+    emit_if_active!(config, lifecycle, MetricsReconciled {
         admin: config.admin,
         total_repos: metrics.total_repos,
         total_modules: metrics.total_modules,
@@ -212,7 +263,11 @@ pub fn handle(ctx: Context<RecordMetrics>, args: RecordMetricsArgs) -> Result<()
         total_observations: metrics.total_observations,
         total_lines_of_code: metrics.total_lines_of_code,
         total_files_processed: metrics.total_files_processed,
+        active_repos: metrics.active_repos,
+        active_modules: metrics.active_modules,
+        active_forks: metrics.active_forks,
         updated_at: metrics.updated_at,
+        event_seq: metrics.next_event_seq()?,
     });
 
     Ok(())
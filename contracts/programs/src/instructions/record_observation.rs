@@ -13,14 +13,62 @@
 //!
 //! On success this instruction:
 //! - updates per-repo observation stats on the `Repo` account
+//! - optionally attributes the observation to one module of the repo,
+//!   bumping `Module::observation_count`
 //! - aggregates metrics into the global `Metrics` account
 //! - emits an `ObservationRecorded` event for indexers and dashboards
 //!
 //! Guards:
 //! - lifecycle must allow writes (`Lifecycle::assert_writes_allowed`)
 //! - global config must be active (`Config::assert_active`)
-//! - repo must be active and allow observation (`Repo::assert_observable`)
-//! - any signer may perform an observation if the repo allows it
+//! - repo must be active and allow observation (`Repo::assert_observation_allowed`)
+//! - observer must be in `Repo::allowed_observers` when that list is
+//!   non-empty (`Repo::assert_observer_allowed`); otherwise any signer may
+//!   perform an observation if the repo allows it
+//! - `observed_at_override` may only be set by the `Config::admin`
+//! - the deployment-wide budget `Config::max_observations_per_window` must
+//!   not be exhausted (`Metrics::consume_observation_budget`)
+//! - when `Config::credits_per_observation` is non-zero, the observer's
+//!   `ObserverCredits` must cover it (`ObserverCredits::charge`)
+//! - when `Repo::require_access_proof` is set, the observation must carry
+//!   an `access_proof` (`ObservationNotAllowed`); only the latest proof
+//!   hash is stored, verification happens off-chain
+//! - when `Config::min_observer_lamports` is non-zero, the observer's
+//!   wallet must hold at least that many lamports
+//!   (`InsufficientObserverBalance`)
+//! - the observation cooldown must have elapsed since the repository was
+//!   last observed (`CooldownActive`, see `CooldownConfig`); the program
+//!   log then carries `retry_at=<unix seconds>`, `last_observed_at` plus
+//!   the window
+//! - an optional `attestor` may co-sign the observation for provenance; it
+//!   must differ from the observer and is mandatory when
+//!   `Config::require_attestor` is set (`Config::resolve_attestor`)
+//!
+//! Counter headroom:
+//! - if a global counter is within `METRICS_OVERFLOW_MARGIN` of `u64::MAX`
+//!   (for example after a bad reconciliation), the observation is not
+//!   applied instead of failing deep inside with `CounterOverflow`:
+//!   - with `Config::auto_freeze_on_inconsistency` set, the lifecycle is
+//!     frozen and `CircuitBreakerTripped` is emitted; the call returns `Ok`
+//!     so the freeze is committed
+//!   - otherwise the call fails with `MetricsInconsistent`
+//!
+//! Retries:
+//! - a call carrying the same `idempotency_key` as the last keyed
+//!   observation of the repo, within `IDEMPOTENCY_WINDOW_SECS`, succeeds as
+//!   a no-op (`Repo::is_idempotent_replay`), so at-least-once workers can
+//!   resubmit safely
+//!
+//! Metadata-only runs:
+//! - with `kind` set to `ObservationKind::MetadataOnly` only the repo's
+//!   `last_observed_at`, revision and note are refreshed
+//!   (`Repo::record_metadata_observation`), along with a supplied
+//!   `source_region_hash` and `access_proof`; LOC, files and modules must
+//!   be zero and no module may be attributed
+//! - `Metrics` is not modified at all: no overflow headroom check, no
+//!   observation budget, no aggregates and no `ObservationRecorded` event,
+//!   since event sequence numbers are drawn from `Metrics`
+//! - the guards above, credits metering and idempotent retries still apply
 //!
 //! Typical usage (off-chain worker):
 //! - run analysis on a repo at a particular commit
@@ -33,8 +81,12 @@ use anchor_lang::prelude::*;
 
 use crate::constants::*;
 use crate::errors::Unit09Error;
-use crate::events::ObservationRecorded;
-use crate::state::{Config, Lifecycle, Metrics, Repo};
+use crate::events::{CircuitBreakerTripped, ObservationRecorded, RepoObservationCapReached};
+use crate::state::{
+    Config, Lifecycle, LifecyclePhase, Metrics, Module, ObservationKind, ObserverCredits, Repo,
+};
+use crate::utils::time::current_checked;
+use crate::utils::validators::{assert_max_len, validate_revision, Validate};
 
 /// Arguments for the `record_observation` instruction.
 ///
@@ -65,6 +117,101 @@ pub struct RecordObservationArgs {
     /// - "incremental diff since last observation"
     /// - "hot path refactor analysis"
     pub note: String,
+
+    /// Optional module PDA to attribute this observation to.
+    ///
+    /// When set, the matching `module` account must be provided and must
+    /// belong to the observed repository.
+    pub module: Option<Pubkey>,
+
+    /// Optional "as of" timestamp for backfilling historical scans.
+    ///
+    /// Admin only. Clamped so it cannot be in the future and used instead of
+    /// the clock for `Repo::last_observed_at` (see
+    /// `Config::resolve_observed_at`).
+    pub observed_at_override: Option<i64>,
+
+    /// Optional client-chosen key identifying this observation across
+    /// retries.
+    ///
+    /// A retry presenting the repository's last key within
+    /// `IDEMPOTENCY_WINDOW_SECS` returns success without applying anything.
+    pub idempotency_key: Option<[u8; IDEMPOTENCY_KEY_LEN]>,
+
+    /// Optional hash of the worker region that produced this observation,
+    /// for bucketing by region without storing IPs or other PII.
+    ///
+    /// Stored as `Repo::last_source_region_hash`. All zero is rejected with
+    /// `MetadataInvalid`.
+    pub source_region_hash: Option<[u8; SOURCE_REGION_HASH_LEN]>,
+
+    /// Optional proof-of-access token hash for private repositories.
+    ///
+    /// Mandatory when `Repo::require_access_proof` is set. Stored as
+    /// `Repo::last_access_proof`; the program does not verify it.
+    pub access_proof: Option<[u8; 32]>,
+
+    /// `ObservationKind` as `u8`: 0 for a full scan, 1 for a metadata-only
+    /// run that skips all aggregates. Other values fail with
+    /// `ValidationFailed`.
+    pub kind: u8,
+}
+
+impl RecordObservationArgs {
+    /// Whether this is an `ObservationKind::MetadataOnly` run.
+    pub fn is_metadata_only(&self) -> bool {
+        ObservationKind::from_u8(self.kind) == Some(ObservationKind::MetadataOnly)
+    }
+}
+
+impl Validate for RecordObservationArgs {
+    /// A known `kind`; for full scans non-zero lines of code and files
+    /// within the per-observation maxima and `modules_touched` within its
+    /// maximum, for metadata-only runs all three zero and no module. Always
+    /// a well-formed revision, a note within `Repo::MAX_OBSERVATION_NOTE_LEN`,
+    /// and a non-zero source region hash. `Config::min_loc_per_observation`
+    /// is checked by the handler.
+    fn validate(&self) -> Result<()> {
+        let kind = ObservationKind::from_u8(self.kind).ok_or(Unit09Error::ValidationFailed)?;
+
+        if kind == ObservationKind::MetadataOnly {
+            // Nothing is aggregated, so non-zero counts would be silently lost.
+            if self.lines_of_code != 0
+                || self.files_processed != 0
+                || self.modules_touched != 0
+                || self.module.is_some()
+            {
+                return err!(Unit09Error::ValidationFailed);
+            }
+        } else {
+            if self.lines_of_code == 0 {
+                return err!(Unit09Error::ValueOutOfRange);
+            }
+            if self.lines_of_code > MAX_LOC_PER_OBSERVATION {
+                return err!(Unit09Error::ObservationDataTooLarge);
+            }
+
+            if self.files_processed == 0 {
+                return err!(Unit09Error::ValueOutOfRange);
+            }
+            if self.files_processed as u64 > MAX_FILES_PER_OBSERVATION as u64 {
+                return err!(Unit09Error::ObservationDataTooLarge);
+            }
+
+            // `modules_touched` can be zero, but we still enforce an upper
+            // bound to avoid nonsensical values.
+            if self.modules_touched as u64 > MAX_MODULES_PER_OBSERVATION as u64 {
+                return err!(Unit09Error::ObservationDataTooLarge);
+            }
+        }
+
+        validate_revision(&self.revision)?;
+        assert_max_len(&self.note, Repo::MAX_OBSERVATION_NOTE_LEN)?;
+        if let Some(hash) = self.source_region_hash.as_ref() {
+            Repo::validate_source_region_hash(hash)?;
+        }
+        Ok(())
+    }
 }
 
 /// Accounts required for the `record_observation` instruction.
@@ -79,6 +226,12 @@ pub struct RecordObservation<'info> {
     #[account(mut)]
     pub observer: Signer<'info>,
 
+    /// Optional second signer vouching for the observation.
+    ///
+    /// Stored as `Repo::last_attestor` and reported in `ObservationRecorded`.
+    /// Required when `Config::require_attestor` is set.
+    pub attestor: Option<Signer<'info>>,
+
     /// Global configuration account.
     #[account(
         mut,
@@ -118,6 +271,48 @@ pub struct RecordObservation<'info> {
     )]
     pub repo: Account<'info, Repo>,
 
+    /// Optional module the observation is attributed to.
+    ///
+    /// Required when `args.module` is set. The PDA is checked against the
+    /// module's own `repo`, so a module from another repository fails in the
+    /// handler with `ModuleRepoMismatch` rather than a seeds error.
+    ///
+    /// PDA:
+    ///   seeds = [
+    ///       MODULE_SEED.as_bytes(),
+    ///       module.repo.as_ref(),
+    ///       module.module_key.as_ref(),
+    ///   ]
+    ///   bump  = module.bump
+    #[account(
+        mut,
+        seeds = [
+            MODULE_SEED.as_bytes(),
+            module.repo.as_ref(),
+            module.module_key.as_ref(),
+        ],
+        bump = module.bump,
+    )]
+    pub module: Option<Account<'info, Module>>,
+
+    /// Optional prepaid credits of `observer`.
+    ///
+    /// Required when `Config::credits_per_observation` is non-zero; its
+    /// absence then fails with `InsufficientCredits`.
+    ///
+    /// PDA:
+    ///   seeds = [OBSERVER_CREDITS_SEED.as_bytes(), observer.key().as_ref()]
+    ///   bump  = observer_credits.bump
+    #[account(
+        mut,
+        seeds = [
+            OBSERVER_CREDITS_SEED.as_bytes(),
+            observer.key().as_ref(),
+        ],
+        bump = observer_credits.bump,
+    )]
+    pub observer_credits: Option<Account<'info, ObserverCredits>>,
+
     /// System program.
     pub system_program: Program<'info, System>,
 
@@ -133,84 +328,200 @@ pub struct RecordObservation<'info> {
 ///
 /// Steps:
 /// 1. Enforce lifecycle and config guards.
-/// 2. Enforce that the repo is active and observable.
-/// 3. Validate numeric fields against configured bounds.
-/// 4. Apply per-repo observation update.
-/// 5. Aggregate values into global metrics.
-/// 6. Emit `ObservationRecorded` event.
+/// 2. Return early if `args.idempotency_key` marks this call as a retry of
+///    an already applied observation, before any balance, access-proof or
+///    cooldown check can reject the retry.
+/// 3. Enforce that the repo is active and observable by this observer,
+///    resolve the (possibly backfilled) observation timestamp, and check
+///    the attestor.
+/// 4. Validate arguments (`args.validate()`) and the configured minimum LOC.
+///    Metadata-only runs charge credits, refresh the repo's revision, note,
+///    region hash and access proof, and return without touching `Metrics`. Full scans return early
+///    if a global counter is too close to overflow (tripping the circuit
+///    breaker when enabled).
+/// 5. Charge `Config::credits_per_observation` to the observer's credits.
+/// 6. Consume the deployment-wide observation budget.
+/// 7. Apply per-repo observation update and remember the idempotency key
+///    and source region hash.
+/// 8. Attribute the observation to `args.module`, if set.
+/// 9. Aggregate values into global metrics.
+/// 10. Emit `ObservationRecorded` event, plus `RepoObservationCapReached`
+///     when the repository just reached its soft observation cap.
 pub fn handle(ctx: Context<RecordObservation>, args: RecordObservationArgs) -> Result<()> {
     let RecordObservation {
         observer,
+        attestor,
         mut config,
         mut lifecycle,
         mut metrics,
         mut repo,
+        module,
+        observer_credits,
         system_program: _,
         clock,
     } = ctx.accounts;
 
     let clock_ref: &Clock = clock;
+    current_checked(clock_ref)?;
 
     // -----------------------------------------------------------------------
     // Lifecycle and configuration guards
     // -----------------------------------------------------------------------
 
+    // New activity; hard-blocked during `Sunset` (`InvalidLifecycleState`).
     lifecycle.assert_writes_allowed()?;
     config.assert_active()?;
 
+    // -----------------------------------------------------------------------
+    // Idempotent retries
+    // -----------------------------------------------------------------------
+
+    // Nothing is applied, consumed or emitted for a replay. Checked ahead of
+    // the balance, access-proof and cooldown guards so a retry inside the
+    // cooldown window still succeeds.
+    if let Some(key) = args.idempotency_key.as_ref() {
+        if repo.is_idempotent_replay(key, clock_ref.unix_timestamp) {
+            return Ok(());
+        }
+    }
+
+    // -----------------------------------------------------------------------
+    // Observer and repository guards
+    // -----------------------------------------------------------------------
+
+    // Anti-sybil heuristic; no-op while `min_observer_lamports` is 0.
+    config.assert_observer_balance(observer.lamports())?;
+
     // Ensure repository is active and allows observation.
     repo.assert_active()?;
-    repo.assert_observable()?;
+    repo.assert_observation_allowed()?;
+    repo.assert_observer_allowed(&observer.key())?;
+    repo.assert_access_proof(args.access_proof.as_ref())?;
+    config
+        .cooldowns
+        .assert_observation_elapsed(repo.last_observed_at, clock_ref)?;
+
+    // Backfill timestamp, admin only; `clock` otherwise.
+    let observed_at =
+        config.resolve_observed_at(&observer.key(), args.observed_at_override, clock_ref)?;
+
+    // Provenance co-signer; mandatory when `require_attestor` is set.
+    let attestor_key = attestor.as_ref().map(|attestor| attestor.key());
+    let attestor_key = config.resolve_attestor(&observer.key(), attestor_key.as_ref())?;
 
     // -----------------------------------------------------------------------
-    // Early validation on numeric fields
+    // Early argument validation
     // -----------------------------------------------------------------------
 
-    if args.lines_of_code == 0 {
-        return err!(Unit09Error::ValueOutOfRange);
-    }
-    if args.lines_of_code > MAX_LOC_PER_OBSERVATION {
-        return err!(Unit09Error::ObservationDataTooLarge);
+    args.validate()?;
+    let metadata_only = args.is_metadata_only();
+    if !metadata_only {
+        config.assert_min_loc(args.lines_of_code)?;
     }
 
-    if args.files_processed == 0 {
-        return err!(Unit09Error::ValueOutOfRange);
-    }
-    if args.files_processed as u64 > MAX_FILES_PER_OBSERVATION as u64 {
-        return err!(Unit09Error::ObservationDataTooLarge);
-    }
+    // -----------------------------------------------------------------------
+    // Metadata-only fast path
+    // -----------------------------------------------------------------------
 
-    // `modules_touched` can be zero (for example, metadata-only runs), but
-    // we still enforce an upper bound to avoid nonsensical values.
-    if args.modules_touched as u64 > MAX_MODULES_PER_OBSERVATION as u64 {
-        return err!(Unit09Error::ObservationDataTooLarge);
+    // No `Metrics` field is read or written past this point for such runs.
+    if metadata_only {
+        ObserverCredits::charge(
+            observer_credits.as_deref_mut(),
+            config.credits_per_observation,
+            clock_ref,
+        )?;
+        repo.record_metadata_observation(args.revision, args.note, observed_at, clock_ref)?;
+        if let Some(key) = args.idempotency_key {
+            repo.remember_idempotency_key(key, clock_ref.unix_timestamp);
+        }
+        if let Some(hash) = args.source_region_hash {
+            repo.last_source_region_hash = hash;
+        }
+        if let Some(proof) = args.access_proof {
+            repo.last_access_proof = proof;
+        }
+        return Ok(());
     }
 
     // -----------------------------------------------------------------------
-    // Basic validation on string fields
+    // Counter headroom
     // -----------------------------------------------------------------------
 
-    if args.revision.len() > Repo::MAX_REVISION_LEN {
-        return err!(Unit09Error::StringTooLong);
+    if let Some(near) = metrics.find_counter_near_overflow(METRICS_OVERFLOW_MARGIN) {
+        if !config.auto_freeze_on_inconsistency {
+            return err!(Unit09Error::MetricsInconsistent);
+        }
+
+        lifecycle.set_phase(LifecyclePhase::Frozen, clock_ref)?;
+        lifecycle.update_freeze_reason(format!("circuit breaker: {} near overflow", near.counter))?;
+
+        let event_seq = metrics.next_event_seq()?;
+        emit!(CircuitBreakerTripped {
+            admin: None,
+            triggered_by: observer.key(),
+            counter: near.counter.to_string(),
+            current_value: near.current_value,
+            proposed_value: near.proposed_value,
+            tripped_at: clock_ref.unix_timestamp,
+            event_seq,
+        });
+
+        // Return Ok so the freeze is committed; the observation is dropped.
+        return Ok(());
     }
 
-    if args.note.len() > Repo::MAX_OBSERVATION_NOTE_LEN {
-        return err!(Unit09Error::StringTooLong);
-    }
+    // -----------------------------------------------------------------------
+    // Metering
+    // -----------------------------------------------------------------------
+
+    ObserverCredits::charge(
+        observer_credits.as_deref_mut(),
+        config.credits_per_observation,
+        clock_ref,
+    )?;
+
+    // -----------------------------------------------------------------------
+    // Deployment-wide throttle
+    // -----------------------------------------------------------------------
+
+    metrics.consume_observation_budget(config.max_observations_per_window, clock_ref)?;
 
     // -----------------------------------------------------------------------
     // Apply per-repo observation update
     // -----------------------------------------------------------------------
 
-    repo.record_observation(
+    let soft_cap_reached = repo.record_observation(
         args.lines_of_code,
         args.files_processed,
         args.modules_touched,
         args.revision.clone(),
         args.note.clone(),
         observer.key(),
+        attestor_key,
+        observed_at,
         clock_ref,
     )?;
+    if let Some(key) = args.idempotency_key {
+        repo.remember_idempotency_key(key, clock_ref.unix_timestamp);
+    }
+    if let Some(hash) = args.source_region_hash {
+        repo.last_source_region_hash = hash;
+    }
+    if let Some(proof) = args.access_proof {
+        repo.last_access_proof = proof;
+    }
+
+    // -----------------------------------------------------------------------
+    // Optional module attribution
+    // -----------------------------------------------------------------------
+
+    if let Some(module_address) = args.module {
+        let module = module
+            .as_mut()
+            .ok_or(Unit09Error::MissingRequiredAccount)?;
+        require_keys_eq!(module.key(), module_address, Unit09Error::ValidationFailed);
+        module.record_observation(&repo.key(), clock_ref)?;
+    }
 
     // -----------------------------------------------------------------------
     // Aggregate into global metrics
@@ -223,16 +534,37 @@ pub fn handle(ctx: Context<RecordObservation>, args: RecordObservationArgs) -> R
     // Emit ObservationRecorded event
     // -----------------------------------------------------------------------
 
+    let event_seq = metrics.next_event_seq()?;
     emit!(ObservationRecorded {
         repo: repo.key(),
         observer: observer.key(),
+        attestor: attestor_key,
         lines_of_code: args.lines_of_code,
         files_processed: args.files_processed,
         modules_touched: args.modules_touched,
         revision: args.revision,
         note: args.note,
-        observed_at: repo.last_observed_at,
+        module: args.module,
+        source_region_hash: args.source_region_hash,
+        observed_at,
+        first_observed_at: repo.first_observed_at,
+        daily_observations: repo.daily_observations,
+        is_stale: false,
+        score: repo.score,
+        event_seq,
     });
 
+    if soft_cap_reached {
+        let event_seq = metrics.next_event_seq()?;
+        emit!(RepoObservationCapReached {
+            repo: repo.key(),
+            observation_count: repo.observation_count,
+            soft_cap: SOFT_MAX_OBSERVATIONS_PER_REPO,
+            hard_cap: MAX_OBSERVATIONS_HARD_CAP,
+            reached_at: clock_ref.unix_timestamp,
+            event_seq,
+        });
+    }
+
     Ok(())
 }
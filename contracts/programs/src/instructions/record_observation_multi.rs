@@ -0,0 +1,427 @@
+//! ===========================================================================
+//! Unit09 – Record Observation (Multi-Repo) Instruction
+//! Path: contracts/unit09-program/programs/unit09_program/src/instructions/record_observation_multi.rs
+//!
+//! This instruction records a single scan that spans several repositories,
+//! such as a monorepo with submodules that are tracked as separate repos.
+//!
+//! The caller provides the scan totals plus a list of per-repo splits. Each
+//! split references a repository passed in `remaining_accounts` by index.
+//!
+//! Behavior:
+//! - splits must sum exactly to the provided totals, otherwise the call
+//!   fails with `MetricsInconsistent`
+//! - each referenced `Repo` receives its own share via
+//!   `Repo::record_observation`
+//! - global `Metrics` are aggregated ONCE with the totals, so the scan
+//!   counts as a single observation run
+//! - one `ObservationRecorded` event is emitted per split
+//! - `RepoObservationCapReached` is emitted for any repo that reaches its
+//!   soft observation cap
+//!
+//! Atomicity:
+//! - with `atomic = true`, any split failing its repository guards or
+//!   bookkeeping reverts the whole call
+//! - with `atomic = false`, such splits are skipped (their repository is
+//!   left untouched), global `Metrics` only receive the applied splits, and
+//!   `BatchPartiallyApplied` reports how many splits were applied and
+//!   skipped
+//! - malformed input (totals, split sums, account pairing) always fails the
+//!   whole call
+//! - passing the same repository twice in `remaining_accounts` fails with
+//!   `ValidationFailed`; several splits target one repository through the
+//!   same `repo_index` instead
+//!
+//! Guards are the same as `record_observation` and are applied to every
+//! referenced repository. The optional `attestor` co-signs the whole scan
+//! and is recorded on every repository. The whole call consumes a single
+//! unit of the deployment-wide observation budget and is charged
+//! `Config::credits_per_observation` once, and only if at least one split
+//! was applied; a non-atomic call whose splits were all skipped is free.
+//!
+//! Splits carry no access proof, so repositories with
+//! `Repo::require_access_proof` set fail with `ObservationNotAllowed` and
+//! must be observed through `record_observation`.
+//!
+//! ===========================================================================
+
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::Unit09Error;
+use crate::events::{debug_msg, BatchPartiallyApplied, ObservationRecorded, RepoObservationCapReached};
+use crate::state::{Config, Lifecycle, Metrics, ObserverCredits, Repo};
+use crate::utils::time::current_checked;
+use crate::utils::validators::{assert_max_len, assert_unique_keys, validate_revision, Validate};
+
+/// Share of a multi-repo observation credited to one repository.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct ObservationSplit {
+    /// Index of the repository in `remaining_accounts`.
+    pub repo_index: u8,
+
+    /// Expected `Repo::repo_key` of the account at `repo_index`.
+    ///
+    /// Checked before any repository is mutated so that a misordered
+    /// account list fails fast with `RepoKeyMismatch`.
+    pub repo_key: Pubkey,
+
+    /// Lines of code attributed to this repository.
+    pub lines_of_code: u64,
+
+    /// Files processed in this repository.
+    pub files_processed: u32,
+
+    /// Modules touched in this repository.
+    pub modules_touched: u32,
+}
+
+/// Arguments for the `record_observation_multi` instruction.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct RecordObservationMultiArgs {
+    /// Total lines of code processed by the scan.
+    pub lines_of_code: u64,
+
+    /// Total number of files processed by the scan.
+    pub files_processed: u32,
+
+    /// Total number of modules touched by the scan.
+    pub modules_touched: u32,
+
+    /// Per-repository splits; must sum to the totals above.
+    pub splits: Vec<ObservationSplit>,
+
+    /// Optional commit or revision identifier shared by all splits.
+    pub revision: String,
+
+    /// Optional note shared by all splits.
+    pub note: String,
+
+    /// Whether a failing split reverts the whole call (`true`) or is
+    /// skipped and counted (`false`).
+    pub atomic: bool,
+}
+
+impl Validate for RecordObservationMultiArgs {
+    /// 1..=`MAX_OBSERVATION_SPLITS` splits summing exactly to the totals
+    /// (`MetricsInconsistent`), non-zero files, `modules_touched` within its
+    /// maximum, a well-formed revision and a note within
+    /// `Repo::MAX_OBSERVATION_NOTE_LEN`. `Config::min_loc_per_observation`
+    /// is checked by the handler.
+    fn validate(&self) -> Result<()> {
+        if self.splits.is_empty() || self.splits.len() > MAX_OBSERVATION_SPLITS {
+            return err!(Unit09Error::ValueOutOfRange);
+        }
+        if self.files_processed == 0 {
+            return err!(Unit09Error::ValueOutOfRange);
+        }
+        if self.modules_touched as u64 > MAX_MODULES_PER_OBSERVATION as u64 {
+            return err!(Unit09Error::ObservationDataTooLarge);
+        }
+
+        validate_revision(&self.revision)?;
+        assert_max_len(&self.note, Repo::MAX_OBSERVATION_NOTE_LEN)?;
+
+        // Splits must sum exactly to the totals.
+        let mut sum_lines: u64 = 0;
+        let mut sum_files: u32 = 0;
+        let mut sum_modules: u32 = 0;
+
+        for split in self.splits.iter() {
+            sum_lines = sum_lines
+                .checked_add(split.lines_of_code)
+                .ok_or(Unit09Error::CounterOverflow)?;
+            sum_files = sum_files
+                .checked_add(split.files_processed)
+                .ok_or(Unit09Error::CounterOverflow)?;
+            sum_modules = sum_modules
+                .checked_add(split.modules_touched)
+                .ok_or(Unit09Error::CounterOverflow)?;
+        }
+
+        if sum_lines != self.lines_of_code
+            || sum_files != self.files_processed
+            || sum_modules != self.modules_touched
+        {
+            return err!(Unit09Error::MetricsInconsistent);
+        }
+        Ok(())
+    }
+}
+
+/// Accounts required for the `record_observation_multi` instruction.
+///
+/// The observed `Repo` accounts are passed as writable `remaining_accounts`.
+#[derive(Accounts)]
+pub struct RecordObservationMulti<'info> {
+    /// Signer performing the observation.
+    #[account(mut)]
+    pub observer: Signer<'info>,
+
+    /// Optional second signer vouching for the scan; required when
+    /// `Config::require_attestor` is set.
+    pub attestor: Option<Signer<'info>>,
+
+    /// Global configuration account.
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Lifecycle account controlling global write permissions.
+    #[account(
+        seeds = [LIFECYCLE_SEED.as_bytes()],
+        bump = lifecycle.bump,
+    )]
+    pub lifecycle: Account<'info, Lifecycle>,
+
+    /// Global metrics account that aggregates deployment-wide counters.
+    #[account(
+        mut,
+        seeds = [METRICS_SEED.as_bytes()],
+        bump = metrics.bump,
+    )]
+    pub metrics: Account<'info, Metrics>,
+
+    /// Optional prepaid credits of `observer`; required when
+    /// `Config::credits_per_observation` is non-zero.
+    #[account(
+        mut,
+        seeds = [
+            OBSERVER_CREDITS_SEED.as_bytes(),
+            observer.key().as_ref(),
+        ],
+        bump = observer_credits.bump,
+    )]
+    pub observer_credits: Option<Account<'info, ObserverCredits>>,
+
+    /// Clock sysvar for timestamps.
+    pub clock: Sysvar<'info, Clock>,
+}
+
+// ---------------------------------------------------------------------------
+// Handler
+// ---------------------------------------------------------------------------
+
+/// Entry point for the `record_observation_multi` instruction.
+///
+/// Steps:
+/// 1. Enforce lifecycle and config guards, and check the attestor.
+/// 2. Validate totals, strings, and that the splits sum to the totals.
+/// 3. Load every repository from `remaining_accounts` and pair each split
+///    with its account by `repo_key`.
+/// 4. Apply each split to its repository and emit `ObservationRecorded`;
+///    in non-atomic mode, skip failing splits.
+/// 5. If any split was applied, charge `Config::credits_per_observation`,
+///    consume the observation budget and aggregate the applied totals into
+///    global metrics, once for the whole call.
+/// 6. Emit `BatchPartiallyApplied` if any split was skipped.
+/// 7. Persist the repository accounts.
+pub fn handle<'info>(
+    ctx: Context<'_, '_, 'info, 'info, RecordObservationMulti<'info>>,
+    args: RecordObservationMultiArgs,
+) -> Result<()> {
+    let remaining_accounts = ctx.remaining_accounts;
+    let RecordObservationMulti {
+        observer,
+        attestor,
+        config,
+        lifecycle,
+        metrics,
+        observer_credits,
+        clock,
+    } = ctx.accounts;
+
+    let clock_ref: &Clock = clock;
+    current_checked(clock_ref)?;
+
+    // -----------------------------------------------------------------------
+    // Lifecycle and configuration guards
+    // -----------------------------------------------------------------------
+
+    lifecycle.assert_writes_allowed()?;
+    config.assert_active()?;
+    config.assert_observer_balance(observer.lamports())?;
+
+    let attestor_key = attestor.as_ref().map(|attestor| attestor.key());
+    let attestor_key = config.resolve_attestor(&observer.key(), attestor_key.as_ref())?;
+
+    // -----------------------------------------------------------------------
+    // Validate totals, strings and splits
+    // -----------------------------------------------------------------------
+
+    args.validate()?;
+    config.assert_min_loc(args.lines_of_code)?;
+
+    // -----------------------------------------------------------------------
+    // Load repositories
+    // -----------------------------------------------------------------------
+    //
+    // All repositories are deserialized up front so that several splits may
+    // target the same repository without overwriting each other. A
+    // repository passed twice would be loaded twice, and its last copy
+    // would overwrite the other when persisted, so repeated keys are
+    // rejected.
+
+    assert_unique_keys(remaining_accounts.iter().map(|account_info| account_info.key))?;
+
+    let mut repos: Vec<Account<'info, Repo>> = Vec::with_capacity(remaining_accounts.len());
+    for account_info in remaining_accounts.iter() {
+        if !account_info.is_writable {
+            return err!(Unit09Error::ExpectedWritableAccount);
+        }
+        repos.push(Account::try_from(account_info)?);
+    }
+
+    // -----------------------------------------------------------------------
+    // Pair splits with accounts (before any mutation)
+    // -----------------------------------------------------------------------
+
+    for split in args.splits.iter() {
+        let repo = repos
+            .get(split.repo_index as usize)
+            .ok_or(Unit09Error::MissingRequiredAccount)?;
+        if repo.repo_key != split.repo_key {
+            return err!(Unit09Error::RepoKeyMismatch);
+        }
+    }
+
+    // -----------------------------------------------------------------------
+    // Apply each split
+    // -----------------------------------------------------------------------
+
+    let mut applied: u32 = 0;
+    let mut skipped: u32 = 0;
+    let mut applied_lines: u64 = 0;
+    let mut applied_files: u32 = 0;
+
+    // The observation cooldown is measured from each repository's state
+    // before this call, so several splits may still target one repository.
+    let observed_before: Vec<i64> = repos.iter().map(|repo| repo.last_observed_at).collect();
+
+    for split in args.splits.iter() {
+        let repo = repos
+            .get_mut(split.repo_index as usize)
+            .ok_or(Unit09Error::MissingRequiredAccount)?;
+
+        let before = (**repo).clone();
+        let result = config
+            .cooldowns
+            .assert_observation_elapsed(observed_before[split.repo_index as usize], clock_ref)
+            .and_then(|_| {
+                apply_split(repo, split, &args, &observer.key(), attestor_key, clock_ref)
+            });
+        let soft_cap_reached = match result {
+            Ok(soft_cap_reached) => soft_cap_reached,
+            Err(err) if args.atomic => return Err(err),
+            Err(err) => {
+                debug_msg!(config, "skipping split for repo {}: {}", repo.key(), err);
+                repo.set_inner(before);
+                skipped += 1;
+                continue;
+            }
+        };
+        applied += 1;
+        applied_lines += split.lines_of_code;
+        applied_files += split.files_processed;
+
+        let event_seq = metrics.next_event_seq()?;
+        emit!(ObservationRecorded {
+            repo: repo.key(),
+            observer: observer.key(),
+            attestor: attestor_key,
+            lines_of_code: split.lines_of_code,
+            files_processed: split.files_processed,
+            modules_touched: split.modules_touched,
+            revision: args.revision.clone(),
+            note: args.note.clone(),
+            module: None,
+            source_region_hash: None,
+            observed_at: repo.last_observed_at,
+            first_observed_at: repo.first_observed_at,
+            daily_observations: repo.daily_observations,
+            is_stale: false,
+            score: repo.score,
+            event_seq,
+        });
+
+        if soft_cap_reached {
+            let event_seq = metrics.next_event_seq()?;
+            emit!(RepoObservationCapReached {
+                repo: repo.key(),
+                observation_count: repo.observation_count,
+                soft_cap: SOFT_MAX_OBSERVATIONS_PER_REPO,
+                hard_cap: MAX_OBSERVATIONS_HARD_CAP,
+                reached_at: clock_ref.unix_timestamp,
+                event_seq,
+            });
+        }
+    }
+
+    // -----------------------------------------------------------------------
+    // Metering, deployment-wide throttle and global metrics (once for the
+    // whole scan, and only when it recorded something)
+    // -----------------------------------------------------------------------
+
+    // Applied sums are bounded by the validated totals.
+    if applied > 0 {
+        ObserverCredits::charge(
+            observer_credits.as_deref_mut(),
+            config.credits_per_observation,
+            clock_ref,
+        )?;
+        metrics.consume_observation_budget(config.max_observations_per_window, clock_ref)?;
+        metrics.record_observation(applied_lines, applied_files, clock_ref)?;
+        metrics.updated_at = clock_ref.unix_timestamp;
+    }
+
+    if skipped > 0 {
+        let event_seq = metrics.next_event_seq()?;
+        emit!(BatchPartiallyApplied {
+            instruction: "record_observation_multi".to_string(),
+            signer: observer.key(),
+            applied,
+            skipped,
+            event_seq,
+        });
+    }
+
+    // -----------------------------------------------------------------------
+    // Persist repositories
+    // -----------------------------------------------------------------------
+
+    for repo in repos.iter() {
+        repo.exit(&crate::ID)?;
+    }
+
+    Ok(())
+}
+
+/// Apply one split to its repository: repository guards, then
+/// `Repo::record_observation`. Returns whether the soft cap was reached.
+fn apply_split(
+    repo: &mut Repo,
+    split: &ObservationSplit,
+    args: &RecordObservationMultiArgs,
+    observer: &Pubkey,
+    attestor: Option<Pubkey>,
+    clock: &Clock,
+) -> Result<bool> {
+    repo.assert_active()?;
+    repo.assert_observation_allowed()?;
+    repo.assert_observer_allowed(observer)?;
+    repo.assert_access_proof(None)?;
+
+    repo.record_observation(
+        split.lines_of_code,
+        split.files_processed,
+        split.modules_touched,
+        args.revision.clone(),
+        args.note.clone(),
+        *observer,
+        attestor,
+        clock.unix_timestamp,
+        clock,
+    )
+}
@@ -0,0 +1,114 @@
+//! ===========================================================================
+//! Unit09 – Refresh Bumps Instruction
+//! Path: contracts/unit09-program/programs/unit09_program/src/instructions/refresh_bumps.rs
+//!
+//! Admin-only diagnostic instruction that re-derives every singleton PDA
+//! with `find_program_address` and checks it against the stored bump.
+//!
+//! Singleton accounts cache their canonical bump at creation time and the
+//! rest of the program trusts that cached value (`bump = x.bump`). If a
+//! future framework upgrade changed bump handling, a drifted cache would
+//! surface as confusing seed-constraint failures. This instruction makes
+//! such drift explicit:
+//!
+//! - `config`, `metrics`, `lifecycle`, and (if created) `global_metadata`
+//!   are loaded WITHOUT seed constraints
+//! - each address and stored bump is compared to the freshly derived pair
+//! - any mismatch fails with `InternalError`
+//!
+//! The instruction mutates nothing; success means all cached bumps are
+//! consistent.
+//!
+//! ===========================================================================
+
+use anchor_lang::prelude::*;
+
+use crate::errors::Unit09Error;
+use crate::state::{Config, GlobalMetadata, Lifecycle, Metrics};
+use crate::utils::seeds::{config_pda, global_metadata_pda, lifecycle_pda, metrics_pda};
+
+/// Accounts required for the `refresh_bumps` instruction.
+///
+/// Seed constraints are intentionally omitted so that a drifted bump is
+/// reported by the handler rather than rejected by Anchor.
+#[derive(Accounts)]
+pub struct RefreshBumps<'info> {
+    /// Admin signer; must match `config.admin`.
+    pub admin: Signer<'info>,
+
+    /// Global configuration account.
+    pub config: Account<'info, Config>,
+
+    /// Global metrics account.
+    pub metrics: Account<'info, Metrics>,
+
+    /// Lifecycle account.
+    pub lifecycle: Account<'info, Lifecycle>,
+
+    /// Global metadata account; omitted if `set_metadata` was never called.
+    pub global_metadata: Option<Account<'info, GlobalMetadata>>,
+}
+
+// ---------------------------------------------------------------------------
+// Handler
+// ---------------------------------------------------------------------------
+
+/// Entry point for the `refresh_bumps` instruction.
+///
+/// Steps:
+/// 1. Verify `admin` matches `config.admin`.
+/// 2. Re-derive each singleton PDA and compare address and bump.
+pub fn handle(ctx: Context<RefreshBumps>) -> Result<()> {
+    let program_id = ctx.program_id;
+    let RefreshBumps {
+        admin,
+        config,
+        metrics,
+        lifecycle,
+        global_metadata,
+    } = ctx.accounts;
+
+    // -----------------------------------------------------------------------
+    // Admin authority check
+    // -----------------------------------------------------------------------
+
+    config.assert_admin(admin)?;
+
+    // -----------------------------------------------------------------------
+    // Re-derive and compare
+    // -----------------------------------------------------------------------
+
+    assert_bump_matches(config.key(), config.bump, config_pda(program_id))?;
+    assert_bump_matches(metrics.key(), metrics.bump, metrics_pda(program_id))?;
+    assert_bump_matches(lifecycle.key(), lifecycle.bump, lifecycle_pda(program_id))?;
+
+    if let Some(global_metadata) = global_metadata {
+        assert_bump_matches(
+            global_metadata.key(),
+            global_metadata.bump,
+            global_metadata_pda(program_id),
+        )?;
+    }
+
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// Ensure that an account's address and cached bump match the derived PDA.
+fn assert_bump_matches(key: Pubkey, stored_bump: u8, derived: (Pubkey, u8)) -> Result<()> {
+    let (expected_key, expected_bump) = derived;
+    if key != expected_key || stored_bump != expected_bump {
+        msg!(
+            "bump mismatch for {}: stored {}, derived {} ({})",
+            key,
+            stored_bump,
+            expected_bump,
+            expected_key
+        );
+        return err!(Unit09Error::InternalError);
+    }
+    Ok(())
+}
@@ -21,14 +21,15 @@
 //! - Global config must be active (`Config::assert_active`)
 //! - Target repo must be active (`Repo::assert_active`)
 //! - Only the repo authority can register modules for that repo
+//! - With `Repo::enforce_unique_module_uri`, the metadata URI must not match
+//!   a recently used module URI of the repo (`MetadataInvalid`)
 //!
 //! PDA layout:
 //! - Module:
 //!     seeds = [MODULE_SEED, repo.key().as_ref(), module_key.as_ref()]
-//! - ModuleVersion (optional initial snapshot):
+//! - ModuleVersion (optional initial snapshot, index 0):
 //!     seeds = [MODULE_VERSION_SEED, module.key().as_ref(),
-//!              major_version.to_le_bytes(), minor_version.to_le_bytes(),
-//!              patch_version.to_le_bytes()]
+//!              0u32.to_le_bytes()]
 //!
 //! ===========================================================================
 
@@ -38,6 +39,11 @@ use crate::constants::*;
 use crate::errors::Unit09Error;
 use crate::events::{ModuleRegistered, ModuleVersionRegistered};
 use crate::state::{Config, Lifecycle, Metrics, Module, ModuleVersion, Repo};
+use crate::utils::tags::merge_tags;
+use crate::utils::time::current_checked;
+use crate::utils::validators::{
+    assert_max_len, assert_non_empty_str, assert_semver_non_zero, Validate,
+};
 
 /// Arguments for the `register_module` instruction.
 ///
@@ -84,7 +90,8 @@ pub struct RegisterModuleArgs {
     /// Example: "alpha", "beta", "v1-initial"
     pub version_label: String,
 
-    /// Optional version-specific changelog URI.
+    /// Optional changelog URI, stored on the module (`Module::changelog_uri`)
+    /// and on the initial `ModuleVersion` snapshot. Empty for none.
     ///
     /// Example: "https://unit09.org/changelog/module-x/v1.0.0"
     pub changelog_uri: String,
@@ -96,6 +103,42 @@ pub struct RegisterModuleArgs {
     ///
     /// If false, only the `Module` account is created.
     pub create_initial_version_snapshot: bool,
+
+    /// Whether to merge the repository's tags into `tags`.
+    ///
+    /// When true, both tag strings are normalized and merged (module tags
+    /// first, duplicates removed). Fails with `StringTooLong` if the merged
+    /// result exceeds `MAX_TAGS_LEN`.
+    pub inherit_repo_tags: bool,
+
+    /// Unix timestamp until which `update_module` rejects edits to the new
+    /// module (`ModuleImmutable`). Pass 0 for no immutability window.
+    ///
+    /// Must be at most `MAX_MODULE_IMMUTABILITY_SECS` in the future
+    /// (`TimestampInFuture`).
+    pub immutable_until: i64,
+}
+
+impl Validate for RegisterModuleArgs {
+    /// Non-empty name, metadata URI and category, all strings within the
+    /// `Module` and `ModuleVersion` limits, and a non-zero version. The name
+    /// length limit comes from `Config` and is checked by the handler.
+    fn validate(&self) -> Result<()> {
+        assert_non_empty_str(&self.name)?;
+
+        assert_non_empty_str(&self.metadata_uri)?;
+        assert_max_len(&self.metadata_uri, Module::MAX_METADATA_URI_LEN)?;
+
+        assert_non_empty_str(&self.category)?;
+        assert_max_len(&self.category, Module::MAX_CATEGORY_LEN)?;
+
+        assert_max_len(&self.tags, Module::MAX_TAGS_LEN)?;
+
+        // Initial `ModuleVersion` snapshot
+        assert_max_len(&self.version_label, ModuleVersion::MAX_LABEL_LEN)?;
+        assert_max_len(&self.changelog_uri, ModuleVersion::MAX_CHANGELOG_URI_LEN)?;
+        assert_semver_non_zero(self.version)
+    }
 }
 
 /// Accounts required for the `register_module` instruction.
@@ -178,13 +221,14 @@ pub struct RegisterModule<'info> {
     /// When `args.create_initial_version_snapshot` is true, this account
     /// must be provided and will be initialized. When false, it is unused.
     ///
+    /// Snapshots are seeded by their index (`ModuleVersion::index`); the
+    /// initial snapshot of a new module is index 0.
+    ///
     /// PDA:
     ///   seeds = [
     ///       MODULE_VERSION_SEED.as_bytes(),
     ///       module.key().as_ref(),
-    ///       &args.version.0.to_le_bytes(),
-    ///       &args.version.1.to_le_bytes(),
-    ///       &args.version.2.to_le_bytes(),
+    ///       &0u32.to_le_bytes(),
     ///   ]
     ///   bump  = module_version.bump
     #[account(
@@ -194,9 +238,7 @@ pub struct RegisterModule<'info> {
         seeds = [
             MODULE_VERSION_SEED.as_bytes(),
             module.key().as_ref(),
-            &args.version.0.to_le_bytes(),
-            &args.version.1.to_le_bytes(),
-            &args.version.2.to_le_bytes(),
+            &0u32.to_le_bytes(),
         ],
         bump,
     )]
@@ -242,6 +284,7 @@ pub fn handle(ctx: Context<RegisterModule>, args: RegisterModuleArgs) -> Result<
     } = ctx.accounts;
 
     let clock_ref: &Clock = clock;
+    current_checked(clock_ref)?;
 
     // -----------------------------------------------------------------------
     // Lifecycle and configuration guards
@@ -259,53 +302,19 @@ pub fn handle(ctx: Context<RegisterModule>, args: RegisterModuleArgs) -> Result<
     // Early validation on provided arguments
     // -----------------------------------------------------------------------
 
-    // Name
-    if args.name.is_empty() {
-        return err!(Unit09Error::StringEmpty);
-    }
-    if args.name.len() > Module::MAX_NAME_LEN {
-        return err!(Unit09Error::StringTooLong);
-    }
-
-    // Metadata URI
-    if args.metadata_uri.is_empty() {
-        return err!(Unit09Error::StringEmpty);
-    }
-    if args.metadata_uri.len() > Module::MAX_METADATA_URI_LEN {
-        return err!(Unit09Error::StringTooLong);
-    }
-
-    // Category
-    if args.category.is_empty() {
-        return err!(Unit09Error::StringEmpty);
-    }
-    if args.category.len() > Module::MAX_CATEGORY_LEN {
-        return err!(Unit09Error::StringTooLong);
-    }
-
-    // Tags
-    if args.tags.len() > Module::MAX_TAGS_LEN {
-        return err!(Unit09Error::StringTooLong);
-    }
-
-    // Version label (for ModuleVersion)
-    if args.version_label.len() > ModuleVersion::MAX_LABEL_LEN {
-        return err!(Unit09Error::StringTooLong);
-    }
+    args.validate()?;
+    config.assert_name_len(&args.name)?;
 
-    // Changelog URI (for ModuleVersion)
-    if args.changelog_uri.len() > ModuleVersion::MAX_CHANGELOG_URI_LEN {
-        return err!(Unit09Error::StringTooLong);
-    }
-
-    // Version sanity
+    // Tags, optionally inheriting the repository's tags
+    let tags = if args.inherit_repo_tags {
+        merge_tags(&args.tags, &repo.tags, Module::MAX_TAGS_LEN)?
+    } else {
+        args.tags
+    };
     let version = args.version;
-    {
-        let (major, minor, patch) = version;
-        if major == 0 && minor == 0 && patch == 0 {
-            return err!(Unit09Error::ValueOutOfRange);
-        }
-    }
+
+    // Metadata URI uniqueness across the repo's modules (best-effort)
+    repo.remember_module_uri(&args.metadata_uri)?;
 
     // -----------------------------------------------------------------------
     // Derive PDA bumps from Anchor context
@@ -327,11 +336,13 @@ pub fn handle(ctx: Context<RegisterModule>, args: RegisterModuleArgs) -> Result<
         args.name,
         args.metadata_uri,
         args.category,
-        args.tags,
+        tags,
         version,
         module_bump,
         clock_ref,
     )?;
+    module.set_changelog_uri(args.changelog_uri.clone(), clock_ref)?;
+    module.set_immutable_until(args.immutable_until, clock_ref)?;
 
     // -----------------------------------------------------------------------
     // Optionally initialize ModuleVersion snapshot
@@ -339,11 +350,13 @@ pub fn handle(ctx: Context<RegisterModule>, args: RegisterModuleArgs) -> Result<
 
     if args.create_initial_version_snapshot {
         let bump = module_version_bump.ok_or(Unit09Error::InternalError)?;
+        let index = module.next_version_index()?;
 
         module_version.init(
             module.key(),
             authority.key(),
             version,
+            index,
             module.metadata_uri.clone(),
             args.changelog_uri,
             args.version_label,
@@ -352,12 +365,14 @@ pub fn handle(ctx: Context<RegisterModule>, args: RegisterModuleArgs) -> Result<
             clock_ref,
         )?;
 
+        let event_seq = metrics.next_event_seq()?;
         emit!(ModuleVersionRegistered {
             module: module.key(),
             major_version: version.0,
             minor_version: version.1,
             patch_version: version.2,
             is_stable: module_version.is_stable,
+            event_seq,
         });
     }
 
@@ -369,12 +384,14 @@ pub fn handle(ctx: Context<RegisterModule>, args: RegisterModuleArgs) -> Result<
     repo.updated_at = clock_ref.unix_timestamp;
 
     metrics.increment_modules()?;
+    metrics.increment_active_modules()?;
     metrics.updated_at = clock_ref.unix_timestamp;
 
     // -----------------------------------------------------------------------
     // Emit ModuleRegistered event
     // -----------------------------------------------------------------------
 
+    let event_seq = metrics.next_event_seq()?;
     emit!(ModuleRegistered {
         module: module.key(),
         repo: repo.key(),
@@ -383,6 +400,8 @@ pub fn handle(ctx: Context<RegisterModule>, args: RegisterModuleArgs) -> Result<
         major_version: module.major_version,
         minor_version: module.minor_version,
         patch_version: module.patch_version,
+        changelog_uri: module.changelog_uri.clone(),
+        event_seq,
     });
 
     Ok(())
@@ -10,14 +10,24 @@
 //! - aggregating observation stats
 //!
 //! On success this instruction:
-//! - creates and initializes a `Repo` PDA
-//! - increments the global `Metrics::total_repos` counter
+//! - creates and initializes a `Repo` PDA, active unless
+//!   `Config::review_gated` is set
+//! - increments the global `Metrics::total_repos` counter, and
+//!   `active_repos` for an active repository
+//! - claims the name's `RepoNameIndex` when one is passed
 //! - emits a `RepoRegistered` event
 //!
 //! Design notes:
 //! - Any signer can become a repository authority (no admin gate by default)
 //! - The deployment must be active (`Config`) and writable (`Lifecycle`)
 //! - Basic string and bounds validation is handled by `Repo::init`
+//! - When `Config::enforce_url_keyed` is set, `repo_key` must equal
+//!   `Repo::key_for_url(url)` so each URL has a single canonical repo
+//! - When `Config::enforce_unique_repo_name` is set, the `RepoNameIndex`
+//!   for `args.name` is required and a name already claimed fails with
+//!   `RepoNameTaken`. There is no repo close instruction yet; one must
+//!   close the name index too so the name is freed.
+//! - URLs on a host in `Config::blocked_hosts` fail with `RepoBlocked`
 //!
 //! ===========================================================================
 
@@ -26,13 +36,15 @@ use anchor_lang::prelude::*;
 use crate::constants::*;
 use crate::errors::Unit09Error;
 use crate::events::RepoRegistered;
-use crate::state::{Config, Lifecycle, Metrics, Repo};
+use crate::state::{Config, Lifecycle, Metrics, Repo, RepoNameIndex};
+use crate::utils::time::current_checked;
+use crate::utils::validators::{assert_max_len, assert_non_empty_str, Validate};
 
 /// Arguments for the `register_repo` instruction.
 ///
 /// The caller chooses a `repo_key` that will be used in PDA derivation.
 /// Common patterns:
-/// - hash of a repository URL
+/// - hash of a repository URL (required when `Config::enforce_url_keyed`)
 /// - random key generated locally
 /// - wallet public key for a personal code space
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
@@ -55,12 +67,32 @@ pub struct RegisterRepoArgs {
     /// Example: "solana,anchor,protocol"
     pub tags: String,
 
+    /// Optional metadata URI for a richer repository manifest.
+    ///
+    /// Empty string means "no manifest". Non-empty values must use an
+    /// `http(s)://`, `ipfs://`, or `ar://` scheme.
+    pub metadata_uri: String,
+
     /// Whether automated observation is allowed for this repository.
     pub allow_observation: bool,
 }
 
+impl Validate for RegisterRepoArgs {
+    /// Non-empty name and URL, and URL, tags and metadata URI within the
+    /// `Repo` limits. The name length limit comes from `Config` and the
+    /// metadata URI scheme is checked by `Repo::init`.
+    fn validate(&self) -> Result<()> {
+        assert_non_empty_str(&self.name)?;
+        assert_non_empty_str(&self.url)?;
+        assert_max_len(&self.url, Repo::MAX_URL_LEN)?;
+        assert_max_len(&self.tags, Repo::MAX_TAGS_LEN)?;
+        assert_max_len(&self.metadata_uri, Repo::MAX_METADATA_URI_LEN)
+    }
+}
+
 /// Accounts required for the `register_repo` instruction.
 #[derive(Accounts)]
+#[instruction(args: RegisterRepoArgs)]
 pub struct RegisterRepo<'info> {
     /// Payer for the newly created `Repo` account.
     #[account(mut)]
@@ -117,6 +149,27 @@ pub struct RegisterRepo<'info> {
     )]
     pub repo: Account<'info, Repo>,
 
+    /// Name reservation for `args.name`.
+    ///
+    /// Optional unless `Config::enforce_unique_repo_name` is set. Created on
+    /// first use; an index already claimed by another repository fails with
+    /// `RepoNameTaken`.
+    ///
+    /// PDA:
+    ///   seeds = [REPO_NAME_INDEX_SEED.as_bytes(), &RepoNameIndex::name_hash(&args.name)]
+    ///   bump  = repo_name_index.bump
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = RepoNameIndex::LEN,
+        seeds = [
+            REPO_NAME_INDEX_SEED.as_bytes(),
+            &RepoNameIndex::name_hash(&args.name),
+        ],
+        bump,
+    )]
+    pub repo_name_index: Option<Account<'info, RepoNameIndex>>,
+
     /// System program.
     pub system_program: Program<'info, System>,
 
@@ -135,10 +188,12 @@ pub struct RegisterRepo<'info> {
 ///
 /// Steps:
 /// 1. Ensure lifecycle allows write operations.
-/// 2. Ensure global config is active (if enforced).
-/// 3. Initialize the `Repo` account with validated metadata.
-/// 4. Increment global repository counter in `Metrics`.
-/// 5. Emit `RepoRegistered` event.
+/// 2. Ensure global config is active (if enforced) and, when URL keying is
+///    enforced, that `repo_key` matches the URL hash.
+/// 3. Claim the name's `RepoNameIndex` (required when names must be unique).
+/// 4. Initialize the `Repo` account with validated metadata.
+/// 5. Increment global repository counter in `Metrics`.
+/// 6. Emit `RepoRegistered` event.
 pub fn handle(ctx: Context<RegisterRepo>, args: RegisterRepoArgs) -> Result<()> {
     let RegisterRepo {
         payer: _,
@@ -147,12 +202,14 @@ pub fn handle(ctx: Context<RegisterRepo>, args: RegisterRepoArgs) -> Result<()>
         mut lifecycle,
         mut metrics,
         mut repo,
+        mut repo_name_index,
         system_program: _,
         rent: _,
         clock,
     } = ctx.accounts;
 
     let clock_ref: &Clock = clock;
+    current_checked(clock_ref)?;
 
     // -----------------------------------------------------------------------
     // Lifecycle and configuration guards
@@ -171,22 +228,15 @@ pub fn handle(ctx: Context<RegisterRepo>, args: RegisterRepoArgs) -> Result<()>
     // Detailed validation is also performed inside `Repo::init`, but we
     // perform simple checks here to fail fast and avoid unnecessary work.
 
-    if args.name.is_empty() {
-        return err!(Unit09Error::StringEmpty);
-    }
-    if args.name.len() > Repo::MAX_NAME_LEN {
-        return err!(Unit09Error::StringTooLong);
-    }
+    args.validate()?;
+    config.assert_name_len(&args.name)?;
 
-    if args.url.is_empty() {
-        return err!(Unit09Error::StringEmpty);
-    }
-    if args.url.len() > Repo::MAX_URL_LEN {
-        return err!(Unit09Error::StringTooLong);
-    }
+    // Canonical identity: optionally require `repo_key == hash(url)`.
+    config.assert_repo_key_allowed(&args.repo_key, &args.url)?;
+    config.assert_host_allowed(&args.url)?;
 
-    if args.tags.len() > Repo::MAX_TAGS_LEN {
-        return err!(Unit09Error::StringTooLong);
+    if config.enforce_unique_repo_name && repo_name_index.is_none() {
+        return err!(Unit09Error::MissingRequiredAccount);
     }
 
     // -----------------------------------------------------------------------
@@ -195,6 +245,18 @@ pub fn handle(ctx: Context<RegisterRepo>, args: RegisterRepoArgs) -> Result<()>
 
     let repo_bump = *ctx.bumps.get("repo").ok_or(Unit09Error::InternalError)?;
 
+    // -----------------------------------------------------------------------
+    // Claim the repository name
+    // -----------------------------------------------------------------------
+
+    if let Some(repo_name_index) = repo_name_index.as_mut() {
+        let index_bump = *ctx
+            .bumps
+            .get("repo_name_index")
+            .ok_or(Unit09Error::InternalError)?;
+        repo_name_index.claim(repo.key(), &args.name, index_bump, clock_ref)?;
+    }
+
     // -----------------------------------------------------------------------
     // Initialize Repo account
     // -----------------------------------------------------------------------
@@ -205,26 +267,38 @@ pub fn handle(ctx: Context<RegisterRepo>, args: RegisterRepoArgs) -> Result<()>
         args.name,
         args.url,
         args.tags,
+        args.metadata_uri,
         args.allow_observation,
         repo_bump,
         clock_ref,
     )?;
 
+    // Review-gated deployments register repositories inactive; an admin
+    // activates them later through `update_repo`.
+    repo.is_active = !config.review_gated;
+
     // -----------------------------------------------------------------------
     // Update global metrics
     // -----------------------------------------------------------------------
 
     metrics.increment_repos()?;
+    if repo.is_active {
+        metrics.increment_active_repos()?;
+    }
     metrics.updated_at = clock_ref.unix_timestamp;
 
     // -----------------------------------------------------------------------
     // Emit RepoRegistered event
     // -----------------------------------------------------------------------
 
+    let event_seq = metrics.next_event_seq()?;
     emit!(RepoRegistered {
         repo: repo.key(),
         owner: repo.authority,
         url: repo.url.clone(),
+        host: repo.host,
+        metadata_uri: repo.metadata_uri.clone(),
+        event_seq,
     });
 
     Ok(())
@@ -0,0 +1,120 @@
+//! ===========================================================================
+//! Unit09 – Remove Blocked Host Instruction
+//! Path: contracts/unit09-program/programs/unit09_program/src/instructions/remove_blocked_host.rs
+//!
+//! This instruction removes a host from `Config::blocked_hosts`, allowing
+//! repositories on it to be registered again.
+//!
+//! On success this instruction:
+//! - removes the host hash from `Config::blocked_hosts`
+//!   (`NoChangeRequested` if it is not listed)
+//! - emits `BlockedHostsChanged`
+//!
+//! Notes:
+//! - Only the current `Config::admin` is allowed to call this instruction.
+//! - `host` may be a bare host (`"example.org"`) or any URL on it; it is
+//!   reduced to `RepoHost::normalized_host` before hashing, so only the
+//!   hash is stored (`Config::host_hash`).
+//!
+//! ===========================================================================
+
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::events::BlockedHostsChanged;
+use crate::state::{Config, Metrics, Repo};
+use crate::utils::time::current_checked;
+use crate::utils::validators::{assert_max_len, assert_non_empty_str, Validate};
+
+/// Arguments for the `remove_blocked_host` instruction.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct RemoveBlockedHostArgs {
+    /// Host, or a URL on it, to unblock.
+    pub host: String,
+}
+
+impl Validate for RemoveBlockedHostArgs {
+    /// Non-empty host within `Repo::MAX_URL_LEN`.
+    fn validate(&self) -> Result<()> {
+        assert_non_empty_str(&self.host)?;
+        assert_max_len(&self.host, Repo::MAX_URL_LEN)
+    }
+}
+
+/// Accounts required for the `remove_blocked_host` instruction.
+#[derive(Accounts)]
+pub struct RemoveBlockedHost<'info> {
+    /// Admin signer; must match `config.admin`.
+    pub admin: Signer<'info>,
+
+    /// Global configuration account holding `blocked_hosts`.
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Global metrics account, used for event sequencing.
+    #[account(
+        mut,
+        seeds = [METRICS_SEED.as_bytes()],
+        bump = metrics.bump,
+    )]
+    pub metrics: Account<'info, Metrics>,
+
+    /// Clock sysvar used for timestamps.
+    pub clock: Sysvar<'info, Clock>,
+}
+
+// ---------------------------------------------------------------------------
+// Handler
+// ---------------------------------------------------------------------------
+
+/// Entry point for the `remove_blocked_host` instruction.
+///
+/// Steps:
+/// 1. Verify that the caller is the current admin.
+/// 2. Remove the host hash (`Config::unblock_host`).
+/// 3. Emit `BlockedHostsChanged`.
+pub fn handle(ctx: Context<RemoveBlockedHost>, args: RemoveBlockedHostArgs) -> Result<()> {
+    let RemoveBlockedHost {
+        admin,
+        config,
+        metrics,
+        clock,
+    } = ctx.accounts;
+
+    let clock_ref: &Clock = clock;
+    current_checked(clock_ref)?;
+
+    // -----------------------------------------------------------------------
+    // Admin authority check
+    // -----------------------------------------------------------------------
+
+    config.assert_admin(admin)?;
+
+    args.validate()?;
+
+    // -----------------------------------------------------------------------
+    // Update blocked hosts
+    // -----------------------------------------------------------------------
+
+    let host_hash = Config::host_hash(&args.host);
+    config.unblock_host(&host_hash, clock_ref)?;
+
+    // -----------------------------------------------------------------------
+    // Emit event
+    // -----------------------------------------------------------------------
+
+    let event_seq = metrics.next_event_seq()?;
+    emit!(BlockedHostsChanged {
+        admin: admin.key(),
+        host_hash,
+        blocked: false,
+        blocked_count: config.blocked_hosts.len() as u8,
+        event_seq,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,126 @@
+//! ===========================================================================
+//! Unit09 – Remove Co-Admin Instruction
+//! Path: contracts/unit09-program/programs/unit09_program/src/instructions/remove_co_admin.rs
+//!
+//! This instruction removes a co-admin registered by `add_co_admin`.
+//!
+//! On success this instruction:
+//! - closes the co-admin's `Authority` PDA, refunding its rent to the admin
+//! - decrements `Config::co_admin_count`
+//! - emits `AuthorityRoleRevoked`
+//!
+//! Notes:
+//! - `Config::admin` must sign, together with enough co-admins to reach
+//!   `required_admin_sigs` (same `remaining_accounts` layout as
+//!   `set_config`). The co-admin being removed may be one of them.
+//! - Removing a co-admin that would leave fewer admins than
+//!   `required_admin_sigs` fails with `AdminQuorumUnreachable`; lower the
+//!   requirement through `set_config` first.
+//!
+//! ===========================================================================
+
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::Unit09Error;
+use crate::events::AuthorityRoleRevoked;
+use crate::state::{role_flags, Authority, Config, Metrics};
+use crate::utils::time::current_checked;
+
+/// Accounts required for the `remove_co_admin` instruction.
+#[derive(Accounts)]
+pub struct RemoveCoAdmin<'info> {
+    /// Admin signer; must match `config.admin`. Receives the refunded rent.
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// Global configuration account holding `co_admin_count`.
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Global metrics account, used for event sequencing.
+    #[account(
+        mut,
+        seeds = [METRICS_SEED.as_bytes()],
+        bump = metrics.bump,
+    )]
+    pub metrics: Account<'info, Metrics>,
+
+    /// `Authority` entry of the co-admin to remove; must be a global admin
+    /// entry (`ValidationFailed` otherwise).
+    ///
+    /// PDA:
+    ///   seeds = [AUTHORITY_SEED.as_bytes(), authority.authority.as_ref()]
+    ///   bump  = authority.bump
+    #[account(
+        mut,
+        close = admin,
+        seeds = [
+            AUTHORITY_SEED.as_bytes(),
+            authority.authority.as_ref(),
+        ],
+        bump = authority.bump,
+        constraint = authority.is_global
+            && authority.has_any_role(role_flags::ADMIN) @ Unit09Error::ValidationFailed,
+    )]
+    pub authority: Account<'info, Authority>,
+
+    /// Clock sysvar used for timestamps.
+    pub clock: Sysvar<'info, Clock>,
+}
+
+// ---------------------------------------------------------------------------
+// Handler
+// ---------------------------------------------------------------------------
+
+/// Entry point for the `remove_co_admin` instruction.
+///
+/// Steps:
+/// 1. Verify the admin quorum.
+/// 2. Decrement `Config::co_admin_count` (the `Authority` entry is closed
+///    when the instruction exits).
+/// 3. Emit `AuthorityRoleRevoked`.
+pub fn handle(ctx: Context<RemoveCoAdmin>) -> Result<()> {
+    let co_signers = ctx.remaining_accounts;
+    let RemoveCoAdmin {
+        admin,
+        config,
+        metrics,
+        authority,
+        clock,
+    } = ctx.accounts;
+
+    let clock_ref: &Clock = clock;
+    current_checked(clock_ref)?;
+
+    // -----------------------------------------------------------------------
+    // Admin authority check
+    // -----------------------------------------------------------------------
+
+    config.assert_admin_quorum(admin, co_signers)?;
+
+    // -----------------------------------------------------------------------
+    // Remove co-admin
+    // -----------------------------------------------------------------------
+
+    config.remove_co_admin()?;
+    config.updated_at = clock_ref.unix_timestamp;
+
+    // -----------------------------------------------------------------------
+    // Emit event
+    // -----------------------------------------------------------------------
+
+    let event_seq = metrics.next_event_seq()?;
+    emit!(AuthorityRoleRevoked {
+        authority: authority.authority,
+        role: "admin".to_string(),
+        revoked_at: clock_ref.unix_timestamp,
+        event_seq,
+    });
+
+    Ok(())
+}
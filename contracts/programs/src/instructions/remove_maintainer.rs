@@ -0,0 +1,132 @@
+//! ===========================================================================
+//! Unit09 – Remove Maintainer Instruction
+//! Path: contracts/unit09-program/programs/unit09_program/src/instructions/remove_maintainer.rs
+//!
+//! This instruction removes a key from `Repo::maintainers`, revoking its
+//! right to change repository metadata through `update_repo`.
+//!
+//! On success this instruction:
+//! - removes `maintainer` from `Repo::maintainers`
+//! - emits `RepoMaintainersChanged`
+//!
+//! Design notes:
+//! - Only the current `Repo::authority` may call it; maintainers cannot
+//!   manage the list themselves
+//! - Deployment must be active (`Config`) and writable (`Lifecycle`)
+//!
+//! ===========================================================================
+
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::Unit09Error;
+use crate::events::RepoMaintainersChanged;
+use crate::state::{Config, Lifecycle, Metrics, Repo};
+use crate::utils::time::current_checked;
+
+/// Arguments for the `remove_maintainer` instruction.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct RemoveMaintainerArgs {
+    /// Key to remove; must currently be listed.
+    pub maintainer: Pubkey,
+}
+
+/// Accounts required for the `remove_maintainer` instruction.
+#[derive(Accounts)]
+pub struct RemoveMaintainer<'info> {
+    /// Authority that owns this repository entry.
+    pub authority: Signer<'info>,
+
+    /// Global configuration account.
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Lifecycle account controlling high-level operation and freezes.
+    #[account(
+        seeds = [LIFECYCLE_SEED.as_bytes()],
+        bump = lifecycle.bump,
+    )]
+    pub lifecycle: Account<'info, Lifecycle>,
+
+    /// Global metrics account, used for event sequencing.
+    #[account(
+        mut,
+        seeds = [METRICS_SEED.as_bytes()],
+        bump = metrics.bump,
+    )]
+    pub metrics: Account<'info, Metrics>,
+
+    /// Repository whose maintainer list is changed.
+    ///
+    /// PDA:
+    ///   seeds = [REPO_SEED.as_bytes(), repo.repo_key.as_ref()]
+    ///   bump  = repo.bump
+    #[account(
+        mut,
+        seeds = [
+            REPO_SEED.as_bytes(),
+            repo.repo_key.as_ref(),
+        ],
+        bump = repo.bump,
+        has_one = authority @ Unit09Error::InvalidAuthority,
+    )]
+    pub repo: Account<'info, Repo>,
+
+    /// Clock sysvar used for timestamps.
+    pub clock: Sysvar<'info, Clock>,
+}
+
+// ---------------------------------------------------------------------------
+// Handler
+// ---------------------------------------------------------------------------
+
+/// Entry point for the `remove_maintainer` instruction.
+///
+/// Steps:
+/// 1. Ensure lifecycle allows writes and config is active.
+/// 2. Remove `args.maintainer` (`Repo::remove_maintainer`).
+/// 3. Emit `RepoMaintainersChanged`.
+pub fn handle(ctx: Context<RemoveMaintainer>, args: RemoveMaintainerArgs) -> Result<()> {
+    let RemoveMaintainer {
+        authority: _,
+        config,
+        lifecycle,
+        metrics,
+        repo,
+        clock,
+    } = ctx.accounts;
+
+    let clock_ref: &Clock = clock;
+    current_checked(clock_ref)?;
+
+    // -----------------------------------------------------------------------
+    // Lifecycle and configuration guards
+    // -----------------------------------------------------------------------
+
+    lifecycle.assert_writes_allowed()?;
+    config.assert_active()?;
+
+    // -----------------------------------------------------------------------
+    // Update maintainer list
+    // -----------------------------------------------------------------------
+
+    repo.remove_maintainer(&args.maintainer, clock_ref)?;
+
+    // -----------------------------------------------------------------------
+    // Emit event
+    // -----------------------------------------------------------------------
+
+    let event_seq = metrics.next_event_seq()?;
+    emit!(RepoMaintainersChanged {
+        repo: repo.key(),
+        maintainer: args.maintainer,
+        added: false,
+        maintainer_count: repo.maintainers.len() as u8,
+        event_seq,
+    });
+
+    Ok(())
+}
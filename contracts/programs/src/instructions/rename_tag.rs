@@ -0,0 +1,188 @@
+//! ===========================================================================
+//! Unit09 – Rename Tag Instruction
+//! Path: contracts/unit09-program/programs/unit09_program/src/instructions/rename_tag.rs
+//!
+//! This instruction lets the admin migrate a tag across many repositories
+//! when the tag vocabulary changes (for example `"legacy"` -> `"archived"`).
+//!
+//! Batching:
+//!     remaining_accounts = [repo, repo, ...]   (writable)
+//!
+//! For every repository that carries `old`, the tag is replaced in its
+//! normalized tag string (see `Repo::rename_tag`), `tag_bits` is kept in
+//! sync and a `TagRenamed` event is emitted. Repositories without `old` are
+//! skipped. Large deployments split the work across several calls.
+//!
+//! Guards:
+//! - lifecycle must allow writes (`Lifecycle::assert_writes_allowed`)
+//! - only `Config::admin` may call this instruction
+//! - `old` and `new` must be single, non-empty, distinct tags
+//! - every remaining account must be a writable `Repo` owned by this program
+//! - no account may be passed twice in `remaining_accounts`
+//!   (`ValidationFailed`)
+//! - a renamed tag string must still fit `Repo::MAX_TAGS_LEN`; otherwise the
+//!   whole batch fails with `StringTooLong`
+//!
+//! ===========================================================================
+
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::Unit09Error;
+use crate::events::TagRenamed;
+use crate::state::{Config, Lifecycle, Metrics, Repo};
+use crate::utils::time::current_checked;
+use crate::utils::validators::{assert_unique_keys, Validate};
+
+/// Arguments for the `rename_tag` instruction.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct RenameTagArgs {
+    /// Tag to replace (matched case-insensitively).
+    pub old: String,
+
+    /// Replacement tag.
+    pub new: String,
+}
+
+impl Validate for RenameTagArgs {
+    /// `old` and `new` are single, non-empty tags within
+    /// `Repo::MAX_TAGS_LEN` that still differ once normalized.
+    fn validate(&self) -> Result<()> {
+        let old_tag = normalize_tag_name(&self.old);
+        let new_tag = normalize_tag_name(&self.new);
+        assert_single_tag(&old_tag)?;
+        assert_single_tag(&new_tag)?;
+        require!(old_tag != new_tag, Unit09Error::ValidationFailed);
+        Ok(())
+    }
+}
+
+/// Accounts required for the `rename_tag` instruction.
+///
+/// The `Repo` accounts to migrate are passed as writable
+/// `remaining_accounts`.
+#[derive(Accounts)]
+pub struct RenameTag<'info> {
+    /// Admin signer; must match `config.admin`.
+    pub admin: Signer<'info>,
+
+    /// Global configuration account.
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Lifecycle account controlling global write permissions.
+    #[account(
+        seeds = [LIFECYCLE_SEED.as_bytes()],
+        bump = lifecycle.bump,
+    )]
+    pub lifecycle: Account<'info, Lifecycle>,
+
+    /// Global metrics account, used for event sequencing.
+    #[account(
+        mut,
+        seeds = [METRICS_SEED.as_bytes()],
+        bump = metrics.bump,
+    )]
+    pub metrics: Account<'info, Metrics>,
+
+    /// Clock sysvar used for timestamps.
+    pub clock: Sysvar<'info, Clock>,
+}
+
+// ---------------------------------------------------------------------------
+// Handler
+// ---------------------------------------------------------------------------
+
+/// Entry point for the `rename_tag` instruction.
+///
+/// Steps:
+/// 1. Ensure lifecycle allows writes and the caller is admin.
+/// 2. Validate (`args.validate()`) and normalize `old` and `new`.
+/// 3. Rename the tag in every `Repo` from `remaining_accounts` carrying it,
+///    emitting `TagRenamed` for each.
+/// 4. Persist the modified repositories.
+pub fn handle<'info>(
+    ctx: Context<'_, '_, 'info, 'info, RenameTag<'info>>,
+    args: RenameTagArgs,
+) -> Result<()> {
+    let remaining_accounts = ctx.remaining_accounts;
+    let RenameTag {
+        admin,
+        config,
+        lifecycle,
+        metrics,
+        clock,
+    } = ctx.accounts;
+
+    let clock_ref: &Clock = clock;
+    current_checked(clock_ref)?;
+
+    // -----------------------------------------------------------------------
+    // Lifecycle and admin guards
+    // -----------------------------------------------------------------------
+
+    lifecycle.assert_writes_allowed()?;
+    config.assert_admin(admin)?;
+
+    // -----------------------------------------------------------------------
+    // Validate tag names
+    // -----------------------------------------------------------------------
+
+    args.validate()?;
+    let old_tag = normalize_tag_name(&args.old);
+    let new_tag = normalize_tag_name(&args.new);
+
+    // -----------------------------------------------------------------------
+    // Rename across the batch
+    // -----------------------------------------------------------------------
+
+    assert_unique_keys(remaining_accounts.iter().map(|account_info| account_info.key))?;
+
+    for account_info in remaining_accounts.iter() {
+        if !account_info.is_writable {
+            return err!(Unit09Error::ExpectedWritableAccount);
+        }
+        let mut repo: Account<'info, Repo> = Account::try_from(account_info)?;
+
+        if !repo.rename_tag(&old_tag, &new_tag, clock_ref)? {
+            continue;
+        }
+        repo.exit(&crate::ID)?;
+
+        let event_seq = metrics.next_event_seq()?;
+        emit!(TagRenamed {
+            repo: repo.key(),
+            old_tag: old_tag.clone(),
+            new_tag: new_tag.clone(),
+            tags: repo.tags.clone(),
+            event_seq,
+        });
+    }
+
+    Ok(())
+}
+
+/// Trim and lowercase a single tag name.
+fn normalize_tag_name(tag: &str) -> String {
+    tag.trim().to_ascii_lowercase()
+}
+
+/// Ensure a normalized tag name is a single tag.
+///
+/// Fails with `StringEmpty` for a blank name, `StringTooLong` if it cannot
+/// fit a tag string, and `ValidationFailed` if it contains a separator.
+fn assert_single_tag(tag: &str) -> Result<()> {
+    if tag.is_empty() {
+        return err!(Unit09Error::StringEmpty);
+    }
+    if tag.len() > Repo::MAX_TAGS_LEN {
+        return err!(Unit09Error::StringTooLong);
+    }
+    if tag.contains(',') {
+        return err!(Unit09Error::ValidationFailed);
+    }
+    Ok(())
+}
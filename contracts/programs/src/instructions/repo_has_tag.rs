@@ -0,0 +1,62 @@
+//! ===========================================================================
+//! Unit09 – Repo Has Tag Instruction
+//! Path: contracts/unit09-program/programs/unit09_program/src/instructions/repo_has_tag.rs
+//!
+//! Read-only query reporting whether a repository carries a tag.
+//!
+//! Matching follows `utils::tags::has_tag`: whole comma-separated entries,
+//! ignoring surrounding whitespace and ASCII case. Clients filtering
+//! repositories off-chain should use the same rule so both sides agree.
+//!
+//! The result is written with `set_return_data` as a Borsh-encoded `bool`.
+//!
+//! ===========================================================================
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::set_return_data;
+
+use crate::constants::*;
+use crate::state::Repo;
+use crate::utils::tags::has_tag;
+
+/// Arguments for the `repo_has_tag` instruction.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct RepoHasTagArgs {
+    /// Tag to look for, for example "anchor".
+    pub tag: String,
+}
+
+/// Accounts required for the `repo_has_tag` instruction.
+#[derive(Accounts)]
+pub struct RepoHasTag<'info> {
+    /// Repository whose tags are checked.
+    ///
+    /// PDA:
+    ///   seeds = [REPO_SEED.as_bytes(), repo.repo_key.as_ref()]
+    ///   bump  = repo.bump
+    #[account(
+        seeds = [
+            REPO_SEED.as_bytes(),
+            repo.repo_key.as_ref(),
+        ],
+        bump = repo.bump,
+    )]
+    pub repo: Account<'info, Repo>,
+}
+
+// ---------------------------------------------------------------------------
+// Handler
+// ---------------------------------------------------------------------------
+
+/// Entry point for the `repo_has_tag` instruction.
+///
+/// Steps:
+/// 1. Match `args.tag` against `repo.tags`.
+/// 2. Return the result via `set_return_data`.
+pub fn handle(ctx: Context<RepoHasTag>, args: RepoHasTagArgs) -> Result<()> {
+    let RepoHasTag { repo } = ctx.accounts;
+
+    set_return_data(&has_tag(&repo.tags, &args.tag).try_to_vec()?);
+
+    Ok(())
+}
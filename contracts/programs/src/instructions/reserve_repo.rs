@@ -0,0 +1,132 @@
+//! ===========================================================================
+//! Unit09 – Reserve Repo Instruction
+//! Path: contracts/unit09-program/programs/unit09_program/src/instructions/reserve_repo.rs
+//!
+//! This instruction pre-allocates a `Repo` PDA without activating it.
+//!
+//! Provisioning can then be staged: the account (and its rent) is claimed
+//! up front under `repo_key`, and `finalize_repo` fills in the name, URL,
+//! tags and metadata later and activates the repository.
+//!
+//! A reserved repository:
+//! - is inactive, so observations and module registration are rejected
+//! - is not counted in `Metrics` until it is finalized
+//! - can only be activated through `finalize_repo`
+//!
+//! Guards:
+//! - lifecycle must allow writes (`Lifecycle::assert_writes_allowed`)
+//! - global config must be active (`Config::assert_active`)
+//!
+//! PDA layout:
+//! - Repo:
+//!     seeds = [REPO_SEED.as_bytes(), args.repo_key.as_ref()]
+//!     bump  = repo.bump
+//!
+//! ===========================================================================
+
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::Unit09Error;
+use crate::state::{Config, Lifecycle, Repo};
+use crate::utils::time::current_checked;
+
+/// Arguments for the `reserve_repo` instruction.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct ReserveRepoArgs {
+    /// Key used together with `REPO_SEED` to derive the `Repo` PDA.
+    ///
+    /// When `Config::enforce_url_keyed` is set, `finalize_repo` requires it
+    /// to equal `Repo::key_for_url(url)`.
+    pub repo_key: Pubkey,
+}
+
+/// Accounts required for the `reserve_repo` instruction.
+#[derive(Accounts)]
+#[instruction(args: ReserveRepoArgs)]
+pub struct ReserveRepo<'info> {
+    /// Payer for the reserved `Repo` account.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Authority that will own this repository entry and finalize it.
+    pub authority: Signer<'info>,
+
+    /// Global configuration account.
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Lifecycle account controlling global write permissions.
+    #[account(
+        seeds = [LIFECYCLE_SEED.as_bytes()],
+        bump = lifecycle.bump,
+    )]
+    pub lifecycle: Account<'info, Lifecycle>,
+
+    /// The repository account to reserve.
+    ///
+    /// PDA:
+    ///   seeds = [REPO_SEED.as_bytes(), args.repo_key.as_ref()]
+    ///   bump  = repo.bump
+    #[account(
+        init,
+        payer = payer,
+        space = Repo::LEN,
+        seeds = [
+            REPO_SEED.as_bytes(),
+            args.repo_key.as_ref(),
+        ],
+        bump,
+    )]
+    pub repo: Account<'info, Repo>,
+
+    /// System program.
+    pub system_program: Program<'info, System>,
+
+    /// Clock sysvar used for timestamps.
+    pub clock: Sysvar<'info, Clock>,
+}
+
+// ---------------------------------------------------------------------------
+// Handler
+// ---------------------------------------------------------------------------
+
+/// Entry point for the `reserve_repo` instruction.
+///
+/// Steps:
+/// 1. Enforce lifecycle and config guards.
+/// 2. Initialize the `Repo` account as a reserved, inactive entry.
+pub fn handle(ctx: Context<ReserveRepo>, args: ReserveRepoArgs) -> Result<()> {
+    let repo_bump = *ctx.bumps.get("repo").ok_or(Unit09Error::InternalError)?;
+
+    let ReserveRepo {
+        payer: _,
+        authority,
+        config,
+        lifecycle,
+        repo,
+        system_program: _,
+        clock,
+    } = ctx.accounts;
+
+    let clock_ref: &Clock = clock;
+    current_checked(clock_ref)?;
+
+    // -----------------------------------------------------------------------
+    // Lifecycle and configuration guards
+    // -----------------------------------------------------------------------
+
+    lifecycle.assert_writes_allowed()?;
+    config.assert_active()?;
+
+    // -----------------------------------------------------------------------
+    // Reserve Repo account
+    // -----------------------------------------------------------------------
+
+    repo.reserve(args.repo_key, authority.key(), repo_bump, clock_ref);
+
+    Ok(())
+}
@@ -0,0 +1,144 @@
+//! ===========================================================================
+//! Unit09 – Restore Metrics Instruction
+//! Path: contracts/unit09-program/programs/unit09_program/src/instructions/restore_metrics.rs
+//!
+//! Admin-only instruction that rolls the global `Metrics` counters back to
+//! a `MetricsSnapshot` taken earlier with `take_metrics_snapshot`.
+//!
+//! Restoring is destructive: every counter captured by the snapshot is
+//! overwritten (see `Metrics::restore_from_snapshot`). Unlike other admin
+//! instructions it therefore always requires the full admin quorum, so a
+//! single compromised admin key cannot roll the metrics back.
+//!
+//! Guards:
+//! - lifecycle must allow wind-down writes (permitted during `Sunset`)
+//! - `Config::admin` must sign, and together with the co-signers in
+//!   `remaining_accounts` at least `Config::required_admin_sigs` distinct
+//!   admins must have signed (`UnauthorizedAdminAction`)
+//!
+//! Co-signers:
+//!     remaining_accounts = [signer, authority, signer, authority, ...]
+//!
+//! Same layout as `set_config`: each `signer` must sign the transaction
+//! and `authority` must be its global `Authority` entry with the admin
+//! role (see `Config::assert_admin_quorum`).
+//!
+//! On success this instruction:
+//! - copies the snapshot counters into `Metrics`
+//! - emits `MetricsRestored`
+//!
+//! ===========================================================================
+
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::events::MetricsRestored;
+use crate::state::{Config, Lifecycle, Metrics, MetricsSnapshot};
+use crate::utils::time::current_checked;
+
+/// Accounts required for the `restore_metrics` instruction.
+#[derive(Accounts)]
+pub struct RestoreMetrics<'info> {
+    /// Admin signer; must match `config.admin`.
+    pub admin: Signer<'info>,
+
+    /// Global configuration account.
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Lifecycle account controlling global write permissions.
+    #[account(
+        seeds = [LIFECYCLE_SEED.as_bytes()],
+        bump = lifecycle.bump,
+    )]
+    pub lifecycle: Account<'info, Lifecycle>,
+
+    /// Global metrics account whose counters are restored.
+    #[account(
+        mut,
+        seeds = [METRICS_SEED.as_bytes()],
+        bump = metrics.bump,
+    )]
+    pub metrics: Account<'info, Metrics>,
+
+    /// Snapshot to restore from.
+    ///
+    /// PDA:
+    ///   seeds = [
+    ///       METRICS_SNAPSHOT_SEED.as_bytes(),
+    ///       &metrics_snapshot.index.to_le_bytes(),
+    ///   ]
+    ///   bump  = metrics_snapshot.bump
+    #[account(
+        seeds = [
+            METRICS_SNAPSHOT_SEED.as_bytes(),
+            &metrics_snapshot.index.to_le_bytes(),
+        ],
+        bump = metrics_snapshot.bump,
+    )]
+    pub metrics_snapshot: Account<'info, MetricsSnapshot>,
+
+    /// Clock sysvar used for timestamps.
+    pub clock: Sysvar<'info, Clock>,
+}
+
+// ---------------------------------------------------------------------------
+// Handler
+// ---------------------------------------------------------------------------
+
+/// Entry point for the `restore_metrics` instruction.
+///
+/// Steps:
+/// 1. Ensure lifecycle allows wind-down writes.
+/// 2. Verify the admin and the co-signing admins reach the quorum.
+/// 3. Copy the snapshot counters into `Metrics`.
+/// 4. Emit `MetricsRestored`.
+pub fn handle(ctx: Context<RestoreMetrics>) -> Result<()> {
+    let co_signers = ctx.remaining_accounts;
+    let RestoreMetrics {
+        admin,
+        config,
+        lifecycle,
+        mut metrics,
+        metrics_snapshot,
+        clock,
+    } = ctx.accounts;
+
+    let clock_ref: &Clock = clock;
+    current_checked(clock_ref)?;
+
+    // -----------------------------------------------------------------------
+    // Lifecycle and admin quorum guards
+    // -----------------------------------------------------------------------
+
+    lifecycle.assert_wind_down_allowed()?;
+
+    // Always the full quorum, even for deployments that let a single admin
+    // run other maintenance instructions.
+    config.assert_admin_quorum(admin, co_signers)?;
+
+    // -----------------------------------------------------------------------
+    // Restore counters
+    // -----------------------------------------------------------------------
+
+    metrics.restore_from_snapshot(metrics_snapshot, clock_ref)?;
+
+    // -----------------------------------------------------------------------
+    // Emit MetricsRestored
+    // -----------------------------------------------------------------------
+
+    let event_seq = metrics.next_event_seq()?;
+    emit!(MetricsRestored {
+        admin: admin.key(),
+        snapshot: metrics_snapshot.key(),
+        snapshot_index: metrics_snapshot.index,
+        snapshot_taken_at: metrics_snapshot.taken_at,
+        restored_at: clock_ref.unix_timestamp,
+        event_seq,
+    });
+
+    Ok(())
+}
@@ -8,14 +8,32 @@
 //! - adjust protocol-wide fee basis points
 //! - change the maximum modules-per-repository limit
 //! - toggle the active flag
-//! - update an off-chain policy reference hash
+//! - update an off-chain policy reference hash (bumping
+//!   `Config::policy_version` when it changes)
+//! - enable or disable the metrics circuit breaker
+//! - change the fee recipient used by `collect_fee`
+//! - require a prefix on fork labels (`label_prefix_required`)
+//! - set per-instruction cooldown windows (`cooldowns`)
+//! - require a minimum observer wallet balance (`min_observer_lamports`)
+//! - require unique repository names (`enforce_unique_repo_name`)
 //!
 //! Notes:
 //! - Only the current `Config::admin` is allowed to call this instruction.
+//! - When `Config::required_admin_sigs` is above 1, additional admins must
+//!   co-sign; see "Co-signers" below.
 //! - All fields are optional; only provided values are updated.
 //! - Bounds and validity checks are delegated to `Config::apply_update`.
 //! - A `ConfigUpdated` event is emitted for indexers and dashboards.
 //!
+//! Co-signers:
+//!     remaining_accounts = [signer, authority, signer, authority, ...]
+//!
+//! Each `signer` must sign the transaction and `authority` must be its
+//! `Authority` PDA created by `add_co_admin`. `Config::admin` plus the
+//! distinct co-signers must reach the `required_admin_sigs` value stored
+//! before this update, otherwise the call fails with
+//! `UnauthorizedAdminAction`.
+//!
 //! ===========================================================================
 
 use anchor_lang::prelude::*;
@@ -23,13 +41,15 @@ use anchor_lang::prelude::*;
 use crate::constants::*;
 use crate::errors::Unit09Error;
 use crate::events::ConfigUpdated;
-use crate::state::Config;
+use crate::state::{Config, CooldownConfig, Metrics};
+use crate::utils::time::current_checked;
+use crate::utils::validators::{assert_fee_bps_in_range, assert_non_zero_u32, Validate};
 
 /// Arguments for the `set_config` instruction.
 ///
 /// All fields are optional. If a field is `None`, the corresponding value
 /// on the `Config` account is left unchanged.
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default)]
 pub struct SetConfigArgs {
     /// Optional new fee in basis points (0–10_000).
     ///
@@ -50,8 +70,112 @@ pub struct SetConfigArgs {
 
     /// Optional new policy reference (hash or opaque bytes).
     ///
-    /// If not provided, the existing policy reference is left unchanged.
+    /// If not provided, the existing policy reference is left unchanged. A
+    /// value different from the current one bumps `Config::policy_version`.
     pub policy_ref: Option<[u8; 32]>,
+
+    /// Optional new circuit-breaker flag.
+    ///
+    /// When enabled, `record_metrics` freezes the deployment instead of
+    /// applying a reconciliation that shrinks any total by more than
+    /// `CIRCUIT_BREAKER_MAX_DECREASE_BPS`.
+    pub auto_freeze_on_inconsistency: Option<bool>,
+
+    /// Optional new fee recipient.
+    ///
+    /// If `Some`, the key must not be the default pubkey.
+    pub fee_recipient: Option<Pubkey>,
+
+    /// Optional new URL-keyed registration flag.
+    ///
+    /// When enabled, `register_repo` requires `repo_key` to be the hash of
+    /// the repository URL.
+    pub enforce_url_keyed: Option<bool>,
+
+    /// Optional new deployment-wide observation budget per
+    /// `OBSERVATION_WINDOW_SECS` window. `Some(0)` disables throttling.
+    pub max_observations_per_window: Option<u64>,
+
+    /// Optional new minimum lines of code per observation (at least 1).
+    pub min_loc_per_observation: Option<u64>,
+
+    /// Optional new number of distinct admins required to sign sensitive
+    /// admin actions (1..=`MAX_REQUIRED_ADMIN_SIGS`; 1 = single admin).
+    /// Values above `1 + Config::co_admin_count` fail with
+    /// `AdminQuorumUnreachable`.
+    pub required_admin_sigs: Option<u8>,
+
+    /// Optional new value for `Config::quiet_when_frozen`.
+    ///
+    /// - true  => optional telemetry events are suppressed while frozen
+    /// - false => all events are emitted regardless of lifecycle
+    pub quiet_when_frozen: Option<bool>,
+
+    /// Optional new `Config::credits_per_observation`; 0 disables metering.
+    pub credits_per_observation: Option<u64>,
+
+    /// Optional new `Config::staleness_threshold_secs`; 0 disables
+    /// staleness, negative values fail with `ValueOutOfRange`.
+    pub staleness_threshold_secs: Option<i64>,
+
+    /// Optional new `Config::max_name_len_override`.
+    ///
+    /// Must be within 1..=`MAX_NAME_LEN`; `MAX_NAME_LEN` itself removes the
+    /// override, since it is the default limit.
+    pub max_name_len_override: Option<u16>,
+
+    /// Optional new `Config::require_attestor`:
+    /// - true  => observations must be co-signed by an attestor
+    /// - false => an attestor is optional
+    pub require_attestor: Option<bool>,
+
+    /// Optional new `Config::event_verbosity` (0 = critical only,
+    /// 1 = normal, 2 = debug). Larger values fail with `ValueOutOfRange`.
+    pub event_verbosity: Option<u8>,
+
+    /// Optional new `Config::review_gated`.
+    ///
+    /// - true  => `register_repo` and `finalize_repo` create inactive
+    ///   repositories until an admin activates them through `update_repo`
+    /// - false => new repositories start active
+    pub review_gated: Option<bool>,
+
+    /// Optional new `Config::label_prefix_required`, the prefix every
+    /// fork label must start with. `""` removes the requirement; longer
+    /// than `MAX_LABEL_PREFIX_LEN` fails with `StringTooLong`.
+    pub label_prefix_required: Option<String>,
+
+    /// Optional new `Config::cooldowns`. Replaces all three windows at
+    /// once; a window of 0 disables that cooldown.
+    pub cooldowns: Option<CooldownConfig>,
+
+    /// Optional new `Config::min_observer_lamports`. 0 disables the
+    /// observer balance check.
+    pub min_observer_lamports: Option<u64>,
+
+    /// Optional new `Config::enforce_unique_repo_name`. Only affects repos
+    /// registered afterwards; existing names are not claimed retroactively.
+    pub enforce_unique_repo_name: Option<bool>,
+}
+
+impl Validate for SetConfigArgs {
+    /// Fee within `MAX_FEE_BPS`, a non-zero module cap and a non-default fee
+    /// recipient, when provided. The remaining fields are validated by
+    /// `Config::apply_update`.
+    fn validate(&self) -> Result<()> {
+        if let Some(fee_bps) = self.fee_bps {
+            assert_fee_bps_in_range(fee_bps)?;
+        }
+        if let Some(max_modules) = self.max_modules_per_repo {
+            assert_non_zero_u32(max_modules)?;
+        }
+        if let Some(fee_recipient) = self.fee_recipient {
+            if fee_recipient == Pubkey::default() {
+                return err!(Unit09Error::InvalidFeeRecipient);
+            }
+        }
+        Ok(())
+    }
 }
 
 /// Accounts required for the `set_config` instruction.
@@ -75,6 +199,14 @@ pub struct SetConfig<'info> {
     )]
     pub config: Account<'info, Config>,
 
+    /// Global metrics account, used for event sequencing.
+    #[account(
+        mut,
+        seeds = [METRICS_SEED.as_bytes()],
+        bump = metrics.bump,
+    )]
+    pub metrics: Account<'info, Metrics>,
+
     /// System program (required by Anchor for CPI safety in some flows).
     pub system_program: Program<'info, System>,
 
@@ -89,41 +221,35 @@ pub struct SetConfig<'info> {
 /// Entry point for the `set_config` instruction.
 ///
 /// Steps:
-/// 1. Verify that the caller is the current admin.
+/// 1. Verify that the caller is the current admin and that enough
+///    co-signing admins are present.
 /// 2. Perform early, lightweight validation of arguments.
 /// 3. Call `Config::apply_update` to mutate the configuration.
 /// 4. Emit `ConfigUpdated` event for indexers.
 pub fn handle(ctx: Context<SetConfig>, args: SetConfigArgs) -> Result<()> {
+    let co_signers = ctx.remaining_accounts;
     let SetConfig {
         admin,
         mut config,
+        mut metrics,
         system_program: _,
         clock,
     } = ctx.accounts;
 
     let clock_ref: &Clock = clock;
+    current_checked(clock_ref)?;
 
     // -----------------------------------------------------------------------
     // Admin authority check
     // -----------------------------------------------------------------------
 
-    config.assert_admin(admin)?;
+    config.assert_admin_quorum(admin, co_signers)?;
 
     // -----------------------------------------------------------------------
     // Early validation on provided arguments
     // -----------------------------------------------------------------------
 
-    if let Some(fee_bps) = args.fee_bps {
-        if fee_bps > MAX_FEE_BPS {
-            return err!(Unit09Error::InvalidFeeBps);
-        }
-    }
-
-    if let Some(max_modules) = args.max_modules_per_repo {
-        if max_modules == 0 {
-            return err!(Unit09Error::ValueOutOfRange);
-        }
-    }
+    args.validate()?;
 
     // -----------------------------------------------------------------------
     // Apply updates to Config
@@ -134,6 +260,23 @@ pub fn handle(ctx: Context<SetConfig>, args: SetConfigArgs) -> Result<()> {
         args.max_modules_per_repo,
         args.is_active,
         args.policy_ref,
+        args.auto_freeze_on_inconsistency,
+        args.fee_recipient,
+        args.enforce_url_keyed,
+        args.max_observations_per_window,
+        args.min_loc_per_observation,
+        args.required_admin_sigs,
+        args.quiet_when_frozen,
+        args.credits_per_observation,
+        args.staleness_threshold_secs,
+        args.max_name_len_override,
+        args.require_attestor,
+        args.event_verbosity,
+        args.review_gated,
+        args.label_prefix_required,
+        args.cooldowns,
+        args.min_observer_lamports,
+        args.enforce_unique_repo_name,
         clock_ref,
     )?;
 
@@ -141,10 +284,13 @@ pub fn handle(ctx: Context<SetConfig>, args: SetConfigArgs) -> Result<()> {
     // Emit ConfigUpdated event
     // -----------------------------------------------------------------------
 
+    let event_seq = metrics.next_event_seq()?;
     emit!(ConfigUpdated {
         admin: config.admin,
         fee_bps: config.fee_bps,
         max_modules_per_repo: config.max_modules_per_repo,
+        policy_version: config.policy_version,
+        event_seq,
     });
 
     Ok(())
@@ -0,0 +1,137 @@
+//! ===========================================================================
+//! Unit09 – Set Lifecycle Instruction
+//! Path: contracts/unit09-program/programs/unit09_program/src/instructions/set_lifecycle.rs
+//!
+//! This instruction lets the admin move the deployment between lifecycle
+//! phases and toggle the emergency global freeze.
+//!
+//! When the resulting state is frozen (`Frozen` phase or `global_freeze`),
+//! the provided `freeze_reason` is stored on the `Lifecycle` account so that
+//! operators and users can see why writes are blocked. Leaving the frozen
+//! state clears the reason.
+//!
+//! Notes:
+//! - Only the current `Config::admin` is allowed to call this instruction.
+//! - No lifecycle guard is applied; this is the instruction used to unfreeze.
+//! - A `LifecyclePhaseChanged` event is emitted on every call.
+//!
+//! ===========================================================================
+
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::Unit09Error;
+use crate::events::LifecyclePhaseChanged;
+use crate::state::{Config, Lifecycle, LifecyclePhase, Metrics};
+use crate::utils::time::current_checked;
+
+/// Arguments for the `set_lifecycle` instruction.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct SetLifecycleArgs {
+    /// Target phase as a raw `LifecyclePhase` value.
+    pub phase: u8,
+
+    /// Optional new global freeze flag; `None` leaves it unchanged.
+    pub global_freeze: Option<bool>,
+
+    /// Reason recorded while frozen (at most `MAX_NAME_LEN` bytes).
+    ///
+    /// Ignored when the resulting state is not frozen.
+    pub freeze_reason: String,
+}
+
+/// Accounts required for the `set_lifecycle` instruction.
+#[derive(Accounts)]
+pub struct SetLifecycle<'info> {
+    /// Admin signer; must match `config.admin`.
+    pub admin: Signer<'info>,
+
+    /// Global configuration account.
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Lifecycle account being updated.
+    #[account(
+        mut,
+        seeds = [LIFECYCLE_SEED.as_bytes()],
+        bump = lifecycle.bump,
+    )]
+    pub lifecycle: Account<'info, Lifecycle>,
+
+    /// Global metrics account, used for event sequencing.
+    #[account(
+        mut,
+        seeds = [METRICS_SEED.as_bytes()],
+        bump = metrics.bump,
+    )]
+    pub metrics: Account<'info, Metrics>,
+
+    /// Clock sysvar used for timestamps.
+    pub clock: Sysvar<'info, Clock>,
+}
+
+// ---------------------------------------------------------------------------
+// Handler
+// ---------------------------------------------------------------------------
+
+/// Entry point for the `set_lifecycle` instruction.
+///
+/// Steps:
+/// 1. Verify `admin` matches `config.admin`.
+/// 2. Apply the phase and optional global freeze flag.
+/// 3. Store or clear the freeze reason.
+/// 4. Emit `LifecyclePhaseChanged` event.
+pub fn handle(ctx: Context<SetLifecycle>, args: SetLifecycleArgs) -> Result<()> {
+    let SetLifecycle {
+        admin,
+        config,
+        lifecycle,
+        metrics,
+        clock,
+    } = ctx.accounts;
+
+    let clock_ref: &Clock = clock;
+    current_checked(clock_ref)?;
+
+    // -----------------------------------------------------------------------
+    // Admin authority check
+    // -----------------------------------------------------------------------
+
+    config.assert_admin(admin)?;
+
+    // -----------------------------------------------------------------------
+    // Apply phase and freeze flag
+    // -----------------------------------------------------------------------
+
+    let new_phase =
+        LifecyclePhase::from_u8(args.phase).ok_or(Unit09Error::InvalidLifecycleState)?;
+    let previous_phase = lifecycle.phase;
+
+    lifecycle.set_phase(new_phase, clock_ref)?;
+
+    if let Some(freeze) = args.global_freeze {
+        lifecycle.set_global_freeze(freeze, clock_ref)?;
+    }
+
+    lifecycle.update_freeze_reason(args.freeze_reason)?;
+
+    // -----------------------------------------------------------------------
+    // Emit LifecyclePhaseChanged event
+    // -----------------------------------------------------------------------
+
+    let event_seq = metrics.next_event_seq()?;
+    emit!(LifecyclePhaseChanged {
+        admin: admin.key(),
+        previous_phase,
+        new_phase: lifecycle.phase,
+        global_freeze: lifecycle.global_freeze,
+        freeze_reason: lifecycle.freeze_reason.clone(),
+        changed_at: clock_ref.unix_timestamp,
+        event_seq,
+    });
+
+    Ok(())
+}
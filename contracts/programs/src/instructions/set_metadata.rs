@@ -33,51 +33,53 @@ use anchor_lang::prelude::*;
 use crate::constants::*;
 use crate::errors::Unit09Error;
 use crate::events::GlobalMetadataUpdated;
-use crate::state::{Config, GlobalMetadata, Lifecycle};
+use crate::state::{Config, GlobalMetadata, Lifecycle, Metrics};
+use crate::utils::time::current_checked;
+use crate::utils::validators::{assert_max_len, assert_url_like, Validate};
 
 /// Arguments for the `set_metadata` instruction.
 ///
 /// All fields are optional; only non-`None` values are applied. This makes it
 /// safe to perform partial updates without rewriting the entire structure.
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default)]
 pub struct SetMetadataArgs {
     /// Optional high-level description for the deployment.
     ///
     /// Example:
     /// "Unit09 is a story-driven on-chain AI raccoon that consumes Solana code,
     ///  generates runnable modules, and evolves through forks."
-    pub description: Option<String>;
+    pub description: Option<String>,
 
     /// Optional comma-separated tag string.
     ///
     /// Example:
     /// "solana,ai,module,framework,story"
-    pub tags: Option<String>;
+    pub tags: Option<String>,
 
     /// Optional canonical website URL.
     ///
     /// Example:
     /// "https://unit09.org"
-    pub website_url: Option<String>;
+    pub website_url: Option<String>,
 
     /// Optional documentation URL.
     ///
     /// Example:
     /// "https://docs.unit09.org"
-    pub docs_url: Option<String>;
+    pub docs_url: Option<String>,
 
     /// Optional dashboard URL (metrics, explorers, etc.).
     ///
     /// Example:
     /// "https://unit09.org/dashboard"
-    pub dashboard_url: Option<String>;
+    pub dashboard_url: Option<String>,
 
     /// Optional icon or logo URI.
     ///
     /// Example:
     /// - "https://unit09.org/assets/icon.png"
     /// - "ipfs://Qm..."
-    pub icon_uri: Option<String>;
+    pub icon_uri: Option<String>,
 
     /// Optional extra JSON payload, stored as a string.
     ///
@@ -86,7 +88,41 @@ pub struct SetMetadataArgs {
     ///
     /// Example:
     /// "{ \"theme\": \"wasteland\", \"chapter\": 2 }"
-    pub extra_json: Option<String>;
+    pub extra_json: Option<String>,
+}
+
+impl Validate for SetMetadataArgs {
+    /// Provided fields within the `GlobalMetadata` limits; non-empty website,
+    /// docs and dashboard URLs must use `http(s)://` and a non-empty icon URI
+    /// a known scheme (`MetadataInvalid`).
+    fn validate(&self) -> Result<()> {
+        if let Some(ref description) = self.description {
+            assert_max_len(description, GlobalMetadata::MAX_DESCRIPTION_LEN)?;
+        }
+        if let Some(ref tags) = self.tags {
+            assert_max_len(tags, GlobalMetadata::MAX_TAGS_LEN)?;
+        }
+        for url in [&self.website_url, &self.docs_url, &self.dashboard_url]
+            .iter()
+            .copied()
+            .flatten()
+        {
+            assert_max_len(url, GlobalMetadata::MAX_URL_LEN)?;
+            if !url.is_empty() && !has_basic_url_prefix(url) {
+                return err!(Unit09Error::MetadataInvalid);
+            }
+        }
+        if let Some(ref icon_uri) = self.icon_uri {
+            assert_max_len(icon_uri, GlobalMetadata::MAX_ICON_URI_LEN)?;
+            assert_url_like(icon_uri)?;
+        }
+        // `extra_json` is intentionally not parsed on-chain; structure is
+        // delegated to off-chain tooling.
+        if let Some(ref extra_json) = self.extra_json {
+            assert_max_len(extra_json, GlobalMetadata::MAX_EXTRA_JSON_LEN)?;
+        }
+        Ok(())
+    }
 }
 
 /// Accounts required for the `set_metadata` instruction.
@@ -125,6 +161,14 @@ pub struct SetMetadata<'info> {
     )]
     pub lifecycle: Account<'info, Lifecycle>,
 
+    /// Global metrics account, used for event sequencing.
+    #[account(
+        mut,
+        seeds = [METRICS_SEED.as_bytes()],
+        bump = metrics.bump,
+    )]
+    pub metrics: Account<'info, Metrics>,
+
     /// Global metadata account for this deployment.
     ///
     /// PDA:
@@ -155,7 +199,7 @@ pub struct SetMetadata<'info> {
 /// Entry point for the `set_metadata` instruction.
 ///
 /// Steps:
-/// 1. Ensure lifecycle allows writes.
+/// 1. Ensure lifecycle allows wind-down writes (permitted during `Sunset`).
 /// 2. Ensure caller is the admin stored in `Config`.
 /// 3. Perform field-level validation (length, basic URI sanity).
 /// 4. Initialize or update `GlobalMetadata`.
@@ -165,18 +209,22 @@ pub fn handle(ctx: Context<SetMetadata>, args: SetMetadataArgs) -> Result<()> {
         admin,
         mut config,
         mut lifecycle,
+        mut metrics,
         mut global_metadata,
         system_program: _,
         clock,
     } = ctx.accounts;
 
     let clock_ref: &Clock = clock;
+    current_checked(clock_ref)?;
 
     // -----------------------------------------------------------------------
     // Guards
     // -----------------------------------------------------------------------
 
-    lifecycle.assert_writes_allowed()?;
+    // Metadata updates stay available during `Sunset` so the deployment can
+    // publish wind-down notices.
+    lifecycle.assert_wind_down_allowed()?;
     config.assert_admin(admin)?;
 
     // Optional: require active deployment to change metadata.
@@ -186,73 +234,7 @@ pub fn handle(ctx: Context<SetMetadata>, args: SetMetadataArgs) -> Result<()> {
     // Early validation on provided fields
     // -----------------------------------------------------------------------
 
-    // Description
-    if let Some(ref description) = args.description {
-        if description.len() > GlobalMetadata::MAX_DESCRIPTION_LEN {
-            return err!(Unit09Error::StringTooLong);
-        }
-    }
-
-    // Tags
-    if let Some(ref tags) = args.tags {
-        if tags.len() > GlobalMetadata::MAX_TAGS_LEN {
-            return err!(Unit09Error::StringTooLong);
-        }
-    }
-
-    // Website URL
-    if let Some(ref url) = args.website_url {
-        if url.len() > GlobalMetadata::MAX_URL_LEN {
-            return err!(Unit09Error::StringTooLong);
-        }
-        if !url.is_empty() && !has_basic_url_prefix(url) {
-            return err!(Unit09Error::MetadataInvalid);
-        }
-    }
-
-    // Docs URL
-    if let Some(ref url) = args.docs_url {
-        if url.len() > GlobalMetadata::MAX_URL_LEN {
-            return err!(Unit09Error::StringTooLong);
-        }
-        if !url.is_empty() && !has_basic_url_prefix(url) {
-            return err!(Unit09Error::MetadataInvalid);
-        }
-    }
-
-    // Dashboard URL
-    if let Some(ref url) = args.dashboard_url {
-        if url.len() > GlobalMetadata::MAX_URL_LEN {
-            return err!(Unit09Error::StringTooLong);
-        }
-        if !url.is_empty() && !has_basic_url_prefix(url) {
-            return err!(Unit09Error::MetadataInvalid);
-        }
-    }
-
-    // Icon URI
-    if let Some(ref icon_uri) = args.icon_uri {
-        if icon_uri.len() > GlobalMetadata::MAX_ICON_URI_LEN {
-            return err!(Unit09Error::StringTooLong);
-        }
-        if !icon_uri.is_empty()
-            && !icon_uri.starts_with("http://")
-            && !icon_uri.starts_with("https://")
-            && !icon_uri.starts_with("ipfs://")
-            && !icon_uri.starts_with("ar://")
-        {
-            return err!(Unit09Error::MetadataInvalid);
-        }
-    }
-
-    // Extra JSON
-    if let Some(ref extra_json) = args.extra_json {
-        if extra_json.len() > GlobalMetadata::MAX_EXTRA_JSON_LEN {
-            return err!(Unit09Error::StringTooLong);
-        }
-        // This field is intentionally not parsed on-chain; structure is
-        // delegated to off-chain tooling.
-    }
+    args.validate()?;
 
     // -----------------------------------------------------------------------
     // Derive bump from Anchor context
@@ -300,6 +282,7 @@ pub fn handle(ctx: Context<SetMetadata>, args: SetMetadataArgs) -> Result<()> {
     // Emit GlobalMetadataUpdated event
     // -----------------------------------------------------------------------
 
+    let event_seq = metrics.next_event_seq()?;
     emit!(GlobalMetadataUpdated {
         admin: config.admin,
         description: global_metadata.description.clone(),
@@ -308,6 +291,7 @@ pub fn handle(ctx: Context<SetMetadata>, args: SetMetadataArgs) -> Result<()> {
         dashboard_url: global_metadata.dashboard_url.clone(),
         icon_uri: global_metadata.icon_uri.clone(),
         updated_at: global_metadata.updated_at,
+        event_seq,
     });
 
     Ok(())
@@ -0,0 +1,170 @@
+//! ===========================================================================
+//! Unit09 – Set Primary Module Instruction
+//! Path: contracts/unit09-program/programs/unit09_program/src/instructions/set_primary_module.rs
+//!
+//! This instruction marks one module as the repository's "primary" (flagship)
+//! module, or clears the mark.
+//!
+//! On success this instruction:
+//! - sets or clears `Repo::primary_module` and updates `updated_at`
+//! - emits `PrimaryModuleSet`
+//!
+//! Design notes:
+//! - Only the current `Repo::authority` may call it
+//! - Deployment must be active (`Config`) and writable (`Lifecycle`)
+//! - When setting, the `module` account must be passed, must match
+//!   `args.module`, and must belong to this repository
+//!   (`ModuleRepoMismatch`)
+//! - Passing `None` clears the primary module; no module account is needed
+//!
+//! ===========================================================================
+
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::Unit09Error;
+use crate::events::PrimaryModuleSet;
+use crate::state::{Config, Lifecycle, Metrics, Module, Repo};
+use crate::utils::time::current_checked;
+
+/// Arguments for the `set_primary_module` instruction.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct SetPrimaryModuleArgs {
+    /// PDA of the new primary module, or `None` to clear it.
+    pub module: Option<Pubkey>,
+}
+
+/// Accounts required for the `set_primary_module` instruction.
+#[derive(Accounts)]
+pub struct SetPrimaryModule<'info> {
+    /// Authority that owns this repository entry.
+    pub authority: Signer<'info>,
+
+    /// Global configuration account.
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Lifecycle account controlling high-level operation and freezes.
+    #[account(
+        seeds = [LIFECYCLE_SEED.as_bytes()],
+        bump = lifecycle.bump,
+    )]
+    pub lifecycle: Account<'info, Lifecycle>,
+
+    /// Global metrics account, used for event sequencing.
+    #[account(
+        mut,
+        seeds = [METRICS_SEED.as_bytes()],
+        bump = metrics.bump,
+    )]
+    pub metrics: Account<'info, Metrics>,
+
+    /// Repository whose primary module is changed.
+    ///
+    /// PDA:
+    ///   seeds = [REPO_SEED.as_bytes(), repo.repo_key.as_ref()]
+    ///   bump  = repo.bump
+    #[account(
+        mut,
+        seeds = [
+            REPO_SEED.as_bytes(),
+            repo.repo_key.as_ref(),
+        ],
+        bump = repo.bump,
+        has_one = authority @ Unit09Error::InvalidAuthority,
+    )]
+    pub repo: Account<'info, Repo>,
+
+    /// Module to mark as primary. Required when `args.module` is `Some`.
+    ///
+    /// PDA:
+    ///   seeds = [
+    ///       MODULE_SEED.as_bytes(),
+    ///       module.repo.as_ref(),
+    ///       module.module_key.as_ref(),
+    ///   ]
+    ///   bump  = module.bump
+    #[account(
+        seeds = [
+            MODULE_SEED.as_bytes(),
+            module.repo.as_ref(),
+            module.module_key.as_ref(),
+        ],
+        bump = module.bump,
+    )]
+    pub module: Option<Account<'info, Module>>,
+
+    /// Clock sysvar used for timestamps.
+    pub clock: Sysvar<'info, Clock>,
+}
+
+// ---------------------------------------------------------------------------
+// Handler
+// ---------------------------------------------------------------------------
+
+/// Entry point for the `set_primary_module` instruction.
+///
+/// Steps:
+/// 1. Ensure lifecycle allows writes and config is active.
+/// 2. When setting, check the module account matches `args.module` and
+///    belongs to the repository.
+/// 3. Set or clear `Repo::primary_module`.
+/// 4. Emit `PrimaryModuleSet`.
+pub fn handle(ctx: Context<SetPrimaryModule>, args: SetPrimaryModuleArgs) -> Result<()> {
+    let SetPrimaryModule {
+        authority: _,
+        config,
+        lifecycle,
+        metrics,
+        repo,
+        module,
+        clock,
+    } = ctx.accounts;
+
+    let clock_ref: &Clock = clock;
+    current_checked(clock_ref)?;
+
+    // -----------------------------------------------------------------------
+    // Lifecycle and configuration guards
+    // -----------------------------------------------------------------------
+
+    lifecycle.assert_writes_allowed()?;
+    config.assert_active()?;
+
+    // -----------------------------------------------------------------------
+    // Module ownership check
+    // -----------------------------------------------------------------------
+
+    if let Some(module_address) = args.module {
+        let module = module
+            .as_ref()
+            .ok_or(Unit09Error::MissingRequiredAccount)?;
+        require_keys_eq!(module.key(), module_address, Unit09Error::ValidationFailed);
+        require_keys_eq!(module.repo, repo.key(), Unit09Error::ModuleRepoMismatch);
+    }
+
+    // -----------------------------------------------------------------------
+    // Apply change
+    // -----------------------------------------------------------------------
+
+    let previous_primary_module = repo.primary_module;
+    repo.set_primary_module(args.module, clock_ref);
+
+    // -----------------------------------------------------------------------
+    // Emit event
+    // -----------------------------------------------------------------------
+
+    let event_seq = metrics.next_event_seq()?;
+    emit!(PrimaryModuleSet {
+        repo: repo.key(),
+        primary_module: repo.primary_module,
+        previous_primary_module,
+        updated_at: repo.updated_at,
+        event_seq,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,150 @@
+//! ===========================================================================
+//! Unit09 – Set Repo Fee Override Instruction
+//! Path: contracts/unit09-program/programs/unit09_program/src/instructions/set_repo_fee_override.rs
+//!
+//! This instruction sets or clears a repository's fee rate override
+//! (`Repo::fee_bps_override`), which `collect_fee` uses in place of
+//! `Config::fee_bps` for fees charged on that repository.
+//!
+//! On success this instruction:
+//! - sets or clears `Repo::fee_bps_override` and updates `updated_at`
+//! - emits `RepoFeeOverrideSet`
+//!
+//! Design notes:
+//! - Only the deployment admin (`Config::admin`) may call it; the
+//!   repository authority cannot change its own fee rate
+//! - Deployment must be active (`Config`) and writable (`Lifecycle`)
+//! - `Some(bps)` must not exceed `MAX_FEE_BPS` (`InvalidFeeBps`)
+//! - Passing `None` clears the override
+//!
+//! ===========================================================================
+
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::events::RepoFeeOverrideSet;
+use crate::state::{Config, Lifecycle, Metrics, Repo};
+use crate::utils::time::current_checked;
+use crate::utils::validators::{assert_fee_bps_in_range, Validate};
+
+/// Arguments for the `set_repo_fee_override` instruction.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct SetRepoFeeOverrideArgs {
+    /// New fee rate in basis points, or `None` to clear the override.
+    pub fee_bps_override: Option<u16>,
+}
+
+impl Validate for SetRepoFeeOverrideArgs {
+    /// Override within `MAX_FEE_BPS` (`InvalidFeeBps`), when provided.
+    fn validate(&self) -> Result<()> {
+        if let Some(fee_bps) = self.fee_bps_override {
+            assert_fee_bps_in_range(fee_bps)?;
+        }
+        Ok(())
+    }
+}
+
+/// Accounts required for the `set_repo_fee_override` instruction.
+#[derive(Accounts)]
+pub struct SetRepoFeeOverride<'info> {
+    /// Deployment admin; must match `config.admin`.
+    pub admin: Signer<'info>,
+
+    /// Global configuration account.
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Lifecycle account controlling high-level operation and freezes.
+    #[account(
+        seeds = [LIFECYCLE_SEED.as_bytes()],
+        bump = lifecycle.bump,
+    )]
+    pub lifecycle: Account<'info, Lifecycle>,
+
+    /// Global metrics account, used for event sequencing.
+    #[account(
+        mut,
+        seeds = [METRICS_SEED.as_bytes()],
+        bump = metrics.bump,
+    )]
+    pub metrics: Account<'info, Metrics>,
+
+    /// Repository whose fee override is changed.
+    ///
+    /// PDA:
+    ///   seeds = [REPO_SEED.as_bytes(), repo.repo_key.as_ref()]
+    ///   bump  = repo.bump
+    #[account(
+        mut,
+        seeds = [
+            REPO_SEED.as_bytes(),
+            repo.repo_key.as_ref(),
+        ],
+        bump = repo.bump,
+    )]
+    pub repo: Account<'info, Repo>,
+
+    /// Clock sysvar used for timestamps.
+    pub clock: Sysvar<'info, Clock>,
+}
+
+// ---------------------------------------------------------------------------
+// Handler
+// ---------------------------------------------------------------------------
+
+/// Entry point for the `set_repo_fee_override` instruction.
+///
+/// Steps:
+/// 1. Ensure lifecycle allows writes, config is active and `admin` is the
+///    deployment admin.
+/// 2. Validate arguments (`args.validate()`).
+/// 3. Set or clear `Repo::fee_bps_override`.
+/// 4. Emit `RepoFeeOverrideSet`.
+pub fn handle(ctx: Context<SetRepoFeeOverride>, args: SetRepoFeeOverrideArgs) -> Result<()> {
+    let SetRepoFeeOverride {
+        admin,
+        config,
+        lifecycle,
+        metrics,
+        repo,
+        clock,
+    } = ctx.accounts;
+
+    let clock_ref: &Clock = clock;
+    current_checked(clock_ref)?;
+
+    // -----------------------------------------------------------------------
+    // Lifecycle, configuration and admin guards
+    // -----------------------------------------------------------------------
+
+    lifecycle.assert_writes_allowed()?;
+    config.assert_active()?;
+    config.assert_admin(admin)?;
+    args.validate()?;
+
+    // -----------------------------------------------------------------------
+    // Apply change
+    // -----------------------------------------------------------------------
+
+    let previous_fee_bps_override = repo.fee_bps_override;
+    repo.set_fee_bps_override(args.fee_bps_override)?;
+    repo.updated_at = clock_ref.unix_timestamp;
+
+    // -----------------------------------------------------------------------
+    // Emit event
+    // -----------------------------------------------------------------------
+
+    let event_seq = metrics.next_event_seq()?;
+    emit!(RepoFeeOverrideSet {
+        repo: repo.key(),
+        fee_bps_override: repo.fee_bps_override,
+        previous_fee_bps_override,
+        updated_at: repo.updated_at,
+        event_seq,
+    });
+
+    Ok(())
+}
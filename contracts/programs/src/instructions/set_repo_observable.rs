@@ -0,0 +1,136 @@
+//! ===========================================================================
+//! Unit09 – Set Repo Observable Instruction
+//! Path: contracts/unit09-program/programs/unit09_program/src/instructions/set_repo_observable.rs
+//!
+//! This instruction flips only `Repo::allow_observation`.
+//!
+//! Operators pausing scans on a single repository can use it instead of
+//! `update_repo`, without having to resend (or risk touching) the name, URL,
+//! tags, or activation flag.
+//!
+//! On success this instruction:
+//! - sets `allow_observation` and `updated_at` on the `Repo` account
+//! - emits `RepoObservabilityChanged`
+//!
+//! Design notes:
+//! - Only the current `Repo::authority` may call it
+//! - Deployment must be active (`Config`) and writable (`Lifecycle`)
+//!
+//! ===========================================================================
+
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::Unit09Error;
+use crate::events::RepoObservabilityChanged;
+use crate::state::{Config, Lifecycle, Metrics, Repo};
+use crate::utils::time::current_checked;
+
+/// Arguments for the `set_repo_observable` instruction.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct SetRepoObservableArgs {
+    /// New observation permission.
+    ///
+    /// - true  => automated observation is allowed
+    /// - false => automated observation is paused
+    pub allow_observation: bool,
+}
+
+/// Accounts required for the `set_repo_observable` instruction.
+#[derive(Accounts)]
+pub struct SetRepoObservable<'info> {
+    /// Authority that owns this repository entry.
+    pub authority: Signer<'info>,
+
+    /// Global configuration account.
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Lifecycle account controlling high-level operation and freezes.
+    #[account(
+        seeds = [LIFECYCLE_SEED.as_bytes()],
+        bump = lifecycle.bump,
+    )]
+    pub lifecycle: Account<'info, Lifecycle>,
+
+    /// Global metrics account, used for event sequencing.
+    #[account(
+        mut,
+        seeds = [METRICS_SEED.as_bytes()],
+        bump = metrics.bump,
+    )]
+    pub metrics: Account<'info, Metrics>,
+
+    /// Repository whose observation flag is changed.
+    ///
+    /// PDA:
+    ///   seeds = [REPO_SEED.as_bytes(), repo.repo_key.as_ref()]
+    ///   bump  = repo.bump
+    #[account(
+        mut,
+        seeds = [
+            REPO_SEED.as_bytes(),
+            repo.repo_key.as_ref(),
+        ],
+        bump = repo.bump,
+        has_one = authority @ Unit09Error::InvalidAuthority,
+    )]
+    pub repo: Account<'info, Repo>,
+
+    /// Clock sysvar used for timestamps.
+    pub clock: Sysvar<'info, Clock>,
+}
+
+// ---------------------------------------------------------------------------
+// Handler
+// ---------------------------------------------------------------------------
+
+/// Entry point for the `set_repo_observable` instruction.
+///
+/// Steps:
+/// 1. Ensure lifecycle allows writes and config is active.
+/// 2. Set `Repo::allow_observation`.
+/// 3. Emit `RepoObservabilityChanged`.
+pub fn handle(ctx: Context<SetRepoObservable>, args: SetRepoObservableArgs) -> Result<()> {
+    let SetRepoObservable {
+        authority: _,
+        config,
+        lifecycle,
+        metrics,
+        repo,
+        clock,
+    } = ctx.accounts;
+
+    let clock_ref: &Clock = clock;
+    current_checked(clock_ref)?;
+
+    // -----------------------------------------------------------------------
+    // Lifecycle and configuration guards
+    // -----------------------------------------------------------------------
+
+    lifecycle.assert_writes_allowed()?;
+    config.assert_active()?;
+
+    // -----------------------------------------------------------------------
+    // Apply flag
+    // -----------------------------------------------------------------------
+
+    repo.set_allow_observation(args.allow_observation, clock_ref);
+
+    // -----------------------------------------------------------------------
+    // Emit event
+    // -----------------------------------------------------------------------
+
+    let event_seq = metrics.next_event_seq()?;
+    emit!(RepoObservabilityChanged {
+        repo: repo.key(),
+        allow_observation: repo.allow_observation,
+        updated_at: repo.updated_at,
+        event_seq,
+    });
+
+    Ok(())
+}
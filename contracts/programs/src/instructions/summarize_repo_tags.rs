@@ -0,0 +1,113 @@
+//! ===========================================================================
+//! Unit09 – Summarize Repo Tags Instruction
+//! Path: contracts/unit09-program/programs/unit09_program/src/instructions/summarize_repo_tags.rs
+//!
+//! Read-only query returning the most common tags among the modules of a
+//! repository, for repository overviews.
+//!
+//! Batching:
+//!     remaining_accounts = [module, module, ...]
+//!
+//! Module tags are normalized (see `utils::tags`) and tallied; each module
+//! counts a tag at most once. The `REPO_TAG_SUMMARY_LEN` most common tags
+//! are written with `set_return_data` as a Borsh-encoded `Vec<TagCount>`,
+//! most common first. Ties are broken by tag in lexicographic order (see
+//! `utils::ranking`), so the result does not depend on the module order.
+//!
+//! Guards:
+//! - every remaining account must be a `Module` owned by this program
+//! - every module must belong to `repo` (`ModuleRepoMismatch`)
+//! - no account may be passed twice in `remaining_accounts`
+//!   (`ValidationFailed`)
+//!
+//! ===========================================================================
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::set_return_data;
+
+use crate::constants::*;
+use crate::errors::Unit09Error;
+use crate::state::{Module, Repo};
+use crate::utils::ranking::{rank_top, Ranked};
+use crate::utils::tags::normalized_tag_list;
+use crate::utils::validators::assert_unique_keys;
+
+/// Number of modules of a repository carrying one tag.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct TagCount {
+    /// Normalized tag.
+    pub tag: String,
+
+    /// Number of passed modules carrying the tag.
+    pub count: u32,
+}
+
+impl Ranked for TagCount {
+    type Key = str;
+
+    fn rank_count(&self) -> u64 {
+        self.count as u64
+    }
+
+    fn rank_key(&self) -> &str {
+        &self.tag
+    }
+}
+
+/// Accounts required for the `summarize_repo_tags` instruction.
+///
+/// The repository's `Module` accounts are passed as `remaining_accounts`.
+#[derive(Accounts)]
+pub struct SummarizeRepoTags<'info> {
+    /// Repository whose modules are summarized.
+    ///
+    /// PDA:
+    ///   seeds = [REPO_SEED.as_bytes(), repo.repo_key.as_ref()]
+    ///   bump  = repo.bump
+    #[account(
+        seeds = [
+            REPO_SEED.as_bytes(),
+            repo.repo_key.as_ref(),
+        ],
+        bump = repo.bump,
+    )]
+    pub repo: Account<'info, Repo>,
+}
+
+// ---------------------------------------------------------------------------
+// Handler
+// ---------------------------------------------------------------------------
+
+/// Entry point for the `summarize_repo_tags` instruction.
+///
+/// Steps:
+/// 1. Load every `Module` from `remaining_accounts` and check it belongs to
+///    `repo`.
+/// 2. Tally the normalized tags of all modules.
+/// 3. Rank the tags (`utils::ranking`) and return the most common via
+///    `set_return_data`.
+pub fn handle<'info>(ctx: Context<'_, '_, 'info, 'info, SummarizeRepoTags<'info>>) -> Result<()> {
+    let remaining_accounts = ctx.remaining_accounts;
+    let SummarizeRepoTags { repo } = ctx.accounts;
+
+    assert_unique_keys(remaining_accounts.iter().map(|account_info| account_info.key))?;
+
+    let mut counts: Vec<TagCount> = Vec::new();
+    for account_info in remaining_accounts.iter() {
+        let module: Account<'info, Module> = Account::try_from(account_info)?;
+        require_keys_eq!(module.repo, repo.key(), Unit09Error::ModuleRepoMismatch);
+
+        for tag in normalized_tag_list(&module.tags) {
+            match counts.iter_mut().find(|entry| entry.tag == tag) {
+                Some(entry) => entry.count = entry.count.saturating_add(1),
+                None => counts.push(TagCount { tag, count: 1 }),
+            }
+        }
+    }
+
+    rank_top(&mut counts, REPO_TAG_SUMMARY_LEN);
+
+    set_return_data(&counts.try_to_vec()?);
+
+    Ok(())
+}
@@ -0,0 +1,129 @@
+//! ===========================================================================
+//! Unit09 – Take Metrics Snapshot Instruction
+//! Path: contracts/unit09-program/programs/unit09_program/src/instructions/take_metrics_snapshot.rs
+//!
+//! Admin-only instruction that copies the current `Metrics` counters into a
+//! new, immutable `MetricsSnapshot` account.
+//!
+//! Snapshots are numbered in creation order by `Metrics::snapshot_count`,
+//! so dashboards can derive every snapshot PDA and compare any two of them
+//! with `compare_snapshots`.
+//!
+//! Guards:
+//! - lifecycle must allow wind-down writes (permitted during `Sunset`)
+//! - only `Config::admin` may call this instruction
+//!
+//! PDA layout:
+//! - MetricsSnapshot:
+//!     seeds = [METRICS_SNAPSHOT_SEED.as_bytes(), metrics.snapshot_count.to_le_bytes()]
+//!     bump  = metrics_snapshot.bump
+//!
+//! ===========================================================================
+
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::Unit09Error;
+use crate::state::{Config, Lifecycle, Metrics, MetricsSnapshot};
+use crate::utils::time::current_checked;
+
+/// Accounts required for the `take_metrics_snapshot` instruction.
+#[derive(Accounts)]
+pub struct TakeMetricsSnapshot<'info> {
+    /// Payer for the snapshot account.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Admin signer; must match `config.admin`.
+    pub admin: Signer<'info>,
+
+    /// Global configuration account.
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Lifecycle account controlling global write permissions.
+    #[account(
+        seeds = [LIFECYCLE_SEED.as_bytes()],
+        bump = lifecycle.bump,
+    )]
+    pub lifecycle: Account<'info, Lifecycle>,
+
+    /// Global metrics account; source of the copied counters.
+    #[account(
+        mut,
+        seeds = [METRICS_SEED.as_bytes()],
+        bump = metrics.bump,
+    )]
+    pub metrics: Account<'info, Metrics>,
+
+    /// Snapshot account to be created at the next index.
+    ///
+    /// PDA:
+    ///   seeds = [
+    ///       METRICS_SNAPSHOT_SEED.as_bytes(),
+    ///       &metrics.snapshot_count.to_le_bytes(),
+    ///   ]
+    ///   bump  = metrics_snapshot.bump
+    #[account(
+        init,
+        payer = payer,
+        space = MetricsSnapshot::LEN,
+        seeds = [
+            METRICS_SNAPSHOT_SEED.as_bytes(),
+            &metrics.snapshot_count.to_le_bytes(),
+        ],
+        bump,
+    )]
+    pub metrics_snapshot: Account<'info, MetricsSnapshot>,
+
+    /// System program.
+    pub system_program: Program<'info, System>,
+
+    /// Clock sysvar used for timestamps.
+    pub clock: Sysvar<'info, Clock>,
+}
+
+// ---------------------------------------------------------------------------
+// Handler
+// ---------------------------------------------------------------------------
+
+/// Entry point for the `take_metrics_snapshot` instruction.
+///
+/// Steps:
+/// 1. Ensure lifecycle allows wind-down writes and the caller is admin.
+/// 2. Claim the next snapshot index.
+/// 3. Copy the current counters into the snapshot.
+pub fn handle(ctx: Context<TakeMetricsSnapshot>) -> Result<()> {
+    let snapshot_bump = *ctx.bumps.get("metrics_snapshot").ok_or(Unit09Error::InternalError)?;
+
+    let TakeMetricsSnapshot {
+        payer: _,
+        admin,
+        config,
+        lifecycle,
+        metrics,
+        metrics_snapshot,
+        system_program: _,
+        clock,
+    } = ctx.accounts;
+
+    // -----------------------------------------------------------------------
+    // Lifecycle and admin guards
+    // -----------------------------------------------------------------------
+
+    lifecycle.assert_wind_down_allowed()?;
+    config.assert_admin(admin)?;
+    current_checked(clock)?;
+
+    // -----------------------------------------------------------------------
+    // Capture snapshot
+    // -----------------------------------------------------------------------
+
+    let index = metrics.next_snapshot_index()?;
+    metrics_snapshot.capture(index, metrics, snapshot_bump, clock);
+
+    Ok(())
+}
@@ -0,0 +1,195 @@
+//! ===========================================================================
+//! Unit09 – Top Up Credits Instruction
+//! Path: contracts/unit09-program/programs/unit09_program/src/instructions/top_up_credits.rs
+//!
+//! This instruction buys prepaid observation credits for an observer.
+//!
+//! The payer transfers `amount` lamports to `Config::fee_recipient` and the
+//! observer's `ObserverCredits` balance grows by `amount` credits (1 credit
+//! per lamport). The credits account is created on the first top-up.
+//!
+//! Observation runs are then charged `Config::credits_per_observation`
+//! (see `ObserverCredits::charge`).
+//!
+//! Guards:
+//! - lifecycle must allow writes (`Lifecycle::assert_writes_allowed`)
+//! - global config must be active (`Config::assert_active`)
+//! - a fee recipient must be configured (`Config::assert_fee_recipient_set`)
+//!
+//! Notes:
+//! - Anyone may top up any observer; the credits can only be spent by
+//!   observations signed by that observer.
+//!
+//! PDA layout:
+//! - ObserverCredits:
+//!     seeds = [OBSERVER_CREDITS_SEED.as_bytes(), observer.as_ref()]
+//!     bump  = observer_credits.bump
+//!
+//! ===========================================================================
+
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+
+use crate::constants::*;
+use crate::errors::Unit09Error;
+use crate::events::CreditsToppedUp;
+use crate::state::{Config, Lifecycle, Metrics, ObserverCredits};
+use crate::utils::time::current_checked;
+use crate::utils::validators::{assert_non_zero_u64, Validate};
+
+/// Arguments for the `top_up_credits` instruction.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct TopUpCreditsArgs {
+    /// Observer whose balance is topped up.
+    pub observer: Pubkey,
+
+    /// Lamports to pay, and credits to add. Must be non-zero.
+    pub amount: u64,
+}
+
+impl Validate for TopUpCreditsArgs {
+    /// Non-zero amount (`ValueOutOfRange`).
+    fn validate(&self) -> Result<()> {
+        assert_non_zero_u64(self.amount)
+    }
+}
+
+/// Accounts required for the `top_up_credits` instruction.
+#[derive(Accounts)]
+#[instruction(args: TopUpCreditsArgs)]
+pub struct TopUpCredits<'info> {
+    /// Account paying for the credits (and for the credits account rent on
+    /// the first top-up).
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Global configuration account.
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Lifecycle account controlling global write permissions.
+    #[account(
+        seeds = [LIFECYCLE_SEED.as_bytes()],
+        bump = lifecycle.bump,
+    )]
+    pub lifecycle: Account<'info, Lifecycle>,
+
+    /// Global metrics account, used for event sequencing.
+    #[account(
+        mut,
+        seeds = [METRICS_SEED.as_bytes()],
+        bump = metrics.bump,
+    )]
+    pub metrics: Account<'info, Metrics>,
+
+    /// Fee recipient; must match `config.fee_recipient`.
+    #[account(
+        mut,
+        address = config.fee_recipient @ Unit09Error::InvalidFeeRecipient,
+    )]
+    pub fee_recipient: SystemAccount<'info>,
+
+    /// Prepaid credits of `args.observer`, created on first use.
+    ///
+    /// PDA:
+    ///   seeds = [OBSERVER_CREDITS_SEED.as_bytes(), args.observer.as_ref()]
+    ///   bump  = observer_credits.bump
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = ObserverCredits::LEN,
+        seeds = [
+            OBSERVER_CREDITS_SEED.as_bytes(),
+            args.observer.as_ref(),
+        ],
+        bump,
+    )]
+    pub observer_credits: Account<'info, ObserverCredits>,
+
+    /// System program used for account creation and the lamport transfer.
+    pub system_program: Program<'info, System>,
+
+    /// Clock sysvar used for timestamps.
+    pub clock: Sysvar<'info, Clock>,
+}
+
+// ---------------------------------------------------------------------------
+// Handler
+// ---------------------------------------------------------------------------
+
+/// Entry point for the `top_up_credits` instruction.
+///
+/// Steps:
+/// 1. Enforce lifecycle and config guards.
+/// 2. Initialize `ObserverCredits` on first use.
+/// 3. Transfer `args.amount` lamports from `payer` to `fee_recipient`.
+/// 4. Credit the observer's balance.
+/// 5. Emit `CreditsToppedUp`.
+pub fn handle(ctx: Context<TopUpCredits>, args: TopUpCreditsArgs) -> Result<()> {
+    let credits_bump = *ctx.bumps.get("observer_credits").ok_or(Unit09Error::InternalError)?;
+
+    let TopUpCredits {
+        payer,
+        config,
+        lifecycle,
+        metrics,
+        fee_recipient,
+        observer_credits,
+        system_program,
+        clock,
+    } = ctx.accounts;
+
+    let clock_ref: &Clock = clock;
+    current_checked(clock_ref)?;
+
+    // -----------------------------------------------------------------------
+    // Lifecycle and configuration guards
+    // -----------------------------------------------------------------------
+
+    lifecycle.assert_writes_allowed()?;
+    config.assert_active()?;
+    config.assert_fee_recipient_set()?;
+
+    args.validate()?;
+
+    // -----------------------------------------------------------------------
+    // Initialize on first use
+    // -----------------------------------------------------------------------
+
+    if observer_credits.created_at == 0 && observer_credits.updated_at == 0 {
+        observer_credits.init(args.observer, credits_bump, clock_ref);
+    }
+
+    // -----------------------------------------------------------------------
+    // Payment and balance update
+    // -----------------------------------------------------------------------
+
+    let cpi_ctx = CpiContext::new(
+        system_program.to_account_info(),
+        Transfer {
+            from: payer.to_account_info(),
+            to: fee_recipient.to_account_info(),
+        },
+    );
+    system_program::transfer(cpi_ctx, args.amount)?;
+
+    observer_credits.top_up(args.amount, clock_ref)?;
+
+    // -----------------------------------------------------------------------
+    // Emit CreditsToppedUp event
+    // -----------------------------------------------------------------------
+
+    let event_seq = metrics.next_event_seq()?;
+    emit!(CreditsToppedUp {
+        observer: args.observer,
+        payer: payer.key(),
+        amount: args.amount,
+        balance: observer_credits.balance,
+        event_seq,
+    });
+
+    Ok(())
+}
@@ -20,6 +20,11 @@
 //! - lifecycle must allow writes (`Lifecycle::assert_writes_allowed`)
 //! - global config must be active (`Config::assert_active`)
 //! - only the fork owner may update the fork (`Fork::assert_owner`)
+//! - a new label must fit `Config::max_name_len` (`StringTooLong`) and
+//!   start with `Config::label_prefix_required`, when set
+//!   (`ValidationFailed`)
+//! - an inactive fork can only be reactivated (`is_active: Some(true)`);
+//!   any other update fails with `ForkInactive`
 //!
 //! Design notes:
 //! - All fields in `UpdateForkStateArgs` are optional; only provided values
@@ -35,12 +40,14 @@ use anchor_lang::prelude::*;
 use crate::constants::*;
 use crate::errors::Unit09Error;
 use crate::events::ForkUpdated;
-use crate::state::{Config, Fork, Lifecycle};
+use crate::state::{Config, Fork, Lifecycle, Metrics};
+use crate::utils::time::current_checked;
+use crate::utils::validators::{assert_max_len, assert_optional_str_len, assert_url_like, Validate};
 
 /// Arguments for the `update_fork_state` instruction.
 ///
 /// All fields are optional; only non-`None` values will be applied.
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default)]
 pub struct UpdateForkStateArgs {
     /// Optional new label for this fork.
     ///
@@ -66,6 +73,25 @@ pub struct UpdateForkStateArgs {
     pub is_active: Option<bool>,
 }
 
+impl Validate for UpdateForkStateArgs {
+    /// Provided label and metadata URI non-empty and within the `Fork`
+    /// limits, the metadata URI with a known scheme, and tags within
+    /// `Fork::MAX_TAGS_LEN`. The `Config` label length limit is checked by
+    /// the handler.
+    fn validate(&self) -> Result<()> {
+        assert_optional_str_len(&self.label, Fork::MAX_LABEL_LEN)?;
+        assert_optional_str_len(&self.metadata_uri, Fork::MAX_METADATA_URI_LEN)?;
+        if let Some(ref metadata_uri) = self.metadata_uri {
+            // Basic scheme check to avoid obviously malformed URIs.
+            assert_url_like(metadata_uri)?;
+        }
+        if let Some(ref tags) = self.tags {
+            assert_max_len(tags, Fork::MAX_TAGS_LEN)?;
+        }
+        Ok(())
+    }
+}
+
 /// Accounts required for the `update_fork_state` instruction.
 #[derive(Accounts)]
 pub struct UpdateForkState<'info> {
@@ -93,6 +119,14 @@ pub struct UpdateForkState<'info> {
     )]
     pub lifecycle: Account<'info, Lifecycle>,
 
+    /// Global metrics account, used to track active fork counts.
+    #[account(
+        mut,
+        seeds = [METRICS_SEED.as_bytes()],
+        bump = metrics.bump,
+    )]
+    pub metrics: Account<'info, Metrics>,
+
     /// Fork account to be updated.
     ///
     /// PDA:
@@ -127,7 +161,8 @@ pub struct UpdateForkState<'info> {
 ///
 /// Steps:
 /// 1. Ensure lifecycle allows writes and config is active.
-/// 2. Ensure caller is the fork owner.
+/// 2. Ensure caller is the fork owner and the fork is active, unless the
+///    update reactivates it.
 /// 3. Validate any provided label / metadata / tags values.
 /// 4. Apply updates via `Fork::apply_update`.
 /// 5. Emit `ForkUpdated` event.
@@ -136,12 +171,14 @@ pub fn handle(ctx: Context<UpdateForkState>, args: UpdateForkStateArgs) -> Resul
         owner,
         mut config,
         mut lifecycle,
+        mut metrics,
         mut fork,
         system_program: _,
         clock,
     } = ctx.accounts;
 
     let clock_ref: &Clock = clock;
+    current_checked(clock_ref)?;
 
     // -----------------------------------------------------------------------
     // Lifecycle and configuration guards
@@ -154,42 +191,18 @@ pub fn handle(ctx: Context<UpdateForkState>, args: UpdateForkStateArgs) -> Resul
     // `has_one = owner` but we keep the explicit check for clarity.
     fork.assert_owner(owner)?;
 
+    // Reactivation is the one change allowed on an inactive fork; it may
+    // carry metadata edits since the fork ends up active.
+    fork.assert_update_allowed(args.is_active == Some(true))?;
+
     // -----------------------------------------------------------------------
     // Early validation on provided fields
     // -----------------------------------------------------------------------
 
+    args.validate()?;
     if let Some(ref label) = args.label {
-        if label.is_empty() {
-            return err!(Unit09Error::StringEmpty);
-        }
-        if label.len() > Fork::MAX_LABEL_LEN {
-            return err!(Unit09Error::StringTooLong);
-        }
-    }
-
-    if let Some(ref metadata_uri) = args.metadata_uri {
-        if metadata_uri.is_empty() {
-            return err!(Unit09Error::StringEmpty);
-        }
-        if metadata_uri.len() > Fork::MAX_METADATA_URI_LEN {
-            return err!(Unit09Error::StringTooLong);
-        }
-
-        // Optional: basic scheme check to avoid obviously malformed URIs.
-        let has_known_prefix = metadata_uri.starts_with("http://")
-            || metadata_uri.starts_with("https://")
-            || metadata_uri.starts_with("ipfs://")
-            || metadata_uri.starts_with("ar://");
-
-        if !has_known_prefix {
-            return err!(Unit09Error::MetadataInvalid);
-        }
-    }
-
-    if let Some(ref tags) = args.tags {
-        if tags.len() > Fork::MAX_TAGS_LEN {
-            return err!(Unit09Error::StringTooLong);
-        }
+        config.assert_name_len(label)?;
+        config.assert_label_prefix(label)?;
     }
 
     let previous_is_active = fork.is_active;
@@ -206,16 +219,27 @@ pub fn handle(ctx: Context<UpdateForkState>, args: UpdateForkStateArgs) -> Resul
         clock_ref,
     )?;
 
+    if fork.is_active != previous_is_active {
+        if fork.is_active {
+            metrics.increment_active_forks()?;
+        } else {
+            metrics.decrement_active_forks();
+        }
+        metrics.updated_at = clock_ref.unix_timestamp;
+    }
+
     // -----------------------------------------------------------------------
     // Emit ForkUpdated event
     // -----------------------------------------------------------------------
 
+    let event_seq = metrics.next_event_seq()?;
     emit!(ForkUpdated {
         fork: fork.key(),
         owner: fork.owner,
         previous_is_active,
         new_is_active: fork.is_active,
         updated_at: fork.updated_at,
+        event_seq,
     });
 
     Ok(())
@@ -27,6 +27,12 @@
 //! - Global config must be active
 //! - Repo must be active
 //! - Only repo authority may update its modules
+//! - The module's immutability window must have passed
+//!   (`Module::immutable_until`, `ModuleImmutable`)
+//! - The module touch cooldown must have elapsed since the module was last
+//!   updated (`CooldownActive`, see `CooldownConfig`)
+//! - With `Repo::enforce_unique_module_uri`, a new metadata URI must not
+//!   match a recently used module URI of the repo (`MetadataInvalid`)
 //!
 //! ===========================================================================
 
@@ -35,12 +41,17 @@ use anchor_lang::prelude::*;
 use crate::constants::*;
 use crate::errors::Unit09Error;
 use crate::events::{ModuleUpdated, ModuleVersionRegistered};
-use crate::state::{Config, Lifecycle, Module, ModuleVersion, Repo};
+use crate::state::{Config, Lifecycle, Metrics, Module, ModuleVersion, Repo};
+use crate::utils::time::current_checked;
+use crate::utils::validators::{
+    assert_max_len, assert_non_empty_str, assert_optional_str_len, assert_semver_non_zero,
+    Validate,
+};
 
 /// Arguments for the `update_module` instruction.
 ///
 /// All fields are optional; only provided values will be updated.
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default)]
 pub struct UpdateModuleArgs {
     /// Optional new name for the module.
     pub name: Option<String>,
@@ -54,6 +65,10 @@ pub struct UpdateModuleArgs {
     /// Optional new tags for discovery.
     pub tags: Option<String>,
 
+    /// Optional explicit `TAG_VOCABULARY` bitmask, as an alternative to the
+    /// string form. Vocabulary tags present in `tags` stay set regardless.
+    pub tag_bits: Option<u64>,
+
     /// Optional activation flag.
     pub is_active: Option<bool>,
 
@@ -70,13 +85,46 @@ pub struct UpdateModuleArgs {
     /// Version label for the snapshot.
     pub version_label: Option<String>,
 
-    /// Changelog URI for the snapshot.
+    /// Optional new module changelog URI (`""` clears it); also used for
+    /// the snapshot when one is created.
     pub changelog_uri: Option<String>,
 
     /// Whether the version is considered stable.
     pub is_stable: Option<bool>,
 }
 
+impl Validate for UpdateModuleArgs {
+    /// Provided strings non-empty (name, metadata URI, category) and within
+    /// the `Module` limits. With `create_version_snapshot`, `new_version`
+    /// must be set and non-zero, and the version label and changelog URI
+    /// must fit `ModuleVersion`. The name length limit comes from `Config`
+    /// and is checked by the handler.
+    fn validate(&self) -> Result<()> {
+        if let Some(ref name) = self.name {
+            assert_non_empty_str(name)?;
+        }
+        assert_optional_str_len(&self.metadata_uri, Module::MAX_METADATA_URI_LEN)?;
+        assert_optional_str_len(&self.category, Module::MAX_CATEGORY_LEN)?;
+        if let Some(ref tags) = self.tags {
+            assert_max_len(tags, Module::MAX_TAGS_LEN)?;
+        }
+
+        if self.create_version_snapshot {
+            // Version must be provided when snapshotting.
+            let version = self.new_version.ok_or(Unit09Error::ValueOutOfRange)?;
+            assert_semver_non_zero(version)?;
+
+            if let Some(ref version_label) = self.version_label {
+                assert_max_len(version_label, ModuleVersion::MAX_LABEL_LEN)?;
+            }
+            if let Some(ref changelog_uri) = self.changelog_uri {
+                assert_max_len(changelog_uri, ModuleVersion::MAX_CHANGELOG_URI_LEN)?;
+            }
+        }
+        Ok(())
+    }
+}
+
 /// Accounts required for the `update_module` instruction.
 #[derive(Accounts)]
 pub struct UpdateModule<'info> {
@@ -100,6 +148,14 @@ pub struct UpdateModule<'info> {
     )]
     pub lifecycle: Account<'info, Lifecycle>,
 
+    /// Global metrics account, used to track active module counts.
+    #[account(
+        mut,
+        seeds = [METRICS_SEED.as_bytes()],
+        bump = metrics.bump,
+    )]
+    pub metrics: Account<'info, Metrics>,
+
     /// Repository that owns this module.
     #[account(
         mut,
@@ -129,13 +185,14 @@ pub struct UpdateModule<'info> {
     /// This account will be initialized ONLY when:
     ///     args.create_version_snapshot == true
     ///
+    /// Snapshots are seeded by their index, so this is the PDA of the next
+    /// snapshot (`module.version_count`).
+    ///
     /// PDA Seeds:
     ///   seeds = [
     ///       MODULE_VERSION_SEED.as_bytes(),
     ///       module.key().as_ref(),
-    ///       &version.0.to_le_bytes(),
-    ///       &version.1.to_le_bytes(),
-    ///       &version.2.to_le_bytes(),
+    ///       &module.version_count.to_le_bytes(),
     ///   ]
     #[account(
         init_if_needed,
@@ -144,15 +201,7 @@ pub struct UpdateModule<'info> {
         seeds = [
             MODULE_VERSION_SEED.as_bytes(),
             module.key().as_ref(),
-            // We will fill version bytes inside handler after validation
-            // anchor does not allow dynamic seeds here in code comments
-            // but we will derive bump using ctx.bumps if needed
-            // see handler below
-            // placeholder bytes replaced in handler logic
-            // dummy for compile
-            &[0u8; 2],
-            &[0u8; 2],
-            &[0u8; 2],
+            &module.version_count.to_le_bytes(),
         ],
         bump,
     )]
@@ -174,6 +223,7 @@ pub fn handle(ctx: Context<UpdateModule>, args: UpdateModuleArgs) -> Result<()>
         authority: _,
         mut config,
         mut lifecycle,
+        mut metrics,
         mut repo,
         mut module,
         mut module_version,
@@ -182,6 +232,7 @@ pub fn handle(ctx: Context<UpdateModule>, args: UpdateModuleArgs) -> Result<()>
     } = ctx.accounts;
 
     let clock_ref: &Clock = clock;
+    current_checked(clock_ref)?;
 
     // -----------------------------------------------------------------------
     // Guards
@@ -191,74 +242,30 @@ pub fn handle(ctx: Context<UpdateModule>, args: UpdateModuleArgs) -> Result<()>
     config.assert_active()?;
     repo.assert_active()?;
     repo.assert_authority(&ctx.accounts.authority)?;
+    module.assert_mutable(clock_ref)?;
+    config
+        .cooldowns
+        .assert_module_touch_elapsed(module.updated_at, clock_ref)?;
 
     // -----------------------------------------------------------------------
     // Early validation
     // -----------------------------------------------------------------------
 
+    args.validate()?;
     if let Some(ref name) = args.name {
-        if name.is_empty() {
-            return err!(Unit09Error::StringEmpty);
-        }
-        if name.len() > Module::MAX_NAME_LEN {
-            return err!(Unit09Error::StringTooLong);
-        }
-    }
-
-    if let Some(ref metadata_uri) = args.metadata_uri {
-        if metadata_uri.is_empty() {
-            return err!(Unit09Error::StringEmpty);
-        }
-        if metadata_uri.len() > Module::MAX_METADATA_URI_LEN {
-            return err!(Unit09Error::StringTooLong);
-        }
-    }
-
-    if let Some(ref category) = args.category {
-        if category.is_empty() {
-            return err!(Unit09Error::StringEmpty);
-        }
-        if category.len() > Module::MAX_CATEGORY_LEN {
-            return err!(Unit09Error::StringTooLong);
-        }
-    }
-
-    if let Some(ref tags) = args.tags {
-        if tags.len() > Module::MAX_TAGS_LEN {
-            return err!(Unit09Error::StringTooLong);
-        }
-    }
-
-    if args.create_version_snapshot {
-        // Version must be provided when snapshotting.
-        let version = args
-            .new_version
-            .ok_or(Unit09Error::ValueOutOfRange)?;
-
-        let (major, minor, patch) = version;
-        if major == 0 && minor == 0 && patch == 0 {
-            return err!(Unit09Error::ValueOutOfRange);
-        }
-
-        // Version label
-        if let Some(ref vlabel) = args.version_label {
-            if vlabel.len() > ModuleVersion::MAX_LABEL_LEN {
-                return err!(Unit09Error::StringTooLong);
-            }
-        }
-
-        // Changelog URI
-        if let Some(ref curl) = args.changelog_uri {
-            if curl.len() > ModuleVersion::MAX_CHANGELOG_URI_LEN {
-                return err!(Unit09Error::StringTooLong);
-            }
-        }
+        config.assert_name_len(name)?;
     }
 
     // -----------------------------------------------------------------------
     // Apply updates to Module
     // -----------------------------------------------------------------------
 
+    if let Some(ref uri) = args.metadata_uri {
+        if *uri != module.metadata_uri {
+            repo.remember_module_uri(uri)?;
+        }
+    }
+
     let previous_is_active = module.is_active;
     let previous_version = (module.major_version, module.minor_version, module.patch_version);
 
@@ -271,6 +278,21 @@ pub fn handle(ctx: Context<UpdateModule>, args: UpdateModuleArgs) -> Result<()>
         args.new_version,
         clock_ref,
     )?;
+    if let Some(tag_bits) = args.tag_bits {
+        module.set_tag_bits(tag_bits)?;
+    }
+    if let Some(ref changelog_uri) = args.changelog_uri {
+        module.set_changelog_uri(changelog_uri.clone(), clock_ref)?;
+    }
+
+    if module.is_active != previous_is_active {
+        if module.is_active {
+            metrics.increment_active_modules()?;
+        } else {
+            metrics.decrement_active_modules();
+        }
+        metrics.updated_at = clock_ref.unix_timestamp;
+    }
 
     // -----------------------------------------------------------------------
     // Create ModuleVersion snapshot (optional)
@@ -280,16 +302,17 @@ pub fn handle(ctx: Context<UpdateModule>, args: UpdateModuleArgs) -> Result<()>
         let version = args.new_version.unwrap();
         let (major, minor, patch) = version;
 
-        // Recompute PDA bump since our seeds are dynamic
         let bump = *ctx
             .bumps
             .get("module_version")
             .ok_or(Unit09Error::InternalError)?;
+        let index = module.next_version_index()?;
 
         module_version.init(
             module.key(),
             module.authority,
             version,
+            index,
             module.metadata_uri.clone(),
             args.changelog_uri.unwrap_or_else(|| "".to_string()),
             args.version_label.unwrap_or_else(|| "".to_string()),
@@ -298,12 +321,14 @@ pub fn handle(ctx: Context<UpdateModule>, args: UpdateModuleArgs) -> Result<()>
             clock_ref,
         )?;
 
+        let event_seq = metrics.next_event_seq()?;
         emit!(ModuleVersionRegistered {
             module: module.key(),
             major_version: major,
             minor_version: minor,
             patch_version: patch,
             is_stable: module_version.is_stable,
+            event_seq,
         });
     }
 
@@ -311,6 +336,7 @@ pub fn handle(ctx: Context<UpdateModule>, args: UpdateModuleArgs) -> Result<()>
     // Emit ModuleUpdated
     // -----------------------------------------------------------------------
 
+    let event_seq = metrics.next_event_seq()?;
     emit!(ModuleUpdated {
         module: module.key(),
         repo: repo.key(),
@@ -323,6 +349,8 @@ pub fn handle(ctx: Context<UpdateModule>, args: UpdateModuleArgs) -> Result<()>
         previous_is_active,
         new_is_active: module.is_active,
         updated_at: module.updated_at,
+        changelog_uri: module.changelog_uri.clone(),
+        event_seq,
     });
 
     Ok(())
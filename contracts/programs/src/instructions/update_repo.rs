@@ -11,34 +11,60 @@
 //! - change the human-readable name
 //! - update the canonical URL
 //! - adjust tags used for discovery
+//! - set or clear the repository metadata URI
 //! - toggle `is_active`
 //! - toggle `allow_observation`
+//! - require an access proof on observations (`require_access_proof`)
+//!
+//! Activating an inactive repository while `Config::review_gated` is set
+//! additionally requires the deployment admin to co-sign as `admin`.
+//! `Repo::fee_bps_override` is set by the admin through
+//! `set_repo_fee_override` instead.
 //!
 //! On success this instruction:
 //! - mutates the `Repo` account fields via `Repo::apply_update`
 //! - updates the `updated_at` timestamp
+//! - adjusts `Metrics::active_repos` when `is_active` changes
 //! - emits:
 //!     * `RepoUpdated` (always)
 //!     * `RepoActivationChanged` (when `is_active` changes)
 //!
 //! Design notes:
-//! - Only the current `Repo::authority` may perform updates
+//! - Only the current `Repo::authority` may perform updates; its
+//!   maintainers (`Repo::maintainers`) may change metadata only
 //! - Deployment must be active (`Config`) and writable (`Lifecycle`)
-//! - All arguments are optional; only provided fields are updated
+//! - Reserved repositories (see `reserve_repo`) must be finalized first
+//! - All arguments are optional; only provided fields are validated and
+//!   updated, so a tags-only update leaves name and URL untouched
+//! - `RepoUpdated::changed_fields` reports which fields actually changed
+//! - A repository holding the `RepoNameIndex` of its current name cannot
+//!   be renamed (`ValidationFailed`), since the old index cannot be freed
+//!   yet; renames therefore require `current_name_index`. While
+//!   `Config::enforce_unique_repo_name` is set no repository can be renamed
+//! - A new URL on a host in `Config::blocked_hosts` fails with
+//!   `RepoBlocked`; other updates of a repo on such a host still work
+//! - A clock behind `Repo::updated_at` fails with `InvalidTimeRange`
+//!   (see `utils::time::current_checked_since`)
+//! - The account is never reallocated: shorter strings leave its size (and
+//!   rent) unchanged, because `Repo` is allocated at `Repo::LEN` (see there)
 //! ===========================================================================
 
 use anchor_lang::prelude::*;
 
 use crate::constants::*;
 use crate::errors::Unit09Error;
-use crate::events::{RepoActivationChanged, RepoUpdated};
-use crate::state::{Config, Lifecycle, Repo};
+use crate::events::{repo_update_fields, RepoActivationChanged, RepoUpdated};
+use crate::state::{Config, Lifecycle, Metrics, Repo, RepoNameIndex};
+use crate::utils::time::current_checked_since;
+use crate::utils::validators::{
+    assert_max_len, assert_non_empty_str, assert_optional_str_len, Validate,
+};
 
 /// Arguments for the `update_repo` instruction.
 ///
 /// All fields are optional. If a field is `None`, the corresponding value on
 /// the `Repo` account is left unchanged.
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default)]
 pub struct UpdateRepoArgs {
     /// Optional new human-readable repository name.
     ///
@@ -55,6 +81,11 @@ pub struct UpdateRepoArgs {
     /// Example: "solana,anchor,protocol"
     pub tags: Option<String>,
 
+    /// Optional new metadata URI. `Some("")` clears the URI.
+    ///
+    /// Example: "https://unit09.org/meta/repo/unit09-solana-core.json"
+    pub metadata_uri: Option<String>,
+
     /// Optional new activation flag.
     ///
     /// - true  => repository is active and can be observed
@@ -66,21 +97,89 @@ pub struct UpdateRepoArgs {
     /// - true  => automated observation is allowed
     /// - false => automated observation should be disabled
     pub allow_observation: Option<bool>,
+
+    /// Optional replacement observer allow-list (at most
+    /// `MAX_ALLOWED_OBSERVERS` entries). `Some(vec![])` lets any signer
+    /// observe again.
+    pub allowed_observers: Option<Vec<Pubkey>>,
+
+    /// Optional explicit `TAG_VOCABULARY` bitmask, as an alternative to the
+    /// string form. Vocabulary tags present in `tags` stay set regardless.
+    pub tag_bits: Option<u64>,
+
+    /// Optional new value for `Repo::enforce_unique_module_uri`.
+    ///
+    /// - true  => modules may not reuse a recently used metadata URI
+    /// - false => duplicate module metadata URIs are allowed
+    pub enforce_unique_module_uri: Option<bool>,
+
+    /// Optional new value for `Repo::require_access_proof`.
+    ///
+    /// - true  => observations must carry an `access_proof` (private repos)
+    /// - false => the access proof is optional
+    pub require_access_proof: Option<bool>,
+}
+
+impl UpdateRepoArgs {
+    /// Whether any field beyond the metadata (name, URL, tags, metadata URI,
+    /// tag bits) is set. Only the repository authority may change those;
+    /// maintainers are limited to metadata.
+    pub fn touches_authority_fields(&self) -> bool {
+        self.is_active.is_some()
+            || self.allow_observation.is_some()
+            || self.allowed_observers.is_some()
+            || self.enforce_unique_module_uri.is_some()
+            || self.require_access_proof.is_some()
+    }
+}
+
+impl Validate for UpdateRepoArgs {
+    /// Provided strings within the `Repo` limits (name and URL also
+    /// non-empty) and at most `Repo::MAX_ALLOWED_OBSERVERS` observers. The
+    /// name length limit comes from `Config` and is checked by the handler.
+    fn validate(&self) -> Result<()> {
+        if let Some(ref name) = self.name {
+            assert_non_empty_str(name)?;
+        }
+        assert_optional_str_len(&self.url, Repo::MAX_URL_LEN)?;
+        if let Some(ref tags) = self.tags {
+            assert_max_len(tags, Repo::MAX_TAGS_LEN)?;
+        }
+        if let Some(ref metadata_uri) = self.metadata_uri {
+            assert_max_len(metadata_uri, Repo::MAX_METADATA_URI_LEN)?;
+        }
+        if let Some(ref allowed_observers) = self.allowed_observers {
+            if allowed_observers.len() > Repo::MAX_ALLOWED_OBSERVERS {
+                return err!(Unit09Error::ValueOutOfRange);
+            }
+        }
+        Ok(())
+    }
 }
 
 /// Accounts required for the `update_repo` instruction.
 #[derive(Accounts)]
 pub struct UpdateRepo<'info> {
-    /// Authority that owns this repository entry.
+    /// Authority that owns this repository entry, or one of its
+    /// maintainers.
     ///
-    /// Must match `repo.authority`. Only this signer can update the repo.
+    /// Must match `repo.authority` or be listed in `repo.maintainers`.
+    /// Maintainers may only change metadata (see
+    /// `UpdateRepoArgs::touches_authority_fields`).
     #[account(mut)]
     pub authority: Signer<'info>,
 
+    /// Deployment admin (`config.admin`).
+    ///
+    /// Required only when activating a repository while
+    /// `config.review_gated` is set.
+    pub admin: Option<Signer<'info>>,
+
     /// Global configuration account.
     ///
-    /// Used to ensure the deployment is active. Admin authority is not
-    /// required to update a repository; repository-level authority is used.
+    /// Used to ensure the deployment is active and to read `review_gated`.
+    /// Repository-level authority is used for updates; admin authority is
+    /// only required to activate a repository in review-gated mode.
     #[account(
         mut,
         seeds = [CONFIG_SEED.as_bytes()],
@@ -96,6 +195,14 @@ pub struct UpdateRepo<'info> {
     )]
     pub lifecycle: Account<'info, Lifecycle>,
 
+    /// Global metrics account, used to track active repository counts.
+    #[account(
+        mut,
+        seeds = [METRICS_SEED.as_bytes()],
+        bump = metrics.bump,
+    )]
+    pub metrics: Account<'info, Metrics>,
+
     /// Repository to be updated.
     ///
     /// PDA:
@@ -108,10 +215,24 @@ pub struct UpdateRepo<'info> {
             repo.repo_key.as_ref(),
         ],
         bump = repo.bump,
-        has_one = authority @ Unit09Error::InvalidAuthority,
     )]
     pub repo: Account<'info, Repo>,
 
+    /// `RepoNameIndex` PDA of the current `repo.name`, which may not exist.
+    ///
+    /// Required only when `args.name` renames the repository.
+    ///
+    /// CHECK: The seeds pin it to the current name; the handler only reads
+    /// it when it is an initialized account owned by this program.
+    #[account(
+        seeds = [
+            REPO_NAME_INDEX_SEED.as_bytes(),
+            &RepoNameIndex::name_hash(&repo.name),
+        ],
+        bump,
+    )]
+    pub current_name_index: Option<UncheckedAccount<'info>>,
+
     /// System program (required by Anchor for some flows).
     pub system_program: Program<'info, System>,
 
@@ -127,21 +248,28 @@ pub struct UpdateRepo<'info> {
 ///
 /// Steps:
 /// 1. Ensure lifecycle allows writes and config is active.
-/// 2. Ensure caller is the repository authority.
+/// 2. Ensure caller is the repository authority (or a maintainer changing
+///    metadata only), and that the admin co-signs when a review-gated
+///    repository is activated.
 /// 3. Perform early string length validation on provided values.
 /// 4. Call `Repo::apply_update` to mutate fields.
-/// 5. Emit `RepoUpdated` and optionally `RepoActivationChanged`.
+/// 5. Adjust active repository metrics on activation changes.
+/// 6. Emit `RepoUpdated` and optionally `RepoActivationChanged`.
 pub fn handle(ctx: Context<UpdateRepo>, args: UpdateRepoArgs) -> Result<()> {
     let UpdateRepo {
-        authority: _,
+        authority,
+        admin,
         mut config,
         mut lifecycle,
+        mut metrics,
         mut repo,
+        current_name_index,
         system_program: _,
         clock,
     } = ctx.accounts;
 
     let clock_ref: &Clock = clock;
+    current_checked_since(clock_ref, repo.updated_at)?;
 
     // -----------------------------------------------------------------------
     // Lifecycle and configuration guards
@@ -153,44 +281,76 @@ pub fn handle(ctx: Context<UpdateRepo>, args: UpdateRepoArgs) -> Result<()> {
     // Ensure the configuration is currently active.
     config.assert_active()?;
 
-    // `has_one = authority` in the account constraint already enforces that
-    // the signer is the repo authority, but we keep an explicit check for
-    // clarity and defensiveness in case constraints are modified later.
-    repo.assert_authority(&ctx.accounts.authority)?;
+    // Maintainers may edit metadata; everything else stays with the
+    // repository authority.
+    let signer = authority.key();
+    repo.assert_maintainer_or_authority(&signer)?;
+    if signer != repo.authority && args.touches_authority_fields() {
+        return err!(Unit09Error::InvalidAuthority);
+    }
 
-    // -----------------------------------------------------------------------
-    // Early validation on provided arguments
-    // -----------------------------------------------------------------------
+    // Reserved repositories are activated by `finalize_repo` only.
+    repo.assert_not_reserved()?;
 
-    if let Some(ref name) = args.name {
-        if name.is_empty() {
-            return err!(Unit09Error::StringEmpty);
+    // Renaming would leave the old `RepoNameIndex` claimed, and nothing can
+    // release it yet, so unique names are fixed once registered. Without
+    // enforcement only repositories that claimed their name are fixed.
+    if args.name.as_ref().map_or(false, |name| *name != repo.name) {
+        if config.enforce_unique_repo_name {
+            return err!(Unit09Error::ValidationFailed);
         }
-        if name.len() > Repo::MAX_NAME_LEN {
-            return err!(Unit09Error::StringTooLong);
+
+        let index_info = current_name_index
+            .as_ref()
+            .ok_or(Unit09Error::MissingRequiredAccount)?;
+        if index_info.owner == &crate::ID && !index_info.data_is_empty() {
+            let data = index_info.try_borrow_data()?;
+            let index = RepoNameIndex::try_deserialize(&mut &data[..])?;
+            if index.repo == repo.key() {
+                return err!(Unit09Error::ValidationFailed);
+            }
         }
     }
 
-    if let Some(ref url) = args.url {
-        if url.is_empty() {
-            return err!(Unit09Error::StringEmpty);
-        }
-        if url.len() > Repo::MAX_URL_LEN {
-            return err!(Unit09Error::StringTooLong);
+    // Activation in review-gated mode is admin-only on top of the repo
+    // authority.
+    let activates_gated_repo =
+        args.is_active == Some(true) && !repo.is_active && config.review_gated;
+    if activates_gated_repo {
+        let admin = admin
+            .as_ref()
+            .ok_or(Unit09Error::UnauthorizedAdminAction)?;
+        if admin.key() != config.admin {
+            return err!(Unit09Error::UnauthorizedAdminAction);
         }
     }
 
-    if let Some(ref tags) = args.tags {
-        if tags.len() > Repo::MAX_TAGS_LEN {
-            return err!(Unit09Error::StringTooLong);
-        }
+    // -----------------------------------------------------------------------
+    // Early validation on provided arguments
+    // -----------------------------------------------------------------------
+
+    args.validate()?;
+    if let Some(ref name) = args.name {
+        config.assert_name_len(name)?;
+    }
+    if let Some(ref url) = args.url {
+        config.assert_host_allowed(url)?;
     }
 
     // -----------------------------------------------------------------------
-    // Detect activation changes for event emission
+    // Snapshot previous values for change detection
     // -----------------------------------------------------------------------
 
+    let previous_name = repo.name.clone();
+    let previous_url = repo.url.clone();
+    let previous_tags = repo.tags.clone();
+    let previous_tag_bits = repo.tag_bits;
+    let previous_metadata_uri = repo.metadata_uri.clone();
     let previous_is_active = repo.is_active;
+    let previous_allow_observation = repo.allow_observation;
+    let previous_allowed_observers = repo.allowed_observers.clone();
+    let previous_enforce_unique_module_uri = repo.enforce_unique_module_uri;
+    let previous_require_access_proof = repo.require_access_proof;
 
     // -----------------------------------------------------------------------
     // Apply updates to Repo
@@ -200,18 +360,76 @@ pub fn handle(ctx: Context<UpdateRepo>, args: UpdateRepoArgs) -> Result<()> {
         args.name,
         args.url,
         args.tags,
+        args.metadata_uri,
         args.is_active,
         args.allow_observation,
+        args.allowed_observers,
         clock_ref,
     )?;
+    if let Some(tag_bits) = args.tag_bits {
+        repo.set_tag_bits(tag_bits)?;
+    }
+    if let Some(enforce) = args.enforce_unique_module_uri {
+        repo.enforce_unique_module_uri = enforce;
+    }
+    if let Some(require) = args.require_access_proof {
+        repo.require_access_proof = require;
+    }
+
+    let mut changed_fields: u16 = 0;
+    if repo.name != previous_name {
+        changed_fields |= repo_update_fields::NAME;
+    }
+    if repo.url != previous_url {
+        changed_fields |= repo_update_fields::URL;
+    }
+    if repo.tags != previous_tags || repo.tag_bits != previous_tag_bits {
+        changed_fields |= repo_update_fields::TAGS;
+    }
+    if repo.metadata_uri != previous_metadata_uri {
+        changed_fields |= repo_update_fields::METADATA_URI;
+    }
+    if repo.is_active != previous_is_active {
+        changed_fields |= repo_update_fields::IS_ACTIVE;
+    }
+    if repo.allow_observation != previous_allow_observation {
+        changed_fields |= repo_update_fields::ALLOW_OBSERVATION;
+    }
+    if repo.allowed_observers != previous_allowed_observers {
+        changed_fields |= repo_update_fields::ALLOWED_OBSERVERS;
+    }
+    if repo.enforce_unique_module_uri != previous_enforce_unique_module_uri {
+        changed_fields |= repo_update_fields::ENFORCE_UNIQUE_MODULE_URI;
+    }
+    if repo.require_access_proof != previous_require_access_proof {
+        changed_fields |= repo_update_fields::REQUIRE_ACCESS_PROOF;
+    }
+
+    // -----------------------------------------------------------------------
+    // Track active repository count
+    // -----------------------------------------------------------------------
+
+    if repo.is_active != previous_is_active {
+        if repo.is_active {
+            metrics.increment_active_repos()?;
+        } else {
+            metrics.decrement_active_repos();
+        }
+        metrics.updated_at = clock_ref.unix_timestamp;
+    }
 
     // -----------------------------------------------------------------------
     // Emit RepoUpdated event (always)
     // -----------------------------------------------------------------------
 
+    let event_seq = metrics.next_event_seq()?;
     emit!(RepoUpdated {
         repo: repo.key(),
+        changed_fields,
         url: repo.url.clone(),
+        host: repo.host,
+        metadata_uri: repo.metadata_uri.clone(),
+        event_seq,
     });
 
     // -----------------------------------------------------------------------
@@ -219,10 +437,12 @@ pub fn handle(ctx: Context<UpdateRepo>, args: UpdateRepoArgs) -> Result<()> {
     // -----------------------------------------------------------------------
 
     if repo.is_active != previous_is_active {
+        let event_seq = metrics.next_event_seq()?;
         emit!(RepoActivationChanged {
             repo: repo.key(),
             is_active: repo.is_active,
             updated_at: repo.updated_at,
+            event_seq,
         });
     }
 
@@ -65,6 +65,8 @@ pub mod unit09_program {
     /// Accounts:
     /// - `config`   – PDA storing global configuration
     /// - `metrics`  – PDA storing global metrics
+    /// - `lifecycle` – PDA storing lifecycle flags
+    /// - `global_metadata` – optional PDA, required when `require_all` is set
     /// - `payer`    – funds account creations
     /// - `system_program`
     pub fn initialize(ctx: Context<Initialize>, args: InitializeArgs) -> Result<()> {
@@ -82,6 +84,73 @@ pub mod unit09_program {
         instructions::set_config::handler(ctx, args)
     }
 
+    /// Charge `Config::fee_bps` on a lamport amount and transfer the fee
+    /// to `Config::fee_recipient`.
+    ///
+    /// Accounts:
+    /// - `payer`          – signer, pays the fee
+    /// - `config`         – configuration PDA
+    /// - `fee_recipient`  – must match `config.fee_recipient`
+    /// - `repo`           – repository paid for, owned by `payer`; its
+    ///                      `fee_bps_override` takes precedence
+    /// - `system_program`
+    pub fn collect_fee(ctx: Context<CollectFee>, args: CollectFeeArgs) -> Result<()> {
+        instructions::collect_fee(ctx, args)
+    }
+
+    /// Change the lifecycle phase and/or global freeze flag, recording a
+    /// freeze reason while frozen.
+    ///
+    /// Accounts:
+    /// - `admin`     – signer, must match `config.admin`
+    /// - `config`    – configuration PDA
+    /// - `lifecycle` – lifecycle PDA
+    /// - `metrics`   – metrics PDA (event sequencing)
+    pub fn set_lifecycle(ctx: Context<SetLifecycle>, args: SetLifecycleArgs) -> Result<()> {
+        instructions::set_lifecycle(ctx, args)
+    }
+
+    /// Add a host to `Config::blocked_hosts`, rejecting new repositories
+    /// on it with `RepoBlocked` (admin only).
+    pub fn add_blocked_host(ctx: Context<AddBlockedHost>, args: AddBlockedHostArgs) -> Result<()> {
+        instructions::add_blocked_host(ctx, args)
+    }
+
+    /// Remove a host from `Config::blocked_hosts` (admin only).
+    pub fn remove_blocked_host(
+        ctx: Context<RemoveBlockedHost>,
+        args: RemoveBlockedHostArgs,
+    ) -> Result<()> {
+        instructions::remove_blocked_host(ctx, args)
+    }
+
+    /// Register a co-admin by creating its global `Authority` entry with the
+    /// admin role (admin quorum required).
+    ///
+    /// Accounts:
+    /// - `admin`     – signer and payer, must match `config.admin`
+    /// - `config`    – configuration PDA
+    /// - `metrics`   – metrics PDA (event sequencing)
+    /// - `authority` – `Authority` PDA of the new co-admin
+    /// - `remaining_accounts` – `[signer, authority]` co-signer pairs
+    pub fn add_co_admin(ctx: Context<AddCoAdmin>, args: AddCoAdminArgs) -> Result<()> {
+        instructions::add_co_admin(ctx, args)
+    }
+
+    /// Remove a co-admin and close its `Authority` entry (admin quorum
+    /// required). Fails with `AdminQuorumUnreachable` if the remaining admins
+    /// could not reach `Config::required_admin_sigs`.
+    ///
+    /// Accounts:
+    /// - `admin`     – signer, must match `config.admin`; receives the rent
+    /// - `config`    – configuration PDA
+    /// - `metrics`   – metrics PDA (event sequencing)
+    /// - `authority` – `Authority` PDA of the co-admin to remove
+    /// - `remaining_accounts` – `[signer, authority]` co-signer pairs
+    pub fn remove_co_admin(ctx: Context<RemoveCoAdmin>) -> Result<()> {
+        instructions::remove_co_admin(ctx)
+    }
+
     // -------------------------------------------------------------------------
     //  Repository Management
     // -------------------------------------------------------------------------
@@ -102,6 +171,22 @@ pub mod unit09_program {
         instructions::register_repo::handler(ctx, args)
     }
 
+    /// Pre-allocate a repository PDA without activating it.
+    ///
+    /// The entry stays inactive and uncounted until `finalize_repo`.
+    pub fn reserve_repo(ctx: Context<ReserveRepo>, args: ReserveRepoArgs) -> Result<()> {
+        instructions::reserve_repo(ctx, args)
+    }
+
+    /// Fill in and activate a repository reserved by `reserve_repo`.
+    ///
+    /// In review-gated mode (`Config::review_gated`) the repository stays
+    /// inactive until an admin activates it. Emits `RepoRegistered` like a
+    /// direct registration.
+    pub fn finalize_repo(ctx: Context<FinalizeRepo>, args: FinalizeRepoArgs) -> Result<()> {
+        instructions::finalize_repo(ctx, args)
+    }
+
     /// Update repository metadata and activation status.
     ///
     /// Allows the authority to:
@@ -111,10 +196,85 @@ pub mod unit09_program {
     /// Accounts:
     /// - `repo`      – target repository account
     /// - `authority` – signer, must match `repo.authority`
+    /// - `admin`     – optional signer, required to activate a repository in
+    ///                 review-gated mode
+    /// - `current_name_index` – `RepoNameIndex` PDA of the current name,
+    ///                 required to rename the repository
     pub fn update_repo(ctx: Context<UpdateRepo>, args: UpdateRepoArgs) -> Result<()> {
         instructions::update_repo::handler(ctx, args)
     }
 
+    /// Add a maintainer who may update repository metadata via
+    /// `update_repo` (repository authority only).
+    pub fn add_maintainer(ctx: Context<AddMaintainer>, args: AddMaintainerArgs) -> Result<()> {
+        instructions::add_maintainer(ctx, args)
+    }
+
+    /// Remove a maintainer from the repository (repository authority only).
+    pub fn remove_maintainer(
+        ctx: Context<RemoveMaintainer>,
+        args: RemoveMaintainerArgs,
+    ) -> Result<()> {
+        instructions::remove_maintainer(ctx, args)
+    }
+
+    /// Enable or disable automated observation for a repository.
+    ///
+    /// Flips only `Repo::allow_observation`; all other metadata is left
+    /// untouched.
+    ///
+    /// Accounts:
+    /// - `repo`      – target repository account
+    /// - `authority` – signer, must match `repo.authority`
+    pub fn set_repo_observable(
+        ctx: Context<SetRepoObservable>,
+        args: SetRepoObservableArgs,
+    ) -> Result<()> {
+        instructions::set_repo_observable(ctx, args)
+    }
+
+    /// Mark a module as the repository's primary module, or clear it.
+    ///
+    /// Only the repository authority may call this. The module must belong to
+    /// the repository (`ModuleRepoMismatch`); passing `None` clears the mark.
+    pub fn set_primary_module(
+        ctx: Context<SetPrimaryModule>,
+        args: SetPrimaryModuleArgs,
+    ) -> Result<()> {
+        instructions::set_primary_module(ctx, args)
+    }
+
+    /// Set or clear a repository's fee rate override used by `collect_fee`
+    /// (deployment admin only).
+    ///
+    /// Accounts:
+    /// - `admin`     – signer, must match `config.admin`
+    /// - `config`    – configuration PDA
+    /// - `lifecycle` – lifecycle PDA
+    /// - `metrics`   – metrics PDA (event sequencing)
+    /// - `repo`      – target repository account
+    pub fn set_repo_fee_override(
+        ctx: Context<SetRepoFeeOverride>,
+        args: SetRepoFeeOverrideArgs,
+    ) -> Result<()> {
+        instructions::set_repo_fee_override(ctx, args)
+    }
+
+    /// Rename a tag across a batch of repositories (admin only).
+    ///
+    /// Accounts:
+    /// - `admin`     – admin signer
+    /// - `config`    – configuration PDA
+    /// - `lifecycle` – lifecycle PDA
+    /// - `metrics`   – metrics PDA
+    /// - remaining accounts – writable `Repo` accounts to migrate
+    pub fn rename_tag<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RenameTag<'info>>,
+        args: RenameTagArgs,
+    ) -> Result<()> {
+        instructions::rename_tag(ctx, args)
+    }
+
     // -------------------------------------------------------------------------
     //  Module Management
     // -------------------------------------------------------------------------
@@ -154,11 +314,18 @@ pub mod unit09_program {
     /// to a repository, or when reorganizing modules across repositories.
     ///
     /// Accounts:
-    /// - `module`    – module to relink (authority must sign)
-    /// - `repo`      – target repository
-    /// - `authority` – signer, must match `module.authority`
-    pub fn link_module_to_repo(ctx: Context<LinkModuleToRepo>) -> Result<()> {
-        instructions::link_module_to_repo::handler(ctx)
+    /// - `module`      – module to relink
+    /// - `repo`        – target repository
+    /// - `source_repo` – `module.repo`, required for cross-repo links
+    /// - `authority`   – signer, module authority, repo authority or admin
+    ///
+    /// Cross-repo links require both repos to share an authority unless the
+    /// admin signs.
+    pub fn link_module_to_repo(
+        ctx: Context<LinkModuleToRepo>,
+        args: LinkModuleToRepoArgs,
+    ) -> Result<()> {
+        instructions::link_module_to_repo(ctx, args)
     }
 
     // -------------------------------------------------------------------------
@@ -182,6 +349,22 @@ pub mod unit09_program {
         instructions::create_fork::handler(ctx, args)
     }
 
+    /// Create the signer's `OwnerForkIndex`, enabling fork label uniqueness
+    /// checks in `create_fork` when the index is passed.
+    pub fn init_owner_fork_index(ctx: Context<InitOwnerForkIndex>) -> Result<()> {
+        instructions::init_owner_fork_index(ctx)
+    }
+
+    /// Create a new fork from an existing template fork.
+    ///
+    /// The clone uses `"<source.label>-<label_suffix>"` as its label, copies the
+    /// source metadata URI and tags, and links to the source with
+    /// `parent = source.fork_key` and `depth = source.depth + 1` (bounded by
+    /// `MAX_FORK_DEPTH`).
+    pub fn clone_fork(ctx: Context<CloneFork>, args: CloneForkArgs) -> Result<()> {
+        instructions::clone_fork(ctx, args)
+    }
+
     /// Update the state of an existing fork.
     ///
     /// This can:
@@ -195,6 +378,26 @@ pub mod unit09_program {
         instructions::update_fork_state::handler(ctx, args)
     }
 
+    /// Reassign a fork to a new owner without the current owner's signature.
+    ///
+    /// Recovery tool for forks whose owner key was lost; only the config
+    /// admin may call it.
+    pub fn admin_reassign_fork(
+        ctx: Context<AdminReassignFork>,
+        args: AdminReassignForkArgs,
+    ) -> Result<()> {
+        instructions::admin_reassign_fork(ctx, args)
+    }
+
+    /// Close inactive, childless forks passed as writable remaining accounts,
+    /// refunding their rent to `refund_recipient` (admin only).
+    pub fn prune_forks<'info>(
+        ctx: Context<'_, '_, 'info, 'info, PruneForks<'info>>,
+        args: PruneForksArgs,
+    ) -> Result<()> {
+        instructions::prune_forks(ctx, args)
+    }
+
     // -------------------------------------------------------------------------
     //  Observations and Metrics
     // -------------------------------------------------------------------------
@@ -214,6 +417,31 @@ pub mod unit09_program {
         instructions::record_observation::handler(ctx, args)
     }
 
+    /// Record one scan that spans several repositories, crediting each repo
+    /// (passed in `remaining_accounts`) with its split and aggregating global
+    /// metrics once.
+    ///
+    /// Accounts:
+    /// - `observer`  – signer
+    /// - `config`    – configuration PDA
+    /// - `lifecycle` – lifecycle PDA
+    /// - `metrics`   – metrics PDA
+    /// - remaining accounts – writable `Repo` accounts referenced by the splits
+    pub fn record_observation_multi<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RecordObservationMulti<'info>>,
+        args: RecordObservationMultiArgs,
+    ) -> Result<()> {
+        instructions::record_observation_multi(ctx, args)
+    }
+
+    /// Buy prepaid observation credits for an observer.
+    ///
+    /// Pays `amount` lamports to the fee recipient and adds the same number of
+    /// credits to the observer's `ObserverCredits` balance.
+    pub fn top_up_credits(ctx: Context<TopUpCredits>, args: TopUpCreditsArgs) -> Result<()> {
+        instructions::top_up_credits(ctx, args)
+    }
+
     /// Manually adjust aggregate metrics.
     ///
     /// This is an escape hatch for:
@@ -227,6 +455,45 @@ pub mod unit09_program {
         instructions::record_metrics::handler(ctx, args)
     }
 
+    /// Re-derive repository-based metrics totals from `Repo` accounts.
+    ///
+    /// Admin-only. Repositories are passed via `remaining_accounts`. The first
+    /// batch sets `reset`, the last sets `finalize`; a single call may set both.
+    /// Emits `MetricsRecomputed` on finalize.
+    pub fn recompute_metrics(
+        ctx: Context<RecomputeMetrics>,
+        args: RecomputeMetricsArgs,
+    ) -> Result<()> {
+        instructions::recompute_metrics(ctx, args)
+    }
+
+    /// Copy the current `Metrics` counters into a new, immutable
+    /// `MetricsSnapshot` at the next index (admin only).
+    pub fn take_metrics_snapshot(ctx: Context<TakeMetricsSnapshot>) -> Result<()> {
+        instructions::take_metrics_snapshot(ctx)
+    }
+
+    /// Roll the `Metrics` counters back to a `MetricsSnapshot`.
+    ///
+    /// Destructive, so it always requires `Config::required_admin_sigs` distinct
+    /// admins: co-signers are passed in `remaining_accounts` as
+    /// `[signer, authority]` pairs, like for `set_config`. Emits `MetricsRestored`.
+    pub fn restore_metrics(ctx: Context<RestoreMetrics>) -> Result<()> {
+        instructions::restore_metrics(ctx)
+    }
+
+    /// Acknowledge that the off-chain indexer processed `count` observations,
+    /// decrementing `Metrics::pending_unindexed` (saturating at zero).
+    ///
+    /// Accounts:
+    /// - `admin`     – signer, must match `config.admin`
+    /// - `config`    – configuration PDA
+    /// - `lifecycle` – lifecycle PDA
+    /// - `metrics`   – metrics PDA
+    pub fn ack_indexed(ctx: Context<AckIndexed>, args: AckIndexedArgs) -> Result<()> {
+        instructions::ack_indexed(ctx, args)
+    }
+
     // -------------------------------------------------------------------------
     //  Global Metadata
     // -------------------------------------------------------------------------
@@ -245,6 +512,204 @@ pub mod unit09_program {
     pub fn set_metadata(ctx: Context<SetMetadata>, args: SetMetadataArgs) -> Result<()> {
         instructions::set_metadata::handler(ctx, args)
     }
+
+    // -------------------------------------------------------------------------
+    //  Read-only Queries
+    // -------------------------------------------------------------------------
+
+    /// Report the rent-exempt minimum balance for each core account type.
+    ///
+    /// Read-only; the result is returned via `set_return_data` as a
+    /// Borsh-encoded `RentEstimates` struct. Call it in a simulated
+    /// transaction to fund PDAs without hard-coding account sizes.
+    ///
+    /// Accounts:
+    /// - `rent` – rent sysvar
+    pub fn estimate_rents(ctx: Context<EstimateRents>) -> Result<()> {
+        instructions::estimate_rents(ctx)
+    }
+
+    /// Report approximate compute-unit costs for each write instruction.
+    ///
+    /// Read-only; the result is returned via `set_return_data` as a
+    /// Borsh-encoded `Vec<CuEstimate>` built from `CU_ESTIMATES`. The values are
+    /// static reference numbers for capacity planning.
+    pub fn get_cu_estimates(ctx: Context<GetCuEstimates>) -> Result<()> {
+        instructions::get_cu_estimates(ctx)
+    }
+
+    /// Report which groups of instructions the deployment-wide guards
+    /// currently allow.
+    ///
+    /// Read-only; the result is returned via `set_return_data` as a
+    /// Borsh-encoded `u32` of `permission_bits`.
+    ///
+    /// Accounts:
+    /// - `config`    – global config PDA
+    /// - `lifecycle` – lifecycle PDA
+    pub fn get_permissions(ctx: Context<GetPermissions>) -> Result<()> {
+        instructions::get_permissions(ctx)
+    }
+
+    /// Report which instruction groups an `Authority` entry may call.
+    ///
+    /// Read-only; the result is returned via `set_return_data` as a
+    /// Borsh-encoded `u32` of `permission_bits`, derived from the entry's roles
+    /// only (see `Authority::permissions`).
+    ///
+    /// Accounts:
+    /// - `authority` – `Authority` entry to inspect
+    pub fn get_authority_permissions(ctx: Context<GetAuthorityPermissions>) -> Result<()> {
+        instructions::get_authority_permissions(ctx)
+    }
+
+    /// Report which of the given repositories are stale.
+    ///
+    /// Returns the stale `Repo` PDAs from `remaining_accounts` as a Borsh
+    /// `Vec<Pubkey>` via return data (see `Config::staleness_threshold_secs`).
+    pub fn get_stale_repos<'info>(
+        ctx: Context<'_, '_, 'info, 'info, GetStaleRepos<'info>>,
+    ) -> Result<()> {
+        instructions::get_stale_repos(ctx)
+    }
+
+    /// Return the number of `ModuleVersion` snapshots of a module as a Borsh
+    /// `u32` via return data.
+    pub fn get_version_count(ctx: Context<GetVersionCount>) -> Result<()> {
+        instructions::get_version_count(ctx)
+    }
+
+    /// Return per-counter deltas and the time span between two
+    /// `MetricsSnapshot` accounts via `set_return_data`.
+    pub fn compare_snapshots(ctx: Context<CompareSnapshots>) -> Result<()> {
+        instructions::compare_snapshots(ctx)
+    }
+
+    /// Return `Repo::score`, the weighted activity score of a repository, as
+    /// return data (Borsh `u64`).
+    pub fn get_repo_score(ctx: Context<GetRepoScore>) -> Result<()> {
+        instructions::get_repo_score(ctx)
+    }
+
+    /// Return a versioned `RepoView` of a repository as return data,
+    /// independent of the `Repo` account layout.
+    pub fn get_repo(ctx: Context<GetRepo>) -> Result<()> {
+        instructions::get_repo(ctx)
+    }
+
+    /// Return the most common module tags of a repository as return data
+    /// (Borsh `Vec<TagCount>`). Modules are passed as `remaining_accounts`.
+    pub fn summarize_repo_tags<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SummarizeRepoTags<'info>>,
+    ) -> Result<()> {
+        instructions::summarize_repo_tags(ctx)
+    }
+
+    /// Return whether a repository carries `args.tag` as return data
+    /// (Borsh `bool`), using the matching rule of `utils::tags::has_tag`.
+    pub fn repo_has_tag(ctx: Context<RepoHasTag>, args: RepoHasTagArgs) -> Result<()> {
+        instructions::repo_has_tag(ctx, args)
+    }
+
+    /// Check whether `ancestor` (a fork key) appears in a fork's parent chain.
+    ///
+    /// Read-only; the parent chain is passed as remaining accounts, nearest
+    /// parent first, and walked up to `MAX_FORK_DEPTH` parents. The result is
+    /// returned via `set_return_data` as a Borsh-encoded `bool`. Malformed
+    /// chains fail with `InvalidForkParent`.
+    ///
+    /// Accounts:
+    /// - `candidate` – fork PDA whose lineage is checked
+    pub fn check_fork_descendant(
+        ctx: Context<CheckForkDescendant>,
+        args: CheckForkDescendantArgs,
+    ) -> Result<()> {
+        instructions::check_fork_descendant(ctx, args)
+    }
+
+    /// Return the estimated number of forks below a fork.
+    ///
+    /// The value is written as return data (Borsh `u64`). It only counts
+    /// descendants created with this fork passed as an ancestor to
+    /// `create_fork`.
+    ///
+    /// Accounts:
+    /// - `fork` – fork whose subtree size is returned
+    pub fn get_descendant_count(ctx: Context<GetDescendantCount>) -> Result<()> {
+        instructions::get_descendant_count(ctx)
+    }
+
+    // -------------------------------------------------------------------------
+    //  Maintenance and Diagnostics
+    // -------------------------------------------------------------------------
+
+    /// Re-derive each singleton PDA and verify its cached bump, failing with
+    /// `InternalError` on drift. Diagnostic only; mutates nothing.
+    ///
+    /// Accounts:
+    /// - `admin`           – signer, must match `config.admin`
+    /// - `config`          – configuration PDA
+    /// - `metrics`         – metrics PDA
+    /// - `lifecycle`       – lifecycle PDA
+    /// - `global_metadata` – optional, omit if never created
+    pub fn refresh_bumps(ctx: Context<RefreshBumps>) -> Result<()> {
+        instructions::refresh_bumps(ctx)
+    }
+
+    /// Upgrade the global `Config` and `Metrics` accounts to
+    /// `CURRENT_SCHEMA_VERSION`.
+    ///
+    /// Grows both accounts to their current `LEN` and initializes the fields
+    /// appended since the stored version. Must run before the other `migrate_*`
+    /// instructions. Admin only; rejects a deployment that is already current
+    /// with `MigrationAlreadyApplied`.
+    ///
+    /// Accounts:
+    /// - `admin`   – signer and payer, must match `config.admin`
+    /// - `config`  – configuration PDA to upgrade
+    /// - `metrics` – metrics PDA to upgrade
+    pub fn migrate_deployment(ctx: Context<MigrateDeployment>) -> Result<()> {
+        instructions::migrate_deployment(ctx)
+    }
+
+    /// Upgrade a repository account to `CURRENT_SCHEMA_VERSION`.
+    ///
+    /// Grows the account to `Repo::LEN` and initializes the fields appended
+    /// since the stored version. Admin only; rejects repositories that are
+    /// already current with `MigrationAlreadyApplied`.
+    ///
+    /// Accounts:
+    /// - `admin` – signer and payer, must match `config.admin`
+    /// - `repo`  – repository account to upgrade
+    pub fn migrate_repo(ctx: Context<MigrateRepo>) -> Result<()> {
+        instructions::migrate_repo(ctx)
+    }
+
+    /// Upgrade a module account to `CURRENT_SCHEMA_VERSION`.
+    ///
+    /// Grows the account to `Module::LEN` and initializes the fields appended
+    /// since the stored version. Admin only; rejects modules that are already
+    /// current with `MigrationAlreadyApplied`.
+    ///
+    /// Accounts:
+    /// - `admin`  – signer and payer, must match `config.admin`
+    /// - `module` – module account to upgrade
+    pub fn migrate_module(ctx: Context<MigrateModule>) -> Result<()> {
+        instructions::migrate_module(ctx)
+    }
+
+    /// Upgrade a fork account to `CURRENT_SCHEMA_VERSION`.
+    ///
+    /// Grows the account to `Fork::LEN`; the fields appended since the stored
+    /// version start empty. Admin only; rejects forks that are already current
+    /// with `MigrationAlreadyApplied`.
+    ///
+    /// Accounts:
+    /// - `admin` – signer and payer, must match `config.admin`
+    /// - `fork`  – fork account to upgrade
+    pub fn migrate_fork(ctx: Context<MigrateFork>) -> Result<()> {
+        instructions::migrate_fork(ctx)
+    }
 }
 
 // ===================================================================================
@@ -264,6 +729,11 @@ pub mod state {
     pub mod lifecycle;
     pub mod metrics;
     pub mod authority;
+    pub mod global_metadata;
+    pub mod observer_credits;
+    pub mod owner_fork_index;
+    pub mod metrics_snapshot;
+    pub mod repo_name_index;
 
     pub use config::*;
     pub use repo::*;
@@ -273,6 +743,11 @@ pub mod state {
     pub use lifecycle::*;
     pub use metrics::*;
     pub use authority::*;
+    pub use global_metadata::*;
+    pub use observer_credits::*;
+    pub use owner_fork_index::*;
+    pub use metrics_snapshot::*;
+    pub use repo_name_index::*;
 }
 
 /// Utility helpers re-export.
@@ -281,14 +756,31 @@ pub mod state {
 /// - PDA seeds
 /// - Common validators
 /// - Time utilities
+/// - Rent estimation
+/// - Account size self-checks (`size-check` feature)
+/// - Schema migrations
 pub mod utils {
     pub mod seeds;
     pub mod validators;
     pub mod time;
+    pub mod rent;
+    pub mod fees;
+    pub mod tags;
+    pub mod update;
+    pub mod num;
+    pub mod size;
+    pub mod ranking;
+    pub mod migration;
 
     pub use seeds::*;
     pub use validators::*;
     pub use time::*;
+    pub use rent::*;
+    pub use fees::*;
+    pub use tags::*;
+    pub use num::*;
+    pub use size::*;
+    pub use ranking::*;
 }
 
 /// Instruction module re-export (already used above, but also available to
@@ -13,6 +13,8 @@
 //! Responsibilities:
 //! - Map authority public keys to role bitmasks (admin, maintainer, observer)
 //! - Track which resources an authority is scoped to (global or per-repo)
+//! - Map roles to the `permission_bits` instruction groups they may call
+//!   (see `Authority::permissions`)
 //! - Provide helpers for:
 //!     * checking roles
 //!     * checking whether a role applies to a specific target
@@ -23,6 +25,8 @@
 use anchor_lang::prelude::*;
 
 use crate::errors::Unit09Error;
+use crate::state::permission_bits;
+use crate::utils::size::assert_fits_len;
 
 /// Roles are represented as a bitmask for compact storage and flexible checks.
 ///
@@ -141,6 +145,7 @@ impl Authority {
         self.schema_version = 1;
         self.bump = bump;
         self.reserved = [0u8; 62];
+        assert_fits_len(self, Self::LEN);
 
         Ok(())
     }
@@ -224,6 +229,30 @@ impl Authority {
         (self.roles & roles_mask) == roles_mask
     }
 
+    /// `permission_bits` mask of the instruction groups this entry's roles
+    /// may call:
+    /// - `ADMIN`      => every group
+    /// - `MAINTAINER` => repository, module, and fork writes
+    /// - `OBSERVER`   => observations
+    ///
+    /// Only roles are considered; the resource scope and the deployment-wide
+    /// guards (see `Lifecycle::permissions`) are not.
+    pub fn permissions(&self) -> u32 {
+        let mut mask = 0;
+        if self.has_any_role(role_flags::ADMIN) {
+            mask |= permission_bits::ALL;
+        }
+        if self.has_any_role(role_flags::MAINTAINER) {
+            mask |= permission_bits::WRITE_REPOS
+                | permission_bits::WRITE_MODULES
+                | permission_bits::WRITE_FORKS;
+        }
+        if self.has_any_role(role_flags::OBSERVER) {
+            mask |= permission_bits::RECORD_OBSERVATIONS;
+        }
+        mask
+    }
+
     /// Returns true if this entry applies to the given resource.
     ///
     /// Resource scoping rules:
@@ -274,3 +303,41 @@ impl Authority {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn authority_with(roles: u64) -> Authority {
+        Authority {
+            authority: Pubkey::new_unique(),
+            roles,
+            is_global: true,
+            resource_scope: Pubkey::default(),
+            created_at: 0,
+            updated_at: 0,
+            schema_version: 1,
+            bump: 0,
+            reserved: [0u8; 62],
+        }
+    }
+
+    #[test]
+    fn observer_may_only_record_observations() {
+        assert_eq!(
+            authority_with(role_flags::OBSERVER).permissions(),
+            permission_bits::RECORD_OBSERVATIONS
+        );
+    }
+
+    #[test]
+    fn roles_combine_their_permissions() {
+        assert_eq!(authority_with(0).permissions(), 0);
+        assert_eq!(authority_with(role_flags::ADMIN).permissions(), permission_bits::ALL);
+
+        let maintainer = authority_with(role_flags::MAINTAINER | role_flags::OBSERVER).permissions();
+        assert_eq!(maintainer & permission_bits::WRITES, permission_bits::WRITES);
+        assert_eq!(maintainer & permission_bits::RECORD_METRICS, 0);
+        assert_eq!(maintainer & permission_bits::COLLECT_FEE, 0);
+    }
+}
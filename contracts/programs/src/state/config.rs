@@ -7,7 +7,7 @@
 //! Responsibilities:
 //! - Store protocol-wide parameters such as:
 //!     * admin authority
-//!     * fee basis points
+//!     * fee basis points and fee recipient
 //!     * maximum modules per repository
 //!     * schema version
 //! - Provide helper methods for:
@@ -21,9 +21,14 @@
 //! ===========================================================================
 
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
 
 use crate::constants::*;
 use crate::errors::Unit09Error;
+use crate::state::{role_flags, Authority, Repo, RepoHost};
+use crate::utils::size::assert_fits_len;
+use crate::utils::time::clamp_to_past;
+use crate::utils::update::apply_if_some;
 
 /// Global configuration account for the Unit09 protocol.
 ///
@@ -77,6 +82,117 @@ pub struct Config {
     /// Bump used for PDA derivation of this account.
     pub bump: u8,
 
+    /// Whether `record_metrics` should freeze the deployment instead of
+    /// applying a reconciliation that looks inconsistent (circuit breaker).
+    pub auto_freeze_on_inconsistency: bool,
+
+    /// Account that receives fees collected via `collect_fee`.
+    ///
+    /// Defaults to the admin at initialization and can be changed by the
+    /// admin through `set_config`. Must never be the default pubkey.
+    pub fee_recipient: Pubkey,
+
+    /// Whether `register_repo` requires `repo_key` to equal the SHA-256 hash
+    /// of the repository URL (see `Repo::key_for_url`).
+    ///
+    /// When enabled, each URL maps to exactly one canonical repository key.
+    pub enforce_url_keyed: bool,
+
+    /// Deployment-wide cap on observations per `OBSERVATION_WINDOW_SECS`
+    /// window, tracked on `Metrics`. Zero disables the throttle.
+    pub max_observations_per_window: u64,
+
+    /// Minimum `lines_of_code` an observation must report.
+    ///
+    /// Scans below this are rejected with `ValueOutOfRange`. Defaults to 1,
+    /// which only rejects empty scans.
+    pub min_loc_per_observation: u64,
+
+    /// Number of distinct admins that must sign sensitive admin actions
+    /// such as `set_config` (see `assert_admin_quorum`).
+    ///
+    /// `Config::admin` counts as one; the others are co-admins registered
+    /// through `add_co_admin`. 1 means single-admin behavior. Never exceeds
+    /// `1 + co_admin_count`, so the quorum is always reachable.
+    pub required_admin_sigs: u8,
+
+    /// When set, optional telemetry events (see `emit_if_active!`) are not
+    /// emitted while the lifecycle is read-only (`Frozen`, `Sunset`, or a
+    /// global freeze). Critical events are always emitted.
+    pub quiet_when_frozen: bool,
+
+    /// Credits charged from the observer's `ObserverCredits` per observation
+    /// run. 0 disables metering.
+    pub credits_per_observation: u64,
+
+    /// Seconds after `Repo::last_observed_at` at which a repository is
+    /// reported as stale (see `Repo::is_stale`). 0 disables staleness.
+    pub staleness_threshold_secs: i64,
+
+    /// Optional deployment-specific cap on name and label lengths, in bytes.
+    ///
+    /// Can only tighten `MAX_NAME_LEN`, never loosen it, since account sizes
+    /// are computed from the constant (see `max_name_len`).
+    pub max_name_len_override: Option<u16>,
+
+    /// When set, every observation must be co-signed by an attestor distinct
+    /// from the observer (see `resolve_attestor`).
+    pub require_attestor: bool,
+
+    /// Human-trackable version of `policy_ref`, bumped each time
+    /// `apply_update` changes it. 0 is the policy set at initialization.
+    pub policy_version: u32,
+
+    /// How many events and logs this deployment emits (see `emits_at`):
+    /// - `EVENT_VERBOSITY_CRITICAL` (0): only critical events
+    /// - `EVENT_VERBOSITY_NORMAL` (1): plus optional telemetry
+    /// - `EVENT_VERBOSITY_DEBUG` (2): plus debug events and `msg!` logs
+    pub event_verbosity: u8,
+
+    /// Prefix every new fork label must start with (for example
+    /// `"storyline-1:"`), enforced through `assert_label_prefix` by
+    /// `create_fork`, `clone_fork` and label changes in
+    /// `update_fork_state`. `None` means no requirement.
+    ///
+    /// At most `MAX_LABEL_PREFIX_LEN` bytes.
+    pub label_prefix_required: Option<String>,
+
+    /// Per-instruction cooldown windows (see `CooldownConfig`). All zero,
+    /// meaning no cooldowns, at initialization.
+    pub cooldowns: CooldownConfig,
+
+    /// Minimum lamport balance an observer's wallet must hold to record
+    /// observations, a cheap anti-sybil heuristic (see
+    /// `assert_observer_balance`). 0 disables the check.
+    pub min_observer_lamports: u64,
+
+    /// Whether repository names must be unique across the deployment.
+    ///
+    /// When set, `register_repo` requires the name's `RepoNameIndex` and
+    /// rejects a name that is already claimed with `RepoNameTaken`.
+    pub enforce_unique_repo_name: bool,
+
+    /// Hosts that may not be registered or moved to, as `host_hash`
+    /// values. A blocked host also blocks its subdomains. Managed by
+    /// `add_blocked_host` / `remove_blocked_host`; checked by
+    /// `assert_host_allowed`.
+    ///
+    /// At most `MAX_BLOCKED_HOSTS` entries. Appended after the version 1
+    /// fields and allocated in full by `Config::LEN`, so deployments
+    /// upgraded by `migrate_deployment` start with an empty list and the
+    /// list grows in place without a realloc.
+    pub blocked_hosts: Vec<[u8; 32]>,
+
+    /// Number of co-admins: global `Authority` entries with the admin role,
+    /// created by `add_co_admin` and closed by `remove_co_admin`.
+    pub co_admin_count: u8,
+
+    /// Review-gated mode: repositories created by `register_repo` or
+    /// `finalize_repo` start inactive, and only an admin co-signing
+    /// `update_repo` can activate them. `false` (the default, and the
+    /// value on migrated deployments) creates them active.
+    pub review_gated: bool,
+
     /// Reserved bytes for future upgrades.
     ///
     /// Keeping a reserved area allows new fields to be introduced in-place
@@ -101,6 +217,26 @@ impl Config {
         + 8   // updated_at: i64
         + 32  // policy_ref: [u8; 32]
         + 1   // bump: u8
+        + 1   // auto_freeze_on_inconsistency: bool
+        + 32  // fee_recipient: Pubkey
+        + 1   // enforce_url_keyed: bool
+        + 8   // max_observations_per_window: u64
+        + 8   // min_loc_per_observation: u64
+        + 1   // required_admin_sigs: u8
+        + 1   // quiet_when_frozen: bool
+        + 8   // credits_per_observation: u64
+        + 8   // staleness_threshold_secs: i64
+        + 1 + 2 // max_name_len_override: Option<u16>
+        + 1   // require_attestor: bool
+        + 4   // policy_version: u32
+        + 1   // event_verbosity: u8
+        + 1 + 4 + MAX_LABEL_PREFIX_LEN // label_prefix_required: Option<String>
+        + CooldownConfig::LEN // cooldowns: CooldownConfig
+        + 8   // min_observer_lamports: u64
+        + 1   // enforce_unique_repo_name: bool
+        + 4 + 32 * MAX_BLOCKED_HOSTS // blocked_hosts: Vec<[u8; 32]>
+        + 1   // co_admin_count: u8
+        + 1   // review_gated: bool
         + 63; // reserved: [u8; 63]
 
     /// Initialize the configuration account with sane defaults and values
@@ -125,42 +261,187 @@ impl Config {
         self.created_at = clock.unix_timestamp;
         self.updated_at = clock.unix_timestamp;
         self.policy_ref = policy_ref;
+        self.auto_freeze_on_inconsistency = false;
+        self.fee_recipient = admin;
+        self.enforce_url_keyed = false;
+        self.max_observations_per_window = 0;
+        self.min_loc_per_observation = DEFAULT_MIN_LOC_PER_OBSERVATION;
+        self.required_admin_sigs = 1;
+        self.quiet_when_frozen = false;
+        self.credits_per_observation = 0;
+        self.staleness_threshold_secs = 0;
+        self.max_name_len_override = None;
+        self.require_attestor = false;
+        self.policy_version = 0;
+        self.event_verbosity = EVENT_VERBOSITY_NORMAL;
+        self.label_prefix_required = None;
+        self.cooldowns = CooldownConfig::default();
+        self.min_observer_lamports = 0;
+        self.enforce_unique_repo_name = false;
+        self.blocked_hosts = Vec::new();
+        self.co_admin_count = 0;
+        self.review_gated = false;
         self.bump = bump;
         self.reserved = [0u8; 63];
+        assert_fits_len(self, Self::LEN);
 
         Ok(())
     }
 
+    /// Upgrade this configuration from an older `schema_version` to
+    /// `CURRENT_SCHEMA_VERSION` and return the version it was upgraded from.
+    ///
+    /// Expects an account already grown to `Config::LEN`, so every field
+    /// added since the stored version decodes from zeroed bytes; each step
+    /// sets the ones whose default is not zero. Fails with
+    /// `MigrationAlreadyApplied` if the configuration is already current.
+    pub fn migrate(&mut self, clock: &Clock) -> Result<u8> {
+        let from_version = self.schema_version;
+        if from_version >= CURRENT_SCHEMA_VERSION {
+            return err!(Unit09Error::MigrationAlreadyApplied);
+        }
+
+        // 1 -> 2: same defaults as `init`.
+        if from_version < 2 {
+            self.fee_recipient = self.admin;
+            self.min_loc_per_observation = DEFAULT_MIN_LOC_PER_OBSERVATION;
+            self.required_admin_sigs = 1;
+            self.event_verbosity = EVENT_VERBOSITY_NORMAL;
+        }
+
+        self.schema_version = CURRENT_SCHEMA_VERSION;
+        self.updated_at = clock.unix_timestamp;
+
+        Ok(from_version)
+    }
+
     /// Apply an update to the configuration account.
     ///
     /// This does not modify fields that are not explicitly passed in; it only
     /// updates values that are provided as `Some(...)` in the args.
+    #[allow(clippy::too_many_arguments)]
     pub fn apply_update(
         &mut self,
         maybe_fee_bps: Option<u16>,
         maybe_max_modules_per_repo: Option<u32>,
         maybe_is_active: Option<bool>,
         maybe_policy_ref: Option<[u8; 32]>,
+        maybe_auto_freeze_on_inconsistency: Option<bool>,
+        maybe_fee_recipient: Option<Pubkey>,
+        maybe_enforce_url_keyed: Option<bool>,
+        maybe_max_observations_per_window: Option<u64>,
+        maybe_min_loc_per_observation: Option<u64>,
+        maybe_required_admin_sigs: Option<u8>,
+        maybe_quiet_when_frozen: Option<bool>,
+        maybe_credits_per_observation: Option<u64>,
+        maybe_staleness_threshold_secs: Option<i64>,
+        maybe_max_name_len_override: Option<u16>,
+        maybe_require_attestor: Option<bool>,
+        maybe_event_verbosity: Option<u8>,
+        maybe_review_gated: Option<bool>,
+        maybe_label_prefix_required: Option<String>,
+        maybe_cooldowns: Option<CooldownConfig>,
+        maybe_min_observer_lamports: Option<u64>,
+        maybe_enforce_unique_repo_name: Option<bool>,
         clock: &Clock,
     ) -> Result<()> {
-        if let Some(fee_bps) = maybe_fee_bps {
-            Self::validate_fee_bps(fee_bps)?;
-            self.fee_bps = fee_bps;
-        }
+        apply_if_some!(self.fee_bps, maybe_fee_bps, |v: &u16| {
+            Self::validate_fee_bps(*v)
+        });
+
+        apply_if_some!(
+            self.max_modules_per_repo,
+            maybe_max_modules_per_repo,
+            |v: &u32| Self::validate_max_modules(*v)
+        );
+
+        apply_if_some!(self.is_active, maybe_is_active);
 
-        if let Some(max_modules) = maybe_max_modules_per_repo {
-            Self::validate_max_modules(max_modules)?;
-            self.max_modules_per_repo = max_modules;
+        // Only an actual change counts as a new policy version.
+        if let Some(policy_ref) = maybe_policy_ref {
+            if policy_ref != self.policy_ref {
+                self.policy_version = self
+                    .policy_version
+                    .checked_add(1)
+                    .ok_or(Unit09Error::CounterOverflow)?;
+                self.policy_ref = policy_ref;
+            }
         }
 
-        if let Some(is_active) = maybe_is_active {
-            self.is_active = is_active;
+        apply_if_some!(
+            self.auto_freeze_on_inconsistency,
+            maybe_auto_freeze_on_inconsistency
+        );
+
+        apply_if_some!(
+            self.fee_recipient,
+            maybe_fee_recipient,
+            Self::validate_fee_recipient
+        );
+
+        apply_if_some!(self.enforce_url_keyed, maybe_enforce_url_keyed);
+
+        apply_if_some!(
+            self.max_observations_per_window,
+            maybe_max_observations_per_window
+        );
+
+        apply_if_some!(
+            self.min_loc_per_observation,
+            maybe_min_loc_per_observation,
+            |v: &u64| Self::validate_min_loc(*v)
+        );
+
+        let co_admin_count = self.co_admin_count;
+        apply_if_some!(
+            self.required_admin_sigs,
+            maybe_required_admin_sigs,
+            |v: &u8| Self::validate_required_admin_sigs(*v, co_admin_count)
+        );
+
+        apply_if_some!(self.quiet_when_frozen, maybe_quiet_when_frozen);
+
+        apply_if_some!(
+            self.credits_per_observation,
+            maybe_credits_per_observation
+        );
+
+        apply_if_some!(
+            self.staleness_threshold_secs,
+            maybe_staleness_threshold_secs,
+            |v: &i64| Self::validate_staleness_threshold(*v)
+        );
+
+        // An override equal to `MAX_NAME_LEN` is the default limit, so it
+        // is stored as no override.
+        if let Some(max_len) = maybe_max_name_len_override {
+            Self::validate_max_name_len_override(max_len)?;
+            self.max_name_len_override =
+                Some(max_len).filter(|max_len| *max_len as usize != MAX_NAME_LEN);
         }
 
-        if let Some(policy_ref) = maybe_policy_ref {
-            self.policy_ref = policy_ref;
+        apply_if_some!(self.require_attestor, maybe_require_attestor);
+
+        apply_if_some!(self.event_verbosity, maybe_event_verbosity, |v: &u8| {
+            Self::validate_event_verbosity(*v)
+        });
+
+        apply_if_some!(self.review_gated, maybe_review_gated);
+
+        // An empty prefix removes the requirement.
+        if let Some(prefix) = maybe_label_prefix_required {
+            self.label_prefix_required = if prefix.is_empty() {
+                None
+            } else {
+                Self::validate_label_prefix(&prefix)?;
+                Some(prefix)
+            };
         }
 
+        apply_if_some!(self.cooldowns, maybe_cooldowns);
+        apply_if_some!(self.min_observer_lamports, maybe_min_observer_lamports);
+        apply_if_some!(self.enforce_unique_repo_name, maybe_enforce_unique_repo_name);
+
         self.updated_at = clock.unix_timestamp;
         Ok(())
     }
@@ -173,6 +454,277 @@ impl Config {
         Ok(())
     }
 
+    /// Ensure `admin` is the stored admin and that, together with the
+    /// co-signers in `co_signers`, at least `required_admin_sigs` distinct
+    /// admins signed.
+    ///
+    /// `co_signers` holds `[signer, authority]` pairs, typically the
+    /// instruction's `remaining_accounts`: each `signer` must have signed and
+    /// `authority` must be its global `Authority` entry with the admin role.
+    /// Anything else, or too few distinct admins, fails with
+    /// `UnauthorizedAdminAction`.
+    pub fn assert_admin_quorum(&self, admin: &Signer, co_signers: &[AccountInfo]) -> Result<()> {
+        self.assert_admin(admin)?;
+
+        let mut co_admins = Vec::with_capacity(co_signers.len() / 2);
+        for pair in co_signers.chunks(2) {
+            co_admins.push(Self::verified_co_admin(pair)?);
+        }
+        self.assert_quorum_reached(&co_admins)
+    }
+
+    /// Check that `Config::admin` plus the distinct keys in `co_admins`
+    /// (already verified as admins) reach `required_admin_sigs`.
+    pub fn assert_quorum_reached(&self, co_admins: &[Pubkey]) -> Result<()> {
+        let mut signers: Vec<Pubkey> = vec![self.admin];
+        for key in co_admins {
+            if !signers.contains(key) {
+                signers.push(*key);
+            }
+        }
+
+        if signers.len() < self.required_admin_sigs.max(1) as usize {
+            return err!(Unit09Error::UnauthorizedAdminAction);
+        }
+        Ok(())
+    }
+
+    /// Record a new co-admin (see `add_co_admin`).
+    pub fn add_co_admin(&mut self) -> Result<()> {
+        self.co_admin_count = self
+            .co_admin_count
+            .checked_add(1)
+            .ok_or(Unit09Error::CounterOverflow)?;
+        Ok(())
+    }
+
+    /// Forget a co-admin (see `remove_co_admin`).
+    ///
+    /// Fails with `AdminQuorumUnreachable` if the remaining admins could no
+    /// longer reach `required_admin_sigs`; lower it through `set_config`
+    /// first.
+    pub fn remove_co_admin(&mut self) -> Result<()> {
+        let remaining = self
+            .co_admin_count
+            .checked_sub(1)
+            .ok_or(Unit09Error::CounterOverflow)?;
+        if u16::from(self.required_admin_sigs) > 1 + u16::from(remaining) {
+            return err!(Unit09Error::AdminQuorumUnreachable);
+        }
+        self.co_admin_count = remaining;
+        Ok(())
+    }
+
+    /// Verify one `[signer, authority]` pair and return the signer key.
+    ///
+    /// `authority` must be the canonical `Authority` PDA of `signer`, so an
+    /// entry cannot be replayed for another key.
+    fn verified_co_admin(pair: &[AccountInfo]) -> Result<Pubkey> {
+        let (signer, authority_info) = match pair {
+            [signer, authority_info] => (signer, authority_info),
+            _ => return err!(Unit09Error::UnauthorizedAdminAction),
+        };
+        if !signer.is_signer || authority_info.owner != &crate::ID {
+            return err!(Unit09Error::UnauthorizedAdminAction);
+        }
+
+        let data = authority_info.try_borrow_data()?;
+        let authority = Authority::try_deserialize(&mut &data[..])
+            .map_err(|_| error!(Unit09Error::UnauthorizedAdminAction))?;
+        if authority.authority != signer.key()
+            || !authority.is_global
+            || !authority.has_any_role(role_flags::ADMIN)
+        {
+            return err!(Unit09Error::UnauthorizedAdminAction);
+        }
+
+        let expected = Pubkey::create_program_address(
+            &[
+                AUTHORITY_SEED.as_bytes(),
+                signer.key.as_ref(),
+                &[authority.bump],
+            ],
+            &crate::ID,
+        )
+        .map_err(|_| error!(Unit09Error::UnauthorizedAdminAction))?;
+        if expected != authority_info.key() {
+            return err!(Unit09Error::UnauthorizedAdminAction);
+        }
+        Ok(signer.key())
+    }
+
+    /// Ensure that a usable fee recipient is configured.
+    pub fn assert_fee_recipient_set(&self) -> Result<()> {
+        Self::validate_fee_recipient(&self.fee_recipient)
+    }
+
+    /// Ensure that `repo_key` is acceptable for a repository at `url`.
+    ///
+    /// When `enforce_url_keyed` is set the key must equal
+    /// `Repo::key_for_url(url)`; otherwise any key is accepted.
+    pub fn assert_repo_key_allowed(&self, repo_key: &Pubkey, url: &str) -> Result<()> {
+        if self.enforce_url_keyed && *repo_key != Repo::key_for_url(url) {
+            return err!(Unit09Error::RepoKeyMismatch);
+        }
+        Ok(())
+    }
+
+    /// Resolve the timestamp an observation is recorded "as of".
+    ///
+    /// Without an override this is the current clock. An override is only
+    /// accepted from the admin (`UnauthorizedAdminAction` otherwise) and is
+    /// clamped into the past with `clamp_to_past`, so it can never be
+    /// future-dated; overrides that clamp to zero are rejected.
+    pub fn resolve_observed_at(
+        &self,
+        caller: &Pubkey,
+        observed_at_override: Option<i64>,
+        clock: &Clock,
+    ) -> Result<i64> {
+        let requested = match observed_at_override {
+            Some(requested) => requested,
+            None => return Ok(clock.unix_timestamp),
+        };
+
+        if *caller != self.admin {
+            return err!(Unit09Error::UnauthorizedAdminAction);
+        }
+
+        let observed_at = clamp_to_past(clock, requested);
+        if observed_at <= 0 {
+            return err!(Unit09Error::ValueOutOfRange);
+        }
+        Ok(observed_at)
+    }
+
+    /// Ensure an observation reports at least `min_loc_per_observation`
+    /// lines of code.
+    pub fn assert_min_loc(&self, lines_of_code: u64) -> Result<()> {
+        if lines_of_code < self.min_loc_per_observation {
+            return err!(Unit09Error::ValueOutOfRange);
+        }
+        Ok(())
+    }
+
+    /// Validate the optional attestor co-signing an observation by
+    /// `observer` and return its key.
+    ///
+    /// Fails with `MissingAuthority` when `require_attestor` is set and no
+    /// attestor signed, and with `InvalidAuthority` when the attestor is the
+    /// observer itself.
+    pub fn resolve_attestor(
+        &self,
+        observer: &Pubkey,
+        attestor: Option<&Pubkey>,
+    ) -> Result<Option<Pubkey>> {
+        match attestor {
+            Some(attestor) if attestor == observer => err!(Unit09Error::InvalidAuthority),
+            Some(attestor) => Ok(Some(*attestor)),
+            None if self.require_attestor => err!(Unit09Error::MissingAuthority),
+            None => Ok(None),
+        }
+    }
+
+    /// Whether events and logs of `tier` are emitted at this deployment's
+    /// `event_verbosity`. Critical events (tier 0) always are.
+    pub fn emits_at(&self, tier: u8) -> bool {
+        tier <= self.event_verbosity
+    }
+
+    /// Effective maximum length for repository and module names and fork
+    /// labels: `max_name_len_override` when set, capped at `MAX_NAME_LEN`.
+    pub fn max_name_len(&self) -> usize {
+        self.max_name_len_override
+            .map_or(MAX_NAME_LEN, |max_len| (max_len as usize).min(MAX_NAME_LEN))
+    }
+
+    /// Ensure a name or label fits `max_name_len` (`StringTooLong`).
+    pub fn assert_name_len(&self, name: &str) -> Result<()> {
+        if name.len() > self.max_name_len() {
+            return err!(Unit09Error::StringTooLong);
+        }
+        Ok(())
+    }
+
+    /// Ensure a fork label starts with `label_prefix_required`, when set
+    /// (`ValidationFailed`).
+    pub fn assert_label_prefix(&self, label: &str) -> Result<()> {
+        if let Some(ref prefix) = self.label_prefix_required {
+            if !label.starts_with(prefix.as_str()) {
+                return err!(Unit09Error::ValidationFailed);
+            }
+        }
+        Ok(())
+    }
+
+    /// Ensure an observer's wallet balance reaches `min_observer_lamports`
+    /// (`InsufficientObserverBalance`).
+    pub fn assert_observer_balance(&self, observer_lamports: u64) -> Result<()> {
+        if observer_lamports < self.min_observer_lamports {
+            return err!(Unit09Error::InsufficientObserverBalance);
+        }
+        Ok(())
+    }
+
+    /// Key of the host of `url` in `blocked_hosts`: the SHA-256 hash of
+    /// `RepoHost::normalized_host`, so a bare host and any URL on it hash
+    /// alike.
+    pub fn host_hash(url: &str) -> [u8; 32] {
+        hash(RepoHost::normalized_host(url).as_bytes()).to_bytes()
+    }
+
+    /// Add `host_hash` to `blocked_hosts`.
+    ///
+    /// Fails with `NoChangeRequested` if it is already listed, and with
+    /// `ValueOutOfRange` once `MAX_BLOCKED_HOSTS` is hit.
+    pub fn block_host(&mut self, host_hash: [u8; 32], clock: &Clock) -> Result<()> {
+        if self.blocked_hosts.contains(&host_hash) {
+            return err!(Unit09Error::NoChangeRequested);
+        }
+        if self.blocked_hosts.len() >= MAX_BLOCKED_HOSTS {
+            return err!(Unit09Error::ValueOutOfRange);
+        }
+        self.blocked_hosts.push(host_hash);
+        self.updated_at = clock.unix_timestamp;
+        Ok(())
+    }
+
+    /// Remove `host_hash` from `blocked_hosts`. Fails with
+    /// `NoChangeRequested` if it is not listed.
+    pub fn unblock_host(&mut self, host_hash: &[u8; 32], clock: &Clock) -> Result<()> {
+        let position = self
+            .blocked_hosts
+            .iter()
+            .position(|blocked| blocked == host_hash)
+            .ok_or(Unit09Error::NoChangeRequested)?;
+        self.blocked_hosts.remove(position);
+        self.updated_at = clock.unix_timestamp;
+        Ok(())
+    }
+
+    /// Ensure neither the host of `url` nor any of its parent domains is in
+    /// `blocked_hosts` (`RepoBlocked`), so blocking `evil.org` also covers
+    /// `sub.evil.org`.
+    pub fn assert_host_allowed(&self, url: &str) -> Result<()> {
+        // Skip hashing while nothing is blocked.
+        if self.blocked_hosts.is_empty() {
+            return Ok(());
+        }
+
+        let host = RepoHost::normalized_host(url);
+        let mut suffix = host.as_str();
+        loop {
+            let digest = hash(suffix.as_bytes()).to_bytes();
+            if self.blocked_hosts.contains(&digest) {
+                return err!(Unit09Error::RepoBlocked);
+            }
+            match suffix.split_once('.') {
+                Some((_, parent)) if !parent.is_empty() => suffix = parent,
+                _ => return Ok(()),
+            }
+        }
+    }
+
     /// Ensure that the configuration is currently active.
     ///
     /// Handlers may call this at the start of critical instructions.
@@ -184,6 +736,9 @@ impl Config {
     }
 
     /// Validate that a given fee value is within allowable bounds.
+    ///
+    /// The bound is inclusive: `MAX_FEE_BPS` itself (5000 = 50%) is
+    /// accepted, anything above fails with `InvalidFeeBps`.
     fn validate_fee_bps(fee_bps: u16) -> Result<()> {
         if fee_bps > MAX_FEE_BPS {
             return err!(Unit09Error::InvalidFeeBps);
@@ -191,6 +746,71 @@ impl Config {
         Ok(())
     }
 
+    /// Validate that a fee recipient is not the default (all-zero) pubkey.
+    fn validate_fee_recipient(fee_recipient: &Pubkey) -> Result<()> {
+        if *fee_recipient == Pubkey::default() {
+            return err!(Unit09Error::InvalidFeeRecipient);
+        }
+        Ok(())
+    }
+
+    /// Validate a minimum lines-of-code threshold: at least 1 (empty scans
+    /// are never accepted) and no more than `MAX_LOC_PER_OBSERVATION`.
+    fn validate_min_loc(min_loc: u64) -> Result<()> {
+        if min_loc == 0 || min_loc > MAX_LOC_PER_OBSERVATION {
+            return err!(Unit09Error::ValueOutOfRange);
+        }
+        Ok(())
+    }
+
+    /// Validate a required admin signature count: 1..=`MAX_REQUIRED_ADMIN_SIGS`
+    /// (`ValueOutOfRange`), and no more than the admin plus its
+    /// `co_admin_count` co-admins could ever provide
+    /// (`AdminQuorumUnreachable`).
+    fn validate_required_admin_sigs(required: u8, co_admin_count: u8) -> Result<()> {
+        if required == 0 || required > MAX_REQUIRED_ADMIN_SIGS {
+            return err!(Unit09Error::ValueOutOfRange);
+        }
+        if u16::from(required) > 1 + u16::from(co_admin_count) {
+            return err!(Unit09Error::AdminQuorumUnreachable);
+        }
+        Ok(())
+    }
+
+    /// Validate an event verbosity level: at most `EVENT_VERBOSITY_DEBUG`.
+    fn validate_event_verbosity(level: u8) -> Result<()> {
+        if level > EVENT_VERBOSITY_DEBUG {
+            return err!(Unit09Error::ValueOutOfRange);
+        }
+        Ok(())
+    }
+
+    /// Validate a required fork label prefix: at most
+    /// `MAX_LABEL_PREFIX_LEN` bytes (`StringTooLong`).
+    fn validate_label_prefix(prefix: &str) -> Result<()> {
+        if prefix.len() > MAX_LABEL_PREFIX_LEN {
+            return err!(Unit09Error::StringTooLong);
+        }
+        Ok(())
+    }
+
+    /// Validate a name length override: 1..=`MAX_NAME_LEN`. Larger values
+    /// would loosen the compile-time limit and fail with `ValueOutOfRange`.
+    fn validate_max_name_len_override(max_len: u16) -> Result<()> {
+        if max_len == 0 || max_len as usize > MAX_NAME_LEN {
+            return err!(Unit09Error::ValueOutOfRange);
+        }
+        Ok(())
+    }
+
+    /// Validate a staleness threshold: non-negative (0 disables staleness).
+    fn validate_staleness_threshold(threshold_secs: i64) -> Result<()> {
+        if threshold_secs < 0 {
+            return err!(Unit09Error::ValueOutOfRange);
+        }
+        Ok(())
+    }
+
     /// Validate that the maximum modules per repository value is non-zero
     /// and within a reasonable bound.
     fn validate_max_modules(max_modules: u32) -> Result<()> {
@@ -200,3 +820,730 @@ impl Config {
         Ok(())
     }
 }
+
+/// Minimum number of seconds between two calls of the same kind of
+/// instruction, configured through `set_config`.
+///
+/// Each window is checked independently against its own "last seen"
+/// timestamp, and a window of 0 disables that cooldown:
+/// - `observation_secs`: observations of the same repository
+///   (`Repo::last_observed_at`)
+/// - `fork_creation_secs`: fork creations deployment-wide
+///   (`Metrics::last_fork_created_at`)
+/// - `module_touch_secs`: updates of the same module (`Module::updated_at`)
+///
+/// Violations fail with `CooldownActive` and log the earliest retry time
+/// (see `retry_at`).
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CooldownConfig {
+    pub observation_secs: u32,
+    pub fork_creation_secs: u32,
+    pub module_touch_secs: u32,
+}
+
+impl CooldownConfig {
+    /// Serialized length of `CooldownConfig`.
+    pub const LEN: usize = 3 * 4;
+
+    /// Ensure `observation_secs` have elapsed since `last_observed_at`.
+    pub fn assert_observation_elapsed(&self, last_observed_at: i64, clock: &Clock) -> Result<()> {
+        Self::assert_elapsed(self.observation_secs, last_observed_at, clock)
+    }
+
+    /// Ensure `fork_creation_secs` have elapsed since `last_fork_created_at`.
+    pub fn assert_fork_creation_elapsed(
+        &self,
+        last_fork_created_at: i64,
+        clock: &Clock,
+    ) -> Result<()> {
+        Self::assert_elapsed(self.fork_creation_secs, last_fork_created_at, clock)
+    }
+
+    /// Ensure `module_touch_secs` have elapsed since `last_touched_at`.
+    pub fn assert_module_touch_elapsed(&self, last_touched_at: i64, clock: &Clock) -> Result<()> {
+        Self::assert_elapsed(self.module_touch_secs, last_touched_at, clock)
+    }
+
+    /// Earliest Unix timestamp at which an action last performed at
+    /// `last_at` may run again, or `None` when no cooldown applies (zero
+    /// window, or the action never happened).
+    pub fn retry_at(window_secs: u32, last_at: i64) -> Option<i64> {
+        if window_secs == 0 || last_at == 0 {
+            return None;
+        }
+        Some(last_at.saturating_add(window_secs as i64))
+    }
+
+    /// Shared check. On `CooldownActive` the retry time is logged as
+    /// `retry_at=<unix seconds>` so clients can schedule the next attempt.
+    fn assert_elapsed(window_secs: u32, last_at: i64, clock: &Clock) -> Result<()> {
+        if let Some(retry_at) = Self::retry_at(window_secs, last_at) {
+            if clock.unix_timestamp < retry_at {
+                msg!("cooldown active: retry_at={}", retry_at);
+                return err!(Unit09Error::CooldownActive);
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config_with(enforce_url_keyed: bool) -> Config {
+        Config {
+            admin: Pubkey::new_unique(),
+            fee_bps: 0,
+            max_modules_per_repo: DEFAULT_MAX_MODULES_PER_REPO,
+            schema_version: CURRENT_SCHEMA_VERSION,
+            is_active: true,
+            created_at: 0,
+            updated_at: 0,
+            policy_ref: [0u8; 32],
+            auto_freeze_on_inconsistency: false,
+            fee_recipient: Pubkey::new_unique(),
+            enforce_url_keyed,
+            max_observations_per_window: 0,
+            min_loc_per_observation: DEFAULT_MIN_LOC_PER_OBSERVATION,
+            required_admin_sigs: 1,
+            quiet_when_frozen: false,
+            credits_per_observation: 0,
+            staleness_threshold_secs: 0,
+            max_name_len_override: None,
+            require_attestor: false,
+            policy_version: 0,
+            event_verbosity: EVENT_VERBOSITY_NORMAL,
+            label_prefix_required: None,
+            cooldowns: CooldownConfig::default(),
+            min_observer_lamports: 0,
+            enforce_unique_repo_name: false,
+            blocked_hosts: Vec::new(),
+            co_admin_count: 0,
+            review_gated: false,
+            bump: 0,
+            reserved: [0u8; 63],
+        }
+    }
+
+    const URL: &str = "https://github.com/unit09-labs/unit09";
+
+    #[test]
+    fn migrate_sets_non_zero_defaults_once() {
+        let mut config = config_with(false);
+        config.schema_version = 1;
+        config.fee_recipient = Pubkey::default();
+        config.min_loc_per_observation = 0;
+        config.required_admin_sigs = 0;
+        config.event_verbosity = 0;
+        let clock = Clock {
+            unix_timestamp: 1_000,
+            ..Clock::default()
+        };
+
+        assert_eq!(config.migrate(&clock).unwrap(), 1);
+        assert_eq!(config.schema_version, CURRENT_SCHEMA_VERSION);
+        assert_eq!(config.fee_recipient, config.admin);
+        assert_eq!(config.min_loc_per_observation, DEFAULT_MIN_LOC_PER_OBSERVATION);
+        assert_eq!(config.required_admin_sigs, 1);
+        assert_eq!(config.event_verbosity, EVENT_VERBOSITY_NORMAL);
+        assert!(!config.review_gated);
+        assert_eq!(config.updated_at, 1_000);
+
+        assert_eq!(
+            config.migrate(&clock).unwrap_err(),
+            Unit09Error::MigrationAlreadyApplied.into()
+        );
+    }
+
+    #[test]
+    fn enforced_key_must_match_url_hash() {
+        let config = config_with(true);
+        assert!(config
+            .assert_repo_key_allowed(&Repo::key_for_url(URL), URL)
+            .is_ok());
+        assert_eq!(
+            config
+                .assert_repo_key_allowed(&Pubkey::new_unique(), URL)
+                .unwrap_err(),
+            Unit09Error::RepoKeyMismatch.into()
+        );
+    }
+
+    fn clock_at(unix_timestamp: i64) -> Clock {
+        Clock {
+            unix_timestamp,
+            ..Clock::default()
+        }
+    }
+
+    #[test]
+    fn observed_at_defaults_to_clock() {
+        let config = config_with(false);
+        let clock = clock_at(1_000);
+        assert_eq!(
+            config
+                .resolve_observed_at(&Pubkey::new_unique(), None, &clock)
+                .unwrap(),
+            1_000
+        );
+    }
+
+    #[test]
+    fn admin_override_is_clamped_to_past() {
+        let config = config_with(false);
+        let clock = clock_at(1_000);
+        let admin = config.admin;
+
+        assert_eq!(
+            config.resolve_observed_at(&admin, Some(400), &clock).unwrap(),
+            400
+        );
+        assert_eq!(
+            config.resolve_observed_at(&admin, Some(5_000), &clock).unwrap(),
+            1_000
+        );
+        assert_eq!(
+            config
+                .resolve_observed_at(&admin, Some(-5), &clock)
+                .unwrap_err(),
+            Unit09Error::ValueOutOfRange.into()
+        );
+    }
+
+    #[test]
+    fn non_admin_override_is_rejected() {
+        let config = config_with(false);
+        assert_eq!(
+            config
+                .resolve_observed_at(&Pubkey::new_unique(), Some(400), &clock_at(1_000))
+                .unwrap_err(),
+            Unit09Error::UnauthorizedAdminAction.into()
+        );
+    }
+
+    #[test]
+    fn max_fee_bps_is_inclusive() {
+        assert_eq!(MAX_FEE_BPS, 5_000);
+        assert!(Config::validate_fee_bps(MAX_FEE_BPS).is_ok());
+        assert_eq!(
+            Config::validate_fee_bps(MAX_FEE_BPS + 1).unwrap_err(),
+            Unit09Error::InvalidFeeBps.into()
+        );
+    }
+
+    #[test]
+    fn observations_below_min_loc_are_rejected() {
+        let mut config = config_with(false);
+        assert!(config.assert_min_loc(1).is_ok());
+
+        config
+            .apply_update(
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                Some(100),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                &Clock::default(),
+            )
+            .unwrap();
+        assert_eq!(
+            config.assert_min_loc(99).unwrap_err(),
+            Unit09Error::ValueOutOfRange.into()
+        );
+        assert!(config.assert_min_loc(100).is_ok());
+    }
+
+    #[test]
+    fn min_loc_must_be_positive_and_bounded() {
+        assert!(Config::validate_min_loc(0).is_err());
+        assert!(Config::validate_min_loc(MAX_LOC_PER_OBSERVATION).is_ok());
+        assert!(Config::validate_min_loc(MAX_LOC_PER_OBSERVATION + 1).is_err());
+    }
+
+    #[test]
+    fn arbitrary_keys_allowed_when_not_enforced() {
+        let config = config_with(false);
+        assert!(config
+            .assert_repo_key_allowed(&Pubkey::new_unique(), URL)
+            .is_ok());
+    }
+
+    #[test]
+    fn single_admin_quorum_needs_no_co_signers() {
+        let mut config = config_with(false);
+        config.assert_quorum_reached(&[]).unwrap();
+
+        // A zeroed field still means single-admin behavior.
+        config.required_admin_sigs = 0;
+        config.assert_quorum_reached(&[]).unwrap();
+    }
+
+    #[test]
+    fn two_of_n_quorum_counts_distinct_admins() {
+        let mut config = config_with(false);
+        config.required_admin_sigs = 2;
+        let co_admin = Pubkey::new_unique();
+
+        config.assert_quorum_reached(&[co_admin]).unwrap();
+
+        for too_few in [vec![], vec![config.admin]] {
+            assert_eq!(
+                config.assert_quorum_reached(&too_few).unwrap_err(),
+                Unit09Error::UnauthorizedAdminAction.into()
+            );
+        }
+
+        config.required_admin_sigs = 3;
+        assert!(config.assert_quorum_reached(&[co_admin, co_admin]).is_err());
+        config
+            .assert_quorum_reached(&[co_admin, Pubkey::new_unique()])
+            .unwrap();
+    }
+
+    #[test]
+    fn required_admin_sigs_is_bounded() {
+        let mut config = config_with(false);
+        let update = |config: &mut Config, required: u8| {
+            config.apply_update(
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                Some(required),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                &Clock::default(),
+            )
+        };
+
+        assert!(update(&mut config, 0).is_err());
+
+        // Without co-admins only the admin can sign.
+        assert_eq!(
+            update(&mut config, 2).unwrap_err(),
+            Unit09Error::AdminQuorumUnreachable.into()
+        );
+        config.co_admin_count = 1;
+        update(&mut config, 2).unwrap();
+
+        config.co_admin_count = MAX_REQUIRED_ADMIN_SIGS;
+        assert!(update(&mut config, MAX_REQUIRED_ADMIN_SIGS + 1).is_err());
+        update(&mut config, MAX_REQUIRED_ADMIN_SIGS).unwrap();
+        assert_eq!(config.required_admin_sigs, MAX_REQUIRED_ADMIN_SIGS);
+    }
+
+    #[test]
+    fn co_admins_cannot_drop_below_the_quorum() {
+        let mut config = config_with(false);
+        config.add_co_admin().unwrap();
+        config.add_co_admin().unwrap();
+        config.required_admin_sigs = 3;
+
+        assert_eq!(
+            config.remove_co_admin().unwrap_err(),
+            Unit09Error::AdminQuorumUnreachable.into()
+        );
+        assert_eq!(config.co_admin_count, 2);
+
+        config.required_admin_sigs = 2;
+        config.remove_co_admin().unwrap();
+        assert_eq!(config.co_admin_count, 1);
+        assert!(config.remove_co_admin().is_err());
+
+        config.required_admin_sigs = 1;
+        config.remove_co_admin().unwrap();
+        assert_eq!(config.co_admin_count, 0);
+        assert!(config.remove_co_admin().is_err());
+    }
+
+    #[test]
+    fn name_len_override_only_tightens() {
+        let mut config = config_with(false);
+        let update = |config: &mut Config, max_len: u16| {
+            config.apply_update(
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                Some(max_len),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                &Clock::default(),
+            )
+        };
+        let name = "x".repeat(MAX_NAME_LEN);
+        assert!(config.assert_name_len(&name).is_ok());
+
+        update(&mut config, 16).unwrap();
+        assert_eq!(config.max_name_len(), 16);
+        assert!(config.assert_name_len(&name[..16]).is_ok());
+        assert_eq!(
+            config.assert_name_len(&name[..17]).unwrap_err(),
+            Unit09Error::StringTooLong.into()
+        );
+
+        // Cannot exceed the constant; the previous override stays.
+        assert!(update(&mut config, MAX_NAME_LEN as u16 + 1).is_err());
+        assert_eq!(config.max_name_len(), 16);
+
+        assert!(update(&mut config, 0).is_err());
+
+        update(&mut config, MAX_NAME_LEN as u16).unwrap();
+        assert_eq!(config.max_name_len_override, None);
+        assert_eq!(config.max_name_len(), MAX_NAME_LEN);
+    }
+
+    #[test]
+    fn attestor_is_optional_unless_required() {
+        let mut config = config_with(false);
+        let observer = Pubkey::new_unique();
+        let attestor = Pubkey::new_unique();
+
+        assert_eq!(config.resolve_attestor(&observer, None).unwrap(), None);
+        assert_eq!(
+            config.resolve_attestor(&observer, Some(&attestor)).unwrap(),
+            Some(attestor)
+        );
+        assert_eq!(
+            config.resolve_attestor(&observer, Some(&observer)).unwrap_err(),
+            Unit09Error::InvalidAuthority.into()
+        );
+
+        config.require_attestor = true;
+        assert_eq!(
+            config.resolve_attestor(&observer, None).unwrap_err(),
+            Unit09Error::MissingAuthority.into()
+        );
+        assert_eq!(
+            config.resolve_attestor(&observer, Some(&attestor)).unwrap(),
+            Some(attestor)
+        );
+    }
+
+    #[test]
+    fn policy_version_bumps_only_on_policy_ref_change() {
+        let mut config = config_with(false);
+        let update = |config: &mut Config, policy_ref: Option<[u8; 32]>| {
+            config.apply_update(
+                None,
+                None,
+                None,
+                policy_ref,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                &Clock::default(),
+            )
+        };
+
+        update(&mut config, Some([1u8; 32])).unwrap();
+        assert_eq!(config.policy_version, 1);
+
+        // Same ref again, or no ref at all, leaves the version alone.
+        update(&mut config, Some([1u8; 32])).unwrap();
+        update(&mut config, None).unwrap();
+        assert_eq!(config.policy_version, 1);
+
+        update(&mut config, Some([2u8; 32])).unwrap();
+        assert_eq!(config.policy_version, 2);
+        assert_eq!(config.policy_ref, [2u8; 32]);
+
+        config.policy_version = u32::MAX;
+        assert_eq!(
+            update(&mut config, Some([3u8; 32])).unwrap_err(),
+            Unit09Error::CounterOverflow.into()
+        );
+        assert_eq!(config.policy_ref, [2u8; 32]);
+    }
+
+    #[test]
+    fn event_verbosity_gates_tiers() {
+        let mut config = config_with(false);
+        let update = |config: &mut Config, level: u8| {
+            config.apply_update(
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                Some(level),
+                None,
+                None,
+                None,
+                None,
+                None,
+                &Clock::default(),
+            )
+        };
+
+        update(&mut config, EVENT_VERBOSITY_CRITICAL).unwrap();
+        assert!(config.emits_at(EVENT_VERBOSITY_CRITICAL));
+        assert!(!config.emits_at(EVENT_VERBOSITY_NORMAL));
+        assert!(!config.emits_at(EVENT_VERBOSITY_DEBUG));
+
+        update(&mut config, EVENT_VERBOSITY_DEBUG).unwrap();
+        assert!(config.emits_at(EVENT_VERBOSITY_NORMAL));
+        assert!(config.emits_at(EVENT_VERBOSITY_DEBUG));
+
+        assert_eq!(
+            update(&mut config, EVENT_VERBOSITY_DEBUG + 1).unwrap_err(),
+            Unit09Error::ValueOutOfRange.into()
+        );
+        assert_eq!(config.event_verbosity, EVENT_VERBOSITY_DEBUG);
+    }
+
+    #[test]
+    fn fork_labels_must_carry_required_prefix() {
+        let mut config = config_with(false);
+        assert!(config.assert_label_prefix("any-label").is_ok());
+
+        let update = |config: &mut Config, prefix: &str| {
+            config.apply_update(
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                Some(prefix.to_string()),
+                None,
+                None,
+                None,
+                &Clock::default(),
+            )
+        };
+
+        update(&mut config, "storyline-1:").unwrap();
+        assert!(config.assert_label_prefix("storyline-1:intro").is_ok());
+        assert_eq!(
+            config.assert_label_prefix("storyline-2:intro").unwrap_err(),
+            Unit09Error::ValidationFailed.into()
+        );
+
+        assert_eq!(
+            update(&mut config, &"x".repeat(MAX_LABEL_PREFIX_LEN + 1)).unwrap_err(),
+            Unit09Error::StringTooLong.into()
+        );
+
+        update(&mut config, "").unwrap();
+        assert_eq!(config.label_prefix_required, None);
+        assert!(config.assert_label_prefix("storyline-2:intro").is_ok());
+    }
+
+    #[test]
+    fn cooldowns_are_independent() {
+        let cooldowns = CooldownConfig {
+            observation_secs: 60,
+            fork_creation_secs: 0,
+            module_touch_secs: 300,
+        };
+        let clock = Clock {
+            unix_timestamp: 1_100,
+            ..Clock::default()
+        };
+
+        // Observation window: 60s since t=1_000 has elapsed, since t=1_050
+        // it has not.
+        assert!(cooldowns.assert_observation_elapsed(1_000, &clock).is_ok());
+        assert_eq!(
+            cooldowns.assert_observation_elapsed(1_050, &clock).unwrap_err(),
+            Unit09Error::CooldownActive.into()
+        );
+
+        // Fork creation is disabled and ignores the other windows.
+        assert!(cooldowns.assert_fork_creation_elapsed(1_099, &clock).is_ok());
+
+        // Module touches use their own, longer window.
+        assert_eq!(
+            cooldowns.assert_module_touch_elapsed(1_000, &clock).unwrap_err(),
+            Unit09Error::CooldownActive.into()
+        );
+        assert!(cooldowns.assert_module_touch_elapsed(800, &clock).is_ok());
+
+        // Nothing happened yet: never on cooldown.
+        assert!(cooldowns.assert_module_touch_elapsed(0, &clock).is_ok());
+    }
+
+    #[test]
+    fn observer_balance_must_reach_minimum() {
+        let mut config = config_with(false);
+        assert!(config.assert_observer_balance(0).is_ok());
+
+        config.min_observer_lamports = 1_000_000;
+        assert_eq!(
+            config.assert_observer_balance(999_999).unwrap_err(),
+            Unit09Error::InsufficientObserverBalance.into()
+        );
+        assert!(config.assert_observer_balance(1_000_000).is_ok());
+    }
+
+    #[test]
+    fn blocked_host_covers_its_subdomains() {
+        let mut config = config_with(false);
+        let clock = Clock::default();
+        config.block_host(Config::host_hash("evil.org"), &clock).unwrap();
+
+        for url in [
+            "https://sub.evil.org/x",
+            "https://a.b.EVIL.org.:443/x",
+            "deep.sub.evil.org",
+        ] {
+            assert_eq!(
+                config.assert_host_allowed(url).unwrap_err(),
+                Unit09Error::RepoBlocked.into(),
+                "{url}"
+            );
+        }
+        // Only whole labels match: a lookalike host or a sibling is allowed.
+        assert!(config.assert_host_allowed("https://notevil.org/x").is_ok());
+        assert!(config.assert_host_allowed("https://evil.org.example.com").is_ok());
+
+        // Blocking a subdomain leaves its parent allowed.
+        config.unblock_host(&Config::host_hash("evil.org"), &clock).unwrap();
+        config.block_host(Config::host_hash("sub.evil.org"), &clock).unwrap();
+        assert!(config.assert_host_allowed("https://evil.org/x").is_ok());
+        assert!(config.assert_host_allowed("https://x.sub.evil.org/x").is_err());
+    }
+
+    #[test]
+    fn blocked_hosts_reject_any_url_on_the_host() {
+        let mut config = config_with(false);
+        let clock = Clock::default();
+        assert!(config.assert_host_allowed(URL).is_ok());
+
+        config.block_host(Config::host_hash("GitHub.com"), &clock).unwrap();
+        assert_eq!(
+            config.assert_host_allowed(URL).unwrap_err(),
+            Unit09Error::RepoBlocked.into()
+        );
+        assert_eq!(
+            config
+                .assert_host_allowed("https://user@www.github.com:443/other/repo")
+                .unwrap_err(),
+            Unit09Error::RepoBlocked.into()
+        );
+        assert!(config.assert_host_allowed("https://gitlab.com/unit09").is_ok());
+
+        config.block_host(Config::host_hash("evil.org"), &clock).unwrap();
+        for url in [
+            "https://evil.org./x",
+            "https://evil.org?x=1",
+            "https://evil.org#frag",
+            "https://EVIL.org.:443?x#y",
+        ] {
+            assert_eq!(
+                config.assert_host_allowed(url).unwrap_err(),
+                Unit09Error::RepoBlocked.into(),
+                "{url}"
+            );
+        }
+        config.unblock_host(&Config::host_hash("evil.org"), &clock).unwrap();
+
+        assert_eq!(
+            config.block_host(Config::host_hash("github.com"), &clock).unwrap_err(),
+            Unit09Error::NoChangeRequested.into()
+        );
+
+        config.unblock_host(&Config::host_hash(URL), &clock).unwrap();
+        assert!(config.assert_host_allowed(URL).is_ok());
+        assert_eq!(
+            config.unblock_host(&Config::host_hash(URL), &clock).unwrap_err(),
+            Unit09Error::NoChangeRequested.into()
+        );
+
+        for i in 0..MAX_BLOCKED_HOSTS {
+            config.block_host(Config::host_hash(&format!("host{}.org", i)), &clock).unwrap();
+        }
+        assert_eq!(
+            config.block_host(Config::host_hash("one-more.org"), &clock).unwrap_err(),
+            Unit09Error::ValueOutOfRange.into()
+        );
+    }
+
+    #[test]
+    fn cooldown_retry_time_is_last_seen_plus_window() {
+        assert_eq!(CooldownConfig::retry_at(60, 1_000), Some(1_060));
+        assert_eq!(CooldownConfig::retry_at(0, 1_000), None);
+        assert_eq!(CooldownConfig::retry_at(60, 0), None);
+    }
+}
@@ -20,9 +20,13 @@
 //! ===========================================================================
 
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
 
 use crate::constants::*;
 use crate::errors::Unit09Error;
+use crate::utils::size::assert_fits_len;
+use crate::utils::update::apply_if_some;
+use crate::utils::validators::assert_distinct;
 
 /// Fork account tracked by Unit09.
 ///
@@ -111,8 +115,22 @@ pub struct Fork {
     /// Bump used for PDA derivation.
     pub bump: u8,
 
+    /// Approximate number of forks below this one in the tree.
+    ///
+    /// Incremented on every ancestor passed to `create_fork` and meant to be
+    /// decremented when a descendant is removed. Callers that omit ancestor
+    /// accounts leave this count low, so treat it as an estimate.
+    pub descendant_count: u64,
+
+    /// Fork keys of direct children, in creation order (at most
+    /// `MAX_CHILDREN_PER_FORK`).
+    ///
+    /// Appended by `create_fork` (when the parent account is passed) and
+    /// `clone_fork`, so on-chain code can walk a subtree without scanning.
+    pub children: Vec<Pubkey>,
+
     /// Reserved bytes for future upgrades.
-    pub reserved: [u8; 62],
+    pub reserved: [u8; 64],
 }
 
 impl Fork {
@@ -148,7 +166,9 @@ impl Fork {
         + 8 // updated_at: i64
         + 1 // schema_version: u8
         + 1 // bump: u8
-        + 62; // reserved: [u8; 62]
+        + 8 // descendant_count: u64
+        + 4 + 32 * MAX_CHILDREN_PER_FORK // children: Vec<Pubkey>
+        + 64; // reserved: [u8; 64]
 
     // -----------------------------------------------------------------------
     // Initialization
@@ -186,15 +206,38 @@ impl Fork {
         self.depth = depth;
         self.usage_count = 0;
         self.last_used_at = 0;
+        self.descendant_count = 0;
+        self.children = Vec::new();
         self.created_at = clock.unix_timestamp;
         self.updated_at = clock.unix_timestamp;
         self.schema_version = CURRENT_SCHEMA_VERSION;
         self.bump = bump;
-        self.reserved = [0u8; 62];
+        self.reserved = [0u8; 64];
+        assert_fits_len(self, Self::LEN);
 
         Ok(())
     }
 
+    /// Upgrade this fork from an older `schema_version` to
+    /// `CURRENT_SCHEMA_VERSION` and return the version it was upgraded from.
+    ///
+    /// Expects an account already grown to `Fork::LEN`. `descendant_count`
+    /// and `children` start empty: forks created before version 2 were never
+    /// recorded on their parent, which `remove_child` and
+    /// `decrement_descendants` tolerate. Fails with `MigrationAlreadyApplied`
+    /// if the fork is already current.
+    pub fn migrate(&mut self, clock: &Clock) -> Result<u8> {
+        let from_version = self.schema_version;
+        if from_version >= CURRENT_SCHEMA_VERSION {
+            return err!(Unit09Error::MigrationAlreadyApplied);
+        }
+
+        self.schema_version = CURRENT_SCHEMA_VERSION;
+        self.updated_at = clock.unix_timestamp;
+
+        Ok(from_version)
+    }
+
     // -----------------------------------------------------------------------
     // Metadata / State Updates
     // -----------------------------------------------------------------------
@@ -211,29 +254,62 @@ impl Fork {
         maybe_is_active: Option<bool>,
         clock: &Clock,
     ) -> Result<()> {
-        if let Some(label) = maybe_label {
-            Self::validate_label(&label)?;
-            self.label = label;
-        }
+        apply_if_some!(self.label, maybe_label, Self::validate_label);
 
-        if let Some(metadata_uri) = maybe_metadata_uri {
-            Self::validate_metadata_uri(&metadata_uri)?;
-            self.metadata_uri = metadata_uri;
-        }
+        apply_if_some!(self.metadata_uri, maybe_metadata_uri, Self::validate_metadata_uri);
 
-        if let Some(tags) = maybe_tags {
-            Self::validate_tags(&tags)?;
-            self.tags = tags;
-        }
+        apply_if_some!(self.tags, maybe_tags, Self::validate_tags);
 
-        if let Some(is_active) = maybe_is_active {
-            self.is_active = is_active;
-        }
+        apply_if_some!(self.is_active, maybe_is_active);
 
         self.updated_at = clock.unix_timestamp;
         Ok(())
     }
 
+    /// Whether this fork links to a parent fork.
+    ///
+    /// Root forks and forks created without a parent (default pubkey) end a
+    /// lineage walk.
+    pub fn has_parent(&self) -> bool {
+        !self.is_root && self.parent != Pubkey::default()
+    }
+
+    /// Deterministic fork key for `owner` and `label`: the SHA-256 hash of
+    /// the owner's bytes followed by the label's bytes.
+    ///
+    /// Used by `create_fork` with `derive_key`. The same owner and label
+    /// always give the same key, so they identify at most one fork.
+    pub fn derived_key(owner: &Pubkey, label: &str) -> Pubkey {
+        Pubkey::new_from_array(hashv(&[owner.as_ref(), label.as_bytes()]).to_bytes())
+    }
+
+    /// Label for a fork cloned from this one: `"<label>-<suffix>"`.
+    ///
+    /// Fails with `StringEmpty` for an empty suffix and `StringTooLong` if
+    /// the result exceeds `MAX_LABEL_LEN`.
+    pub fn cloned_label(&self, suffix: &str) -> Result<String> {
+        if suffix.is_empty() {
+            return err!(Unit09Error::StringEmpty);
+        }
+        let label = format!("{}-{}", self.label, suffix);
+        Self::validate_label(&label)?;
+        Ok(label)
+    }
+
+    /// Depth of a fork cloned from this one.
+    ///
+    /// Fails with `ValueOutOfRange` beyond `MAX_FORK_DEPTH`.
+    pub fn child_depth(&self) -> Result<u16> {
+        let depth = self
+            .depth
+            .checked_add(1)
+            .ok_or(Unit09Error::CounterOverflow)?;
+        if depth as usize > MAX_FORK_DEPTH {
+            return err!(Unit09Error::ValueOutOfRange);
+        }
+        Ok(depth)
+    }
+
     /// Optionally update the parent and depth for this fork in a controlled
     /// way, if your protocol allows reparenting.
     pub fn reparent(
@@ -249,6 +325,20 @@ impl Fork {
         Ok(())
     }
 
+    /// Hand the fork over to `new_owner` (admin recovery path).
+    ///
+    /// Fails with `InvalidForkOwner` for the default key and
+    /// `NoChangeRequested` if `new_owner` already owns the fork.
+    pub fn reassign_owner(&mut self, new_owner: Pubkey, clock: &Clock) -> Result<()> {
+        if new_owner == Pubkey::default() {
+            return err!(Unit09Error::InvalidForkOwner);
+        }
+        assert_distinct(&self.owner, &new_owner)?;
+        self.owner = new_owner;
+        self.updated_at = clock.unix_timestamp;
+        Ok(())
+    }
+
     // -----------------------------------------------------------------------
     // Authority and Activation Guards
     // -----------------------------------------------------------------------
@@ -269,6 +359,25 @@ impl Fork {
         Ok(())
     }
 
+    /// Ensure that `update_fork_state` may modify the fork: it must be
+    /// active (`ForkInactive`) unless the update reactivates it, which is
+    /// the one change allowed on an inactive fork.
+    pub fn assert_update_allowed(&self, reactivating: bool) -> Result<()> {
+        if reactivating {
+            return Ok(());
+        }
+        self.assert_active()
+    }
+
+    /// Ensure that the fork may be closed by `prune_forks`: it must be
+    /// inactive and list no children (`ForkNotPrunable`).
+    pub fn assert_prunable(&self) -> Result<()> {
+        if self.is_active || !self.children.is_empty() {
+            return err!(Unit09Error::ForkNotPrunable);
+        }
+        Ok(())
+    }
+
     // -----------------------------------------------------------------------
     // Usage Tracking
     // -----------------------------------------------------------------------
@@ -287,6 +396,46 @@ impl Fork {
         Ok(())
     }
 
+    /// Count one more fork in this fork's subtree.
+    pub fn increment_descendants(&mut self) -> Result<()> {
+        self.descendant_count = self
+            .descendant_count
+            .checked_add(1)
+            .ok_or(Unit09Error::CounterOverflow)?;
+        Ok(())
+    }
+
+    /// Count one fewer fork in this fork's subtree.
+    ///
+    /// Saturates at zero, since the count is only an estimate and may never
+    /// have been incremented for the removed descendant.
+    pub fn decrement_descendants(&mut self) {
+        self.descendant_count = self.descendant_count.saturating_sub(1);
+    }
+
+    /// Record `child_key` as a direct child of this fork.
+    ///
+    /// Adding a key that is already listed is a no-op. Fails with
+    /// `ForkLimitReached` once `MAX_CHILDREN_PER_FORK` children are listed.
+    pub fn add_child(&mut self, child_key: Pubkey) -> Result<()> {
+        if self.children.contains(&child_key) {
+            return Ok(());
+        }
+        if self.children.len() >= MAX_CHILDREN_PER_FORK {
+            return err!(Unit09Error::ForkLimitReached);
+        }
+        self.children.push(child_key);
+        Ok(())
+    }
+
+    /// Remove `child_key` from the children list, returning whether it was
+    /// listed. Meant for the close path of a child fork.
+    pub fn remove_child(&mut self, child_key: &Pubkey) -> bool {
+        let before = self.children.len();
+        self.children.retain(|key| key != child_key);
+        self.children.len() != before
+    }
+
     // -----------------------------------------------------------------------
     // Validation Helpers
     // -----------------------------------------------------------------------
@@ -340,3 +489,153 @@ impl Fork {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fork_at_depth(depth: u16) -> Fork {
+        Fork {
+            fork_key: Pubkey::new_unique(),
+            parent: Pubkey::default(),
+            owner: Pubkey::new_unique(),
+            label: "unit09-test".to_string(),
+            metadata_uri: "https://unit09.org/fork.json".to_string(),
+            tags: String::new(),
+            is_active: true,
+            is_root: depth == 0,
+            depth,
+            usage_count: 0,
+            last_used_at: 0,
+            descendant_count: 0,
+            children: Vec::new(),
+            created_at: 0,
+            updated_at: 0,
+            schema_version: CURRENT_SCHEMA_VERSION,
+            bump: 0,
+            reserved: [0u8; 64],
+        }
+    }
+
+    #[test]
+    fn derived_key_is_reproducible() {
+        let owner = Pubkey::new_unique();
+
+        assert_eq!(
+            Fork::derived_key(&owner, "unit09-lab-alpha"),
+            Fork::derived_key(&owner, "unit09-lab-alpha")
+        );
+        assert_ne!(
+            Fork::derived_key(&owner, "unit09-lab-alpha"),
+            Fork::derived_key(&owner, "unit09-lab-beta")
+        );
+        assert_ne!(
+            Fork::derived_key(&owner, "unit09-lab-alpha"),
+            Fork::derived_key(&Pubkey::new_unique(), "unit09-lab-alpha")
+        );
+    }
+
+    #[test]
+    fn cloned_label_keeps_source_prefix() {
+        let source = fork_at_depth(0);
+        assert_eq!(source.cloned_label("eu").unwrap(), "unit09-test-eu");
+        assert!(source.cloned_label("").is_err());
+        assert!(source
+            .cloned_label(&"x".repeat(Fork::MAX_LABEL_LEN))
+            .is_err());
+    }
+
+    #[test]
+    fn reassign_owner_rejects_default_and_current_owner() {
+        let clock = Clock::default();
+        let mut fork = fork_at_depth(0);
+        let current = fork.owner;
+
+        assert_eq!(
+            fork.reassign_owner(Pubkey::default(), &clock).unwrap_err(),
+            Unit09Error::InvalidForkOwner.into()
+        );
+        assert_eq!(
+            fork.reassign_owner(current, &clock).unwrap_err(),
+            Unit09Error::NoChangeRequested.into()
+        );
+
+        let new_owner = Pubkey::new_unique();
+        fork.reassign_owner(new_owner, &clock).unwrap();
+        assert_eq!(fork.owner, new_owner);
+    }
+
+    #[test]
+    fn only_inactive_childless_forks_are_prunable() {
+        let mut fork = fork_at_depth(1);
+        assert!(fork.assert_prunable().is_err());
+
+        fork.is_active = false;
+        fork.add_child(Pubkey::new_unique()).unwrap();
+        assert!(fork.assert_prunable().is_err());
+
+        fork.children.clear();
+        assert!(fork.assert_prunable().is_ok());
+    }
+
+    #[test]
+    fn child_depth_is_bounded() {
+        assert_eq!(fork_at_depth(0).child_depth().unwrap(), 1);
+        assert!(fork_at_depth(MAX_FORK_DEPTH as u16 - 1).child_depth().is_ok());
+        assert!(fork_at_depth(MAX_FORK_DEPTH as u16).child_depth().is_err());
+    }
+
+    #[test]
+    fn child_creation_increments_descendants() {
+        let mut parent = fork_at_depth(0);
+        parent.increment_descendants().unwrap();
+        parent.increment_descendants().unwrap();
+        assert_eq!(parent.descendant_count, 2);
+    }
+
+    #[test]
+    fn descendant_removal_decrements_and_saturates() {
+        let mut parent = fork_at_depth(0);
+        parent.increment_descendants().unwrap();
+
+        parent.decrement_descendants();
+        assert_eq!(parent.descendant_count, 0);
+
+        parent.decrement_descendants();
+        assert_eq!(parent.descendant_count, 0);
+    }
+
+    #[test]
+    fn children_are_appended_until_the_cap() {
+        let mut parent = fork_at_depth(0);
+
+        for _ in 0..MAX_CHILDREN_PER_FORK {
+            parent.add_child(Pubkey::new_unique()).unwrap();
+        }
+        let listed = parent.children[0];
+        parent.add_child(listed).unwrap();
+        assert_eq!(parent.children.len(), MAX_CHILDREN_PER_FORK);
+
+        assert_eq!(
+            parent.add_child(Pubkey::new_unique()).unwrap_err(),
+            Unit09Error::ForkLimitReached.into()
+        );
+
+        assert!(parent.remove_child(&listed));
+        assert!(!parent.remove_child(&listed));
+        parent.add_child(Pubkey::new_unique()).unwrap();
+    }
+
+    #[test]
+    fn inactive_fork_only_accepts_reactivation() {
+        let mut fork = fork_at_depth(1);
+        assert!(fork.assert_update_allowed(false).is_ok());
+
+        fork.is_active = false;
+        assert_eq!(
+            fork.assert_update_allowed(false).unwrap_err(),
+            Unit09Error::ForkInactive.into()
+        );
+        assert!(fork.assert_update_allowed(true).is_ok());
+    }
+}
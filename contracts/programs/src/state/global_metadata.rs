@@ -0,0 +1,202 @@
+//! ===========================================================================
+//! Unit09 – Global Metadata State
+//! Path: contracts/unit09-program/programs/unit09_program/src/state/global_metadata.rs
+//!
+//! The `GlobalMetadata` account stores human-facing, deployment-wide
+//! descriptive information such as a description, tags, and canonical links.
+//!
+//! It is intentionally separate from `Config` so that presentation data can
+//! grow or change without touching protocol parameters. The account is
+//! created lazily by the first `set_metadata` call.
+//!
+//! ===========================================================================
+
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::Unit09Error;
+use crate::utils::size::assert_fits_len;
+use crate::utils::update::apply_if_some;
+
+/// Deployment-wide descriptive metadata.
+///
+/// PDA derived from `GLOBAL_METADATA_SEED` and the program ID.
+#[account]
+pub struct GlobalMetadata {
+    /// High-level description of the deployment.
+    pub description: String,
+
+    /// Comma-separated tag string.
+    pub tags: String,
+
+    /// Canonical website URL.
+    pub website_url: String,
+
+    /// Documentation URL.
+    pub docs_url: String,
+
+    /// Dashboard URL (metrics, explorers, etc.).
+    pub dashboard_url: String,
+
+    /// Icon or logo URI.
+    pub icon_uri: String,
+
+    /// Unstructured JSON payload interpreted by off-chain tooling.
+    pub extra_json: String,
+
+    /// Unix timestamp when this account was created.
+    pub created_at: i64,
+
+    /// Unix timestamp when this account was last updated.
+    pub updated_at: i64,
+
+    /// Schema version for this metadata layout.
+    pub schema_version: u8,
+
+    /// Bump used for PDA derivation.
+    pub bump: u8,
+
+    /// Reserved bytes for future upgrades.
+    pub reserved: [u8; 32],
+}
+
+impl GlobalMetadata {
+    /// Discriminator length used by Anchor.
+    pub const DISCRIMINATOR_LEN: usize = 8;
+
+    /// Maximum length of `description` in bytes.
+    pub const MAX_DESCRIPTION_LEN: usize = MAX_DESCRIPTION_LEN;
+
+    /// Maximum length of `tags` in bytes.
+    pub const MAX_TAGS_LEN: usize = MAX_TAGS_LEN;
+
+    /// Maximum length of each URL field in bytes.
+    pub const MAX_URL_LEN: usize = MAX_URL_LEN;
+
+    /// Maximum length of `icon_uri` in bytes.
+    pub const MAX_ICON_URI_LEN: usize = MAX_METADATA_URI_LEN;
+
+    /// Maximum length of `extra_json` in bytes.
+    pub const MAX_EXTRA_JSON_LEN: usize = 512;
+
+    /// Total serialized length of the `GlobalMetadata` account.
+    pub const LEN: usize = Self::DISCRIMINATOR_LEN
+        + 4 + Self::MAX_DESCRIPTION_LEN // description: String
+        + 4 + Self::MAX_TAGS_LEN        // tags: String
+        + 4 + Self::MAX_URL_LEN         // website_url: String
+        + 4 + Self::MAX_URL_LEN         // docs_url: String
+        + 4 + Self::MAX_URL_LEN         // dashboard_url: String
+        + 4 + Self::MAX_ICON_URI_LEN    // icon_uri: String
+        + 4 + Self::MAX_EXTRA_JSON_LEN  // extra_json: String
+        + 8  // created_at: i64
+        + 8  // updated_at: i64
+        + 1  // schema_version: u8
+        + 1  // bump: u8
+        + 32; // reserved: [u8; 32]
+
+    /// Initialize the metadata account on first use.
+    #[allow(clippy::too_many_arguments)]
+    pub fn init(
+        &mut self,
+        description: String,
+        tags: String,
+        website_url: String,
+        docs_url: String,
+        dashboard_url: String,
+        icon_uri: String,
+        extra_json: String,
+        bump: u8,
+        clock: &Clock,
+    ) -> Result<()> {
+        Self::validate_lengths(
+            &description,
+            &tags,
+            &website_url,
+            &docs_url,
+            &dashboard_url,
+            &icon_uri,
+            &extra_json,
+        )?;
+
+        self.description = description;
+        self.tags = tags;
+        self.website_url = website_url;
+        self.docs_url = docs_url;
+        self.dashboard_url = dashboard_url;
+        self.icon_uri = icon_uri;
+        self.extra_json = extra_json;
+        self.created_at = clock.unix_timestamp;
+        self.updated_at = clock.unix_timestamp;
+        self.schema_version = CURRENT_SCHEMA_VERSION;
+        self.bump = bump;
+        self.reserved = [0u8; 32];
+        assert_fits_len(self, Self::LEN);
+
+        Ok(())
+    }
+
+    /// Apply a partial update; `None` leaves a field unchanged.
+    #[allow(clippy::too_many_arguments)]
+    pub fn apply_update(
+        &mut self,
+        description: Option<String>,
+        tags: Option<String>,
+        website_url: Option<String>,
+        docs_url: Option<String>,
+        dashboard_url: Option<String>,
+        icon_uri: Option<String>,
+        extra_json: Option<String>,
+        clock: &Clock,
+    ) -> Result<()> {
+        apply_if_some!(self.description, description, |v: &String| {
+            Self::validate_len(v, Self::MAX_DESCRIPTION_LEN)
+        });
+        apply_if_some!(self.tags, tags, |v: &String| {
+            Self::validate_len(v, Self::MAX_TAGS_LEN)
+        });
+        apply_if_some!(self.website_url, website_url, |v: &String| {
+            Self::validate_len(v, Self::MAX_URL_LEN)
+        });
+        apply_if_some!(self.docs_url, docs_url, |v: &String| {
+            Self::validate_len(v, Self::MAX_URL_LEN)
+        });
+        apply_if_some!(self.dashboard_url, dashboard_url, |v: &String| {
+            Self::validate_len(v, Self::MAX_URL_LEN)
+        });
+        apply_if_some!(self.icon_uri, icon_uri, |v: &String| {
+            Self::validate_len(v, Self::MAX_ICON_URI_LEN)
+        });
+        apply_if_some!(self.extra_json, extra_json, |v: &String| {
+            Self::validate_len(v, Self::MAX_EXTRA_JSON_LEN)
+        });
+
+        self.updated_at = clock.unix_timestamp;
+        Ok(())
+    }
+
+    fn validate_lengths(
+        description: &str,
+        tags: &str,
+        website_url: &str,
+        docs_url: &str,
+        dashboard_url: &str,
+        icon_uri: &str,
+        extra_json: &str,
+    ) -> Result<()> {
+        Self::validate_len(description, Self::MAX_DESCRIPTION_LEN)?;
+        Self::validate_len(tags, Self::MAX_TAGS_LEN)?;
+        Self::validate_len(website_url, Self::MAX_URL_LEN)?;
+        Self::validate_len(docs_url, Self::MAX_URL_LEN)?;
+        Self::validate_len(dashboard_url, Self::MAX_URL_LEN)?;
+        Self::validate_len(icon_uri, Self::MAX_ICON_URI_LEN)?;
+        Self::validate_len(extra_json, Self::MAX_EXTRA_JSON_LEN)?;
+        Ok(())
+    }
+
+    fn validate_len(value: &str, max_len: usize) -> Result<()> {
+        if value.len() > max_len {
+            return err!(Unit09Error::StringTooLong);
+        }
+        Ok(())
+    }
+}
@@ -21,6 +21,7 @@ use anchor_lang::prelude::*;
 
 use crate::constants::*;
 use crate::errors::Unit09Error;
+use crate::utils::size::assert_fits_len;
 
 /// High-level lifecycle phases for a Unit09 deployment.
 ///
@@ -41,6 +42,10 @@ pub enum LifecyclePhase {
     /// Migration in progress; only migration-related instructions allowed.
     Migration = 4,
     /// Sunset phase; protocol is effectively shut down for new activity.
+    ///
+    /// New activity (observations, forks) is hard-blocked, but wind-down
+    /// operations such as metrics reconciliation, account closes, and global
+    /// metadata updates remain allowed. See `Lifecycle::assert_wind_down_allowed`.
     Sunset = 5,
 }
 
@@ -77,6 +82,38 @@ impl LifecyclePhase {
     }
 }
 
+/// Bits of the mask returned by `Lifecycle::permissions` (and the
+/// `get_permissions` instruction). A set bit means the listed instructions
+/// currently pass their lifecycle and `Config::is_active` guards; per-account
+/// checks (authority, repo active, limits) still apply.
+///
+/// Admin instructions such as `set_config` and `set_lifecycle` are not gated
+/// by either guard and have no bit.
+pub mod permission_bits {
+    /// `register_repo`, `update_repo`, `set_repo_observable`,
+    /// `set_primary_module`.
+    pub const WRITE_REPOS: u32 = 1 << 0;
+    /// `register_module`, `update_module`, `link_module_to_repo`.
+    pub const WRITE_MODULES: u32 = 1 << 1;
+    /// `create_fork`, `clone_fork`, `update_fork_state`.
+    pub const WRITE_FORKS: u32 = 1 << 2;
+    /// `record_observation`, `record_observation_multi`.
+    pub const RECORD_OBSERVATIONS: u32 = 1 << 3;
+    /// `record_metrics`, `set_metadata`.
+    pub const RECORD_METRICS: u32 = 1 << 4;
+    /// `recompute_metrics`, `ack_indexed` (lifecycle only; no config check).
+    pub const RECONCILE: u32 = 1 << 5;
+    /// `collect_fee` (config only; no lifecycle check).
+    pub const COLLECT_FEE: u32 = 1 << 6;
+
+    /// Bits granted whenever `Lifecycle::assert_writes_allowed` passes and
+    /// the config is active.
+    pub const WRITES: u32 = WRITE_REPOS | WRITE_MODULES | WRITE_FORKS | RECORD_OBSERVATIONS;
+
+    /// Every instruction group.
+    pub const ALL: u32 = WRITES | RECORD_METRICS | RECONCILE | COLLECT_FEE;
+}
+
 /// Lifecycle account for a Unit09 deployment.
 ///
 /// This is a PDA derived from the fixed seed `LIFECYCLE_SEED` and the
@@ -121,14 +158,24 @@ pub struct Lifecycle {
     /// Bump used for PDA derivation.
     pub bump: u8,
 
+    /// Human-readable reason for the current freeze (at most `MAX_NAME_LEN`
+    /// bytes).
+    ///
+    /// Set when the deployment enters a frozen state (`Frozen` phase or
+    /// `global_freeze`) and cleared when it leaves it.
+    pub freeze_reason: String,
+
     /// Reserved bytes for future upgrades.
-    pub reserved: [u8; 77],
+    pub reserved: [u8; 9],
 }
 
 impl Lifecycle {
     /// Discriminator length used by Anchor.
     pub const DISCRIMINATOR_LEN: usize = 8;
 
+    /// Maximum length of `freeze_reason` in bytes (UTF-8).
+    pub const MAX_FREEZE_REASON_LEN: usize = MAX_NAME_LEN;
+
     /// Total serialized length for the `Lifecycle` account.
     pub const LEN: usize = Self::DISCRIMINATOR_LEN
         + 1  // phase: u8
@@ -142,7 +189,8 @@ impl Lifecycle {
         + 8  // updated_at: i64
         + 1  // schema_version: u8
         + 1  // bump: u8
-        + 77; // reserved: [u8; 77]
+        + 4 + Self::MAX_FREEZE_REASON_LEN // freeze_reason: String
+        + 9; // reserved: [u8; 9]
 
     // -----------------------------------------------------------------------
     // Initialization
@@ -159,11 +207,13 @@ impl Lifecycle {
         self.phase_changed_at = now;
         self.migration_state_changed_at = 0;
         self.note_ref = note_ref;
+        self.freeze_reason = String::new();
         self.created_at = now;
         self.updated_at = now;
         self.schema_version = CURRENT_SCHEMA_VERSION;
         self.bump = bump;
-        self.reserved = [0u8; 77];
+        self.reserved = [0u8; 9];
+        assert_fits_len(self, Self::LEN);
 
         Ok(())
     }
@@ -199,6 +249,24 @@ impl Lifecycle {
         Ok(())
     }
 
+    /// Record why the deployment is frozen, or clear the reason if it is not.
+    ///
+    /// Call after any phase or freeze change. While frozen (`Frozen` phase or
+    /// `global_freeze`), `reason` replaces the stored value; otherwise the
+    /// stored reason is cleared and `reason` is ignored.
+    pub fn update_freeze_reason(&mut self, reason: String) -> Result<()> {
+        if !self.is_frozen() {
+            self.freeze_reason = String::new();
+            return Ok(());
+        }
+
+        if reason.len() > Self::MAX_FREEZE_REASON_LEN {
+            return err!(Unit09Error::StringTooLong);
+        }
+        self.freeze_reason = reason;
+        Ok(())
+    }
+
     /// Mark that a migration is required before certain instructions may run.
     pub fn require_migration(&mut self, clock: &Clock) -> Result<()> {
         self.migration_required = true;
@@ -284,6 +352,64 @@ impl Lifecycle {
         Ok(())
     }
 
+    /// Ensure that wind-down operations are allowed in the current state.
+    ///
+    /// This is the guard for instructions that must keep working while a
+    /// deployment is being retired (`record_metrics`, `set_metadata`, and any
+    /// `close_*` instruction). It behaves like `assert_writes_allowed`, except
+    /// that the `Sunset` phase is permitted. A global freeze, the `Frozen`
+    /// and `Migration` phases, and a pending migration still block.
+    pub fn assert_wind_down_allowed(&self) -> Result<()> {
+        let phase = LifecyclePhase::from_u8(self.phase)
+            .ok_or(Unit09Error::InvalidLifecycleState)?;
+
+        if phase == LifecyclePhase::Sunset && !self.global_freeze {
+            return Ok(());
+        }
+
+        self.assert_writes_allowed()
+    }
+
+    /// Bitmask of `permission_bits` allowed in the current state, given
+    /// whether `Config::is_active` is set.
+    ///
+    /// Mirrors the guards the instruction handlers call, so a cleared bit
+    /// means those instructions would fail right now.
+    pub fn permissions(&self, config_active: bool) -> u32 {
+        let writes = self.assert_writes_allowed().is_ok();
+        let wind_down = self.assert_wind_down_allowed().is_ok();
+
+        let mut mask = 0;
+        if writes && config_active {
+            mask |= permission_bits::WRITES;
+        }
+        if wind_down && config_active {
+            mask |= permission_bits::RECORD_METRICS;
+        }
+        if wind_down {
+            mask |= permission_bits::RECONCILE;
+        }
+        if config_active {
+            mask |= permission_bits::COLLECT_FEE;
+        }
+        mask
+    }
+
+    /// Whether optional telemetry events should be emitted.
+    ///
+    /// With `quiet_when_frozen` (`Config::quiet_when_frozen`) set, they are
+    /// suppressed while the deployment is effectively read-only. Used by
+    /// `emit_if_active!`.
+    pub fn emits_telemetry(&self, quiet_when_frozen: bool) -> bool {
+        !quiet_when_frozen || !self.is_effectively_read_only().unwrap_or(true)
+    }
+
+    /// Returns true if writes are frozen, either by the `Frozen` phase or by
+    /// the global freeze flag.
+    pub fn is_frozen(&self) -> bool {
+        self.global_freeze || self.phase == LifecyclePhase::Frozen.as_u8()
+    }
+
     /// Convenience function to check whether this lifecycle is effectively
     /// read-only (either by phase or by freeze).
     pub fn is_effectively_read_only(&self) -> Result<bool> {
@@ -293,3 +419,125 @@ impl Lifecycle {
         Ok(self.global_freeze || phase.is_read_only())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lifecycle_in(phase: LifecyclePhase) -> Lifecycle {
+        Lifecycle {
+            phase: phase.as_u8(),
+            global_freeze: false,
+            migration_required: false,
+            migration_in_progress: false,
+            phase_changed_at: 0,
+            migration_state_changed_at: 0,
+            note_ref: [0u8; 32],
+            freeze_reason: String::new(),
+            created_at: 0,
+            updated_at: 0,
+            schema_version: CURRENT_SCHEMA_VERSION,
+            bump: 0,
+            reserved: [0u8; 9],
+        }
+    }
+
+    /// `record_observation` and `create_fork` use `assert_writes_allowed`.
+    #[test]
+    fn sunset_blocks_new_activity() {
+        let lifecycle = lifecycle_in(LifecyclePhase::Sunset);
+        assert!(lifecycle.assert_writes_allowed().is_err());
+    }
+
+    /// `record_metrics`, `set_metadata`, and `close_*` use
+    /// `assert_wind_down_allowed`.
+    #[test]
+    fn sunset_allows_wind_down() {
+        let lifecycle = lifecycle_in(LifecyclePhase::Sunset);
+        assert!(lifecycle.assert_wind_down_allowed().is_ok());
+    }
+
+    #[test]
+    fn global_freeze_blocks_wind_down_in_sunset() {
+        let mut lifecycle = lifecycle_in(LifecyclePhase::Sunset);
+        lifecycle.global_freeze = true;
+        assert!(lifecycle.assert_wind_down_allowed().is_err());
+    }
+
+    #[test]
+    fn frozen_blocks_wind_down() {
+        let lifecycle = lifecycle_in(LifecyclePhase::Frozen);
+        assert!(lifecycle.assert_wind_down_allowed().is_err());
+    }
+
+    #[test]
+    fn operational_allows_both() {
+        let lifecycle = lifecycle_in(LifecyclePhase::Operational);
+        assert!(lifecycle.assert_writes_allowed().is_ok());
+        assert!(lifecycle.assert_wind_down_allowed().is_ok());
+    }
+
+    #[test]
+    fn permissions_follow_phase_and_config() {
+        let operational = lifecycle_in(LifecyclePhase::Operational);
+        assert_eq!(
+            operational.permissions(true),
+            permission_bits::WRITES
+                | permission_bits::RECORD_METRICS
+                | permission_bits::RECONCILE
+                | permission_bits::COLLECT_FEE
+        );
+        assert_eq!(operational.permissions(false), permission_bits::RECONCILE);
+
+        let frozen = lifecycle_in(LifecyclePhase::Frozen);
+        assert_eq!(frozen.permissions(true), permission_bits::COLLECT_FEE);
+
+        let sunset = lifecycle_in(LifecyclePhase::Sunset);
+        assert_eq!(
+            sunset.permissions(true),
+            permission_bits::RECORD_METRICS | permission_bits::RECONCILE | permission_bits::COLLECT_FEE
+        );
+    }
+
+    #[test]
+    fn telemetry_is_quiet_only_when_frozen_and_configured() {
+        let operational = lifecycle_in(LifecyclePhase::Operational);
+        assert!(operational.emits_telemetry(true));
+        assert!(operational.emits_telemetry(false));
+
+        for phase in [LifecyclePhase::Frozen, LifecyclePhase::Sunset] {
+            let quiet = lifecycle_in(phase);
+            assert!(!quiet.emits_telemetry(true));
+            assert!(quiet.emits_telemetry(false));
+        }
+
+        let mut frozen_flag = lifecycle_in(LifecyclePhase::Operational);
+        frozen_flag.global_freeze = true;
+        assert!(!frozen_flag.emits_telemetry(true));
+    }
+
+    #[test]
+    fn freeze_stores_reason_and_unfreeze_clears_it() {
+        let mut lifecycle = lifecycle_in(LifecyclePhase::Operational);
+        let clock = Clock::default();
+
+        lifecycle.set_phase(LifecyclePhase::Frozen, &clock).unwrap();
+        lifecycle
+            .update_freeze_reason("incident #42: indexer drift".to_string())
+            .unwrap();
+        assert_eq!(lifecycle.freeze_reason, "incident #42: indexer drift");
+
+        lifecycle.set_phase(LifecyclePhase::Operational, &clock).unwrap();
+        lifecycle.update_freeze_reason("ignored".to_string()).unwrap();
+        assert!(lifecycle.freeze_reason.is_empty());
+    }
+
+    #[test]
+    fn freeze_reason_is_bounded() {
+        let mut lifecycle = lifecycle_in(LifecyclePhase::Operational);
+        lifecycle.global_freeze = true;
+
+        let too_long = "x".repeat(Lifecycle::MAX_FREEZE_REASON_LEN + 1);
+        assert!(lifecycle.update_freeze_reason(too_long).is_err());
+    }
+}
@@ -27,6 +27,9 @@ use anchor_lang::prelude::*;
 
 use crate::constants::*;
 use crate::errors::Unit09Error;
+use crate::state::MetricsSnapshot;
+use crate::utils::size::assert_fits_len;
+use crate::utils::time::{assert_time_order, is_older_than};
 
 /// Global aggregate metrics for a Unit09 deployment.
 ///
@@ -68,8 +71,58 @@ pub struct Metrics {
     /// Bump used for PDA derivation.
     pub bump: u8,
 
+    /// Number of repositories currently marked active.
+    pub active_repos: u64,
+
+    /// Number of modules currently marked active.
+    pub active_modules: u64,
+
+    /// Number of forks currently marked active.
+    pub active_forks: u64,
+
+    /// Global monotonic sequence number of the last emitted event.
+    ///
+    /// Every event emitted by the program carries the next value of this
+    /// counter, allowing indexers to detect and backfill gaps.
+    pub event_seq: u64,
+
+    /// Observations recorded but not yet acknowledged by the off-chain
+    /// indexer (see `ack_indexed`).
+    ///
+    /// A steadily growing value means observers are outpacing indexing.
+    pub pending_unindexed: u64,
+
+    /// Observations recorded in the current throttle window.
+    pub observations_this_window: u64,
+
+    /// Unix timestamp at which the current throttle window started
+    /// (0 before the first throttled observation).
+    pub window_start: i64,
+
+    /// Whether a batched `recompute_metrics` run is in progress.
+    ///
+    /// While set, the repository-derived totals hold a partial sum and are
+    /// not authoritative (see `begin_recompute`).
+    pub recompute_in_progress: bool,
+
+    /// Unix timestamp at which the current or last recomputation started.
+    pub recompute_started_at: i64,
+
+    /// Repository-derived totals as they were when the current or last
+    /// recomputation started, used to report drift (see `recompute_drift`).
+    pub recompute_baseline: RepoTotals,
+
+    /// Number of `MetricsSnapshot` accounts taken; also the index of the
+    /// next one (see `next_snapshot_index`).
+    pub snapshot_count: u32,
+
+    /// Unix timestamp of the last fork created through `create_fork` or
+    /// `clone_fork` (0 before the first one), used by the fork creation
+    /// cooldown (see `CooldownConfig`).
+    pub last_fork_created_at: i64,
+
     /// Reserved bytes for future upgrades.
-    pub reserved: [u8; 78],
+    pub reserved: [u8; 64],
 }
 
 impl Metrics {
@@ -89,7 +142,19 @@ impl Metrics {
         + 8  // updated_at: i64
         + 1  // schema_version: u8
         + 1  // bump: u8
-        + 78; // reserved: [u8; 78]
+        + 8  // active_repos: u64
+        + 8  // active_modules: u64
+        + 8  // active_forks: u64
+        + 8  // event_seq: u64
+        + 8  // pending_unindexed: u64
+        + 8  // observations_this_window: u64
+        + 8  // window_start: i64
+        + 1  // recompute_in_progress: bool
+        + 8  // recompute_started_at: i64
+        + RepoTotals::LEN // recompute_baseline: RepoTotals
+        + 4  // snapshot_count: u32
+        + 8  // last_fork_created_at: i64
+        + 64; // reserved: [u8; 64]
 
     // -----------------------------------------------------------------------
     // Initialization
@@ -107,16 +172,56 @@ impl Metrics {
         self.total_observations = 0;
         self.total_lines_of_code = 0;
         self.total_files_processed = 0;
+        self.active_repos = 0;
+        self.active_modules = 0;
+        self.active_forks = 0;
+        self.event_seq = 0;
+        self.pending_unindexed = 0;
+        self.observations_this_window = 0;
+        self.window_start = 0;
         self.last_observation_at = 0;
         self.created_at = now;
         self.updated_at = now;
+        self.recompute_in_progress = false;
+        self.recompute_started_at = 0;
+        self.recompute_baseline = RepoTotals::default();
+        self.snapshot_count = 0;
+        self.last_fork_created_at = 0;
         self.schema_version = CURRENT_SCHEMA_VERSION;
         self.bump = bump;
-        self.reserved = [0u8; 78];
+        self.reserved = [0u8; 64];
+        assert_fits_len(self, Self::LEN);
 
         Ok(())
     }
 
+    /// Upgrade the metrics account from an older `schema_version` to
+    /// `CURRENT_SCHEMA_VERSION` and return the version it was upgraded from.
+    ///
+    /// Expects an account already grown to `Metrics::LEN`, so every counter
+    /// added since the stored version starts at zero. The active counts are
+    /// seeded from the totals, the best estimate available on-chain;
+    /// `recompute_metrics` corrects `active_repos` afterwards. Fails with
+    /// `MigrationAlreadyApplied` if the account is already current.
+    pub fn migrate(&mut self, clock: &Clock) -> Result<u8> {
+        let from_version = self.schema_version;
+        if from_version >= CURRENT_SCHEMA_VERSION {
+            return err!(Unit09Error::MigrationAlreadyApplied);
+        }
+
+        // 1 -> 2: active counters did not exist yet.
+        if from_version < 2 {
+            self.active_repos = self.total_repos;
+            self.active_modules = self.total_modules;
+            self.active_forks = self.total_forks;
+        }
+
+        self.schema_version = CURRENT_SCHEMA_VERSION;
+        self.updated_at = clock.unix_timestamp;
+
+        Ok(from_version)
+    }
+
     // -----------------------------------------------------------------------
     // Creation Counters
     // -----------------------------------------------------------------------
@@ -176,6 +281,83 @@ impl Metrics {
         Ok(())
     }
 
+    // -----------------------------------------------------------------------
+    // Active Counters
+    // -----------------------------------------------------------------------
+    //
+    // Active counters follow `is_active` transitions on repos, modules, and
+    // forks. Decrements saturate at zero: deployments created before these
+    // fields existed start with zeroed active counters, and deactivating an
+    // older entity must not fail on underflow.
+
+    /// Increment the active repositories counter.
+    pub fn increment_active_repos(&mut self) -> Result<()> {
+        self.active_repos = self
+            .active_repos
+            .checked_add(1)
+            .ok_or(Unit09Error::CounterOverflow)?;
+        Ok(())
+    }
+
+    /// Decrement the active repositories counter (saturating).
+    pub fn decrement_active_repos(&mut self) {
+        self.active_repos = self.active_repos.saturating_sub(1);
+    }
+
+    /// Increment the active modules counter.
+    pub fn increment_active_modules(&mut self) -> Result<()> {
+        self.active_modules = self
+            .active_modules
+            .checked_add(1)
+            .ok_or(Unit09Error::CounterOverflow)?;
+        Ok(())
+    }
+
+    /// Decrement the active modules counter (saturating).
+    pub fn decrement_active_modules(&mut self) {
+        self.active_modules = self.active_modules.saturating_sub(1);
+    }
+
+    /// Increment the active forks counter.
+    pub fn increment_active_forks(&mut self) -> Result<()> {
+        self.active_forks = self
+            .active_forks
+            .checked_add(1)
+            .ok_or(Unit09Error::CounterOverflow)?;
+        Ok(())
+    }
+
+    /// Decrement the active forks counter (saturating).
+    pub fn decrement_active_forks(&mut self) {
+        self.active_forks = self.active_forks.saturating_sub(1);
+    }
+
+    // -----------------------------------------------------------------------
+    // Event Sequencing
+    // -----------------------------------------------------------------------
+
+    /// Advance the global event sequence and return the new value.
+    ///
+    /// Call once per emitted event, immediately before `emit!`. The first
+    /// event of a deployment carries sequence number 1.
+    pub fn next_event_seq(&mut self) -> Result<u64> {
+        self.event_seq = self
+            .event_seq
+            .checked_add(1)
+            .ok_or(Unit09Error::CounterOverflow)?;
+        Ok(self.event_seq)
+    }
+
+    /// Claim the index of the next `MetricsSnapshot` and advance
+    /// `snapshot_count`.
+    pub fn next_snapshot_index(&mut self) -> Result<u32> {
+        let index = self.snapshot_count;
+        self.snapshot_count = index
+            .checked_add(1)
+            .ok_or(Unit09Error::CounterOverflow)?;
+        Ok(index)
+    }
+
     // -----------------------------------------------------------------------
     // Observation Aggregation
     // -----------------------------------------------------------------------
@@ -183,13 +365,16 @@ impl Metrics {
     /// Record a single observation and aggregate its contribution.
     ///
     /// This should be called from the `record_observation` instruction, after
-    /// the per-repository update has been performed.
+    /// the per-repository update has been performed. Fails with
+    /// `InvalidTimeRange` if `clock` is behind `updated_at`.
     pub fn record_observation(
         &mut self,
         lines_of_code: u64,
         files_processed: u32,
         clock: &Clock,
     ) -> Result<()> {
+        self.assert_not_before_update(clock)?;
+
         // Bounds check using constants.
         if lines_of_code > MAX_LOC_PER_OBSERVATION {
             return err!(Unit09Error::ObservationDataTooLarge);
@@ -216,12 +401,62 @@ impl Metrics {
             .checked_add(files_processed as u64)
             .ok_or(Unit09Error::CounterOverflow)?;
 
+        // Queue the observation for the off-chain indexer.
+        self.pending_unindexed = self
+            .pending_unindexed
+            .checked_add(1)
+            .ok_or(Unit09Error::CounterOverflow)?;
+
         // Update last observation timestamp.
         self.last_observation_at = clock.unix_timestamp;
+        self.updated_at = clock.unix_timestamp;
+
+        Ok(())
+    }
+
+    /// Reject a write whose timestamp is older than `updated_at`, so the
+    /// metrics clock never moves backwards (`InvalidTimeRange`).
+    fn assert_not_before_update(&self, clock: &Clock) -> Result<()> {
+        assert_time_order(self.updated_at, clock.unix_timestamp)
+    }
+
+    /// Consume one observation from the deployment-wide throttle budget.
+    ///
+    /// `max_per_window` comes from `Config::max_observations_per_window`;
+    /// zero disables throttling. The window restarts once `window_start` is
+    /// older than `OBSERVATION_WINDOW_SECS`. Fails with `RateLimitReached`
+    /// when the budget of the current window is exhausted.
+    pub fn consume_observation_budget(&mut self, max_per_window: u64, clock: &Clock) -> Result<()> {
+        if max_per_window == 0 {
+            return Ok(());
+        }
+
+        if self.window_start == 0
+            || is_older_than(clock, self.window_start, OBSERVATION_WINDOW_SECS)
+        {
+            self.window_start = clock.unix_timestamp;
+            self.observations_this_window = 0;
+        }
 
+        if self.observations_this_window >= max_per_window {
+            return err!(Unit09Error::RateLimitReached);
+        }
+
+        self.observations_this_window = self
+            .observations_this_window
+            .checked_add(1)
+            .ok_or(Unit09Error::CounterOverflow)?;
         Ok(())
     }
 
+    /// Acknowledge that the off-chain indexer processed `count` observations.
+    ///
+    /// Saturates at zero so that an over-eager ack cannot fail or wrap.
+    pub fn ack_indexed(&mut self, count: u64, clock: &Clock) {
+        self.pending_unindexed = self.pending_unindexed.saturating_sub(count);
+        self.updated_at = clock.unix_timestamp;
+    }
+
     // -----------------------------------------------------------------------
     // Bulk Adjustment (Reconciliation)
     // -----------------------------------------------------------------------
@@ -230,7 +465,8 @@ impl Metrics {
     /// off-chain analytics or performing corrective actions.
     ///
     /// This is considered an advanced operation and should only be exposed
-    /// to trusted admin flows.
+    /// to trusted admin flows. A `clock` behind `updated_at` is rejected with
+    /// `InvalidTimeRange` before any total is changed.
     pub fn adjust_aggregate(
         &mut self,
         new_total_repos: Option<u64>,
@@ -241,6 +477,8 @@ impl Metrics {
         new_total_files_processed: Option<u64>,
         clock: &Clock,
     ) -> Result<()> {
+        self.assert_not_before_update(clock)?;
+
         if let Some(v) = new_total_repos {
             self.total_repos = v;
         }
@@ -264,6 +502,209 @@ impl Metrics {
         Ok(())
     }
 
+    /// Roll every counter captured by `snapshot` back to its value at
+    /// `snapshot.taken_at` (see `restore_metrics`).
+    ///
+    /// Counters that snapshots do not capture (event sequence, throttle
+    /// window, snapshot count, ...) are left untouched, so event numbering
+    /// and snapshot indices keep moving forward. A `clock` behind
+    /// `updated_at` is rejected with `InvalidTimeRange`.
+    pub fn restore_from_snapshot(&mut self, snapshot: &MetricsSnapshot, clock: &Clock) -> Result<()> {
+        self.assert_not_before_update(clock)?;
+
+        self.total_repos = snapshot.total_repos;
+        self.total_modules = snapshot.total_modules;
+        self.total_forks = snapshot.total_forks;
+        self.total_observations = snapshot.total_observations;
+        self.total_lines_of_code = snapshot.total_lines_of_code;
+        self.total_files_processed = snapshot.total_files_processed;
+        self.active_repos = snapshot.active_repos;
+        self.active_modules = snapshot.active_modules;
+        self.active_forks = snapshot.active_forks;
+
+        self.updated_at = clock.unix_timestamp;
+        Ok(())
+    }
+
+    /// Check a proposed reconciliation against the circuit-breaker threshold.
+    ///
+    /// Returns the first total that would shrink by more than
+    /// `CIRCUIT_BREAKER_MAX_DECREASE_BPS` of its current value, or `None`
+    /// if the adjustment looks consistent. Increases are never suspicious.
+    pub fn find_suspicious_adjustment(
+        &self,
+        new_total_repos: Option<u64>,
+        new_total_modules: Option<u64>,
+        new_total_forks: Option<u64>,
+        new_total_observations: Option<u64>,
+        new_total_lines_of_code: Option<u64>,
+        new_total_files_processed: Option<u64>,
+    ) -> Option<SuspiciousAdjustment> {
+        let candidates = [
+            ("total_repos", self.total_repos, new_total_repos),
+            ("total_modules", self.total_modules, new_total_modules),
+            ("total_forks", self.total_forks, new_total_forks),
+            ("total_observations", self.total_observations, new_total_observations),
+            ("total_lines_of_code", self.total_lines_of_code, new_total_lines_of_code),
+            ("total_files_processed", self.total_files_processed, new_total_files_processed),
+        ];
+
+        for (counter, current, proposed) in candidates {
+            let proposed = match proposed {
+                Some(v) if v < current => v,
+                _ => continue,
+            };
+
+            let decrease = (current - proposed) as u128;
+            let allowed = current as u128 * CIRCUIT_BREAKER_MAX_DECREASE_BPS as u128;
+            if decrease * BPS_DENOMINATOR as u128 > allowed {
+                return Some(SuspiciousAdjustment {
+                    counter,
+                    current_value: current,
+                    proposed_value: proposed,
+                });
+            }
+        }
+
+        None
+    }
+
+    /// Find a counter that an observation adds to and that is within
+    /// `margin` of `u64::MAX`.
+    ///
+    /// Returns the first such counter with `proposed_value` set to
+    /// `u64::MAX`, or `None` if every counter has enough headroom. Used to
+    /// stop observations before they fail with `CounterOverflow`.
+    pub fn find_counter_near_overflow(&self, margin: u64) -> Option<SuspiciousAdjustment> {
+        let candidates = [
+            ("total_observations", self.total_observations),
+            ("total_lines_of_code", self.total_lines_of_code),
+            ("total_files_processed", self.total_files_processed),
+            ("pending_unindexed", self.pending_unindexed),
+            ("event_seq", self.event_seq),
+        ];
+
+        candidates
+            .into_iter()
+            .find(|(_, current)| u64::MAX - *current < margin)
+            .map(|(counter, current)| SuspiciousAdjustment {
+                counter,
+                current_value: current,
+                proposed_value: u64::MAX,
+            })
+    }
+
+    // -----------------------------------------------------------------------
+    // Recomputation From Repositories
+    // -----------------------------------------------------------------------
+
+    /// Start a recomputation of the repository-derived totals.
+    ///
+    /// Snapshots the current totals into `recompute_baseline`, then zeroes
+    /// `total_repos`, `active_repos`, `total_modules`, `total_observations`,
+    /// `total_lines_of_code`, and `total_files_processed` so they can act as
+    /// the running accumulator for `accumulate_repo`. Fork and module
+    /// activity counters are not derivable from `Repo` accounts and are left
+    /// untouched.
+    pub fn begin_recompute(&mut self, clock: &Clock) {
+        self.recompute_baseline = self.repo_totals();
+        self.total_repos = 0;
+        self.active_repos = 0;
+        self.total_modules = 0;
+        self.total_observations = 0;
+        self.total_lines_of_code = 0;
+        self.total_files_processed = 0;
+        self.recompute_in_progress = true;
+        self.recompute_started_at = clock.unix_timestamp;
+        self.updated_at = clock.unix_timestamp;
+    }
+
+    /// Add one repository's counters to the running recomputation.
+    ///
+    /// Fails with `MetricsRecomputeNotStarted` outside `begin_recompute` /
+    /// `finish_recompute`.
+    pub fn accumulate_repo(
+        &mut self,
+        is_active: bool,
+        module_count: u32,
+        observation_count: u64,
+        lines_of_code: u64,
+        files_processed: u64,
+    ) -> Result<()> {
+        if !self.recompute_in_progress {
+            return err!(Unit09Error::MetricsRecomputeNotStarted);
+        }
+
+        self.total_repos = self
+            .total_repos
+            .checked_add(1)
+            .ok_or(Unit09Error::CounterOverflow)?;
+        if is_active {
+            self.active_repos = self
+                .active_repos
+                .checked_add(1)
+                .ok_or(Unit09Error::CounterOverflow)?;
+        }
+        self.total_modules = self
+            .total_modules
+            .checked_add(module_count as u64)
+            .ok_or(Unit09Error::CounterOverflow)?;
+        self.total_observations = self
+            .total_observations
+            .checked_add(observation_count)
+            .ok_or(Unit09Error::CounterOverflow)?;
+        self.total_lines_of_code = self
+            .total_lines_of_code
+            .checked_add(lines_of_code)
+            .ok_or(Unit09Error::CounterOverflow)?;
+        self.total_files_processed = self
+            .total_files_processed
+            .checked_add(files_processed)
+            .ok_or(Unit09Error::CounterOverflow)?;
+        Ok(())
+    }
+
+    /// Mark the running recomputation as complete; the totals are
+    /// authoritative again.
+    pub fn finish_recompute(&mut self, clock: &Clock) -> Result<()> {
+        if !self.recompute_in_progress {
+            return err!(Unit09Error::MetricsRecomputeNotStarted);
+        }
+        self.recompute_in_progress = false;
+        self.updated_at = clock.unix_timestamp;
+        Ok(())
+    }
+
+    /// Current values of the repository-derived totals.
+    pub fn repo_totals(&self) -> RepoTotals {
+        RepoTotals {
+            total_repos: self.total_repos,
+            active_repos: self.active_repos,
+            total_modules: self.total_modules,
+            total_observations: self.total_observations,
+            total_lines_of_code: self.total_lines_of_code,
+            total_files_processed: self.total_files_processed,
+        }
+    }
+
+    /// Signed correction applied by the last recomputation: recomputed value
+    /// minus the value in `recompute_baseline`, per counter.
+    ///
+    /// Only meaningful once `finish_recompute` has run.
+    pub fn recompute_drift(&self) -> RepoTotalsDrift {
+        let now = self.repo_totals();
+        let base = self.recompute_baseline;
+        let delta = |new: u64, old: u64| new as i128 - old as i128;
+        RepoTotalsDrift {
+            total_repos: delta(now.total_repos, base.total_repos),
+            active_repos: delta(now.active_repos, base.active_repos),
+            total_modules: delta(now.total_modules, base.total_modules),
+            total_observations: delta(now.total_observations, base.total_observations),
+            total_lines_of_code: delta(now.total_lines_of_code, base.total_lines_of_code),
+            total_files_processed: delta(now.total_files_processed, base.total_files_processed),
+        }
+    }
+
     // -----------------------------------------------------------------------
     // Utility Helpers
     // -----------------------------------------------------------------------
@@ -280,6 +721,10 @@ impl Metrics {
             total_observations: self.total_observations,
             total_lines_of_code: self.total_lines_of_code,
             total_files_processed: self.total_files_processed,
+            active_repos: self.active_repos,
+            active_modules: self.active_modules,
+            active_forks: self.active_forks,
+            pending_unindexed: self.pending_unindexed,
             last_observation_at: self.last_observation_at,
         }
     }
@@ -297,5 +742,369 @@ pub struct MetricsSummary {
     pub total_observations: u64,
     pub total_lines_of_code: u64,
     pub total_files_processed: u64,
+    pub active_repos: u64,
+    pub active_modules: u64,
+    pub active_forks: u64,
+    pub pending_unindexed: u64,
     pub last_observation_at: i64,
 }
+
+/// The totals `recompute_metrics` re-derives from `Repo` accounts.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RepoTotals {
+    pub total_repos: u64,
+    pub active_repos: u64,
+    pub total_modules: u64,
+    pub total_observations: u64,
+    pub total_lines_of_code: u64,
+    pub total_files_processed: u64,
+}
+
+impl RepoTotals {
+    /// Serialized length of `RepoTotals`.
+    pub const LEN: usize = 6 * 8;
+}
+
+/// Per-counter difference between recomputed and previous `RepoTotals`.
+///
+/// Positive values mean the live counter was too low.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RepoTotalsDrift {
+    pub total_repos: i128,
+    pub active_repos: i128,
+    pub total_modules: i128,
+    pub total_observations: i128,
+    pub total_lines_of_code: i128,
+    pub total_files_processed: i128,
+}
+
+/// A reconciliation value rejected by the circuit breaker.
+///
+/// Returned by `Metrics::find_suspicious_adjustment`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SuspiciousAdjustment {
+    pub counter: &'static str,
+    pub current_value: u64,
+    pub proposed_value: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn metrics_with_repos(total_repos: u64) -> Metrics {
+        Metrics {
+            total_repos,
+            total_modules: 0,
+            total_forks: 0,
+            total_observations: 0,
+            total_lines_of_code: 0,
+            total_files_processed: 0,
+            active_repos: 0,
+            active_modules: 0,
+            active_forks: 0,
+            event_seq: 0,
+            pending_unindexed: 0,
+            observations_this_window: 0,
+            window_start: 0,
+            last_observation_at: 0,
+            created_at: 0,
+            updated_at: 0,
+            recompute_in_progress: false,
+            recompute_started_at: 0,
+            recompute_baseline: RepoTotals::default(),
+            snapshot_count: 0,
+            last_fork_created_at: 0,
+            schema_version: CURRENT_SCHEMA_VERSION,
+            bump: 0,
+            reserved: [0u8; 64],
+        }
+    }
+
+    #[test]
+    fn migrate_seeds_active_counts_from_totals() {
+        let mut metrics = metrics_with_repos(12);
+        metrics.schema_version = 1;
+        metrics.total_modules = 30;
+        metrics.total_forks = 4;
+        let clock = Clock {
+            unix_timestamp: 1_000,
+            ..Clock::default()
+        };
+
+        assert_eq!(metrics.migrate(&clock).unwrap(), 1);
+        assert_eq!(metrics.active_repos, 12);
+        assert_eq!(metrics.active_modules, 30);
+        assert_eq!(metrics.active_forks, 4);
+        assert_eq!(metrics.schema_version, CURRENT_SCHEMA_VERSION);
+
+        assert_eq!(
+            metrics.migrate(&clock).unwrap_err(),
+            Unit09Error::MigrationAlreadyApplied.into()
+        );
+    }
+
+    #[test]
+    fn large_decrease_trips_circuit_breaker() {
+        let metrics = metrics_with_repos(100);
+        let suspicious = metrics
+            .find_suspicious_adjustment(Some(10), None, None, None, None, None)
+            .expect("90% drop should be suspicious");
+
+        assert_eq!(suspicious.counter, "total_repos");
+        assert_eq!(suspicious.current_value, 100);
+        assert_eq!(suspicious.proposed_value, 10);
+    }
+
+    #[test]
+    fn small_decrease_and_increase_are_consistent() {
+        let metrics = metrics_with_repos(100);
+
+        // Exactly at the 10% threshold is still allowed.
+        assert!(metrics
+            .find_suspicious_adjustment(Some(90), None, None, None, None, None)
+            .is_none());
+        assert!(metrics
+            .find_suspicious_adjustment(Some(500), None, None, None, None, None)
+            .is_none());
+    }
+
+    #[test]
+    fn counters_near_max_are_reported_before_overflow() {
+        let mut metrics = metrics_with_repos(0);
+        assert!(metrics.find_counter_near_overflow(100).is_none());
+
+        metrics.total_lines_of_code = u64::MAX - 100;
+        assert!(metrics.find_counter_near_overflow(100).is_none());
+
+        metrics.total_lines_of_code = u64::MAX - 99;
+        let near = metrics
+            .find_counter_near_overflow(100)
+            .expect("99 left is inside a 100 margin");
+        assert_eq!(near.counter, "total_lines_of_code");
+        assert_eq!(near.current_value, u64::MAX - 99);
+    }
+
+    #[test]
+    fn observations_queue_for_indexing_and_acks_clamp_at_zero() {
+        let mut metrics = metrics_with_repos(0);
+        let clock = Clock::default();
+
+        metrics.record_observation(10, 1, &clock).unwrap();
+        metrics.record_observation(20, 2, &clock).unwrap();
+        assert_eq!(metrics.pending_unindexed, 2);
+
+        metrics.ack_indexed(1, &clock);
+        assert_eq!(metrics.pending_unindexed, 1);
+
+        metrics.ack_indexed(5, &clock);
+        assert_eq!(metrics.pending_unindexed, 0);
+    }
+
+    #[test]
+    fn entity_counters_return_to_baseline_after_create_and_close() {
+        const N: usize = 5;
+        let mut metrics = metrics_with_repos(3);
+        metrics.active_repos = 2;
+        let clock = Clock::default();
+        let baseline = (
+            metrics.total_repos,
+            metrics.total_modules,
+            metrics.total_forks,
+            metrics.active_repos,
+            metrics.active_modules,
+            metrics.active_forks,
+        );
+
+        for _ in 0..N {
+            metrics.increment_repos().unwrap();
+            metrics.increment_active_repos().unwrap();
+            metrics.increment_modules().unwrap();
+            metrics.increment_active_modules().unwrap();
+            metrics.increment_forks().unwrap();
+            metrics.increment_active_forks().unwrap();
+            metrics.record_observation(10, 1, &clock).unwrap();
+        }
+
+        for _ in 0..N {
+            metrics.decrement_forks().unwrap();
+            metrics.decrement_active_forks();
+            metrics.decrement_modules().unwrap();
+            metrics.decrement_active_modules();
+            metrics.decrement_repos().unwrap();
+            metrics.decrement_active_repos();
+        }
+
+        assert_eq!(
+            (
+                metrics.total_repos,
+                metrics.total_modules,
+                metrics.total_forks,
+                metrics.active_repos,
+                metrics.active_modules,
+                metrics.active_forks,
+            ),
+            baseline
+        );
+        // Observation totals are cumulative and survive entity removal.
+        assert_eq!(metrics.total_observations, N as u64);
+        assert!(metrics.decrement_forks().is_err());
+    }
+
+    fn clock_at(unix_timestamp: i64) -> Clock {
+        Clock {
+            unix_timestamp,
+            ..Clock::default()
+        }
+    }
+
+    #[test]
+    fn backwards_timestamps_are_rejected() {
+        let mut metrics = metrics_with_repos(10);
+        metrics
+            .adjust_aggregate(Some(12), None, None, None, None, None, &clock_at(2_000))
+            .unwrap();
+        assert_eq!(metrics.updated_at, 2_000);
+
+        // An overridden reconciliation clock behind the last update.
+        assert_eq!(
+            metrics
+                .adjust_aggregate(Some(20), None, None, None, None, None, &clock_at(1_999))
+                .unwrap_err(),
+            Unit09Error::InvalidTimeRange.into()
+        );
+        assert_eq!(metrics.total_repos, 12);
+
+        assert!(metrics.record_observation(10, 1, &clock_at(1_500)).is_err());
+        assert_eq!(metrics.total_observations, 0);
+
+        // Equal timestamps are not a regression.
+        metrics.record_observation(10, 1, &clock_at(2_000)).unwrap();
+        assert_eq!(metrics.total_observations, 1);
+    }
+
+    #[test]
+    fn restore_copies_snapshot_counters_only() {
+        let mut metrics = metrics_with_repos(10);
+        metrics.event_seq = 42;
+        metrics.snapshot_count = 3;
+        let snapshot = MetricsSnapshot {
+            index: 1,
+            total_repos: 4,
+            total_modules: 6,
+            total_forks: 2,
+            total_observations: 9,
+            total_lines_of_code: 900,
+            total_files_processed: 30,
+            active_repos: 3,
+            active_modules: 5,
+            active_forks: 1,
+            taken_at: 500,
+            taken_slot: 0,
+            bump: 0,
+            reserved: [0u8; 16],
+        };
+
+        metrics.restore_from_snapshot(&snapshot, &clock_at(1_000)).unwrap();
+        assert_eq!(metrics.total_repos, 4);
+        assert_eq!(metrics.total_lines_of_code, 900);
+        assert_eq!(metrics.active_modules, 5);
+        assert_eq!(metrics.updated_at, 1_000);
+        assert_eq!(metrics.event_seq, 42);
+        assert_eq!(metrics.snapshot_count, 3);
+
+        assert_eq!(
+            metrics
+                .restore_from_snapshot(&snapshot, &clock_at(999))
+                .unwrap_err(),
+            Unit09Error::InvalidTimeRange.into()
+        );
+    }
+
+    #[test]
+    fn observation_budget_is_exhausted_within_window() {
+        let mut metrics = metrics_with_repos(0);
+        let clock = clock_at(1_000);
+
+        metrics.consume_observation_budget(2, &clock).unwrap();
+        metrics.consume_observation_budget(2, &clock).unwrap();
+        assert_eq!(
+            metrics.consume_observation_budget(2, &clock).unwrap_err(),
+            Unit09Error::RateLimitReached.into()
+        );
+
+        // Zero disables throttling.
+        metrics.consume_observation_budget(0, &clock).unwrap();
+    }
+
+    #[test]
+    fn observation_budget_resets_after_window() {
+        let mut metrics = metrics_with_repos(0);
+
+        metrics
+            .consume_observation_budget(1, &clock_at(1_000))
+            .unwrap();
+        assert!(metrics
+            .consume_observation_budget(1, &clock_at(1_000 + OBSERVATION_WINDOW_SECS))
+            .is_err());
+
+        let later = clock_at(1_000 + OBSERVATION_WINDOW_SECS + 1);
+        metrics.consume_observation_budget(1, &later).unwrap();
+        assert_eq!(metrics.window_start, later.unix_timestamp);
+        assert_eq!(metrics.observations_this_window, 1);
+    }
+
+    #[test]
+    fn recompute_sums_repo_counters_across_batches() {
+        let mut metrics = metrics_with_repos(42);
+        metrics.total_observations = 999;
+        metrics.total_forks = 7;
+        let clock = clock_at(5_000);
+
+        assert_eq!(
+            metrics.accumulate_repo(true, 1, 1, 1, 1).unwrap_err(),
+            Unit09Error::MetricsRecomputeNotStarted.into()
+        );
+
+        metrics.begin_recompute(&clock);
+        metrics.accumulate_repo(true, 2, 10, 1_000, 20).unwrap();
+        metrics.accumulate_repo(false, 1, 5, 500, 10).unwrap();
+        // Second batch.
+        metrics.accumulate_repo(true, 3, 1, 100, 2).unwrap();
+        metrics.finish_recompute(&clock).unwrap();
+
+        assert_eq!(metrics.total_repos, 3);
+        assert_eq!(metrics.active_repos, 2);
+        assert_eq!(metrics.total_modules, 6);
+        assert_eq!(metrics.total_observations, 16);
+        assert_eq!(metrics.total_lines_of_code, 1_600);
+        assert_eq!(metrics.total_files_processed, 32);
+        assert_eq!(metrics.total_forks, 7);
+        assert!(!metrics.recompute_in_progress);
+        assert!(metrics.finish_recompute(&clock).is_err());
+    }
+
+    #[test]
+    fn recompute_reports_signed_drift_against_baseline() {
+        let mut metrics = metrics_with_repos(5);
+        metrics.active_repos = 5;
+        metrics.total_observations = 10;
+        metrics.total_lines_of_code = 100;
+        let clock = clock_at(5_000);
+
+        metrics.begin_recompute(&clock);
+        assert_eq!(metrics.recompute_baseline.total_repos, 5);
+
+        metrics.accumulate_repo(true, 0, 12, 40, 0).unwrap();
+        metrics.accumulate_repo(false, 0, 3, 20, 0).unwrap();
+        metrics.finish_recompute(&clock).unwrap();
+
+        let drift = metrics.recompute_drift();
+        assert_eq!(drift.total_repos, -3);
+        assert_eq!(drift.active_repos, -4);
+        assert_eq!(drift.total_modules, 0);
+        assert_eq!(drift.total_observations, 5);
+        assert_eq!(drift.total_lines_of_code, -40);
+        assert_eq!(drift.total_files_processed, 0);
+    }
+}
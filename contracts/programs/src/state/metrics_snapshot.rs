@@ -0,0 +1,181 @@
+//! ===========================================================================
+//! Unit09 – Metrics Snapshot State
+//! Path: contracts/unit09-program/programs/unit09_program/src/state/metrics_snapshot.rs
+//!
+//! Point-in-time copy of the global `Metrics` counters.
+//!
+//! Each `MetricsSnapshot` is a PDA derived from:
+//!     seed:  METRICS_SNAPSHOT_SEED
+//!     index: `Metrics::snapshot_count` at creation, as u32 little-endian
+//!
+//! Snapshots are taken by the admin with `take_metrics_snapshot` and never
+//! change afterwards. `compare_snapshots` returns the per-counter deltas
+//! between two of them (see `MetricsSnapshot::compare`), and
+//! `restore_metrics` rolls `Metrics` back to one of them.
+//!
+//! ===========================================================================
+
+use anchor_lang::prelude::*;
+
+use crate::state::Metrics;
+use crate::utils::size::assert_fits_len;
+
+/// Immutable copy of the `Metrics` counters at `taken_at`.
+#[account]
+pub struct MetricsSnapshot {
+    /// Position of this snapshot in creation order.
+    pub index: u32,
+
+    /// Copied from `Metrics::total_repos`.
+    pub total_repos: u64,
+
+    /// Copied from `Metrics::total_modules`.
+    pub total_modules: u64,
+
+    /// Copied from `Metrics::total_forks`.
+    pub total_forks: u64,
+
+    /// Copied from `Metrics::total_observations`.
+    pub total_observations: u64,
+
+    /// Copied from `Metrics::total_lines_of_code`.
+    pub total_lines_of_code: u64,
+
+    /// Copied from `Metrics::total_files_processed`.
+    pub total_files_processed: u64,
+
+    /// Copied from `Metrics::active_repos`.
+    pub active_repos: u64,
+
+    /// Copied from `Metrics::active_modules`.
+    pub active_modules: u64,
+
+    /// Copied from `Metrics::active_forks`.
+    pub active_forks: u64,
+
+    /// Unix timestamp at which the snapshot was taken.
+    pub taken_at: i64,
+
+    /// Slot at which the snapshot was taken.
+    pub taken_slot: u64,
+
+    /// Bump used for PDA derivation.
+    pub bump: u8,
+
+    /// Reserved bytes for future upgrades.
+    pub reserved: [u8; 16],
+}
+
+impl MetricsSnapshot {
+    /// Discriminator length used by Anchor.
+    pub const DISCRIMINATOR_LEN: usize = 8;
+
+    /// Total serialized length for the `MetricsSnapshot` account.
+    pub const LEN: usize = Self::DISCRIMINATOR_LEN
+        + 4  // index: u32
+        + 8 * 9 // counters: 9 x u64
+        + 8  // taken_at: i64
+        + 8  // taken_slot: u64
+        + 1  // bump: u8
+        + 16; // reserved: [u8; 16]
+
+    /// Copy the current counters of `metrics` into this snapshot.
+    pub fn capture(&mut self, index: u32, metrics: &Metrics, bump: u8, clock: &Clock) {
+        let summary = metrics.summary();
+        self.index = index;
+        self.total_repos = summary.total_repos;
+        self.total_modules = summary.total_modules;
+        self.total_forks = summary.total_forks;
+        self.total_observations = summary.total_observations;
+        self.total_lines_of_code = summary.total_lines_of_code;
+        self.total_files_processed = summary.total_files_processed;
+        self.active_repos = summary.active_repos;
+        self.active_modules = summary.active_modules;
+        self.active_forks = summary.active_forks;
+        self.taken_at = clock.unix_timestamp;
+        self.taken_slot = clock.slot;
+        self.bump = bump;
+        self.reserved = [0u8; 16];
+        assert_fits_len(self, Self::LEN);
+    }
+
+    /// Per-counter change from this snapshot to `later`.
+    ///
+    /// Deltas and the time span are `later` minus `self`, so they are
+    /// negative when `later` is actually the older snapshot.
+    pub fn compare(&self, later: &MetricsSnapshot) -> SnapshotComparison {
+        let delta = |from: u64, to: u64| to as i128 - from as i128;
+        SnapshotComparison {
+            from_index: self.index,
+            to_index: later.index,
+            time_span_secs: later.taken_at.saturating_sub(self.taken_at),
+            total_repos: delta(self.total_repos, later.total_repos),
+            total_modules: delta(self.total_modules, later.total_modules),
+            total_forks: delta(self.total_forks, later.total_forks),
+            total_observations: delta(self.total_observations, later.total_observations),
+            total_lines_of_code: delta(self.total_lines_of_code, later.total_lines_of_code),
+            total_files_processed: delta(self.total_files_processed, later.total_files_processed),
+            active_repos: delta(self.active_repos, later.active_repos),
+            active_modules: delta(self.active_modules, later.active_modules),
+            active_forks: delta(self.active_forks, later.active_forks),
+        }
+    }
+}
+
+/// Deltas between two snapshots, returned by `compare_snapshots`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SnapshotComparison {
+    pub from_index: u32,
+    pub to_index: u32,
+    pub time_span_secs: i64,
+    pub total_repos: i128,
+    pub total_modules: i128,
+    pub total_forks: i128,
+    pub total_observations: i128,
+    pub total_lines_of_code: i128,
+    pub total_files_processed: i128,
+    pub active_repos: i128,
+    pub active_modules: i128,
+    pub active_forks: i128,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot_at(index: u32, taken_at: i64, total_repos: u64, active_repos: u64) -> MetricsSnapshot {
+        MetricsSnapshot {
+            index,
+            total_repos,
+            total_modules: 0,
+            total_forks: 0,
+            total_observations: 0,
+            total_lines_of_code: 0,
+            total_files_processed: 0,
+            active_repos,
+            active_modules: 0,
+            active_forks: 0,
+            taken_at,
+            taken_slot: 0,
+            bump: 0,
+            reserved: [0u8; 16],
+        }
+    }
+
+    #[test]
+    fn compare_reports_signed_deltas_and_span() {
+        let earlier = snapshot_at(0, 1_000, 3, 3);
+        let later = snapshot_at(1, 1_600, 5, 2);
+
+        let forward = earlier.compare(&later);
+        assert_eq!((forward.from_index, forward.to_index), (0, 1));
+        assert_eq!(forward.time_span_secs, 600);
+        assert_eq!(forward.total_repos, 2);
+        assert_eq!(forward.active_repos, -1);
+        assert_eq!(forward.total_observations, 0);
+
+        let backward = later.compare(&earlier);
+        assert_eq!(backward.time_span_secs, -600);
+        assert_eq!(backward.total_repos, -2);
+    }
+}
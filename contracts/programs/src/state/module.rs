@@ -26,6 +26,12 @@ use anchor_lang::prelude::*;
 
 use crate::constants::*;
 use crate::errors::Unit09Error;
+use crate::utils::size::assert_fits_len;
+use crate::utils::tags::{
+    normalized_tag_list, tag_bit_index, tag_bit_name, tag_bits_for, validate_tag_bits,
+};
+use crate::utils::time::assert_not_far_future;
+use crate::utils::update::apply_if_some;
 
 /// Module account tracked by Unit09.
 ///
@@ -126,10 +132,40 @@ pub struct Module {
     /// Bump used for PDA derivation.
     pub bump: u8,
 
+    /// Optional link to the module's release notes; empty if none.
+    ///
+    /// Example: "https://unit09.org/changelog/router"
+    pub changelog_uri: String,
+
+    /// Bitmask of `TAG_VOCABULARY` entries carried by this module.
+    ///
+    /// Derived from `tags` whenever it is set and optionally extended with
+    /// explicit bits (see `set_tag_bits`). Entry `i` is bit `1 << i`.
+    pub tag_bits: u64,
+
+    /// Number of observations attributed to this module via
+    /// `record_observation`.
+    pub observation_count: u64,
+
+    /// Unix timestamp of the most recent attributed observation
+    /// (0 if never observed).
+    pub last_observed_at: i64,
+
+    /// Number of `ModuleVersion` snapshots created for this module.
+    ///
+    /// Snapshots are numbered `0..version_count` in creation order (see
+    /// `ModuleVersion::index`). On a module migrated from schema 1, index 0
+    /// may be its legacy initial snapshot (see `Module::migrate`).
+    pub version_count: u32,
+
+    /// Unix timestamp until which the module cannot be edited with
+    /// `update_module` (0 if the module was never made immutable).
+    pub immutable_until: i64,
+
     /// Reserved space for future upgrades.
     ///
     /// This allows adding new fields later without breaking the account size.
-    pub reserved: [u8; 54],
+    pub reserved: [u8; 64],
 }
 
 impl Module {
@@ -142,6 +178,9 @@ impl Module {
     /// Maximum length in bytes (UTF-8) for the `metadata_uri` field.
     pub const MAX_METADATA_URI_LEN: usize = MAX_METADATA_URI_LEN;
 
+    /// Maximum length in bytes (UTF-8) for the `changelog_uri` field.
+    pub const MAX_CHANGELOG_URI_LEN: usize = MAX_METADATA_URI_LEN;
+
     /// Maximum length in bytes (UTF-8) for the `category` field.
     pub const MAX_CATEGORY_LEN: usize = MAX_MODULE_CATEGORY_LEN;
 
@@ -171,7 +210,13 @@ impl Module {
         + 8 // updated_at: i64
         + 1 // schema_version: u8
         + 1 // bump: u8
-        + 54; // reserved: [u8; 54]
+        + 4 + Self::MAX_CHANGELOG_URI_LEN // changelog_uri: String
+        + 8 // tag_bits: u64
+        + 8 // observation_count: u64
+        + 8 // last_observed_at: i64
+        + 4 // version_count: u32
+        + 8 // immutable_until: i64
+        + 64; // reserved: [u8; 64]
 
     // -----------------------------------------------------------------------
     // Initialization
@@ -206,7 +251,9 @@ impl Module {
         self.authority = authority;
         self.name = name;
         self.metadata_uri = metadata_uri;
+        self.changelog_uri = String::new();
         self.category = category;
+        self.tag_bits = tag_bits_for(&tags);
         self.tags = tags;
         self.is_active = true;
         self.is_deprecated = false;
@@ -215,15 +262,49 @@ impl Module {
         self.patch_version = patch;
         self.usage_count = 0;
         self.last_used_at = 0;
+        self.observation_count = 0;
+        self.last_observed_at = 0;
+        self.version_count = 0;
+        self.immutable_until = 0;
         self.created_at = clock.unix_timestamp;
         self.updated_at = clock.unix_timestamp;
         self.schema_version = CURRENT_SCHEMA_VERSION;
         self.bump = bump;
-        self.reserved = [0u8; 54];
+        self.reserved = [0u8; 64];
+        assert_fits_len(self, Self::LEN);
 
         Ok(())
     }
 
+    /// Upgrade this module from an older `schema_version` to
+    /// `CURRENT_SCHEMA_VERSION` and return the version it was upgraded from.
+    ///
+    /// Expects an account already grown to `Module::LEN`, so every field
+    /// added since the stored version decodes from zeroed bytes.
+    /// `legacy_snapshot` is set when the module has the initial
+    /// `ModuleVersion` created by a schema 1 `register_module`; it then
+    /// counts as index 0 (`version_count` becomes 1) but stays at its
+    /// semver-seeded address (`utils::seeds::legacy_module_version_pda`).
+    /// Fails with `MigrationAlreadyApplied` if the module is already current.
+    pub fn migrate(&mut self, legacy_snapshot: bool, clock: &Clock) -> Result<u8> {
+        let from_version = self.schema_version;
+        if from_version >= CURRENT_SCHEMA_VERSION {
+            return err!(Unit09Error::MigrationAlreadyApplied);
+        }
+
+        // 1 -> 2: `tag_bits` is derived from `tags`; snapshots are counted
+        // by `version_count`, starting with the legacy initial snapshot.
+        if from_version < 2 {
+            self.tag_bits = tag_bits_for(&self.tags);
+            self.version_count = u32::from(legacy_snapshot);
+        }
+
+        self.schema_version = CURRENT_SCHEMA_VERSION;
+        self.updated_at = clock.unix_timestamp;
+
+        Ok(from_version)
+    }
+
     // -----------------------------------------------------------------------
     // Metadata / Version Updates
     // -----------------------------------------------------------------------
@@ -243,33 +324,25 @@ impl Module {
         maybe_version: Option<(u16, u16, u16)>,
         clock: &Clock,
     ) -> Result<()> {
-        if let Some(name) = maybe_name {
-            Self::validate_name(&name)?;
-            self.name = name;
-        }
+        apply_if_some!(self.name, maybe_name, Self::validate_name);
 
-        if let Some(metadata_uri) = maybe_metadata_uri {
-            Self::validate_metadata_uri(&metadata_uri)?;
-            self.metadata_uri = metadata_uri;
-        }
+        apply_if_some!(
+            self.metadata_uri,
+            maybe_metadata_uri,
+            Self::validate_metadata_uri
+        );
 
-        if let Some(category) = maybe_category {
-            Self::validate_category(&category)?;
-            self.category = category;
-        }
+        apply_if_some!(self.category, maybe_category, Self::validate_category);
 
-        if let Some(tags) = maybe_tags {
-            Self::validate_tags(&tags)?;
-            self.tags = tags;
+        let tags_changed = maybe_tags.is_some();
+        apply_if_some!(self.tags, maybe_tags, Self::validate_tags);
+        if tags_changed {
+            self.tag_bits = tag_bits_for(&self.tags);
         }
 
-        if let Some(is_active) = maybe_is_active {
-            self.is_active = is_active;
-        }
+        apply_if_some!(self.is_active, maybe_is_active);
 
-        if let Some(is_deprecated) = maybe_is_deprecated {
-            self.is_deprecated = is_deprecated;
-        }
+        apply_if_some!(self.is_deprecated, maybe_is_deprecated);
 
         if let Some(version) = maybe_version {
             Self::validate_version(version)?;
@@ -283,6 +356,62 @@ impl Module {
         Ok(())
     }
 
+    /// Set or clear (`""`) the module changelog URI.
+    ///
+    /// A non-empty URI is checked like `metadata_uri`: bounded length and a
+    /// known scheme (`StringTooLong` / `MetadataInvalid`).
+    pub fn set_changelog_uri(&mut self, uri: String, clock: &Clock) -> Result<()> {
+        Self::validate_changelog_uri(&uri)?;
+        self.changelog_uri = uri;
+        self.updated_at = clock.unix_timestamp;
+        Ok(())
+    }
+
+    /// Make the module immutable until `until` (0 for no window).
+    ///
+    /// Fails with `TimestampInFuture` if `until` is more than
+    /// `MAX_MODULE_IMMUTABILITY_SECS` ahead of `clock`.
+    pub fn set_immutable_until(&mut self, until: i64, clock: &Clock) -> Result<()> {
+        assert_not_far_future(clock, until, MAX_MODULE_IMMUTABILITY_SECS)?;
+        self.immutable_until = until;
+        Ok(())
+    }
+
+    // -----------------------------------------------------------------------
+    // Tag Bitmask
+    // -----------------------------------------------------------------------
+
+    /// Set the `tag_bits` bit for vocabulary entry `index`.
+    ///
+    /// Fails with `ValueOutOfRange` if `index` is not in `TAG_VOCABULARY`.
+    pub fn set_tag_bit(&mut self, index: u8) -> Result<()> {
+        if tag_bit_name(index).is_none() {
+            return err!(Unit09Error::ValueOutOfRange);
+        }
+        self.tag_bits |= 1u64 << index;
+        Ok(())
+    }
+
+    /// Replace `tag_bits` with `bits` plus the bits implied by `tags`.
+    ///
+    /// Vocabulary tags present in the string always keep their bit set.
+    pub fn set_tag_bits(&mut self, bits: u64) -> Result<()> {
+        validate_tag_bits(bits)?;
+        self.tag_bits = bits | tag_bits_for(&self.tags);
+        Ok(())
+    }
+
+    /// Whether this module carries `tag`.
+    ///
+    /// Vocabulary tags are answered from `tag_bits`; other tags fall back to
+    /// the normalized `tags` string.
+    pub fn has_tag(&self, tag: &str) -> bool {
+        match tag_bit_index(tag) {
+            Some(index) => self.tag_bits & (1u64 << index) != 0,
+            None => normalized_tag_list(&self.tags).contains(&tag.trim().to_ascii_lowercase()),
+        }
+    }
+
     // -----------------------------------------------------------------------
     // Authority and Activation Guards
     // -----------------------------------------------------------------------
@@ -303,6 +432,14 @@ impl Module {
         Ok(())
     }
 
+    /// Ensure that the immutability window (`immutable_until`) has passed.
+    pub fn assert_mutable(&self, clock: &Clock) -> Result<()> {
+        if clock.unix_timestamp < self.immutable_until {
+            return err!(Unit09Error::ModuleImmutable);
+        }
+        Ok(())
+    }
+
     /// Ensure that the module is not deprecated.
     pub fn assert_not_deprecated(&self) -> Result<()> {
         if self.is_deprecated {
@@ -329,6 +466,34 @@ impl Module {
         Ok(())
     }
 
+    /// Attribute an observation of `repo` to this module.
+    ///
+    /// Fails with `ModuleRepoMismatch` if the module does not belong to the
+    /// observed repository.
+    pub fn record_observation(&mut self, repo: &Pubkey, clock: &Clock) -> Result<()> {
+        if self.repo != *repo {
+            return err!(Unit09Error::ModuleRepoMismatch);
+        }
+
+        self.observation_count = self
+            .observation_count
+            .checked_add(1)
+            .ok_or(Unit09Error::CounterOverflow)?;
+        self.last_observed_at = clock.unix_timestamp;
+        Ok(())
+    }
+
+    /// Reserve the index of a new `ModuleVersion` snapshot and return it.
+    ///
+    /// Called once per snapshot by the instructions that create them.
+    pub fn next_version_index(&mut self) -> Result<u32> {
+        let index = self.version_count;
+        self.version_count = index
+            .checked_add(1)
+            .ok_or(Unit09Error::CounterOverflow)?;
+        Ok(index)
+    }
+
     // -----------------------------------------------------------------------
     // Validation Helpers
     // -----------------------------------------------------------------------
@@ -368,6 +533,14 @@ impl Module {
         Ok(())
     }
 
+    /// Validate the changelog URI; empty means no changelog.
+    fn validate_changelog_uri(uri: &str) -> Result<()> {
+        if uri.is_empty() {
+            return Ok(());
+        }
+        Self::validate_metadata_uri(uri)
+    }
+
     /// Validate the module category.
     fn validate_category(category: &str) -> Result<()> {
         if category.is_empty() {
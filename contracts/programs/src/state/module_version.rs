@@ -26,6 +26,7 @@ use anchor_lang::prelude::*;
 
 use crate::constants::*;
 use crate::errors::Unit09Error;
+use crate::utils::size::assert_fits_len;
 
 /// Immutable version snapshot for a `Module`.
 ///
@@ -97,8 +98,12 @@ pub struct ModuleVersion {
     /// Bump used for PDA derivation.
     pub bump: u8,
 
+    /// Position of this snapshot among the module's versions, in creation
+    /// order (`0..Module::version_count`). Part of the PDA seeds.
+    pub index: u32,
+
     /// Reserved space for future upgrades.
-    pub reserved: [u8; 63],
+    pub reserved: [u8; 59],
 }
 
 impl ModuleVersion {
@@ -133,7 +138,8 @@ impl ModuleVersion {
         + 32 // created_by: Pubkey
         + 1  // schema_version: u8
         + 1  // bump: u8
-        + 63; // reserved: [u8; 63]
+        + 4  // index: u32
+        + 59; // reserved: [u8; 59]
 
     // -----------------------------------------------------------------------
     // Initialization
@@ -148,6 +154,7 @@ impl ModuleVersion {
         module: Pubkey,
         created_by: Pubkey,
         version: (u16, u16, u16),
+        index: u32,
         metadata_uri: String,
         changelog_uri: String,
         label: String,
@@ -174,9 +181,11 @@ impl ModuleVersion {
         self.created_at = clock.unix_timestamp;
         self.deprecated_at = 0;
         self.created_by = created_by;
+        self.index = index;
         self.schema_version = CURRENT_SCHEMA_VERSION;
         self.bump = bump;
-        self.reserved = [0u8; 63];
+        self.reserved = [0u8; 59];
+        assert_fits_len(self, Self::LEN);
 
         Ok(())
     }
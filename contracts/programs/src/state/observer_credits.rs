@@ -0,0 +1,196 @@
+//! ===========================================================================
+//! Unit09 – Observer Credits State
+//! Path: contracts/unit09-program/programs/unit09_program/src/state/observer_credits.rs
+//!
+//! Prepaid credit balance of one observer, used for metered access to
+//! `record_observation` and `record_observation_multi`.
+//!
+//! Each `ObserverCredits` is a PDA derived from:
+//!     seed: OBSERVER_CREDITS_SEED
+//!     key:  observer pubkey
+//!
+//! Credits are bought with `top_up_credits` (1 credit per lamport paid to
+//! `Config::fee_recipient`) and each observation run costs
+//! `Config::credits_per_observation`. A cost of 0 disables metering and the
+//! account is then not needed at all.
+//!
+//! ===========================================================================
+
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::Unit09Error;
+use crate::utils::size::assert_fits_len;
+
+/// Prepaid credits held by an observer.
+#[account]
+pub struct ObserverCredits {
+    /// Observer whose observations are paid from this balance.
+    pub observer: Pubkey,
+
+    /// Credits currently available.
+    pub balance: u64,
+
+    /// Lifetime credits added by `top_up_credits`.
+    pub total_topped_up: u64,
+
+    /// Lifetime credits spent on observations.
+    pub total_spent: u64,
+
+    /// Unix timestamp when this account was created.
+    pub created_at: i64,
+
+    /// Unix timestamp when this account was last updated.
+    pub updated_at: i64,
+
+    /// Schema version for this account layout.
+    pub schema_version: u8,
+
+    /// Bump used for PDA derivation.
+    pub bump: u8,
+
+    /// Reserved bytes for future upgrades.
+    pub reserved: [u8; 32],
+}
+
+impl ObserverCredits {
+    /// Discriminator length used by Anchor.
+    pub const DISCRIMINATOR_LEN: usize = 8;
+
+    /// Total serialized length for the `ObserverCredits` account.
+    pub const LEN: usize = Self::DISCRIMINATOR_LEN
+        + 32 // observer: Pubkey
+        + 8  // balance: u64
+        + 8  // total_topped_up: u64
+        + 8  // total_spent: u64
+        + 8  // created_at: i64
+        + 8  // updated_at: i64
+        + 1  // schema_version: u8
+        + 1  // bump: u8
+        + 32; // reserved: [u8; 32]
+
+    // -----------------------------------------------------------------------
+    // Initialization
+    // -----------------------------------------------------------------------
+
+    /// Initialize an empty balance for `observer`.
+    pub fn init(&mut self, observer: Pubkey, bump: u8, clock: &Clock) {
+        self.observer = observer;
+        self.balance = 0;
+        self.total_topped_up = 0;
+        self.total_spent = 0;
+        self.created_at = clock.unix_timestamp;
+        self.updated_at = clock.unix_timestamp;
+        self.schema_version = CURRENT_SCHEMA_VERSION;
+        self.bump = bump;
+        self.reserved = [0u8; 32];
+        assert_fits_len(self, Self::LEN);
+    }
+
+    // -----------------------------------------------------------------------
+    // Balance Updates
+    // -----------------------------------------------------------------------
+
+    /// Add `amount` credits. Fails with `ValueOutOfRange` for zero and
+    /// `CounterOverflow` if a counter would overflow.
+    pub fn top_up(&mut self, amount: u64, clock: &Clock) -> Result<()> {
+        if amount == 0 {
+            return err!(Unit09Error::ValueOutOfRange);
+        }
+        self.balance = self
+            .balance
+            .checked_add(amount)
+            .ok_or(Unit09Error::CounterOverflow)?;
+        self.total_topped_up = self
+            .total_topped_up
+            .checked_add(amount)
+            .ok_or(Unit09Error::CounterOverflow)?;
+        self.updated_at = clock.unix_timestamp;
+        Ok(())
+    }
+
+    /// Spend `cost` credits. Fails with `InsufficientCredits` if the balance
+    /// is lower than `cost`, leaving it untouched.
+    pub fn deduct(&mut self, cost: u64, clock: &Clock) -> Result<()> {
+        self.balance = self
+            .balance
+            .checked_sub(cost)
+            .ok_or(Unit09Error::InsufficientCredits)?;
+        self.total_spent = self.total_spent.saturating_add(cost);
+        self.updated_at = clock.unix_timestamp;
+        Ok(())
+    }
+
+    /// Charge one observation run at `cost` credits
+    /// (`Config::credits_per_observation`).
+    ///
+    /// A cost of 0 means metering is disabled and always succeeds. Otherwise
+    /// a missing account counts as an empty balance (`InsufficientCredits`).
+    pub fn charge(credits: Option<&mut ObserverCredits>, cost: u64, clock: &Clock) -> Result<()> {
+        if cost == 0 {
+            return Ok(());
+        }
+        let credits = credits.ok_or(Unit09Error::InsufficientCredits)?;
+        credits.deduct(cost, clock)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn credits_with(balance: u64) -> ObserverCredits {
+        ObserverCredits {
+            observer: Pubkey::new_unique(),
+            balance,
+            total_topped_up: balance,
+            total_spent: 0,
+            created_at: 0,
+            updated_at: 0,
+            schema_version: CURRENT_SCHEMA_VERSION,
+            bump: 0,
+            reserved: [0u8; 32],
+        }
+    }
+
+    #[test]
+    fn charge_deducts_until_exhausted() {
+        let clock = Clock::default();
+        let mut credits = credits_with(10);
+
+        ObserverCredits::charge(Some(&mut credits), 4, &clock).unwrap();
+        ObserverCredits::charge(Some(&mut credits), 4, &clock).unwrap();
+        assert_eq!(credits.balance, 2);
+        assert_eq!(credits.total_spent, 8);
+
+        assert_eq!(
+            ObserverCredits::charge(Some(&mut credits), 4, &clock).unwrap_err(),
+            Unit09Error::InsufficientCredits.into()
+        );
+        assert_eq!(credits.balance, 2);
+    }
+
+    #[test]
+    fn top_up_restores_balance() {
+        let clock = Clock::default();
+        let mut credits = credits_with(0);
+
+        assert!(ObserverCredits::charge(Some(&mut credits), 1, &clock).is_err());
+        assert!(credits.top_up(0, &clock).is_err());
+
+        credits.top_up(5, &clock).unwrap();
+        assert_eq!(credits.total_topped_up, 5);
+        ObserverCredits::charge(Some(&mut credits), 1, &clock).unwrap();
+        assert_eq!(credits.balance, 4);
+    }
+
+    #[test]
+    fn zero_cost_disables_metering() {
+        let clock = Clock::default();
+        assert!(ObserverCredits::charge(None, 0, &clock).is_ok());
+        assert_eq!(
+            ObserverCredits::charge(None, 1, &clock).unwrap_err(),
+            Unit09Error::InsufficientCredits.into()
+        );
+    }
+}
@@ -0,0 +1,169 @@
+//! ===========================================================================
+//! Unit09 – Owner Fork Index State
+//! Path: contracts/unit09-program/programs/unit09_program/src/state/owner_fork_index.rs
+//!
+//! Per-owner index of recently used fork labels, letting `create_fork`
+//! reject a label the same owner already used (`ForkLabelTaken`).
+//!
+//! Each `OwnerForkIndex` is a PDA derived from:
+//!     seed: OWNER_FORK_INDEX_SEED
+//!     key:  owner pubkey
+//!
+//! The index is opt-in: it is created with `init_owner_fork_index` and only
+//! consulted when passed to `create_fork`. It keeps the hashes of the last
+//! `RECENT_FORK_LABEL_SLOTS` labels, so uniqueness is enforced against
+//! recent forks only; older labels rotate out.
+//!
+//! ===========================================================================
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+
+use crate::constants::*;
+use crate::errors::Unit09Error;
+use crate::utils::size::assert_fits_len;
+
+/// Recent fork labels of one owner.
+#[account]
+pub struct OwnerForkIndex {
+    /// Owner whose forks are indexed.
+    pub owner: Pubkey,
+
+    /// SHA-256 hashes of the most recent labels; all-zero slots are empty.
+    pub recent_labels: [[u8; 32]; RECENT_FORK_LABEL_SLOTS],
+
+    /// Next slot of `recent_labels` to overwrite.
+    pub label_cursor: u8,
+
+    /// Total labels recorded over the lifetime of the index.
+    pub label_count: u64,
+
+    /// Unix timestamp when this account was created.
+    pub created_at: i64,
+
+    /// Unix timestamp when this account was last updated.
+    pub updated_at: i64,
+
+    /// Schema version for this account layout.
+    pub schema_version: u8,
+
+    /// Bump used for PDA derivation.
+    pub bump: u8,
+
+    /// Reserved bytes for future upgrades.
+    pub reserved: [u8; 32],
+}
+
+impl OwnerForkIndex {
+    /// Discriminator length used by Anchor.
+    pub const DISCRIMINATOR_LEN: usize = 8;
+
+    /// Total serialized length for the `OwnerForkIndex` account.
+    pub const LEN: usize = Self::DISCRIMINATOR_LEN
+        + 32 // owner: Pubkey
+        + 32 * RECENT_FORK_LABEL_SLOTS // recent_labels: [[u8; 32]; N]
+        + 1  // label_cursor: u8
+        + 8  // label_count: u64
+        + 8  // created_at: i64
+        + 8  // updated_at: i64
+        + 1  // schema_version: u8
+        + 1  // bump: u8
+        + 32; // reserved: [u8; 32]
+
+    // -----------------------------------------------------------------------
+    // Initialization
+    // -----------------------------------------------------------------------
+
+    /// Initialize an empty index for `owner`.
+    pub fn init(&mut self, owner: Pubkey, bump: u8, clock: &Clock) {
+        self.owner = owner;
+        self.recent_labels = [[0u8; 32]; RECENT_FORK_LABEL_SLOTS];
+        self.label_cursor = 0;
+        self.label_count = 0;
+        self.created_at = clock.unix_timestamp;
+        self.updated_at = clock.unix_timestamp;
+        self.schema_version = CURRENT_SCHEMA_VERSION;
+        self.bump = bump;
+        self.reserved = [0u8; 32];
+        assert_fits_len(self, Self::LEN);
+    }
+
+    // -----------------------------------------------------------------------
+    // Labels
+    // -----------------------------------------------------------------------
+
+    /// Hash stored in `recent_labels` for `label`.
+    pub fn label_hash(label: &str) -> [u8; 32] {
+        hash(label.as_bytes()).to_bytes()
+    }
+
+    /// Whether `label` is one of the recent labels of this owner.
+    pub fn contains_label(&self, label: &str) -> bool {
+        let label_hash = Self::label_hash(label);
+        self.recent_labels.contains(&label_hash)
+    }
+
+    /// Record `label`, overwriting the oldest slot.
+    ///
+    /// Fails with `ForkLabelTaken` when the label is already recorded.
+    pub fn remember_label(&mut self, label: &str, clock: &Clock) -> Result<()> {
+        if self.contains_label(label) {
+            return err!(Unit09Error::ForkLabelTaken);
+        }
+
+        let slot = self.label_cursor as usize % RECENT_FORK_LABEL_SLOTS;
+        self.recent_labels[slot] = Self::label_hash(label);
+        self.label_cursor = ((slot + 1) % RECENT_FORK_LABEL_SLOTS) as u8;
+        self.label_count = self.label_count.saturating_add(1);
+        self.updated_at = clock.unix_timestamp;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn empty_index() -> OwnerForkIndex {
+        OwnerForkIndex {
+            owner: Pubkey::new_unique(),
+            recent_labels: [[0u8; 32]; RECENT_FORK_LABEL_SLOTS],
+            label_cursor: 0,
+            label_count: 0,
+            created_at: 0,
+            updated_at: 0,
+            schema_version: CURRENT_SCHEMA_VERSION,
+            bump: 0,
+            reserved: [0u8; 32],
+        }
+    }
+
+    #[test]
+    fn duplicate_label_is_rejected() {
+        let clock = Clock::default();
+        let mut index = empty_index();
+
+        index.remember_label("alpha", &clock).unwrap();
+        index.remember_label("beta", &clock).unwrap();
+        assert_eq!(
+            index.remember_label("alpha", &clock).unwrap_err(),
+            Unit09Error::ForkLabelTaken.into()
+        );
+        assert_eq!(index.label_count, 2);
+    }
+
+    #[test]
+    fn oldest_labels_rotate_out() {
+        let clock = Clock::default();
+        let mut index = empty_index();
+
+        for i in 0..RECENT_FORK_LABEL_SLOTS {
+            index.remember_label(&format!("label-{i}"), &clock).unwrap();
+        }
+        assert!(index.contains_label("label-0"));
+
+        index.remember_label("label-new", &clock).unwrap();
+        assert!(!index.contains_label("label-0"));
+        index.remember_label("label-0", &clock).unwrap();
+    }
+}
@@ -21,9 +21,126 @@
 //! ===========================================================================
 
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
 
 use crate::constants::*;
 use crate::errors::Unit09Error;
+use crate::utils::size::assert_fits_len;
+use crate::utils::tags::{
+    normalized_tag_list, rename_tag, tag_bit_index, tag_bit_name, tag_bits_for,
+    validate_tag_bits,
+};
+use crate::utils::time::{is_older_than, min_non_zero};
+use crate::utils::update::apply_if_some;
+
+/// Hosting service of a repository URL, stored as `Repo::host`.
+///
+/// Derived from the URL by `RepoHost::classify`; used for analytics only.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RepoHost {
+    /// Not a recognizable git host (IPFS, Arweave, plain websites, ...).
+    Other = 0,
+    /// github.com
+    GitHub = 1,
+    /// gitlab.com
+    GitLab = 2,
+    /// bitbucket.org
+    Bitbucket = 3,
+    /// A self-hosted git service, such as `git.example.org` or
+    /// `gitlab.example.org`.
+    SelfHosted = 4,
+}
+
+impl RepoHost {
+    /// Convert from raw `u8` to `RepoHost`.
+    pub fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(RepoHost::Other),
+            1 => Some(RepoHost::GitHub),
+            2 => Some(RepoHost::GitLab),
+            3 => Some(RepoHost::Bitbucket),
+            4 => Some(RepoHost::SelfHosted),
+            _ => None,
+        }
+    }
+
+    /// Convert `RepoHost` to raw `u8`.
+    pub fn as_u8(self) -> u8 {
+        self as u8
+    }
+
+    /// Host of `url`: the part between `scheme://` and the first `/`, `?`
+    /// or `#`, without user info, port, trailing dots or a leading `www.`,
+    /// lowercased.
+    ///
+    /// A bare host such as `"Example.org"` yields itself, lowercased, and
+    /// `"https://Example.org./x?y#z"` yields `"example.org"`.
+    pub fn normalized_host(url: &str) -> String {
+        let rest = url.split_once("://").map_or(url, |(_, rest)| rest);
+        let authority = rest
+            .split(|c| matches!(c, '/' | '?' | '#'))
+            .next()
+            .unwrap_or_default();
+        let host_port = authority.rsplit('@').next().unwrap_or_default();
+        let host = host_port
+            .split(':')
+            .next()
+            .unwrap_or_default()
+            .trim_end_matches('.')
+            .to_ascii_lowercase();
+        match host.strip_prefix("www.") {
+            Some(stripped) => stripped.to_string(),
+            None => host,
+        }
+    }
+
+    /// Classify `url` by its `normalized_host`.
+    ///
+    /// Public hosts match exactly (or as a `www.` subdomain); any other host
+    /// containing `git` is `SelfHosted`.
+    pub fn classify(url: &str) -> Self {
+        let host = Self::normalized_host(url);
+
+        match host.as_str() {
+            "github.com" => RepoHost::GitHub,
+            "gitlab.com" => RepoHost::GitLab,
+            "bitbucket.org" => RepoHost::Bitbucket,
+            _ if host.contains("git") => RepoHost::SelfHosted,
+            _ => RepoHost::Other,
+        }
+    }
+}
+
+/// Kind of an observation run, passed as `RecordObservationArgs::kind`.
+///
+/// Encoded as a `u8` in instruction arguments, like `LifecyclePhase`.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ObservationKind {
+    /// Regular scan; aggregates LOC, files and score on the repository and
+    /// into the global `Metrics`.
+    Full = 0,
+    /// Revision/note refresh only; no repository aggregates and no global
+    /// metrics are touched (see `Repo::record_metadata_observation`).
+    MetadataOnly = 1,
+}
+
+impl ObservationKind {
+    /// Convert from raw `u8` to `ObservationKind`.
+    pub fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(ObservationKind::Full),
+            1 => Some(ObservationKind::MetadataOnly),
+            _ => None,
+        }
+    }
+
+    /// Convert `ObservationKind` to raw `u8`.
+    pub fn as_u8(self) -> u8 {
+        self as u8
+    }
+}
 
 /// Repository account tracked by Unit09.
 ///
@@ -91,8 +208,118 @@ pub struct Repo {
     /// Bump used for PDA derivation.
     pub bump: u8,
 
+    /// `RepoHost` of `url` as a raw `u8`, kept in sync whenever `url`
+    /// changes.
+    pub host: u8,
+
+    /// Bitmask of `TAG_VOCABULARY` entries carried by this repository.
+    ///
+    /// Derived from `tags` whenever it is set and optionally extended with
+    /// explicit bits (see `set_tag_bits`). Entry `i` is bit `1 << i`.
+    pub tag_bits: u64,
+
+    /// Optional URI pointing to a richer off-chain manifest for this
+    /// repository (README, JSON descriptor, etc.). Empty when unset.
+    ///
+    /// Example: "https://unit09.org/meta/repo/unit09-solana-core.json"
+    pub metadata_uri: String,
+
+    /// Observers permitted to record observations for this repository.
+    ///
+    /// An empty list means any signer may observe. Bounded by
+    /// `MAX_ALLOWED_OBSERVERS`.
+    pub allowed_observers: Vec<Pubkey>,
+
+    /// Keys allowed to change this repository's metadata alongside the
+    /// authority (see `assert_maintainer_or_authority`). Bounded by
+    /// `MAX_REPO_MAINTAINERS`; managed by the authority only.
+    pub maintainers: Vec<Pubkey>,
+
+    /// When set, modules of this repository may not reuse a metadata URI
+    /// still held in `recent_module_uris` (see `remember_module_uri`).
+    pub enforce_unique_module_uri: bool,
+
+    /// Fingerprints of the most recent module metadata URIs, used as a
+    /// bounded ring buffer. Zero marks an empty slot.
+    pub recent_module_uris: [u64; RECENT_MODULE_URI_SLOTS],
+
+    /// Next slot of `recent_module_uris` to overwrite.
+    pub recent_module_uri_cursor: u8,
+
+    /// Flagship module of this repository, if any (see `set_primary_module`).
+    ///
+    /// Always a `Module` whose `repo` is this repository when set.
+    pub primary_module: Option<Pubkey>,
+
+    /// Admin-set fee rate for operations scoped to this repository, taking
+    /// precedence over `Config::fee_bps` (see `effective_fee_bps`).
+    pub fee_bps_override: Option<u16>,
+
+    /// Weighted activity score accumulated over all observations (see
+    /// `Repo::observation_score`). Comparable across repositories.
+    pub score: u64,
+
+    /// Signer that recorded the most recent observation.
+    pub last_observer: Pubkey,
+
+    /// Attestor that co-signed the most recent observation, if any (see
+    /// `Config::require_attestor`).
+    pub last_attestor: Option<Pubkey>,
+
+    /// Unix timestamp of the first recorded observation (0 if never observed).
+    ///
+    /// Set once by the first `record_observation` and never overwritten.
+    pub first_observed_at: i64,
+
+    /// Latest `observed_at` of any observation (0 if never observed). A
+    /// backfilled observation older than this never moves it backwards.
+    pub last_observed_at: i64,
+
+    /// Slot of the most recent observation (0 if never observed). Unlike
+    /// `last_observed_at` it is never backfilled; see
+    /// `assert_not_observed_in_slot`.
+    pub last_observed_slot: u64,
+
+    /// Revision identifier reported by the most recent observation.
+    pub last_revision: String,
+
+    /// Note attached to the most recent observation.
+    pub last_note: String,
+
+    /// Observation counts per UTC day, most recent first.
+    ///
+    /// Bucket 0 is the day of `last_observed_at`; bucket `i` is `i` days
+    /// earlier. Buckets shift out as days pass (see
+    /// `rotate_daily_observations`).
+    pub daily_observations: [u32; OBSERVATION_HISTOGRAM_DAYS],
+
+    /// Idempotency key of the last keyed observation (see
+    /// `is_idempotent_replay`).
+    pub last_idempotency_key: [u8; IDEMPOTENCY_KEY_LEN],
+
+    /// Clock timestamp at which `last_idempotency_key` was recorded; 0 when
+    /// no keyed observation has been recorded yet.
+    pub last_idempotency_at: i64,
+
+    /// Hashed worker region of the most recent observation that reported
+    /// one; all zero until then. The mapping back to regions lives off-chain.
+    pub last_source_region_hash: [u8; SOURCE_REGION_HASH_LEN],
+
+    /// When set (private repositories), every observation must carry an
+    /// access proof (see `assert_access_proof`).
+    pub require_access_proof: bool,
+
+    /// Proof-of-access token hash of the most recent observation that
+    /// carried one; all zero until then. Verification happens off-chain.
+    pub last_access_proof: [u8; 32],
+
+    /// Whether this entry was pre-allocated by `reserve_repo` and is still
+    /// waiting for `finalize_repo`. Reserved repositories are inactive and
+    /// not counted in `Metrics`.
+    pub is_reserved: bool,
+
     /// Reserved space for future fields.
-    pub reserved: [u8; 62],
+    pub reserved: [u8; 64],
 }
 
 impl Repo {
@@ -108,10 +335,30 @@ impl Repo {
     /// Maximum length of the `tags` field in bytes (UTF-8).
     pub const MAX_TAGS_LEN: usize = MAX_REPO_TAGS_LEN;
 
+    /// Maximum length of the `metadata_uri` field in bytes (UTF-8).
+    pub const MAX_METADATA_URI_LEN: usize = MAX_METADATA_URI_LEN;
+
+    /// Maximum length of the `last_revision` field in bytes (UTF-8).
+    pub const MAX_REVISION_LEN: usize = MAX_REVISION_LEN;
+
+    /// Maximum length of the `last_note` field in bytes (UTF-8).
+    pub const MAX_OBSERVATION_NOTE_LEN: usize = MAX_OBSERVATION_NOTE_LEN;
+
+    /// Maximum number of entries in `allowed_observers`.
+    pub const MAX_ALLOWED_OBSERVERS: usize = MAX_ALLOWED_OBSERVERS;
+
+    /// Maximum number of entries in `maintainers`.
+    pub const MAX_MAINTAINERS: usize = MAX_REPO_MAINTAINERS;
+
     /// Total serialized length of the `Repo` account.
     ///
     /// String fields are stored as a 4-byte length prefix followed by bytes.
     /// We allocate the maximum size to keep the layout stable.
+    ///
+    /// Repos are never shrunk to their live size: vectors such as
+    /// `maintainers` and `allowed_observers` grow in place in several
+    /// instructions and rely on the full allocation. Older accounts are
+    /// grown to this length by `migrate_repo`.
     pub const LEN: usize = Self::DISCRIMINATOR_LEN
         + 32  // repo_key: Pubkey
         + 32  // authority: Pubkey
@@ -128,7 +375,32 @@ impl Repo {
         + 8  // updated_at: i64
         + 1  // schema_version: u8
         + 1  // bump: u8
-        + 62; // reserved: [u8; 62]
+        + 1  // host: u8
+        + 8 // tag_bits: u64
+        + 4 + Self::MAX_METADATA_URI_LEN // metadata_uri: String
+        + 4 + 32 * Self::MAX_ALLOWED_OBSERVERS // allowed_observers: Vec<Pubkey>
+        + 4 + 32 * Self::MAX_MAINTAINERS // maintainers: Vec<Pubkey>
+        + 1  // enforce_unique_module_uri: bool
+        + 8 * RECENT_MODULE_URI_SLOTS // recent_module_uris: [u64; N]
+        + 1  // recent_module_uri_cursor: u8
+        + 1 + 32 // primary_module: Option<Pubkey>
+        + 1 + 2  // fee_bps_override: Option<u16>
+        + 8  // score: u64
+        + 32 // last_observer: Pubkey
+        + 1 + 32 // last_attestor: Option<Pubkey>
+        + 8  // first_observed_at: i64
+        + 8  // last_observed_at: i64
+        + 8  // last_observed_slot: u64
+        + 4 + Self::MAX_REVISION_LEN         // last_revision: String
+        + 4 + Self::MAX_OBSERVATION_NOTE_LEN // last_note: String
+        + 4 * OBSERVATION_HISTOGRAM_DAYS     // daily_observations: [u32; N]
+        + IDEMPOTENCY_KEY_LEN // last_idempotency_key: [u8; 16]
+        + 8  // last_idempotency_at: i64
+        + SOURCE_REGION_HASH_LEN // last_source_region_hash: [u8; 8]
+        + 1  // require_access_proof: bool
+        + 32 // last_access_proof: [u8; 32]
+        + 1  // is_reserved: bool
+        + 64; // reserved: [u8; 64]
 
     // -----------------------------------------------------------------------
     // Initialization
@@ -144,6 +416,7 @@ impl Repo {
         name: String,
         url: String,
         tags: String,
+        metadata_uri: String,
         allow_observation: bool,
         bump: u8,
         clock: &Clock,
@@ -151,25 +424,152 @@ impl Repo {
         Self::validate_name(&name)?;
         Self::validate_url(&url)?;
         Self::validate_tags(&tags)?;
+        Self::validate_metadata_uri(&metadata_uri)?;
 
-        self.repo_key = repo_key;
-        self.authority = authority;
+        self.write_defaults(repo_key, authority, bump, clock);
         self.name = name;
+        self.host = RepoHost::classify(&url).as_u8();
         self.url = url;
+        self.tag_bits = tag_bits_for(&tags);
         self.tags = tags;
+        self.metadata_uri = metadata_uri;
         self.is_active = true;
         self.allow_observation = allow_observation;
+        assert_fits_len(self, Self::LEN);
+
+        Ok(())
+    }
+
+    /// Pre-allocate a repository under `repo_key` without activating it.
+    ///
+    /// The entry has no name, URL, tags or metadata yet, is inactive and is
+    /// marked `is_reserved` until `finalize` fills it in. Called from the
+    /// `reserve_repo` instruction.
+    pub fn reserve(&mut self, repo_key: Pubkey, authority: Pubkey, bump: u8, clock: &Clock) {
+        self.write_defaults(repo_key, authority, bump, clock);
+        self.is_reserved = true;
+    }
+
+    /// Fill in a reserved repository and activate it.
+    ///
+    /// Validates the fields exactly like `init`. Fails with
+    /// `RepoNotReserved` if this repository is not awaiting finalization.
+    pub fn finalize(
+        &mut self,
+        name: String,
+        url: String,
+        tags: String,
+        metadata_uri: String,
+        allow_observation: bool,
+        clock: &Clock,
+    ) -> Result<()> {
+        if !self.is_reserved {
+            return err!(Unit09Error::RepoNotReserved);
+        }
+
+        Self::validate_name(&name)?;
+        Self::validate_url(&url)?;
+        Self::validate_tags(&tags)?;
+        Self::validate_metadata_uri(&metadata_uri)?;
+
+        self.name = name;
+        self.host = RepoHost::classify(&url).as_u8();
+        self.url = url;
+        self.tag_bits = tag_bits_for(&tags);
+        self.tags = tags;
+        self.metadata_uri = metadata_uri;
+        self.is_active = true;
+        self.allow_observation = allow_observation;
+        self.is_reserved = false;
+        self.updated_at = clock.unix_timestamp;
+
+        Ok(())
+    }
+
+    /// Reset every field to the state of a freshly created, inactive entry.
+    fn write_defaults(&mut self, repo_key: Pubkey, authority: Pubkey, bump: u8, clock: &Clock) {
+        self.repo_key = repo_key;
+        self.authority = authority;
+        self.name = String::new();
+        self.host = RepoHost::Other.as_u8();
+        self.url = String::new();
+        self.tag_bits = 0;
+        self.tags = String::new();
+        self.metadata_uri = String::new();
+        self.is_active = false;
+        self.allow_observation = false;
+        self.allowed_observers = Vec::new();
+        self.maintainers = Vec::new();
         self.module_count = 0;
+        self.enforce_unique_module_uri = false;
+        self.recent_module_uris = [0u64; RECENT_MODULE_URI_SLOTS];
+        self.recent_module_uri_cursor = 0;
+        self.primary_module = None;
+        self.fee_bps_override = None;
         self.observation_count = 0;
         self.total_lines_of_code = 0;
         self.total_files_processed = 0;
+        self.score = 0;
+        self.last_observer = Pubkey::default();
+        self.last_attestor = None;
+        self.first_observed_at = 0;
+        self.last_observed_at = 0;
+        self.last_observed_slot = 0;
+        self.last_revision = String::new();
+        self.last_note = String::new();
+        self.daily_observations = [0u32; OBSERVATION_HISTOGRAM_DAYS];
+        self.last_idempotency_key = [0u8; IDEMPOTENCY_KEY_LEN];
+        self.last_idempotency_at = 0;
+        self.last_source_region_hash = [0u8; SOURCE_REGION_HASH_LEN];
+        self.require_access_proof = false;
+        self.last_access_proof = [0u8; 32];
         self.created_at = clock.unix_timestamp;
         self.updated_at = clock.unix_timestamp;
         self.schema_version = CURRENT_SCHEMA_VERSION;
         self.bump = bump;
-        self.reserved = [0u8; 62];
+        self.is_reserved = false;
+        self.reserved = [0u8; 64];
+    }
 
-        Ok(())
+    /// Upgrade this repository from an older `schema_version` to
+    /// `CURRENT_SCHEMA_VERSION` and return the version it was upgraded from.
+    ///
+    /// Expects an account already grown to `Repo::LEN` (see
+    /// `utils::migration::load_for_migration`), so every field added since
+    /// the stored version decodes from zeroed bytes. Each step then sets the
+    /// fields whose zero value is wrong for existing data. Fails with
+    /// `MigrationAlreadyApplied` if the repository is already current.
+    pub fn migrate(&mut self, clock: &Clock) -> Result<u8> {
+        let from_version = self.schema_version;
+        if from_version >= CURRENT_SCHEMA_VERSION {
+            return err!(Unit09Error::MigrationAlreadyApplied);
+        }
+
+        // 1 -> 2: `host` and `tag_bits` are derived from `url` and `tags`.
+        if from_version < 2 {
+            self.host = RepoHost::classify(&self.url).as_u8();
+            self.tag_bits = tag_bits_for(&self.tags);
+        }
+
+        self.schema_version = CURRENT_SCHEMA_VERSION;
+        self.updated_at = clock.unix_timestamp;
+
+        Ok(from_version)
+    }
+
+    /// Canonical repository key for `url`: the SHA-256 hash of its bytes.
+    ///
+    /// Required by `register_repo` when `Config::enforce_url_keyed` is set.
+    pub fn key_for_url(url: &str) -> Pubkey {
+        Pubkey::new_from_array(hash(url.as_bytes()).to_bytes())
+    }
+
+    /// Fingerprint stored in `recent_module_uris`: the first 8 bytes of the
+    /// SHA-256 hash of `uri`. Never zero, since zero marks an empty slot.
+    pub fn module_uri_fingerprint(uri: &str) -> u64 {
+        let mut prefix = [0u8; 8];
+        prefix.copy_from_slice(&hash(uri.as_bytes()).to_bytes()[..8]);
+        u64::from_le_bytes(prefix).max(1)
     }
 
     // -----------------------------------------------------------------------
@@ -179,42 +579,178 @@ impl Repo {
     /// Update the repository metadata fields that are provided as `Some`.
     ///
     /// This can be used by `update_repo` and similar instructions.
+    /// `maybe_allowed_observers` replaces the whole allow-list; `Some(vec![])`
+    /// clears it.
+    #[allow(clippy::too_many_arguments)]
     pub fn apply_update(
         &mut self,
         maybe_name: Option<String>,
         maybe_url: Option<String>,
         maybe_tags: Option<String>,
+        maybe_metadata_uri: Option<String>,
         maybe_is_active: Option<bool>,
         maybe_allow_observation: Option<bool>,
+        maybe_allowed_observers: Option<Vec<Pubkey>>,
         clock: &Clock,
     ) -> Result<()> {
-        if let Some(name) = maybe_name {
-            Self::validate_name(&name)?;
-            self.name = name;
-        }
+        apply_if_some!(self.name, maybe_name, Self::validate_name);
 
-        if let Some(url) = maybe_url {
-            Self::validate_url(&url)?;
-            self.url = url;
+        let url_changed = maybe_url.is_some();
+        apply_if_some!(self.url, maybe_url, Self::validate_url);
+        if url_changed {
+            self.host = RepoHost::classify(&self.url).as_u8();
         }
 
-        if let Some(tags) = maybe_tags {
-            Self::validate_tags(&tags)?;
-            self.tags = tags;
+        let tags_changed = maybe_tags.is_some();
+        apply_if_some!(self.tags, maybe_tags, Self::validate_tags);
+        if tags_changed {
+            self.tag_bits = tag_bits_for(&self.tags);
         }
 
-        if let Some(is_active) = maybe_is_active {
-            self.is_active = is_active;
+        apply_if_some!(
+            self.metadata_uri,
+            maybe_metadata_uri,
+            Self::validate_metadata_uri
+        );
+
+        apply_if_some!(self.is_active, maybe_is_active);
+
+        apply_if_some!(self.allow_observation, maybe_allow_observation);
+
+        apply_if_some!(
+            self.allowed_observers,
+            maybe_allowed_observers,
+            Self::validate_allowed_observers
+        );
+
+        self.updated_at = clock.unix_timestamp;
+        Ok(())
+    }
+
+    /// Set or clear (`None`) the primary module.
+    ///
+    /// Callers must check that the module belongs to this repository
+    /// (`ModuleRepoMismatch`) before setting it.
+    pub fn set_primary_module(&mut self, primary_module: Option<Pubkey>, clock: &Clock) {
+        self.primary_module = primary_module;
+        self.updated_at = clock.unix_timestamp;
+    }
+
+    /// Set or clear (`None`) the fee override.
+    ///
+    /// Callers must check admin authority first. Fails with `InvalidFeeBps`
+    /// above `MAX_FEE_BPS`.
+    pub fn set_fee_bps_override(&mut self, fee_bps_override: Option<u16>) -> Result<()> {
+        if let Some(fee_bps) = fee_bps_override {
+            if fee_bps > MAX_FEE_BPS {
+                return err!(Unit09Error::InvalidFeeBps);
+            }
         }
+        self.fee_bps_override = fee_bps_override;
+        Ok(())
+    }
 
-        if let Some(allow_obs) = maybe_allow_observation {
-            self.allow_observation = allow_obs;
+    /// Fee rate for operations scoped to this repository: the override when
+    /// set, otherwise `config_fee_bps`.
+    pub fn effective_fee_bps(&self, config_fee_bps: u16) -> u16 {
+        self.fee_bps_override.unwrap_or(config_fee_bps)
+    }
+
+    /// Set only the `allow_observation` flag, leaving all other metadata
+    /// untouched.
+    pub fn set_allow_observation(&mut self, allow_observation: bool, clock: &Clock) {
+        self.allow_observation = allow_observation;
+        self.updated_at = clock.unix_timestamp;
+    }
+
+    // -----------------------------------------------------------------------
+    // Maintainers
+    // -----------------------------------------------------------------------
+
+    /// Add `maintainer` to `maintainers`.
+    ///
+    /// Fails with `MaintainerAlreadyAdded` if it is already listed or is the
+    /// authority, and with `ValueOutOfRange` once `MAX_MAINTAINERS` is hit.
+    pub fn add_maintainer(&mut self, maintainer: Pubkey, clock: &Clock) -> Result<()> {
+        if maintainer == self.authority || self.maintainers.contains(&maintainer) {
+            return err!(Unit09Error::MaintainerAlreadyAdded);
         }
+        if self.maintainers.len() >= Self::MAX_MAINTAINERS {
+            return err!(Unit09Error::ValueOutOfRange);
+        }
+        self.maintainers.push(maintainer);
+        self.updated_at = clock.unix_timestamp;
+        Ok(())
+    }
 
+    /// Remove `maintainer` from `maintainers`. Fails with
+    /// `MaintainerNotFound` if it is not listed.
+    pub fn remove_maintainer(&mut self, maintainer: &Pubkey, clock: &Clock) -> Result<()> {
+        let position = self
+            .maintainers
+            .iter()
+            .position(|key| key == maintainer)
+            .ok_or(Unit09Error::MaintainerNotFound)?;
+        self.maintainers.remove(position);
         self.updated_at = clock.unix_timestamp;
         Ok(())
     }
 
+    // -----------------------------------------------------------------------
+    // Tag Bitmask
+    // -----------------------------------------------------------------------
+
+    /// Set the `tag_bits` bit for vocabulary entry `index`.
+    ///
+    /// Fails with `ValueOutOfRange` if `index` is not in `TAG_VOCABULARY`.
+    pub fn set_tag_bit(&mut self, index: u8) -> Result<()> {
+        if tag_bit_name(index).is_none() {
+            return err!(Unit09Error::ValueOutOfRange);
+        }
+        self.tag_bits |= 1u64 << index;
+        Ok(())
+    }
+
+    /// Replace `tag_bits` with `bits` plus the bits implied by `tags`.
+    ///
+    /// Vocabulary tags present in the string always keep their bit set.
+    pub fn set_tag_bits(&mut self, bits: u64) -> Result<()> {
+        validate_tag_bits(bits)?;
+        self.tag_bits = bits | tag_bits_for(&self.tags);
+        Ok(())
+    }
+
+    /// Rename tag `old` to `new` in `tags`, keeping `tag_bits` in sync.
+    ///
+    /// Returns `Ok(false)` without touching anything when the repository does
+    /// not carry `old`. Fails with `StringTooLong` if the renamed string
+    /// exceeds `MAX_TAGS_LEN`.
+    pub fn rename_tag(&mut self, old: &str, new: &str, clock: &Clock) -> Result<bool> {
+        let tags = match rename_tag(&self.tags, old, new) {
+            Some(tags) => tags,
+            None => return Ok(false),
+        };
+        Self::validate_tags(&tags)?;
+
+        // The bit of `old` was implied by the string; explicit bits stay.
+        let old_bit = tag_bit_index(old).map_or(0, |index| 1u64 << index);
+        self.tag_bits = (self.tag_bits & !old_bit) | tag_bits_for(&tags);
+        self.tags = tags;
+        self.updated_at = clock.unix_timestamp;
+        Ok(true)
+    }
+
+    /// Whether this repository carries `tag`.
+    ///
+    /// Vocabulary tags are answered from `tag_bits`; other tags fall back to
+    /// the normalized `tags` string.
+    pub fn has_tag(&self, tag: &str) -> bool {
+        match tag_bit_index(tag) {
+            Some(index) => self.tag_bits & (1u64 << index) != 0,
+            None => normalized_tag_list(&self.tags).contains(&tag.trim().to_ascii_lowercase()),
+        }
+    }
+
     // -----------------------------------------------------------------------
     // Authority and Activation Guards
     // -----------------------------------------------------------------------
@@ -227,6 +763,14 @@ impl Repo {
         Ok(())
     }
 
+    /// Ensure that `signer` is the authority or one of the maintainers.
+    pub fn assert_maintainer_or_authority(&self, signer: &Pubkey) -> Result<()> {
+        if *signer != self.authority && !self.maintainers.contains(signer) {
+            return err!(Unit09Error::InvalidAuthority);
+        }
+        Ok(())
+    }
+
     /// Ensure that the repository is currently active.
     pub fn assert_active(&self) -> Result<()> {
         if !self.is_active {
@@ -235,6 +779,16 @@ impl Repo {
         Ok(())
     }
 
+    /// Ensure that the repository is not waiting for `finalize_repo`.
+    ///
+    /// Reserved repositories can only be activated by finalizing them.
+    pub fn assert_not_reserved(&self) -> Result<()> {
+        if self.is_reserved {
+            return err!(Unit09Error::RepoInactive);
+        }
+        Ok(())
+    }
+
     /// Ensure that the repository is allowed to be observed.
     pub fn assert_observation_allowed(&self) -> Result<()> {
         if !self.allow_observation {
@@ -243,6 +797,39 @@ impl Repo {
         Ok(())
     }
 
+    /// Ensure that `observer` may record observations for this repository.
+    ///
+    /// An empty `allowed_observers` list permits any observer.
+    pub fn assert_observer_allowed(&self, observer: &Pubkey) -> Result<()> {
+        if !self.allowed_observers.is_empty() && !self.allowed_observers.contains(observer) {
+            return err!(Unit09Error::ObservationNotAllowed);
+        }
+        Ok(())
+    }
+
+    /// Ensure an observation carries an access proof when this repository
+    /// requires one (`ObservationNotAllowed`).
+    pub fn assert_access_proof(&self, access_proof: Option<&[u8; 32]>) -> Result<()> {
+        if self.require_access_proof && access_proof.is_none() {
+            return err!(Unit09Error::ObservationNotAllowed);
+        }
+        Ok(())
+    }
+
+    /// Ensure this repository was not observed in the current slot.
+    ///
+    /// Ordering rule for closing a repository: an observation recorded in
+    /// the same slot (possibly earlier in the same transaction) must not be
+    /// closed out from under. Any `close_repo` handler must call this before
+    /// touching the account; it fails with `CooldownActive`, and the close
+    /// can be retried from a later slot.
+    pub fn assert_not_observed_in_slot(&self, clock: &Clock) -> Result<()> {
+        if self.last_observed_slot != 0 && self.last_observed_slot == clock.slot {
+            return err!(Unit09Error::CooldownActive);
+        }
+        Ok(())
+    }
+
     // -----------------------------------------------------------------------
     // Module Counters
     // -----------------------------------------------------------------------
@@ -274,6 +861,24 @@ impl Repo {
         Ok(())
     }
 
+    /// Record `uri` as the metadata URI of a module of this repository.
+    ///
+    /// With `enforce_unique_module_uri` set, fails with `MetadataInvalid`
+    /// when `uri` is one of the last `RECENT_MODULE_URI_SLOTS` URIs recorded.
+    /// This is best-effort: older URIs fall out of the ring buffer and are no
+    /// longer checked. URIs are recorded even while the flag is off.
+    pub fn remember_module_uri(&mut self, uri: &str) -> Result<()> {
+        let fingerprint = Self::module_uri_fingerprint(uri);
+        if self.enforce_unique_module_uri && self.recent_module_uris.contains(&fingerprint) {
+            return err!(Unit09Error::MetadataInvalid);
+        }
+
+        let slot = self.recent_module_uri_cursor as usize % RECENT_MODULE_URI_SLOTS;
+        self.recent_module_uris[slot] = fingerprint;
+        self.recent_module_uri_cursor = ((slot + 1) % RECENT_MODULE_URI_SLOTS) as u8;
+        Ok(())
+    }
+
     // -----------------------------------------------------------------------
     // Observation Aggregation
     // -----------------------------------------------------------------------
@@ -281,11 +886,38 @@ impl Repo {
     /// Record a single observation result on this repository.
     ///
     /// This is used by `record_observation` instruction handlers.
+    /// `modules_touched` is validated by the handler and only reported in
+    /// events; it is not aggregated on the repository.
+    ///
+    /// `observed_at` is the time the observation is recorded "as of". It is
+    /// normally `clock.unix_timestamp`, but may be an earlier admin-supplied
+    /// timestamp when backfilling (see `Config::resolve_observed_at`). It
+    /// drives `first_observed_at`, `last_observed_at` and the daily
+    /// histogram; `updated_at` always uses the clock. A backfill older than
+    /// `last_observed_at` is counted into its own day's bucket (or dropped
+    /// from the histogram when that day has shifted out) and leaves
+    /// `last_observed_at` where it is.
+    ///
+    /// Returns `true` when this observation brought the repository to
+    /// `SOFT_MAX_OBSERVATIONS_PER_REPO`, so the caller can emit
+    /// `RepoObservationCapReached` exactly once. Fails with
+    /// `RepoObservationLimitReached` once `MAX_OBSERVATIONS_HARD_CAP` is held.
+    ///
+    /// `attestor` is the optional co-signer of the observation and replaces
+    /// `last_attestor`, so a run without one clears it.
+    #[allow(clippy::too_many_arguments)]
     pub fn record_observation(
         &mut self,
         lines_of_code: u64,
         files_processed: u32,
-    ) -> Result<()> {
+        modules_touched: u32,
+        revision: String,
+        note: String,
+        observer: Pubkey,
+        attestor: Option<Pubkey>,
+        observed_at: i64,
+        clock: &Clock,
+    ) -> Result<bool> {
         // Basic bounds checking using constants
         if lines_of_code > MAX_LOC_PER_OBSERVATION {
             return err!(Unit09Error::ObservationDataTooLarge);
@@ -293,16 +925,24 @@ impl Repo {
         if files_processed as u64 > MAX_FILES_PER_OBSERVATION as u64 {
             return err!(Unit09Error::ObservationDataTooLarge);
         }
+        if revision.len() > Self::MAX_REVISION_LEN {
+            return err!(Unit09Error::StringTooLong);
+        }
+        if note.len() > Self::MAX_OBSERVATION_NOTE_LEN {
+            return err!(Unit09Error::StringTooLong);
+        }
+
+        // Hard cap rejects; the soft cap is only reported to the caller.
+        if self.observation_count >= MAX_OBSERVATIONS_HARD_CAP {
+            return err!(Unit09Error::RepoObservationLimitReached);
+        }
 
         // Increment observation count
         self.observation_count = self
             .observation_count
             .checked_add(1)
             .ok_or(Unit09Error::CounterOverflow)?;
-
-        if self.observation_count > SOFT_MAX_OBSERVATIONS_PER_REPO {
-            return err!(Unit09Error::RepoObservationLimitReached);
-        }
+        let soft_cap_reached = self.observation_count == SOFT_MAX_OBSERVATIONS_PER_REPO;
 
         // Aggregate lines of code and files
         self.total_lines_of_code = self
@@ -315,402 +955,924 @@ impl Repo {
             .checked_add(files_processed as u64)
             .ok_or(Unit09Error::CounterOverflow)?;
 
-        Ok(())
-    }
-
-    // -----------------------------------------------------------------------
-    // Validation Helpers
-    // -----------------------------------------------------------------------
+        self.score = self
+            .score
+            .checked_add(Self::observation_score(lines_of_code, files_processed, modules_touched)?)
+            .ok_or(Unit09Error::CounterOverflow)?;
 
-    /// Validate the repository name.
-    fn validate_name(name: &str) -> Result<()> {
-        if name.is_empty() {
-            return err!(Unit09Error::StringEmpty);
+        // Observation bookkeeping. `first_observed_at` keeps the earliest
+        // non-zero timestamp, so it is effectively written once. The
+        // histogram is rotated against the previous `last_observed_at`, so
+        // it must run before that field is advanced.
+        self.rotate_daily_observations(observed_at);
+        self.first_observed_at = min_non_zero(self.first_observed_at, observed_at);
+        self.last_observed_at = self.last_observed_at.max(observed_at);
+        if let Some(bucket) = self.daily_bucket(observed_at) {
+            self.daily_observations[bucket] = self.daily_observations[bucket].saturating_add(1);
         }
-        if name.len() > Self::MAX_NAME_LEN {
-            return err!(Unit09Error::StringTooLong);
-        }
-        Ok(())
+        self.last_observed_slot = clock.slot;
+        self.last_observer = observer;
+        self.last_attestor = attestor;
+        self.last_revision = revision;
+        self.last_note = note;
+        self.updated_at = clock.unix_timestamp;
+
+        Ok(soft_cap_reached)
     }
 
-    /// Validate the repository URL with basic checks.
-    fn validate_url(url: &str) -> Result<()> {
-        if url.is_empty() {
-            return err!(Unit09Error::StringEmpty);
-        }
-        if url.len() > Self::MAX_URL_LEN {
+    /// Record a metadata-only observation.
+    ///
+    /// Only refreshes `last_observed_at`, `last_observed_slot`,
+    /// `last_revision` and `last_note`. Observation count, LOC, files,
+    /// score and the last observer are left untouched, so such runs are
+    /// invisible to the aggregates. The daily histogram is rotated without
+    /// counting anything, keeping bucket 0 on the day of `last_observed_at`;
+    /// as in `record_observation`, a backfill never moves that field back.
+    pub fn record_metadata_observation(
+        &mut self,
+        revision: String,
+        note: String,
+        observed_at: i64,
+        clock: &Clock,
+    ) -> Result<()> {
+        if revision.len() > Self::MAX_REVISION_LEN {
             return err!(Unit09Error::StringTooLong);
         }
-        // Very basic structural check: must contain at least one dot and "://"
-        if !url.contains("://") || !url.contains('.') {
-            return err!(Unit09Error::InvalidUrl);
+        if note.len() > Self::MAX_OBSERVATION_NOTE_LEN {
+            return err!(Unit09Error::StringTooLong);
         }
+
+        self.rotate_daily_observations(observed_at);
+        self.last_observed_at = self.last_observed_at.max(observed_at);
+        self.last_observed_slot = clock.slot;
+        self.last_revision = revision;
+        self.last_note = note;
+        self.updated_at = clock.unix_timestamp;
         Ok(())
     }
 
-    /// Validate the tags string.
-    fn validate_tags(tags: &str) -> Result<()> {
-        if tags.len() > Self::MAX_TAGS_LEN {
-            return err!(Unit09Error::StringTooLong);
-        }
-        Ok(())
+    /// Score added to `score` by one observation: one point per
+    /// `SCORE_LOC_DIVISOR` lines of code, plus `SCORE_WEIGHT_PER_FILE` per
+    /// file and `SCORE_WEIGHT_PER_MODULE` per touched module.
+    pub fn observation_score(
+        lines_of_code: u64,
+        files_processed: u32,
+        modules_touched: u32,
+    ) -> Result<u64> {
+        let files = (files_processed as u64)
+            .checked_mul(SCORE_WEIGHT_PER_FILE)
+            .ok_or(Unit09Error::CounterOverflow)?;
+        let modules = (modules_touched as u64)
+            .checked_mul(SCORE_WEIGHT_PER_MODULE)
+            .ok_or(Unit09Error::CounterOverflow)?;
+        (lines_of_code / SCORE_LOC_DIVISOR)
+            .checked_add(files)
+            .and_then(|score| score.checked_add(modules))
+            .ok_or_else(|| error!(Unit09Error::CounterOverflow))
     }
-}
-//! ===========================================================================
-//! Unit09 – Repository State
-//! Path: contracts/unit09-program/programs/unit09_program/src/state/repo.rs
-//!
-//! A repository represents a real-world codebase that Unit09 observes and
-//! modularizes into runnable units. It is the primary anchor for:
-//! - tracking code sources
-//! - counting observations
-//! - aggregating module statistics
-//!
-//! Each `Repo` is a PDA derived from:
-//!     seed: REPO_SEED
-//!     key:  repo_key (arbitrary Pubkey chosen by the caller)
-//!
-//! This module defines:
-//! - `Repo` account structure
-//! - size constants for rent-exempt allocation
-//! - helper methods for authority checks, activation checks,
-//!   observation recording, and module counters.
-//!
-//! ===========================================================================
-
-use anchor_lang::prelude::*;
 
-use crate::constants::*;
-use crate::errors::Unit09Error;
-
-/// Repository account tracked by Unit09.
-///
-/// A repository may represent:
-/// - a GitHub / GitLab repository
-/// - a monorepo with multiple on-chain programs
-/// - any logical grouping of code that Unit09 parses
-#[account]
-pub struct Repo {
-    /// Arbitrary key chosen to identify this repository at PDA derivation time.
+    /// Whether an observation carrying `key` was already applied: `key` is
+    /// the last recorded idempotency key and it was recorded less than
+    /// `IDEMPOTENCY_WINDOW_SECS` before `now`.
     ///
-    /// In most cases this will be derived from an off-chain identifier
-    /// (for example, the hash of a repository URL or a content identifier).
-    pub repo_key: Pubkey,
+    /// Only the most recent key is kept, so a retry is recognised only until
+    /// another keyed observation lands on this repository.
+    pub fn is_idempotent_replay(&self, key: &[u8; IDEMPOTENCY_KEY_LEN], now: i64) -> bool {
+        self.last_idempotency_at != 0
+            && self.last_idempotency_key == *key
+            && now.saturating_sub(self.last_idempotency_at) < IDEMPOTENCY_WINDOW_SECS
+    }
 
-    /// Authority that controls metadata and activation state for this repository.
-    pub authority: Pubkey,
+    /// Remember `key` as the idempotency key of the observation just applied.
+    pub fn remember_idempotency_key(&mut self, key: [u8; IDEMPOTENCY_KEY_LEN], now: i64) {
+        self.last_idempotency_key = key;
+        self.last_idempotency_at = now;
+    }
 
-    /// Human-readable name for the repository.
+    /// Validate a reported `source_region_hash`: all zero is reserved for
+    /// "no region" and fails with `MetadataInvalid`.
+    pub fn validate_source_region_hash(hash: &[u8; SOURCE_REGION_HASH_LEN]) -> Result<()> {
+        require!(hash.iter().any(|byte| *byte != 0), Unit09Error::MetadataInvalid);
+        Ok(())
+    }
+
+    /// Whether this repository's data is stale: its last observation is more
+    /// than `threshold_secs` (`Config::staleness_threshold_secs`) old.
     ///
-    /// Example: "unit09-solana-core"
-    pub name: String,
+    /// A threshold of 0 disables staleness. A never-observed repository has
+    /// no data to be stale and is not reported.
+    pub fn is_stale(&self, clock: &Clock, threshold_secs: i64) -> bool {
+        is_older_than(clock, self.last_observed_at, threshold_secs)
+    }
 
-    /// URL pointing to the codebase.
+    /// Shift `daily_observations` so that bucket 0 corresponds to the day
+    /// containing `now`.
     ///
-    /// Example: "https://github.com/unit09-labs/unit09"
-    pub url: String,
+    /// The shift is the number of whole `SECONDS_PER_DAY` days between
+    /// `last_observed_at` and `now`. A never-observed repository or a gap of
+    /// `OBSERVATION_HISTOGRAM_DAYS` or more clears every bucket. A `now` on
+    /// an earlier day than `last_observed_at` (a backfill) leaves the
+    /// buckets untouched: the histogram never rotates backwards.
+    pub fn rotate_daily_observations(&mut self, now: i64) {
+        if self.last_observed_at <= 0 {
+            self.daily_observations = [0u32; OBSERVATION_HISTOGRAM_DAYS];
+            return;
+        }
 
-    /// Optional tags describing the repository.
+        let last_day = self.last_observed_at.div_euclid(SECONDS_PER_DAY);
+        let current_day = now.div_euclid(SECONDS_PER_DAY);
+        let delta = current_day.saturating_sub(last_day);
+
+        if delta <= 0 {
+            return;
+        }
+        if delta >= OBSERVATION_HISTOGRAM_DAYS as i64 {
+            self.daily_observations = [0u32; OBSERVATION_HISTOGRAM_DAYS];
+            return;
+        }
+
+        let shift = delta as usize;
+        self.daily_observations.rotate_right(shift);
+        for bucket in self.daily_observations.iter_mut().take(shift) {
+            *bucket = 0;
+        }
+    }
+
+    /// Index into `daily_observations` of the day containing `observed_at`,
+    /// counted back from the day of `last_observed_at`.
     ///
-    /// Example: "solana,anchor,protocol"
-    pub tags: String,
+    /// `None` when that day is older than the histogram window. Expects the
+    /// histogram to be rotated and `last_observed_at` advanced already.
+    fn daily_bucket(&self, observed_at: i64) -> Option<usize> {
+        let last_day = self.last_observed_at.div_euclid(SECONDS_PER_DAY);
+        let observed_day = observed_at.div_euclid(SECONDS_PER_DAY);
+        let age = last_day.saturating_sub(observed_day).max(0);
+        if age >= OBSERVATION_HISTOGRAM_DAYS as i64 {
+            return None;
+        }
+        Some(age as usize)
+    }
 
-    /// Whether this repository is active.
+    // -----------------------------------------------------------------------
+    // Validation Helpers
+    // -----------------------------------------------------------------------
+
+    /// Validate the repository name.
+    fn validate_name(name: &str) -> Result<()> {
+        if name.is_empty() {
+            return err!(Unit09Error::StringEmpty);
+        }
+        if name.len() > Self::MAX_NAME_LEN {
+            return err!(Unit09Error::StringTooLong);
+        }
+        Ok(())
+    }
+
+    /// Validate the repository URL with basic checks.
+    fn validate_url(url: &str) -> Result<()> {
+        if url.is_empty() {
+            return err!(Unit09Error::StringEmpty);
+        }
+        if url.len() > Self::MAX_URL_LEN {
+            return err!(Unit09Error::StringTooLong);
+        }
+        // Very basic structural check: must contain at least one dot and "://"
+        if !url.contains("://") || !url.contains('.') {
+            return err!(Unit09Error::InvalidUrl);
+        }
+        Ok(())
+    }
+
+    /// Validate the tags string.
+    fn validate_tags(tags: &str) -> Result<()> {
+        if tags.len() > Self::MAX_TAGS_LEN {
+            return err!(Unit09Error::StringTooLong);
+        }
+        Ok(())
+    }
+
+    /// Validate the observer allow-list size.
+    fn validate_allowed_observers(allowed_observers: &[Pubkey]) -> Result<()> {
+        if allowed_observers.len() > Self::MAX_ALLOWED_OBSERVERS {
+            return err!(Unit09Error::ValueOutOfRange);
+        }
+        Ok(())
+    }
+
+    /// Validate the optional repository metadata URI.
     ///
-    /// Inactive repositories should not be observed or used for new
-    /// module registrations.
-    pub is_active: bool,
+    /// An empty string clears the URI. Non-empty values must use one of the
+    /// schemes accepted for module and fork metadata.
+    fn validate_metadata_uri(uri: &str) -> Result<()> {
+        if uri.is_empty() {
+            return Ok(());
+        }
+        if uri.len() > Self::MAX_METADATA_URI_LEN {
+            return err!(Unit09Error::StringTooLong);
+        }
 
-    /// Whether Unit09’s external workers are allowed to perform
-    /// automated observation runs for this repository.
-    pub allow_observation: bool,
+        let has_known_prefix = uri.starts_with("http://")
+            || uri.starts_with("https://")
+            || uri.starts_with("ipfs://")
+            || uri.starts_with("ar://");
 
-    /// Total number of modules registered for this repository.
-    pub module_count: u32,
+        if !has_known_prefix {
+            return err!(Unit09Error::MetadataInvalid);
+        }
 
-    /// Total number of observation runs recorded for this repository.
-    pub observation_count: u64,
+        Ok(())
+    }
+}
 
-    /// Aggregated lines of code processed across all observations.
-    pub total_lines_of_code: u64,
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn repo_with_observers(allowed_observers: Vec<Pubkey>) -> Repo {
+        Repo {
+            repo_key: Pubkey::new_unique(),
+            authority: Pubkey::new_unique(),
+            name: "unit09-solana-core".to_string(),
+            url: "https://github.com/unit09-labs/unit09".to_string(),
+            host: RepoHost::GitHub.as_u8(),
+            tags: String::new(),
+            tag_bits: 0,
+            metadata_uri: String::new(),
+            is_active: true,
+            allow_observation: true,
+            allowed_observers,
+            maintainers: Vec::new(),
+            module_count: 0,
+            enforce_unique_module_uri: false,
+            recent_module_uris: [0u64; RECENT_MODULE_URI_SLOTS],
+            recent_module_uri_cursor: 0,
+            primary_module: None,
+            fee_bps_override: None,
+            observation_count: 0,
+            total_lines_of_code: 0,
+            total_files_processed: 0,
+            score: 0,
+            last_observer: Pubkey::default(),
+            last_attestor: None,
+            first_observed_at: 0,
+            last_observed_at: 0,
+            last_observed_slot: 0,
+            last_revision: String::new(),
+            last_note: String::new(),
+            daily_observations: [0u32; OBSERVATION_HISTOGRAM_DAYS],
+            last_idempotency_key: [0u8; IDEMPOTENCY_KEY_LEN],
+            last_idempotency_at: 0,
+            last_source_region_hash: [0u8; SOURCE_REGION_HASH_LEN],
+            require_access_proof: false,
+            last_access_proof: [0u8; 32],
+            created_at: 0,
+            updated_at: 0,
+            schema_version: CURRENT_SCHEMA_VERSION,
+            bump: 0,
+            is_reserved: false,
+            reserved: [0u8; 64],
+        }
+    }
 
-    /// Aggregated files processed across all observations.
-    pub total_files_processed: u64,
+    #[test]
+    fn migrate_upgrades_old_repo_once() {
+        let mut repo = repo_with_observers(Vec::new());
+        repo.schema_version = 1;
+        repo.url = "https://gitlab.com/unit09-labs/unit09".to_string();
+        repo.tags = "solana,anchor".to_string();
+        repo.host = 0;
+        repo.tag_bits = 0;
+        let clock = Clock {
+            unix_timestamp: 1_000,
+            ..Clock::default()
+        };
+
+        assert_eq!(repo.migrate(&clock).unwrap(), 1);
+        assert_eq!(repo.schema_version, CURRENT_SCHEMA_VERSION);
+        assert_eq!(repo.host, RepoHost::GitLab.as_u8());
+        assert_eq!(repo.tag_bits, tag_bits_for("solana,anchor"));
+        assert_eq!(repo.updated_at, 1_000);
+        assert_eq!(repo.name, "unit09-solana-core");
+
+        assert_eq!(
+            repo.migrate(&clock).unwrap_err(),
+            Unit09Error::MigrationAlreadyApplied.into()
+        );
+    }
 
-    /// Unix timestamp when this repository entry was created.
-    pub created_at: i64,
+    #[test]
+    fn appended_fields_decode_from_zeroed_tail() {
+        // A schema version 1 account serializes the version 1 fields only;
+        // growing it to `LEN` leaves zeros where the appended fields live.
+        let repo = repo_with_observers(Vec::new());
+        let mut data = Vec::new();
+        repo.repo_key.serialize(&mut data).unwrap();
+        repo.authority.serialize(&mut data).unwrap();
+        repo.name.serialize(&mut data).unwrap();
+        repo.url.serialize(&mut data).unwrap();
+        repo.tags.serialize(&mut data).unwrap();
+        repo.is_active.serialize(&mut data).unwrap();
+        repo.allow_observation.serialize(&mut data).unwrap();
+        7u32.serialize(&mut data).unwrap();
+        3u64.serialize(&mut data).unwrap();
+        0u64.serialize(&mut data).unwrap();
+        0u64.serialize(&mut data).unwrap();
+        5i64.serialize(&mut data).unwrap();
+        6i64.serialize(&mut data).unwrap();
+        1u8.serialize(&mut data).unwrap();
+        254u8.serialize(&mut data).unwrap();
+        data.resize(Repo::LEN - Repo::DISCRIMINATOR_LEN, 0);
+
+        let decoded = Repo::deserialize(&mut data.as_slice()).unwrap();
+        assert_eq!(decoded.name, repo.name);
+        assert_eq!(decoded.module_count, 7);
+        assert_eq!(decoded.observation_count, 3);
+        assert_eq!(decoded.created_at, 5);
+        assert_eq!(decoded.schema_version, 1);
+        assert_eq!(decoded.bump, 254);
+        assert!(decoded.metadata_uri.is_empty());
+        assert!(decoded.maintainers.is_empty());
+        assert_eq!(decoded.primary_module, None);
+        assert_eq!(decoded.fee_bps_override, None);
+        assert!(!decoded.is_reserved);
+    }
 
-    /// Unix timestamp when this repository entry was last updated.
-    pub updated_at: i64,
+    #[test]
+    fn tag_bits_follow_tags_and_explicit_bits() {
+        let mut repo = repo_with_observers(Vec::new());
+        let clock = Clock::default();
+        repo.apply_update(
+            None,
+            None,
+            Some("Solana,custom".to_string()),
+            None,
+            None,
+            None,
+            None,
+            &clock,
+        )
+        .unwrap();
+        assert!(repo.has_tag("solana"));
+        assert!(repo.has_tag("CUSTOM"));
+        assert!(!repo.has_tag("anchor"));
+
+        repo.set_tag_bit(tag_bit_index("anchor").unwrap()).unwrap();
+        assert!(repo.has_tag("anchor"));
+        assert!(repo.set_tag_bit(TAG_VOCABULARY.len() as u8).is_err());
+
+        // Explicit bits replace earlier explicit bits but keep string tags.
+        repo.set_tag_bits(0).unwrap();
+        assert!(repo.has_tag("solana"));
+        assert!(!repo.has_tag("anchor"));
+    }
 
-    /// Schema version for this repository layout.
-    pub schema_version: u8,
+    #[test]
+    fn rename_tag_moves_vocabulary_bit() {
+        let mut repo = repo_with_observers(Vec::new());
+        let clock = Clock::default();
+        repo.apply_update(None, None, Some("solana,custom".to_string()), None, None, None, None, &clock)
+            .unwrap();
 
-    /// Bump used for PDA derivation.
-    pub bump: u8,
+        assert!(!repo.rename_tag("missing", "anchor", &clock).unwrap());
+        assert_eq!(repo.tags, "solana,custom");
 
-    /// Reserved space for future fields.
-    pub reserved: [u8; 62],
-}
+        assert!(repo.rename_tag("solana", "anchor", &clock).unwrap());
+        assert_eq!(repo.tags, "anchor,custom");
+        assert!(repo.has_tag("anchor"));
+        assert!(!repo.has_tag("solana"));
+    }
 
-impl Repo {
-    /// Discriminator length used by Anchor.
-    pub const DISCRIMINATOR_LEN: usize = 8;
+    fn observe(repo: &mut Repo) -> Result<bool> {
+        repo.record_observation(
+            10,
+            1,
+            1,
+            String::new(),
+            String::new(),
+            Pubkey::new_unique(),
+            None,
+            1,
+            &Clock::default(),
+        )
+    }
 
-    /// Maximum length of the `name` field in bytes (UTF-8).
-    pub const MAX_NAME_LEN: usize = MAX_NAME_LEN;
+    #[test]
+    fn soft_cap_is_signalled_once_without_rejecting() {
+        let mut repo = repo_with_observers(Vec::new());
+        repo.observation_count = SOFT_MAX_OBSERVATIONS_PER_REPO - 2;
 
-    /// Maximum length of the `url` field in bytes (UTF-8).
-    pub const MAX_URL_LEN: usize = MAX_URL_LEN;
+        assert!(!observe(&mut repo).unwrap());
+        assert!(observe(&mut repo).unwrap());
+        assert!(!observe(&mut repo).unwrap());
+        assert_eq!(repo.observation_count, SOFT_MAX_OBSERVATIONS_PER_REPO + 1);
+    }
 
-    /// Maximum length of the `tags` field in bytes (UTF-8).
-    pub const MAX_TAGS_LEN: usize = MAX_REPO_TAGS_LEN;
+    #[test]
+    fn score_accumulates_weighted_observations() {
+        assert_eq!(Repo::observation_score(2_500, 3, 2).unwrap(), 2 + 3 + 20);
 
-    /// Total serialized length of the `Repo` account.
-    ///
-    /// String fields are stored as a 4-byte length prefix followed by bytes.
-    /// We allocate the maximum size to keep the layout stable.
-    pub const LEN: usize = Self::DISCRIMINATOR_LEN
-        + 32  // repo_key: Pubkey
-        + 32  // authority: Pubkey
-        + 4 + Self::MAX_NAME_LEN // name: String
-        + 4 + Self::MAX_URL_LEN  // url: String
-        + 4 + Self::MAX_TAGS_LEN // tags: String
-        + 1  // is_active: bool
-        + 1  // allow_observation: bool
-        + 4  // module_count: u32
-        + 8  // observation_count: u64
-        + 8  // total_lines_of_code: u64
-        + 8  // total_files_processed: u64
-        + 8  // created_at: i64
-        + 8  // updated_at: i64
-        + 1  // schema_version: u8
-        + 1  // bump: u8
-        + 62; // reserved: [u8; 62]
+        let mut repo = repo_with_observers(Vec::new());
+        observe(&mut repo).unwrap();
+        observe(&mut repo).unwrap();
+        // 10 LOC round down to 0 points; 1 file + 1 module = 11 per run.
+        assert_eq!(repo.score, 22);
 
-    // -----------------------------------------------------------------------
-    // Initialization
-    // -----------------------------------------------------------------------
+        repo.score = u64::MAX;
+        assert_eq!(observe(&mut repo).unwrap_err(), Unit09Error::CounterOverflow.into());
+    }
 
-    /// Initialize a new repository with the given parameters.
-    ///
-    /// This is typically called from the `register_repo` instruction.
-    pub fn init(
-        &mut self,
-        repo_key: Pubkey,
-        authority: Pubkey,
-        name: String,
-        url: String,
-        tags: String,
-        allow_observation: bool,
-        bump: u8,
-        clock: &Clock,
-    ) -> Result<()> {
-        Self::validate_name(&name)?;
-        Self::validate_url(&url)?;
-        Self::validate_tags(&tags)?;
+    #[test]
+    fn metadata_observation_leaves_aggregates_untouched() {
+        let aggregates = |repo: &Repo| {
+            (
+                repo.observation_count,
+                repo.total_lines_of_code,
+                repo.total_files_processed,
+                repo.score,
+            )
+        };
+        let mut repo = repo_with_observers(Vec::new());
+        observe(&mut repo).unwrap();
+        let before = aggregates(&repo);
+
+        let clock = Clock {
+            slot: 42,
+            unix_timestamp: 5_000,
+            ..Clock::default()
+        };
+        repo.record_metadata_observation("v2".to_string(), "readme".to_string(), 5_000, &clock)
+            .unwrap();
+
+        assert_eq!(repo.last_observed_at, 5_000);
+        assert_eq!(repo.last_observed_slot, 42);
+        assert_eq!(repo.last_revision, "v2");
+        assert_eq!(repo.last_note, "readme");
+        assert_eq!(aggregates(&repo), before);
+    }
 
-        self.repo_key = repo_key;
-        self.authority = authority;
-        self.name = name;
-        self.url = url;
-        self.tags = tags;
-        self.is_active = true;
-        self.allow_observation = allow_observation;
-        self.module_count = 0;
-        self.observation_count = 0;
-        self.total_lines_of_code = 0;
-        self.total_files_processed = 0;
-        self.created_at = clock.unix_timestamp;
-        self.updated_at = clock.unix_timestamp;
-        self.schema_version = CURRENT_SCHEMA_VERSION;
-        self.bump = bump;
-        self.reserved = [0u8; 62];
+    #[test]
+    fn metadata_observation_keeps_histogram_aligned() {
+        let mut repo = repo_with_observers(Vec::new());
+        observe_at(&mut repo, DAY_ONE + 60);
+
+        let metadata_at = DAY_ONE + 2 * SECONDS_PER_DAY;
+        let clock = Clock {
+            unix_timestamp: metadata_at,
+            ..Clock::default()
+        };
+        repo.record_metadata_observation(String::new(), String::new(), metadata_at, &clock)
+            .unwrap();
+        assert_eq!(repo.daily_observations, [0, 0, 1, 0, 0, 0, 0]);
+
+        // A metadata-only backfill neither rotates nor moves the timestamp.
+        repo.record_metadata_observation(String::new(), String::new(), DAY_ONE, &clock)
+            .unwrap();
+        assert_eq!(repo.last_observed_at, metadata_at);
+
+        observe_at(&mut repo, DAY_ONE + 3 * SECONDS_PER_DAY);
+        assert_eq!(repo.daily_observations, [1, 0, 0, 1, 0, 0, 0]);
+    }
 
-        Ok(())
+    #[test]
+    fn hard_cap_rejects_further_observations() {
+        let mut repo = repo_with_observers(Vec::new());
+        repo.observation_count = MAX_OBSERVATIONS_HARD_CAP - 1;
+
+        observe(&mut repo).unwrap();
+        assert_eq!(
+            observe(&mut repo).unwrap_err(),
+            Unit09Error::RepoObservationLimitReached.into()
+        );
+        assert_eq!(repo.observation_count, MAX_OBSERVATIONS_HARD_CAP);
     }
 
-    // -----------------------------------------------------------------------
-    // Metadata Updates
-    // -----------------------------------------------------------------------
+    #[test]
+    fn set_allow_observation_toggles_observation_guard() {
+        let mut repo = repo_with_observers(Vec::new());
+        let clock = Clock::default();
 
-    /// Update the repository metadata fields that are provided as `Some`.
-    ///
-    /// This can be used by `update_repo` and similar instructions.
-    pub fn apply_update(
-        &mut self,
-        maybe_name: Option<String>,
-        maybe_url: Option<String>,
-        maybe_tags: Option<String>,
-        maybe_is_active: Option<bool>,
-        maybe_allow_observation: Option<bool>,
-        clock: &Clock,
-    ) -> Result<()> {
-        if let Some(name) = maybe_name {
-            Self::validate_name(&name)?;
-            self.name = name;
-        }
+        repo.set_allow_observation(false, &clock);
+        assert_eq!(
+            repo.assert_observation_allowed().unwrap_err(),
+            Unit09Error::ObservationNotAllowed.into()
+        );
 
-        if let Some(url) = maybe_url {
-            Self::validate_url(&url)?;
-            self.url = url;
-        }
+        repo.set_allow_observation(true, &clock);
+        assert!(repo.assert_observation_allowed().is_ok());
+    }
 
-        if let Some(tags) = maybe_tags {
-            Self::validate_tags(&tags)?;
-            self.tags = tags;
-        }
+    #[test]
+    fn empty_allow_list_permits_any_observer() {
+        let repo = repo_with_observers(Vec::new());
+        assert!(repo.assert_observer_allowed(&Pubkey::new_unique()).is_ok());
+    }
 
-        if let Some(is_active) = maybe_is_active {
-            self.is_active = is_active;
-        }
+    #[test]
+    fn listed_observer_is_allowed() {
+        let observer = Pubkey::new_unique();
+        let repo = repo_with_observers(vec![Pubkey::new_unique(), observer]);
+        assert!(repo.assert_observer_allowed(&observer).is_ok());
+    }
 
-        if let Some(allow_obs) = maybe_allow_observation {
-            self.allow_observation = allow_obs;
-        }
+    #[test]
+    fn unlisted_observer_is_rejected() {
+        let repo = repo_with_observers(vec![Pubkey::new_unique()]);
+        assert_eq!(
+            repo.assert_observer_allowed(&Pubkey::new_unique())
+                .unwrap_err(),
+            Unit09Error::ObservationNotAllowed.into()
+        );
+    }
 
-        self.updated_at = clock.unix_timestamp;
-        Ok(())
+    #[test]
+    fn allow_list_is_bounded() {
+        let too_many = vec![Pubkey::new_unique(); Repo::MAX_ALLOWED_OBSERVERS + 1];
+        assert!(Repo::validate_allowed_observers(&too_many).is_err());
+        assert!(Repo::validate_allowed_observers(&too_many[1..]).is_ok());
     }
 
-    // -----------------------------------------------------------------------
-    // Authority and Activation Guards
-    // -----------------------------------------------------------------------
+    fn observe_at(repo: &mut Repo, unix_timestamp: i64) {
+        let clock = Clock {
+            unix_timestamp,
+            ..Clock::default()
+        };
+        repo.record_observation(
+            10,
+            1,
+            1,
+            String::new(),
+            String::new(),
+            Pubkey::new_unique(),
+            None,
+            unix_timestamp,
+            &clock,
+        )
+        .unwrap();
+    }
 
-    /// Ensure that the given signer is the authority for this repository.
-    pub fn assert_authority(&self, signer: &Signer) -> Result<()> {
-        if signer.key() != self.authority {
-            return err!(Unit09Error::InvalidAuthority);
-        }
-        Ok(())
+    const DAY_ONE: i64 = 1_700_000_000 - 1_700_000_000 % SECONDS_PER_DAY;
+
+    #[test]
+    fn same_day_observations_share_a_bucket() {
+        let mut repo = repo_with_observers(Vec::new());
+        observe_at(&mut repo, DAY_ONE + 60);
+        observe_at(&mut repo, DAY_ONE + SECONDS_PER_DAY - 1);
+        assert_eq!(repo.daily_observations, [2, 0, 0, 0, 0, 0, 0]);
     }
 
-    /// Ensure that the repository is currently active.
-    pub fn assert_active(&self) -> Result<()> {
-        if !self.is_active {
-            return err!(Unit09Error::RepoInactive);
-        }
-        Ok(())
+    #[test]
+    fn next_day_rotates_buckets() {
+        let mut repo = repo_with_observers(Vec::new());
+        observe_at(&mut repo, DAY_ONE + 60);
+        observe_at(&mut repo, DAY_ONE + 120);
+        observe_at(&mut repo, DAY_ONE + SECONDS_PER_DAY);
+        assert_eq!(repo.daily_observations, [1, 2, 0, 0, 0, 0, 0]);
+
+        observe_at(&mut repo, DAY_ONE + 3 * SECONDS_PER_DAY);
+        assert_eq!(repo.daily_observations, [1, 0, 1, 2, 0, 0, 0]);
     }
 
-    /// Ensure that the repository is allowed to be observed.
-    pub fn assert_observation_allowed(&self) -> Result<()> {
-        if !self.allow_observation {
-            return err!(Unit09Error::ObservationNotAllowed);
-        }
-        Ok(())
+    #[test]
+    fn multi_day_gap_zeroes_buckets() {
+        let mut repo = repo_with_observers(Vec::new());
+        observe_at(&mut repo, DAY_ONE);
+        observe_at(&mut repo, DAY_ONE + SECONDS_PER_DAY);
+        observe_at(
+            &mut repo,
+            DAY_ONE + (1 + OBSERVATION_HISTOGRAM_DAYS as i64) * SECONDS_PER_DAY,
+        );
+        assert_eq!(repo.daily_observations, [1, 0, 0, 0, 0, 0, 0]);
     }
 
-    // -----------------------------------------------------------------------
-    // Module Counters
-    // -----------------------------------------------------------------------
+    #[test]
+    fn backfill_counts_into_its_own_day() {
+        let mut repo = repo_with_observers(Vec::new());
+        observe_at(&mut repo, DAY_ONE + 3 * SECONDS_PER_DAY);
+        observe_at(&mut repo, DAY_ONE + SECONDS_PER_DAY);
+        assert_eq!(repo.daily_observations, [1, 0, 1, 0, 0, 0, 0]);
+        assert_eq!(repo.last_observed_at, DAY_ONE + 3 * SECONDS_PER_DAY);
+        assert_eq!(repo.first_observed_at, DAY_ONE + SECONDS_PER_DAY);
+
+        // Older than the window: counted, but not in the histogram.
+        observe_at(
+            &mut repo,
+            DAY_ONE + 3 * SECONDS_PER_DAY - OBSERVATION_HISTOGRAM_DAYS as i64 * SECONDS_PER_DAY,
+        );
+        assert_eq!(repo.daily_observations, [1, 0, 1, 0, 0, 0, 0]);
+        assert_eq!(repo.observation_count, 3);
+        assert_eq!(repo.last_observed_at, DAY_ONE + 3 * SECONDS_PER_DAY);
+    }
 
-    /// Increment the module count for this repository.
-    ///
-    /// This should be called when a new module is successfully registered.
-    pub fn increment_module_count(&mut self) -> Result<()> {
-        let new_value = self
-            .module_count
-            .checked_add(1)
-            .ok_or(Unit09Error::CounterOverflow)?;
+    #[test]
+    fn duplicate_module_uri_rejected_only_when_enforced() {
+        let mut repo = repo_with_observers(Vec::new());
+        let uri = "https://unit09.org/meta/module/a.json";
+
+        repo.remember_module_uri(uri).unwrap();
+        repo.remember_module_uri(uri).unwrap();
+
+        repo.enforce_unique_module_uri = true;
+        assert_eq!(
+            repo.remember_module_uri(uri).unwrap_err(),
+            Unit09Error::MetadataInvalid.into()
+        );
+        repo.remember_module_uri("https://unit09.org/meta/module/b.json")
+            .unwrap();
+    }
 
-        if new_value > DEFAULT_MAX_MODULES_PER_REPO {
-            return err!(Unit09Error::RepoModuleLimitReached);
+    #[test]
+    fn recent_module_uris_forget_oldest_entries() {
+        let mut repo = repo_with_observers(Vec::new());
+        repo.enforce_unique_module_uri = true;
+
+        for i in 0..=RECENT_MODULE_URI_SLOTS {
+            repo.remember_module_uri(&format!("https://unit09.org/m/{}.json", i))
+                .unwrap();
         }
 
-        self.module_count = new_value;
-        Ok(())
+        // The first URI has been evicted; the most recent ones are still held.
+        repo.remember_module_uri("https://unit09.org/m/0.json").unwrap();
+        assert!(repo
+            .remember_module_uri(&format!(
+                "https://unit09.org/m/{}.json",
+                RECENT_MODULE_URI_SLOTS
+            ))
+            .is_err());
     }
 
-    /// Decrement the module count for this repository, used if you ever add
-    /// soft-deletion or archival of modules.
-    pub fn decrement_module_count(&mut self) -> Result<()> {
-        self.module_count = self
-            .module_count
-            .checked_sub(1)
-            .ok_or(Unit09Error::CounterOverflow)?;
-        Ok(())
-    }
+    #[test]
+    fn primary_module_can_be_set_and_cleared() {
+        let mut repo = repo_with_observers(Vec::new());
+        let module = Pubkey::new_unique();
 
-    // -----------------------------------------------------------------------
-    // Observation Aggregation
-    // -----------------------------------------------------------------------
+        let clock = Clock {
+            unix_timestamp: 10,
+            ..Clock::default()
+        };
 
-    /// Record a single observation result on this repository.
-    ///
-    /// This is used by `record_observation` instruction handlers.
-    pub fn record_observation(
-        &mut self,
-        lines_of_code: u64,
-        files_processed: u32,
-    ) -> Result<()> {
-        // Basic bounds checking using constants
-        if lines_of_code > MAX_LOC_PER_OBSERVATION {
-            return err!(Unit09Error::ObservationDataTooLarge);
+        repo.set_primary_module(Some(module), &clock);
+        assert_eq!(repo.primary_module, Some(module));
+        assert_eq!(repo.updated_at, 10);
+
+        repo.set_primary_module(None, &clock);
+        assert_eq!(repo.primary_module, None);
+    }
+
+    #[test]
+    fn classifies_representative_urls() {
+        let cases = [
+            ("https://github.com/unit09-labs/unit09", RepoHost::GitHub),
+            ("https://www.github.com/unit09-labs/unit09", RepoHost::GitHub),
+            ("git://github.com/unit09-labs/unit09.git", RepoHost::GitHub),
+            ("https://GitLab.com/group/project", RepoHost::GitLab),
+            ("https://bitbucket.org/team/repo", RepoHost::Bitbucket),
+            ("https://user@bitbucket.org:443/team/repo", RepoHost::Bitbucket),
+            ("https://git.example.org/team/repo", RepoHost::SelfHosted),
+            ("https://gitlab.example.org/team/repo", RepoHost::SelfHosted),
+            ("https://example.org/github.com/repo", RepoHost::Other),
+            ("ipfs://bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi", RepoHost::Other),
+        ];
+        for (url, expected) in cases {
+            assert_eq!(RepoHost::classify(url), expected, "{url}");
         }
-        if files_processed as u64 > MAX_FILES_PER_OBSERVATION as u64 {
-            return err!(Unit09Error::ObservationDataTooLarge);
+    }
+
+    #[test]
+    fn normalized_host_ignores_trailing_dots_and_url_tails() {
+        let cases = [
+            ("https://evil.org/x", "evil.org"),
+            ("https://evil.org./x", "evil.org"),
+            ("https://EVIL.org..", "evil.org"),
+            ("https://evil.org?x=1", "evil.org"),
+            ("https://evil.org#frag", "evil.org"),
+            ("https://user@www.evil.org.:8080?x", "evil.org"),
+            ("evil.org.", "evil.org"),
+        ];
+        for (url, expected) in cases {
+            assert_eq!(RepoHost::normalized_host(url), expected, "{url}");
         }
+    }
 
-        // Increment observation count
-        self.observation_count = self
-            .observation_count
-            .checked_add(1)
-            .ok_or(Unit09Error::CounterOverflow)?;
+    #[test]
+    fn url_updates_keep_host_in_sync() {
+        let mut repo = repo_with_observers(Vec::new());
+        repo.apply_update(
+            None,
+            Some("https://gitlab.com/unit09/unit09".to_string()),
+            None,
+            None,
+            None,
+            None,
+            None,
+            &Clock::default(),
+        )
+        .unwrap();
+        assert_eq!(RepoHost::from_u8(repo.host), Some(RepoHost::GitLab));
+    }
 
-        if self.observation_count > SOFT_MAX_OBSERVATIONS_PER_REPO {
-            return err!(Unit09Error::RepoObservationLimitReached);
-        }
+    #[test]
+    fn same_slot_observation_blocks_close() {
+        let mut repo = repo_with_observers(Vec::new());
+        let clock = Clock {
+            slot: 42,
+            unix_timestamp: 1_000,
+            ..Clock::default()
+        };
+        assert!(repo.assert_not_observed_in_slot(&clock).is_ok());
+
+        repo.record_observation(
+            10,
+            1,
+            1,
+            String::new(),
+            String::new(),
+            Pubkey::new_unique(),
+            None,
+            clock.unix_timestamp,
+            &clock,
+        )
+        .unwrap();
+        assert_eq!(repo.last_observed_slot, 42);
+        assert_eq!(
+            repo.assert_not_observed_in_slot(&clock).unwrap_err(),
+            Unit09Error::CooldownActive.into()
+        );
+
+        let next_slot = Clock {
+            slot: 43,
+            ..clock
+        };
+        assert!(repo.assert_not_observed_in_slot(&next_slot).is_ok());
+    }
 
-        // Aggregate lines of code and files
-        self.total_lines_of_code = self
-            .total_lines_of_code
-            .checked_add(lines_of_code)
-            .ok_or(Unit09Error::CounterOverflow)?;
+    #[test]
+    fn same_slot_metadata_observation_blocks_close() {
+        let mut repo = repo_with_observers(Vec::new());
+        let clock = Clock {
+            slot: 42,
+            unix_timestamp: 1_000,
+            ..Clock::default()
+        };
+        repo.record_metadata_observation(String::new(), String::new(), 1_000, &clock)
+            .unwrap();
+        assert_eq!(repo.observation_count, 0);
+        assert_eq!(
+            repo.assert_not_observed_in_slot(&clock).unwrap_err(),
+            Unit09Error::CooldownActive.into()
+        );
+    }
 
-        self.total_files_processed = self
-            .total_files_processed
-            .checked_add(files_processed as u64)
-            .ok_or(Unit09Error::CounterOverflow)?;
+    #[test]
+    fn staleness_follows_last_observation() {
+        let mut repo = repo_with_observers(Vec::new());
+        let clock = Clock {
+            unix_timestamp: 10_000,
+            ..Clock::default()
+        };
+        assert!(!repo.is_stale(&clock, 3_600));
+
+        repo.last_observed_at = 10_000 - 3_600;
+        assert!(!repo.is_stale(&clock, 3_600));
+
+        repo.last_observed_at = 10_000 - 3_601;
+        assert!(repo.is_stale(&clock, 3_600));
+        assert!(!repo.is_stale(&clock, 0));
+    }
 
-        Ok(())
+    #[test]
+    fn fee_override_is_bounded_and_takes_precedence() {
+        let mut repo = repo_with_observers(Vec::new());
+        assert_eq!(repo.effective_fee_bps(100), 100);
+
+        repo.set_fee_bps_override(Some(MAX_FEE_BPS)).unwrap();
+        assert_eq!(repo.effective_fee_bps(100), MAX_FEE_BPS);
+
+        assert_eq!(
+            repo.set_fee_bps_override(Some(MAX_FEE_BPS + 1)).unwrap_err(),
+            Unit09Error::InvalidFeeBps.into()
+        );
+        assert_eq!(repo.fee_bps_override, Some(MAX_FEE_BPS));
+
+        repo.set_fee_bps_override(None).unwrap();
+        assert_eq!(repo.effective_fee_bps(100), 100);
     }
 
-    // -----------------------------------------------------------------------
-    // Validation Helpers
-    // -----------------------------------------------------------------------
+    #[test]
+    fn idempotency_key_replays_only_within_window() {
+        let mut repo = repo_with_observers(Vec::new());
+        let key = [7u8; IDEMPOTENCY_KEY_LEN];
 
-    /// Validate the repository name.
-    fn validate_name(name: &str) -> Result<()> {
-        if name.is_empty() {
-            return err!(Unit09Error::StringEmpty);
-        }
-        if name.len() > Self::MAX_NAME_LEN {
-            return err!(Unit09Error::StringTooLong);
-        }
-        Ok(())
+        assert!(!repo.is_idempotent_replay(&key, 1_000));
+
+        repo.remember_idempotency_key(key, 1_000);
+        assert!(repo.is_idempotent_replay(&key, 1_000));
+        assert!(repo.is_idempotent_replay(&key, 1_000 + IDEMPOTENCY_WINDOW_SECS - 1));
+        assert!(!repo.is_idempotent_replay(&key, 1_000 + IDEMPOTENCY_WINDOW_SECS));
+        assert!(!repo.is_idempotent_replay(&[8u8; IDEMPOTENCY_KEY_LEN], 1_000));
     }
 
-    /// Validate the repository URL with basic checks.
-    fn validate_url(url: &str) -> Result<()> {
-        if url.is_empty() {
-            return err!(Unit09Error::StringEmpty);
-        }
-        if url.len() > Self::MAX_URL_LEN {
-            return err!(Unit09Error::StringTooLong);
-        }
-        // Very basic structural check: must contain at least one dot and "://"
-        if !url.contains("://") || !url.contains('.') {
-            return err!(Unit09Error::InvalidUrl);
-        }
-        Ok(())
+    #[test]
+    fn reserved_repo_stays_inactive_until_finalized() {
+        let mut repo = repo_with_observers(Vec::new());
+        let authority = repo.authority;
+        let clock = Clock {
+            unix_timestamp: 500,
+            ..Clock::default()
+        };
+
+        let url = "https://github.com/a/b".to_string();
+
+        repo.reserve(repo.repo_key, authority, 1, &clock);
+        assert!(repo.is_reserved);
+        assert!(repo.name.is_empty());
+        assert_eq!(repo.assert_active().unwrap_err(), Unit09Error::RepoInactive.into());
+        assert!(repo.assert_not_reserved().is_err());
+
+        assert_eq!(
+            repo.finalize(String::new(), url.clone(), String::new(), String::new(), true, &clock)
+                .unwrap_err(),
+            Unit09Error::StringEmpty.into()
+        );
+        assert!(repo.is_reserved);
+
+        repo.finalize("core".to_string(), url.clone(), String::new(), String::new(), true, &clock)
+            .unwrap();
+        assert!(!repo.is_reserved);
+        assert!(repo.is_active);
+        assert_eq!(repo.host, RepoHost::GitHub.as_u8());
+        assert_eq!(repo.authority, authority);
+
+        assert_eq!(
+            repo.finalize("core".to_string(), url, String::new(), String::new(), true, &clock)
+                .unwrap_err(),
+            Unit09Error::RepoNotReserved.into()
+        );
     }
 
-    /// Validate the tags string.
-    fn validate_tags(tags: &str) -> Result<()> {
-        if tags.len() > Self::MAX_TAGS_LEN {
-            return err!(Unit09Error::StringTooLong);
+    #[test]
+    fn maintainers_are_bounded_and_unique() {
+        let mut repo = repo_with_observers(Vec::new());
+        let authority = repo.authority;
+        let clock = Clock::default();
+        let maintainer = Pubkey::new_unique();
+
+        assert!(repo.assert_maintainer_or_authority(&authority).is_ok());
+        assert_eq!(
+            repo.assert_maintainer_or_authority(&maintainer).unwrap_err(),
+            Unit09Error::InvalidAuthority.into()
+        );
+
+        repo.add_maintainer(maintainer, &clock).unwrap();
+        assert!(repo.assert_maintainer_or_authority(&maintainer).is_ok());
+        assert_eq!(
+            repo.add_maintainer(maintainer, &clock).unwrap_err(),
+            Unit09Error::MaintainerAlreadyAdded.into()
+        );
+        assert_eq!(
+            repo.add_maintainer(authority, &clock).unwrap_err(),
+            Unit09Error::MaintainerAlreadyAdded.into()
+        );
+
+        for _ in 1..Repo::MAX_MAINTAINERS {
+            repo.add_maintainer(Pubkey::new_unique(), &clock).unwrap();
         }
-        Ok(())
+        assert_eq!(
+            repo.add_maintainer(Pubkey::new_unique(), &clock).unwrap_err(),
+            Unit09Error::ValueOutOfRange.into()
+        );
+
+        repo.remove_maintainer(&maintainer, &clock).unwrap();
+        assert!(repo.assert_maintainer_or_authority(&maintainer).is_err());
+        assert_eq!(
+            repo.remove_maintainer(&maintainer, &clock).unwrap_err(),
+            Unit09Error::MaintainerNotFound.into()
+        );
+    }
+
+    #[test]
+    fn zero_source_region_hash_is_rejected() {
+        assert_eq!(
+            Repo::validate_source_region_hash(&[0u8; SOURCE_REGION_HASH_LEN]).unwrap_err(),
+            Unit09Error::MetadataInvalid.into()
+        );
+        let mut hash = [0u8; SOURCE_REGION_HASH_LEN];
+        hash[SOURCE_REGION_HASH_LEN - 1] = 1;
+        assert!(Repo::validate_source_region_hash(&hash).is_ok());
+    }
+
+    #[test]
+    fn access_proof_is_required_only_when_configured() {
+        let mut repo = repo_with_observers(Vec::new());
+        let proof = [7u8; 32];
+
+        assert!(repo.assert_access_proof(None).is_ok());
+        assert!(repo.assert_access_proof(Some(&proof)).is_ok());
+
+        repo.require_access_proof = true;
+        assert!(repo.assert_access_proof(Some(&proof)).is_ok());
+        assert_eq!(
+            repo.assert_access_proof(None).unwrap_err(),
+            Unit09Error::ObservationNotAllowed.into()
+        );
     }
 }
@@ -0,0 +1,125 @@
+//! ===========================================================================
+//! Unit09 – Repo Name Index State
+//! Path: contracts/unit09-program/programs/unit09_program/src/state/repo_name_index.rs
+//!
+//! Reservation of a repository name, letting `register_repo` reject a name
+//! that another repository of the deployment already uses (`RepoNameTaken`).
+//!
+//! Each `RepoNameIndex` is a PDA derived from:
+//!     seed: REPO_NAME_INDEX_SEED
+//!     key:  SHA-256 hash of the exact name bytes (see `name_hash`)
+//!
+//! The index is created by `register_repo` (or `finalize_repo`) itself when
+//! passed, and is mandatory while `Config::enforce_unique_repo_name` is set.
+//! Names are compared byte for byte, so `"unit09"` and `"Unit09"` are
+//! distinct.
+//!
+//! There is no instruction closing a `Repo` yet. Once there is, it must
+//! close the repository's name index as well so the name becomes free.
+//! Until then `update_repo` refuses to rename a repository holding its
+//! name's index, and any rename while names are enforced.
+//!
+//! ===========================================================================
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+
+use crate::constants::*;
+use crate::errors::Unit09Error;
+use crate::utils::size::assert_fits_len;
+
+/// Reservation of one repository name.
+#[account]
+pub struct RepoNameIndex {
+    /// `Repo` account holding the name; the default pubkey while unclaimed.
+    pub repo: Pubkey,
+
+    /// SHA-256 hash of the reserved name.
+    pub name_hash: [u8; 32],
+
+    /// Unix timestamp when the name was claimed.
+    pub created_at: i64,
+
+    /// Schema version for this account layout.
+    pub schema_version: u8,
+
+    /// Bump used for PDA derivation.
+    pub bump: u8,
+
+    /// Reserved bytes for future upgrades.
+    pub reserved: [u8; 32],
+}
+
+impl RepoNameIndex {
+    /// Discriminator length used by Anchor.
+    pub const DISCRIMINATOR_LEN: usize = 8;
+
+    /// Total serialized length for the `RepoNameIndex` account.
+    pub const LEN: usize = Self::DISCRIMINATOR_LEN
+        + 32 // repo: Pubkey
+        + 32 // name_hash: [u8; 32]
+        + 8  // created_at: i64
+        + 1  // schema_version: u8
+        + 1  // bump: u8
+        + 32; // reserved: [u8; 32]
+
+    /// PDA key component for `name`.
+    pub fn name_hash(name: &str) -> [u8; 32] {
+        hash(name.as_bytes()).to_bytes()
+    }
+
+    /// Claim `name` for `repo` on a freshly created index.
+    ///
+    /// Fails with `RepoNameTaken` when the index already belongs to a
+    /// repository.
+    pub fn claim(&mut self, repo: Pubkey, name: &str, bump: u8, clock: &Clock) -> Result<()> {
+        if self.repo != Pubkey::default() {
+            return err!(Unit09Error::RepoNameTaken);
+        }
+
+        self.repo = repo;
+        self.name_hash = Self::name_hash(name);
+        self.created_at = clock.unix_timestamp;
+        self.schema_version = CURRENT_SCHEMA_VERSION;
+        self.bump = bump;
+        self.reserved = [0u8; 32];
+        assert_fits_len(self, Self::LEN);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn unclaimed_index() -> RepoNameIndex {
+        RepoNameIndex {
+            repo: Pubkey::default(),
+            name_hash: [0u8; 32],
+            created_at: 0,
+            schema_version: 0,
+            bump: 0,
+            reserved: [0u8; 32],
+        }
+    }
+
+    #[test]
+    fn name_can_be_claimed_once() {
+        let mut index = unclaimed_index();
+        let first = Pubkey::new_unique();
+
+        index
+            .claim(first, "unit09-core", 255, &Clock::default())
+            .unwrap();
+        assert_eq!(index.repo, first);
+        assert_eq!(index.name_hash, RepoNameIndex::name_hash("unit09-core"));
+
+        assert_eq!(
+            index
+                .claim(Pubkey::new_unique(), "unit09-core", 255, &Clock::default())
+                .unwrap_err(),
+            Unit09Error::RepoNameTaken.into()
+        );
+        assert_eq!(index.repo, first);
+    }
+}
@@ -0,0 +1,45 @@
+//! ===========================================================================
+//! Unit09 – Fee Utilities
+//! Path: contracts/unit09-program/programs/unit09_program/src/utils/fees.rs
+//!
+//! This module contains the arithmetic behind `Config::fee_bps`.
+//!
+//! Fees are expressed in basis points (see `BPS_DENOMINATOR`) and always
+//! rounded down, so a payer is never charged more than the configured rate.
+//!
+//! ===========================================================================
+
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::Unit09Error;
+
+/// Compute the fee owed on `amount` at `fee_bps`, rounding down.
+///
+/// Fails with `InvalidFeeBps` if `fee_bps` exceeds `MAX_FEE_BPS`.
+pub fn apply_fee_bps(amount: u64, fee_bps: u16) -> Result<u64> {
+    require!(fee_bps <= MAX_FEE_BPS, Unit09Error::InvalidFeeBps);
+
+    // u128 intermediate so `amount * fee_bps` cannot overflow.
+    let fee = (amount as u128) * (fee_bps as u128) / (BPS_DENOMINATOR as u128);
+
+    u64::try_from(fee).map_err(|_| error!(Unit09Error::CounterOverflow))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn computes_fee_and_rounds_down() {
+        assert_eq!(apply_fee_bps(100_000_000, 250).unwrap(), 2_500_000);
+        assert_eq!(apply_fee_bps(399, 250).unwrap(), 9);
+        assert_eq!(apply_fee_bps(1_000, 0).unwrap(), 0);
+        assert_eq!(apply_fee_bps(u64::MAX, 5_000).unwrap(), u64::MAX / 2);
+    }
+
+    #[test]
+    fn rejects_fee_bps_above_maximum() {
+        assert!(apply_fee_bps(1_000, MAX_FEE_BPS + 1).is_err());
+    }
+}
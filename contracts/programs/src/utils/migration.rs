@@ -0,0 +1,76 @@
+//! ===========================================================================
+//! Unit09 – Account Migration Helpers
+//! Path: contracts/unit09-program/programs/unit09_program/src/utils/migration.rs
+//!
+//! Shared plumbing for the `migrate_*` instructions.
+//!
+//! Fields added after schema version 1 are appended after the version-1
+//! fields of each account (just before `reserved`). A version-1 account is
+//! therefore upgraded by growing it to the current `LEN`: the new bytes are
+//! zero, and the appended fields decode from zeros as empty strings and
+//! vectors, `None`, `false` and `0`. Each `migrate` step then sets the fields
+//! whose zero value is not the legacy behavior.
+//!
+//! ===========================================================================
+
+use anchor_lang::error::ErrorCode;
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+
+use crate::errors::Unit09Error;
+
+/// Grow a program-owned `account` to `len` bytes and load it as `T`.
+///
+/// The added bytes are zero-initialized and `payer` tops the account up to
+/// the rent-exempt minimum for `len`. Accounts already at least `len` bytes
+/// long are loaded as they are.
+///
+/// Fails with `InvalidAccountOwner` when `account` is not owned by this
+/// program. The caller still has to verify the PDA seeds of the result.
+pub fn load_for_migration<'info, T>(
+    account: &AccountInfo<'info>,
+    len: usize,
+    payer: &Signer<'info>,
+    system_program: &Program<'info, System>,
+) -> Result<Account<'info, T>>
+where
+    T: AccountSerialize + AccountDeserialize + Owner + Clone,
+{
+    if account.owner != &crate::ID {
+        return err!(Unit09Error::InvalidAccountOwner);
+    }
+
+    if account.data_len() < len {
+        let required = Rent::get()?.minimum_balance(len);
+        let missing = required.saturating_sub(account.lamports());
+        if missing > 0 {
+            let cpi_ctx = CpiContext::new(
+                system_program.to_account_info(),
+                Transfer {
+                    from: payer.to_account_info(),
+                    to: account.clone(),
+                },
+            );
+            system_program::transfer(cpi_ctx, missing)?;
+        }
+        account.realloc(len, true)?;
+    }
+
+    Account::try_from(account)
+}
+
+/// Ensure `account` is the PDA of `seeds` plus `bump` under this program.
+///
+/// Fails with Anchor's `ConstraintSeeds`, like a `seeds` constraint would.
+pub fn assert_migrated_pda(account: &Pubkey, seeds: &[&[u8]], bump: u8) -> Result<()> {
+    let bump_seed = [bump];
+    let mut full_seeds = seeds.to_vec();
+    full_seeds.push(&bump_seed);
+
+    let expected = Pubkey::create_program_address(&full_seeds, &crate::ID)
+        .map_err(|_| error!(ErrorCode::ConstraintSeeds))?;
+    if expected != *account {
+        return err!(ErrorCode::ConstraintSeeds);
+    }
+    Ok(())
+}
@@ -0,0 +1,38 @@
+//! ===========================================================================
+//! Unit09 – Numeric Conversion Helpers
+//! Path: contracts/unit09-program/programs/unit09_program/src/utils/num.rs
+//!
+//! Aggregates are accumulated as `u64`, while a few stored fields and event
+//! payloads are `u32`. Narrowing with `as` silently truncates, so any `u64`
+//! written into a `u32` field should go through these helpers instead.
+//!
+//! ===========================================================================
+
+use anchor_lang::prelude::*;
+
+use crate::errors::Unit09Error;
+
+/// Convert `value` to `u32`, failing with `CounterOverflow` if it does not fit.
+pub fn checked_u32(value: u64) -> Result<u32> {
+    u32::try_from(value).map_err(|_| error!(Unit09Error::CounterOverflow))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepts_values_up_to_u32_max() {
+        assert_eq!(checked_u32(0).unwrap(), 0);
+        assert_eq!(checked_u32(u32::MAX as u64).unwrap(), u32::MAX);
+    }
+
+    #[test]
+    fn rejects_values_above_u32_max() {
+        assert_eq!(
+            checked_u32(u32::MAX as u64 + 1).unwrap_err(),
+            Unit09Error::CounterOverflow.into()
+        );
+        assert!(checked_u32(u64::MAX).is_err());
+    }
+}
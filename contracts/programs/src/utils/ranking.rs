@@ -0,0 +1,85 @@
+//! ===========================================================================
+//! Unit09 – Ranking Helpers
+//! Path: contracts/unit09-program/programs/unit09_program/src/utils/ranking.rs
+//!
+//! Ordering shared by the approximate "top N" queries, such as the most
+//! common tags returned by `summarize_repo_tags`.
+//!
+//! Rule:
+//! - higher counts rank first
+//! - equal counts are ordered by key, ascending: lexicographic byte order
+//!   for strings (`"anchor"` before `"cli"`), byte order for pubkeys
+//!
+//! The result depends only on the tallied data, never on the order in which
+//! accounts were passed, so two callers summarizing the same accounts get
+//! the same ranking and the same cut-off at `limit`.
+//!
+//! ===========================================================================
+
+use core::cmp::Ordering;
+
+/// An entry that can be ranked by `rank_top`.
+pub trait Ranked {
+    /// Key breaking ties between equal counts; smaller keys rank first.
+    type Key: Ord + ?Sized;
+
+    /// Count the entry is ranked by; larger counts rank first.
+    fn rank_count(&self) -> u64;
+
+    /// Tie-break key of the entry.
+    fn rank_key(&self) -> &Self::Key;
+}
+
+/// Compare two entries by the ranking rule: count descending, then key
+/// ascending.
+pub fn rank_order<T: Ranked>(a: &T, b: &T) -> Ordering {
+    b.rank_count()
+        .cmp(&a.rank_count())
+        .then_with(|| a.rank_key().cmp(b.rank_key()))
+}
+
+/// Sort `entries` by `rank_order` and keep the first `limit`.
+pub fn rank_top<T: Ranked>(entries: &mut Vec<T>, limit: usize) {
+    entries.sort_by(rank_order);
+    entries.truncate(limit);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    struct Entry(&'static str, u64);
+
+    impl Ranked for Entry {
+        type Key = str;
+
+        fn rank_count(&self) -> u64 {
+            self.1
+        }
+
+        fn rank_key(&self) -> &str {
+            self.0
+        }
+    }
+
+    #[test]
+    fn ties_are_broken_by_key_whatever_the_input_order() {
+        let expected = vec![Entry("shared", 3), Entry("anchor", 1), Entry("cli", 1)];
+
+        let mut seen_first = vec![Entry("cli", 1), Entry("shared", 3), Entry("anchor", 1)];
+        rank_top(&mut seen_first, 3);
+        assert_eq!(seen_first, expected);
+
+        let mut seen_last = vec![Entry("anchor", 1), Entry("cli", 1), Entry("shared", 3)];
+        rank_top(&mut seen_last, 3);
+        assert_eq!(seen_last, expected);
+    }
+
+    #[test]
+    fn the_cut_off_keeps_the_smallest_tied_keys() {
+        let mut entries = vec![Entry("sdk", 2), Entry("cli", 2), Entry("anchor", 2)];
+        rank_top(&mut entries, 2);
+        assert_eq!(entries, vec![Entry("anchor", 2), Entry("cli", 2)]);
+    }
+}
@@ -0,0 +1,82 @@
+//! ===========================================================================
+//! Unit09 – Rent Utilities
+//! Path: contracts/unit09-program/programs/unit09_program/src/utils/rent.rs
+//!
+//! This module maps each Unit09 account type to its allocated size and
+//! computes the rent-exempt minimum balance for it.
+//!
+//! Clients that fund PDAs ahead of time can use the `estimate_rents`
+//! instruction (which is built on these helpers) instead of hard-coding
+//! account sizes that may drift across schema versions.
+//!
+//! ===========================================================================
+
+use anchor_lang::prelude::*;
+
+use crate::state::{Authority, Config, Fork, Lifecycle, Metrics, Module, ModuleVersion, Repo};
+
+/// Account types whose size is known to the program.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AccountKind {
+    Config,
+    Metrics,
+    Lifecycle,
+    Repo,
+    Module,
+    ModuleVersion,
+    Fork,
+    Authority,
+}
+
+impl AccountKind {
+    /// Allocated size in bytes (including the Anchor discriminator).
+    pub fn space(self) -> usize {
+        match self {
+            AccountKind::Config => Config::LEN,
+            AccountKind::Metrics => Metrics::LEN,
+            AccountKind::Lifecycle => Lifecycle::LEN,
+            AccountKind::Repo => Repo::LEN,
+            AccountKind::Module => Module::LEN,
+            AccountKind::ModuleVersion => ModuleVersion::LEN,
+            AccountKind::Fork => Fork::LEN,
+            AccountKind::Authority => Authority::LEN,
+        }
+    }
+}
+
+/// Return the rent-exempt minimum balance (in lamports) for the given
+/// account type.
+pub fn rent_exempt_minimum(rent: &Rent, account_kind: AccountKind) -> u64 {
+    rent.minimum_balance(account_kind.space())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_rent_minimum_balance_for_each_kind() {
+        let rent = Rent::default();
+
+        assert_eq!(
+            rent_exempt_minimum(&rent, AccountKind::Config),
+            rent.minimum_balance(Config::LEN)
+        );
+        assert_eq!(
+            rent_exempt_minimum(&rent, AccountKind::Metrics),
+            rent.minimum_balance(Metrics::LEN)
+        );
+        assert_eq!(
+            rent_exempt_minimum(&rent, AccountKind::Repo),
+            rent.minimum_balance(Repo::LEN)
+        );
+        assert_eq!(
+            rent_exempt_minimum(&rent, AccountKind::Module),
+            rent.minimum_balance(Module::LEN)
+        );
+        assert_eq!(
+            rent_exempt_minimum(&rent, AccountKind::Fork),
+            rent.minimum_balance(Fork::LEN)
+        );
+    }
+}
@@ -186,15 +186,42 @@ pub fn module_seeds<'a>(
 
 /// Derive the PDA for a `ModuleVersion` account.
 ///
-/// The seed layout encodes module identity plus semantic version components:
+/// The seed layout encodes module identity plus the snapshot's creation
+/// index, so the versions of a module are `0..Module::version_count`:
 ///
 /// Seeds:
 /// - `MODULE_VERSION_SEED.as_bytes()`
 /// - `module_pubkey.as_ref()`
-/// - `major.to_le_bytes()`
-/// - `minor.to_le_bytes()`
-/// - `patch.to_le_bytes()`
+/// - `index.to_le_bytes()`
 pub fn module_version_pda(
+    program_id: &Pubkey,
+    module_pubkey: &Pubkey,
+    index: u32,
+) -> (Pubkey, u8) {
+    let index_bytes = index.to_le_bytes();
+
+    Pubkey::find_program_address(
+        &[
+            MODULE_VERSION_SEED.as_bytes(),
+            module_pubkey.as_ref(),
+            &index_bytes,
+        ],
+        program_id,
+    )
+}
+
+/// Derive the schema 1 PDA of a module's initial `ModuleVersion`.
+///
+/// Before snapshots were indexed, `register_module` seeded the initial
+/// snapshot by its semantic version. Those accounts were not moved: on a
+/// module migrated with its legacy snapshot, index 0 lives here instead of
+/// at `module_version_pda(.., 0)`, and new snapshots start at index 1.
+///
+/// Seeds:
+/// - `MODULE_VERSION_SEED.as_bytes()`
+/// - `module_pubkey.as_ref()`
+/// - `major.to_le_bytes()`, `minor.to_le_bytes()`, `patch.to_le_bytes()`
+pub fn legacy_module_version_pda(
     program_id: &Pubkey,
     module_pubkey: &Pubkey,
     major: u16,
@@ -219,21 +246,15 @@ pub fn module_version_pda(
 
 pub fn module_version_seeds<'a>(
     module_pubkey: &Pubkey,
-    major: u16,
-    minor: u16,
-    patch: u16,
+    index: u32,
     bump: u8,
 ) -> SeedSlice<'a> {
-    let major_bytes = major.to_le_bytes();
-    let minor_bytes = minor.to_le_bytes();
-    let patch_bytes = patch.to_le_bytes();
+    let index_bytes = index.to_le_bytes();
 
     &[
         MODULE_VERSION_SEED.as_bytes(),
         module_pubkey.as_ref(),
-        &major_bytes,
-        &minor_bytes,
-        &patch_bytes,
+        &index_bytes,
         &[bump],
     ]
 }
@@ -0,0 +1,92 @@
+//! ===========================================================================
+//! Unit09 – Account Size Self-Check
+//! Path: contracts/unit09-program/programs/unit09_program/src/utils/size.rs
+//!
+//! Every account type declares its allocated size as a hand-maintained
+//! `LEN` constant. A field added without updating `LEN` under-allocates the
+//! account, which only shows up later as a failed write or a rent mismatch.
+//!
+//! `assert_fits_len` catches that at `init` time: each `init` serializes the
+//! freshly written account and panics when it does not fit `LEN`.
+//!
+//! The check is debug-only. It is compiled in with the `size-check` cargo
+//! feature and in unit tests, and is a no-op otherwise, so release builds
+//! pay no compute units for it.
+//!
+//! ===========================================================================
+
+use anchor_lang::prelude::*;
+
+/// Anchor account discriminator prepended to every serialized account.
+const DISCRIMINATOR_LEN: usize = 8;
+
+/// Serialized size of `account` in bytes, including the discriminator.
+///
+/// Variable-length fields (strings, vectors) count at their current length.
+pub fn serialized_len<T: AnchorSerialize>(account: &T) -> usize {
+    let body = account
+        .try_to_vec()
+        .map(|bytes| bytes.len())
+        .unwrap_or(usize::MAX - DISCRIMINATOR_LEN);
+    DISCRIMINATOR_LEN + body
+}
+
+/// Panic when `account` serializes to more than its declared `len`.
+///
+/// Only compiled with the `size-check` feature or in tests; see the module
+/// docs.
+#[cfg(any(test, feature = "size-check"))]
+pub fn assert_fits_len<T: AnchorSerialize>(account: &T, len: usize) {
+    let size = serialized_len(account);
+    assert!(
+        size <= len,
+        "account serializes to {} bytes but its LEN is {}",
+        size,
+        len
+    );
+}
+
+/// No-op without the `size-check` feature.
+#[cfg(not(any(test, feature = "size-check")))]
+#[inline(always)]
+pub fn assert_fits_len<T: AnchorSerialize>(_account: &T, _len: usize) {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(AnchorSerialize)]
+    struct Sample {
+        counter: u64,
+        label: String,
+    }
+
+    impl Sample {
+        /// Deliberately misses the 4-byte string length prefix.
+        const WRONG_LEN: usize = DISCRIMINATOR_LEN + 8 + 16;
+        const LEN: usize = DISCRIMINATOR_LEN + 8 + 4 + 16;
+    }
+
+    fn sample() -> Sample {
+        Sample {
+            counter: 7,
+            label: "x".repeat(16),
+        }
+    }
+
+    #[test]
+    fn serialized_len_includes_discriminator() {
+        assert_eq!(serialized_len(&sample()), Sample::LEN);
+    }
+
+    #[test]
+    fn correct_len_passes() {
+        assert_fits_len(&sample(), Sample::LEN);
+    }
+
+    #[test]
+    #[should_panic(expected = "its LEN is")]
+    fn wrong_len_is_caught() {
+        assert_fits_len(&sample(), Sample::WRONG_LEN);
+    }
+}
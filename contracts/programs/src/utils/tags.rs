@@ -0,0 +1,207 @@
+//! ===========================================================================
+//! Unit09 – Tag Utilities
+//! Path: contracts/unit09-program/programs/unit09_program/src/utils/tags.rs
+//!
+//! Helpers for the comma-separated tag strings stored on repositories and
+//! modules (for example `"solana,anchor,unit09"`).
+//!
+//! Normalized form:
+//! - entries are trimmed and lowercased
+//! - empty entries are dropped
+//! - duplicates are removed, keeping the first occurrence
+//! - entries are joined with `,` and no surrounding whitespace
+//!
+//! Tags from `TAG_VOCABULARY` can additionally be stored as a `u64`
+//! bitmask (`tag_bits`), where vocabulary entry `i` is bit `1 << i`.
+//!
+//! ===========================================================================
+
+use anchor_lang::prelude::*;
+
+use crate::constants::TAG_VOCABULARY;
+use crate::errors::Unit09Error;
+
+/// Return the normalized entries of a tag string, in original order.
+pub fn normalized_tag_list(tags: &str) -> Vec<String> {
+    let mut out: Vec<String> = Vec::new();
+    for raw in tags.split(',') {
+        let tag = raw.trim().to_ascii_lowercase();
+        if !tag.is_empty() && !out.contains(&tag) {
+            out.push(tag);
+        }
+    }
+    out
+}
+
+/// Normalize a tag string (see module docs for the rules).
+pub fn normalize_tags(tags: &str) -> String {
+    normalized_tag_list(tags).join(",")
+}
+
+/// Whether `tag` is one of the comma-separated entries of `tags`.
+///
+/// Entries are compared whole, ignoring surrounding whitespace and ASCII
+/// case, so `"solana"` does not match `"solana-x"`. An empty `tag` never
+/// matches. This is the matching rule used by `repo_has_tag`.
+pub fn has_tag(tags: &str, tag: &str) -> bool {
+    let tag = tag.trim();
+    !tag.is_empty()
+        && tags
+            .split(',')
+            .any(|entry| entry.trim().eq_ignore_ascii_case(tag))
+}
+
+/// Merge `inherited` tags into `own` tags.
+///
+/// The result is normalized: `own` entries come first, followed by any
+/// `inherited` entries not already present. Fails with `StringTooLong` if
+/// the merged string exceeds `max_len` bytes.
+pub fn merge_tags(own: &str, inherited: &str, max_len: usize) -> Result<String> {
+    let mut merged = normalized_tag_list(own);
+    for tag in normalized_tag_list(inherited) {
+        if !merged.contains(&tag) {
+            merged.push(tag);
+        }
+    }
+
+    let joined = merged.join(",");
+    require!(joined.len() <= max_len, Unit09Error::StringTooLong);
+    Ok(joined)
+}
+
+/// Replace tag `old` with `new` in a tag string.
+///
+/// Both names are matched after normalization. Returns `None` when `old` is
+/// not present; otherwise the normalized result, where `new` takes the
+/// position of `old` unless it was already present earlier.
+pub fn rename_tag(tags: &str, old: &str, new: &str) -> Option<String> {
+    let old = old.trim().to_ascii_lowercase();
+    let new = new.trim().to_ascii_lowercase();
+
+    let list = normalized_tag_list(tags);
+    if !list.contains(&old) {
+        return None;
+    }
+
+    let renamed: Vec<String> = list
+        .into_iter()
+        .map(|tag| if tag == old { new.clone() } else { tag })
+        .collect();
+    Some(normalize_tags(&renamed.join(",")))
+}
+
+/// Bit index of `tag` in `TAG_VOCABULARY`, matched after normalization.
+pub fn tag_bit_index(tag: &str) -> Option<u8> {
+    let tag = tag.trim().to_ascii_lowercase();
+    TAG_VOCABULARY
+        .iter()
+        .position(|known| *known == tag)
+        .map(|index| index as u8)
+}
+
+/// Vocabulary entry for bit `index`, if any.
+pub fn tag_bit_name(index: u8) -> Option<&'static str> {
+    TAG_VOCABULARY.get(index as usize).copied()
+}
+
+/// Bitmask of the vocabulary tags present in a tag string.
+///
+/// Tags outside the vocabulary are ignored.
+pub fn tag_bits_for(tags: &str) -> u64 {
+    normalized_tag_list(tags)
+        .iter()
+        .filter_map(|tag| tag_bit_index(tag))
+        .fold(0u64, |bits, index| bits | (1u64 << index))
+}
+
+/// Vocabulary tag names whose bits are set in `bits`, in vocabulary order.
+pub fn tag_names_for(bits: u64) -> Vec<&'static str> {
+    TAG_VOCABULARY
+        .iter()
+        .enumerate()
+        .filter(|(index, _)| bits & (1u64 << index) != 0)
+        .map(|(_, name)| *name)
+        .collect()
+}
+
+/// Ensure `bits` only uses bits that map to a vocabulary entry.
+///
+/// Fails with `ValueOutOfRange` otherwise.
+pub fn validate_tag_bits(bits: u64) -> Result<()> {
+    let known_mask = if TAG_VOCABULARY.len() >= 64 {
+        u64::MAX
+    } else {
+        (1u64 << TAG_VOCABULARY.len()) - 1
+    };
+    require!(bits & !known_mask == 0, Unit09Error::ValueOutOfRange);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalizes_case_whitespace_and_duplicates() {
+        assert_eq!(normalize_tags(" Solana, anchor ,,SOLANA,unit09 "), "solana,anchor,unit09");
+        assert_eq!(normalize_tags(""), "");
+    }
+
+    #[test]
+    fn has_tag_matches_whole_entries_only() {
+        let tags = "solana-x,Anchor, unit09";
+        assert!(has_tag(tags, "anchor"));
+        assert!(has_tag(tags, " UNIT09 "));
+        assert!(!has_tag(tags, "indexer"));
+        assert!(!has_tag(tags, "solana"));
+        assert!(!has_tag(tags, ""));
+        assert!(!has_tag("", "solana"));
+    }
+
+    #[test]
+    fn merge_keeps_own_order_and_dedups_inherited() {
+        let merged = merge_tags("router,Solana", "solana,anchor,router,indexer", 128).unwrap();
+        assert_eq!(merged, "router,solana,anchor,indexer");
+    }
+
+    #[test]
+    fn merge_rejects_result_over_limit() {
+        assert!(merge_tags("alpha", "beta,gamma", 10).is_err());
+        assert_eq!(merge_tags("alpha", "beta", 10).unwrap(), "alpha,beta");
+    }
+
+    #[test]
+    fn rename_replaces_in_place_and_dedups() {
+        assert_eq!(rename_tag("solana,Legacy,anchor", "legacy", "core").unwrap(), "solana,core,anchor");
+        assert_eq!(rename_tag("core,legacy", "LEGACY", "core").unwrap(), "core");
+        assert_eq!(rename_tag("solana,anchor", "legacy", "core"), None);
+    }
+
+    #[test]
+    fn vocabulary_names_round_trip_through_bits() {
+        assert!(TAG_VOCABULARY.len() <= 64);
+        for (index, name) in TAG_VOCABULARY.iter().enumerate() {
+            assert_eq!(tag_bit_index(name), Some(index as u8));
+            assert_eq!(tag_bit_name(index as u8), Some(*name));
+            assert_eq!(tag_names_for(1u64 << index), vec![*name]);
+        }
+        assert_eq!(tag_bit_index("not-a-known-tag"), None);
+        assert_eq!(tag_bit_name(TAG_VOCABULARY.len() as u8), None);
+    }
+
+    #[test]
+    fn tag_string_maps_to_known_bits_only() {
+        let bits = tag_bits_for(" Solana,custom,ANCHOR ");
+        let solana = 1u64 << tag_bit_index("solana").unwrap();
+        let anchor = 1u64 << tag_bit_index("anchor").unwrap();
+        assert_eq!(bits, solana | anchor);
+        assert_eq!(tag_names_for(bits), vec!["solana", "anchor"]);
+        assert_eq!(tag_bits_for(""), 0);
+    }
+
+    #[test]
+    fn unknown_bits_are_rejected() {
+        assert!(validate_tag_bits(1).is_ok());
+        assert!(validate_tag_bits(1u64 << TAG_VOCABULARY.len()).is_err());
+    }
+}
@@ -11,6 +11,7 @@
 //!     * “age in seconds”
 //!     * “is within window”
 //!     * “has expired”
+//! - Reject unusable clocks in write handlers (`current_checked`)
 //!
 //! All functions operate on i64 (the type used by `Clock::unix_timestamp`).
 //!
@@ -27,6 +28,27 @@ pub fn now(clock: &Clock) -> i64 {
     clock.unix_timestamp
 }
 
+/// Return the current Unix timestamp, rejecting an unusable clock.
+///
+/// A non-positive `unix_timestamp` only shows up on misconfigured local
+/// validators or test harnesses. Write handlers call this before storing
+/// timestamps so such a clock fails with `InternalError` instead of writing
+/// zero or negative `created_at` / `updated_at` values.
+pub fn current_checked(clock: &Clock) -> Result<i64> {
+    let current = now(clock);
+    require!(current > 0, Unit09Error::InternalError);
+    Ok(current)
+}
+
+/// Like `current_checked`, but also reject a clock that is behind
+/// `last_ts`, the last timestamp stored on the account being written
+/// (`InvalidTimeRange`).
+pub fn current_checked_since(clock: &Clock, last_ts: i64) -> Result<i64> {
+    let current = current_checked(clock)?;
+    assert_time_order(last_ts, current)?;
+    Ok(current)
+}
+
 /// Compute the age (in seconds) of a given timestamp relative to `clock`.
 ///
 /// - `created_at` is expected to be a Unix timestamp in seconds.
@@ -159,3 +181,37 @@ pub fn clamp_to_past(clock: &Clock, ts: i64) -> i64 {
 pub fn add_offset_saturating(ts: i64, offset_secs: i64) -> i64 {
     ts.saturating_add(offset_secs)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn clock_at(unix_timestamp: i64) -> Clock {
+        Clock {
+            unix_timestamp,
+            ..Clock::default()
+        }
+    }
+
+    #[test]
+    fn current_checked_rejects_non_positive_timestamps() {
+        assert_eq!(current_checked(&clock_at(1_000)).unwrap(), 1_000);
+        assert_eq!(
+            current_checked(&clock_at(0)).unwrap_err(),
+            Unit09Error::InternalError.into()
+        );
+        assert_eq!(
+            current_checked(&clock_at(-5)).unwrap_err(),
+            Unit09Error::InternalError.into()
+        );
+    }
+
+    #[test]
+    fn current_checked_since_rejects_regressing_clock() {
+        assert_eq!(current_checked_since(&clock_at(1_000), 1_000).unwrap(), 1_000);
+        assert_eq!(
+            current_checked_since(&clock_at(999), 1_000).unwrap_err(),
+            Unit09Error::InvalidTimeRange.into()
+        );
+    }
+}
@@ -0,0 +1,81 @@
+//! ===========================================================================
+//! Unit09 – Partial Update Helpers
+//! Path: contracts/unit09-program/programs/unit09_program/src/utils/update.rs
+//!
+//! `apply_update` methods on state accounts take one `Option` per field and
+//! only touch fields passed as `Some`. This module captures that pattern so
+//! a validator cannot be forgotten when a new field is added.
+//!
+//! Usage (inside a function returning `Result`):
+//!
+//! ```ignore
+//! apply_if_some!(self.name, maybe_name, Self::validate_name);
+//! apply_if_some!(self.is_active, maybe_is_active);
+//! ```
+//!
+//! The validator is called with a reference to the new value and its error
+//! is propagated with `?` before anything is assigned.
+//!
+//! ===========================================================================
+
+/// Assign `$maybe` to `$target` when it is `Some`, optionally running
+/// `$validator(&value)?` first. `None` leaves `$target` untouched.
+macro_rules! apply_if_some {
+    ($target:expr, $maybe:expr) => {
+        if let Some(value) = $maybe {
+            $target = value;
+        }
+    };
+    ($target:expr, $maybe:expr, $validator:expr) => {
+        if let Some(value) = $maybe {
+            $validator(&value)?;
+            $target = value;
+        }
+    };
+}
+
+pub(crate) use apply_if_some;
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::prelude::*;
+
+    use super::apply_if_some;
+    use crate::errors::Unit09Error;
+
+    fn reject_empty(value: &str) -> Result<()> {
+        require!(!value.is_empty(), Unit09Error::StringEmpty);
+        Ok(())
+    }
+
+    fn update(field: &mut String, maybe: Option<String>) -> Result<()> {
+        apply_if_some!(*field, maybe, reject_empty);
+        Ok(())
+    }
+
+    #[test]
+    fn assigns_only_on_some() {
+        let mut field = "old".to_string();
+
+        update(&mut field, None).unwrap();
+        assert_eq!(field, "old");
+
+        update(&mut field, Some("new".to_string())).unwrap();
+        assert_eq!(field, "new");
+
+        let mut flag = false;
+        apply_if_some!(flag, Some(true));
+        assert!(flag);
+        apply_if_some!(flag, None::<bool>);
+        assert!(flag);
+    }
+
+    #[test]
+    fn runs_validator_before_assigning() {
+        let mut field = "old".to_string();
+
+        let err = update(&mut field, Some(String::new())).unwrap_err();
+        assert_eq!(err, Unit09Error::StringEmpty.into());
+        assert_eq!(field, "old");
+    }
+}
@@ -13,6 +13,11 @@
 //! These helpers are intentionally small and explicit. They are not meant to
 //! replace business logic, only to capture common guard patterns.
 //!
+//! Instruction arguments implement `Validate`, which holds every bounds
+//! check that depends on the arguments alone. Handlers call
+//! `args.validate()?` first and keep only the checks that need accounts or
+//! `Config` (name length overrides, label prefixes, minimum LOC, ...).
+//!
 //! ===========================================================================
 
 use anchor_lang::prelude::*;
@@ -20,6 +25,16 @@ use anchor_lang::prelude::*;
 use crate::constants::*;
 use crate::errors::Unit09Error;
 
+/// Context-free validation of instruction arguments.
+///
+/// Implementations perform only checks that depend on `self`: string
+/// lengths, ranges, formats. Anything that needs an account belongs in the
+/// handler.
+pub trait Validate {
+    /// Check every argument-only bound, returning the first violation.
+    fn validate(&self) -> Result<()>;
+}
+
 /// Validate that a string is not empty.
 pub fn assert_non_empty_str(value: &str) -> Result<()> {
     require!(!value.is_empty(), Unit09Error::StringEmpty);
@@ -131,6 +146,30 @@ pub fn assert_authority_signer(authority: &Pubkey, signer: &Pubkey) -> Result<()
     Ok(())
 }
 
+/// Ensure that a transfer target differs from the current holder
+/// (`NoChangeRequested`).
+///
+/// Every instruction handing over an admin, authority or owner key should
+/// call this before writing, so a self-transfer does not churn state.
+pub fn assert_distinct(current: &Pubkey, new: &Pubkey) -> Result<()> {
+    require_keys_neq!(*current, *new, Unit09Error::NoChangeRequested);
+    Ok(())
+}
+
+/// Ensure that no account key appears twice in a batch (`ValidationFailed`).
+///
+/// Batch instructions call this on `remaining_accounts` before loading
+/// them: a repeated account would be applied twice, and its copies would
+/// overwrite each other when persisted.
+pub fn assert_unique_keys<'a>(keys: impl IntoIterator<Item = &'a Pubkey>) -> Result<()> {
+    let mut seen: Vec<&Pubkey> = Vec::new();
+    for key in keys {
+        require!(!seen.contains(&key), Unit09Error::ValidationFailed);
+        seen.push(key);
+    }
+    Ok(())
+}
+
 /// Ensure that a Boolean flag is true, mapping failures to a specific error.
 pub fn assert_flag_true(flag: bool, err: Unit09Error) -> Result<()> {
     require!(flag, err);
@@ -166,6 +205,25 @@ pub fn assert_revision_len(revision: &str, max_len: usize) -> Result<()> {
     assert_max_len(revision, max_len)
 }
 
+/// Validate the format of an observation revision identifier.
+///
+/// Empty revisions are allowed. Otherwise the value must fit in
+/// `MAX_REVISION_LEN` and consist only of printable, non-whitespace ASCII,
+/// so that forms like `"9f2a1c7"` or `"main@2025-01-01T12:00:00Z"` are
+/// accepted while control characters and embedded spaces are rejected with
+/// `MetadataInvalid`.
+pub fn validate_revision(revision: &str) -> Result<()> {
+    if revision.is_empty() {
+        return Ok(());
+    }
+
+    assert_max_len(revision, MAX_REVISION_LEN)?;
+
+    let ok = revision.bytes().all(|b| b.is_ascii_graphic());
+    require!(ok, Unit09Error::MetadataInvalid);
+    Ok(())
+}
+
 /// Validate an observation note string against a maximum length.
 pub fn assert_observation_note_len(note: &str, max_len: usize) -> Result<()> {
     assert_max_len(note, max_len)
@@ -179,3 +237,435 @@ pub fn assert_deployment_active(is_active: bool) -> Result<()> {
     require!(is_active, Unit09Error::DeploymentInactive);
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::instructions::*;
+    use crate::state::{Fork, Module, ModuleRepoLink, ObservationKind, Repo};
+
+    #[test]
+    fn accepts_git_hash_revision() {
+        assert!(validate_revision("9f2a1c7").is_ok());
+        assert!(validate_revision("").is_ok());
+    }
+
+    #[test]
+    fn accepts_branch_at_timestamp_revision() {
+        assert!(validate_revision("main@2025-01-01T12:00:00Z").is_ok());
+        assert!(validate_revision("release/v1.2_rc-3").is_ok());
+    }
+
+    #[test]
+    fn rejects_control_characters_and_whitespace() {
+        assert_eq!(
+            validate_revision("main\n9f2a1c7").unwrap_err(),
+            Unit09Error::MetadataInvalid.into()
+        );
+        assert!(validate_revision("main 9f2a1c7").is_err());
+        assert!(validate_revision(&"a".repeat(MAX_REVISION_LEN + 1)).is_err());
+    }
+
+    #[test]
+    fn self_transfer_is_rejected() {
+        let holder = Pubkey::new_unique();
+        assert!(assert_distinct(&holder, &Pubkey::new_unique()).is_ok());
+        assert_eq!(
+            assert_distinct(&holder, &holder).unwrap_err(),
+            Unit09Error::NoChangeRequested.into()
+        );
+    }
+
+    #[test]
+    fn repeated_batch_accounts_are_rejected() {
+        let first = Pubkey::new_unique();
+        let second = Pubkey::new_unique();
+        assert!(assert_unique_keys([&first, &second]).is_ok());
+        assert!(assert_unique_keys(core::iter::empty()).is_ok());
+        assert_eq!(
+            assert_unique_keys([&first, &second, &first]).unwrap_err(),
+            Unit09Error::ValidationFailed.into()
+        );
+    }
+
+    fn register_repo_args() -> RegisterRepoArgs {
+        RegisterRepoArgs {
+            repo_key: Pubkey::new_unique(),
+            name: "unit09-core".to_string(),
+            url: "https://github.com/unit09-labs/unit09".to_string(),
+            tags: "solana,anchor".to_string(),
+            metadata_uri: String::new(),
+            allow_observation: true,
+        }
+    }
+
+    fn create_fork_args() -> CreateForkArgs {
+        CreateForkArgs {
+            fork_key: Pubkey::new_unique(),
+            parent: None,
+            label: "unit09-lab-alpha".to_string(),
+            metadata_uri: "https://unit09.org/forks/alpha.json".to_string(),
+            tags: String::new(),
+            is_root: true,
+            depth: None,
+            derive_key: false,
+        }
+    }
+
+    fn register_module_args() -> RegisterModuleArgs {
+        RegisterModuleArgs {
+            module_key: Pubkey::new_unique(),
+            name: "parser".to_string(),
+            metadata_uri: "https://unit09.org/modules/parser.json".to_string(),
+            category: "tooling".to_string(),
+            tags: String::new(),
+            version: (1, 0, 0),
+            version_label: String::new(),
+            changelog_uri: String::new(),
+            is_stable: true,
+            create_initial_version_snapshot: false,
+            inherit_repo_tags: false,
+            immutable_until: 0,
+        }
+    }
+
+    fn record_observation_args() -> RecordObservationArgs {
+        RecordObservationArgs {
+            lines_of_code: 1_000,
+            files_processed: 10,
+            modules_touched: 0,
+            revision: "9f2a1c7".to_string(),
+            note: String::new(),
+            module: None,
+            observed_at_override: None,
+            idempotency_key: None,
+            source_region_hash: None,
+            access_proof: None,
+            kind: ObservationKind::Full.as_u8(),
+        }
+    }
+
+    fn split(lines_of_code: u64, files_processed: u32) -> ObservationSplit {
+        ObservationSplit {
+            repo_index: 0,
+            repo_key: Pubkey::new_unique(),
+            lines_of_code,
+            files_processed,
+            modules_touched: 0,
+        }
+    }
+
+    fn record_observation_multi_args() -> RecordObservationMultiArgs {
+        RecordObservationMultiArgs {
+            lines_of_code: 300,
+            files_processed: 3,
+            modules_touched: 0,
+            splits: vec![split(100, 1), split(200, 2)],
+            revision: String::new(),
+            note: String::new(),
+            atomic: true,
+        }
+    }
+
+    fn assert_rejects<T: Validate>(args: &T, expected: Unit09Error) {
+        assert_eq!(args.validate().unwrap_err(), expected.into());
+    }
+
+    #[test]
+    fn validates_register_repo_args() {
+        assert!(register_repo_args().validate().is_ok());
+
+        let mut args = register_repo_args();
+        args.name = String::new();
+        assert_rejects(&args, Unit09Error::StringEmpty);
+
+        let mut args = register_repo_args();
+        args.url = "x".repeat(Repo::MAX_URL_LEN + 1);
+        assert_rejects(&args, Unit09Error::StringTooLong);
+
+        let mut args = register_repo_args();
+        args.tags = "x".repeat(Repo::MAX_TAGS_LEN + 1);
+        assert_rejects(&args, Unit09Error::StringTooLong);
+    }
+
+    #[test]
+    fn validates_update_repo_args() {
+        assert!(UpdateRepoArgs::default().validate().is_ok());
+
+        let args = UpdateRepoArgs {
+            url: Some(String::new()),
+            ..UpdateRepoArgs::default()
+        };
+        assert_rejects(&args, Unit09Error::StringEmpty);
+
+        let args = UpdateRepoArgs {
+            allowed_observers: Some(vec![Pubkey::default(); Repo::MAX_ALLOWED_OBSERVERS + 1]),
+            ..UpdateRepoArgs::default()
+        };
+        assert_rejects(&args, Unit09Error::ValueOutOfRange);
+    }
+
+    #[test]
+    fn validates_create_fork_args() {
+        assert!(create_fork_args().validate().is_ok());
+
+        let mut args = create_fork_args();
+        args.label = String::new();
+        assert_rejects(&args, Unit09Error::StringEmpty);
+
+        let mut args = create_fork_args();
+        args.metadata_uri = "x".repeat(Fork::MAX_METADATA_URI_LEN + 1);
+        assert_rejects(&args, Unit09Error::StringTooLong);
+    }
+
+    #[test]
+    fn validates_update_fork_state_args() {
+        assert!(UpdateForkStateArgs::default().validate().is_ok());
+
+        let args = UpdateForkStateArgs {
+            metadata_uri: Some("ftp://unit09.org/fork.json".to_string()),
+            ..UpdateForkStateArgs::default()
+        };
+        assert_rejects(&args, Unit09Error::MetadataInvalid);
+
+        let args = UpdateForkStateArgs {
+            label: Some("x".repeat(Fork::MAX_LABEL_LEN + 1)),
+            ..UpdateForkStateArgs::default()
+        };
+        assert_rejects(&args, Unit09Error::StringTooLong);
+    }
+
+    #[test]
+    fn validates_register_module_args() {
+        assert!(register_module_args().validate().is_ok());
+
+        let mut args = register_module_args();
+        args.category = String::new();
+        assert_rejects(&args, Unit09Error::StringEmpty);
+
+        let mut args = register_module_args();
+        args.version = (0, 0, 0);
+        assert_rejects(&args, Unit09Error::ValueOutOfRange);
+    }
+
+    #[test]
+    fn validates_update_module_args() {
+        assert!(UpdateModuleArgs::default().validate().is_ok());
+
+        // Snapshots need a version.
+        let args = UpdateModuleArgs {
+            create_version_snapshot: true,
+            ..UpdateModuleArgs::default()
+        };
+        assert_rejects(&args, Unit09Error::ValueOutOfRange);
+
+        let args = UpdateModuleArgs {
+            metadata_uri: Some("x".repeat(Module::MAX_METADATA_URI_LEN + 1)),
+            ..UpdateModuleArgs::default()
+        };
+        assert_rejects(&args, Unit09Error::StringTooLong);
+    }
+
+    #[test]
+    fn validates_record_observation_args() {
+        assert!(record_observation_args().validate().is_ok());
+
+        let mut args = record_observation_args();
+        args.lines_of_code = 0;
+        assert_rejects(&args, Unit09Error::ValueOutOfRange);
+
+        let mut args = record_observation_args();
+        args.files_processed = MAX_FILES_PER_OBSERVATION + 1;
+        assert_rejects(&args, Unit09Error::ObservationDataTooLarge);
+
+        let mut args = record_observation_args();
+        args.revision = "main 9f2a1c7".to_string();
+        assert_rejects(&args, Unit09Error::MetadataInvalid);
+
+        let mut args = record_observation_args();
+        args.kind = 2;
+        assert_rejects(&args, Unit09Error::ValidationFailed);
+
+        // Metadata-only runs carry no counts.
+        let mut args = record_observation_args();
+        args.kind = ObservationKind::MetadataOnly.as_u8();
+        assert_rejects(&args, Unit09Error::ValidationFailed);
+        args.lines_of_code = 0;
+        args.files_processed = 0;
+        assert!(args.validate().is_ok());
+    }
+
+    #[test]
+    fn validates_record_observation_multi_args() {
+        assert!(record_observation_multi_args().validate().is_ok());
+
+        let mut args = record_observation_multi_args();
+        args.splits.clear();
+        assert_rejects(&args, Unit09Error::ValueOutOfRange);
+
+        let mut args = record_observation_multi_args();
+        args.lines_of_code += 1;
+        assert_rejects(&args, Unit09Error::MetricsInconsistent);
+    }
+
+    #[test]
+    fn validates_set_config_args() {
+        assert!(SetConfigArgs::default().validate().is_ok());
+
+        let args = SetConfigArgs {
+            fee_bps: Some(MAX_FEE_BPS + 1),
+            ..SetConfigArgs::default()
+        };
+        assert_rejects(&args, Unit09Error::InvalidFeeBps);
+
+        let args = SetConfigArgs {
+            fee_recipient: Some(Pubkey::default()),
+            ..SetConfigArgs::default()
+        };
+        assert_rejects(&args, Unit09Error::InvalidFeeRecipient);
+    }
+
+    #[test]
+    fn validates_blocked_host_args() {
+        let args = AddBlockedHostArgs {
+            host: "example.org".to_string(),
+        };
+        assert!(args.validate().is_ok());
+
+        let args = RemoveBlockedHostArgs {
+            host: String::new(),
+        };
+        assert_rejects(&args, Unit09Error::StringEmpty);
+    }
+
+    #[test]
+    fn validates_initialize_args() {
+        let args = InitializeArgs {
+            admin: Pubkey::new_unique(),
+            fee_bps: 0,
+            max_modules_per_repo: DEFAULT_MAX_MODULES_PER_REPO,
+            policy_ref: [0u8; 32],
+            lifecycle_note_ref: [0u8; 32],
+            require_all: false,
+        };
+        assert!(args.validate().is_ok());
+
+        let zero_modules = InitializeArgs {
+            max_modules_per_repo: 0,
+            ..args
+        };
+        assert_rejects(&zero_modules, Unit09Error::ValueOutOfRange);
+    }
+
+    #[test]
+    fn validates_set_metadata_args() {
+        assert!(SetMetadataArgs::default().validate().is_ok());
+
+        let args = SetMetadataArgs {
+            docs_url: Some("ipfs://docs".to_string()),
+            ..SetMetadataArgs::default()
+        };
+        assert_rejects(&args, Unit09Error::MetadataInvalid);
+
+        let args = SetMetadataArgs {
+            icon_uri: Some("ipfs://icon".to_string()),
+            ..SetMetadataArgs::default()
+        };
+        assert!(args.validate().is_ok());
+    }
+
+    #[test]
+    fn validates_link_module_to_repo_args() {
+        let args = LinkModuleToRepoArgs {
+            is_primary: true,
+            notes: String::new(),
+        };
+        assert!(args.validate().is_ok());
+
+        let args = LinkModuleToRepoArgs {
+            is_primary: false,
+            notes: "x".repeat(ModuleRepoLink::MAX_NOTES_LEN + 1),
+        };
+        assert_rejects(&args, Unit09Error::StringTooLong);
+    }
+
+    #[test]
+    fn validates_top_up_credits_args() {
+        let args = TopUpCreditsArgs {
+            observer: Pubkey::new_unique(),
+            amount: 1,
+        };
+        assert!(args.validate().is_ok());
+
+        let args = TopUpCreditsArgs {
+            observer: Pubkey::new_unique(),
+            amount: 0,
+        };
+        assert_rejects(&args, Unit09Error::ValueOutOfRange);
+    }
+
+    #[test]
+    fn validates_record_metrics_args() {
+        assert_rejects(&RecordMetricsArgs::default(), Unit09Error::ValidationFailed);
+
+        let args = RecordMetricsArgs {
+            total_forks: Some(u64::MAX),
+            ..RecordMetricsArgs::default()
+        };
+        assert_rejects(&args, Unit09Error::ValueOutOfRange);
+
+        let args = RecordMetricsArgs {
+            total_forks: Some(12),
+            ..RecordMetricsArgs::default()
+        };
+        assert!(args.validate().is_ok());
+    }
+
+    #[test]
+    fn validates_rename_tag_args() {
+        let rename = |old: &str, new: &str| RenameTagArgs {
+            old: old.to_string(),
+            new: new.to_string(),
+        };
+        assert!(rename("legacy", "archived").validate().is_ok());
+
+        assert_rejects(&rename("  ", "archived"), Unit09Error::StringEmpty);
+        assert_rejects(&rename("legacy", "a,b"), Unit09Error::ValidationFailed);
+        assert_rejects(
+            &rename("legacy", &"x".repeat(Repo::MAX_TAGS_LEN + 1)),
+            Unit09Error::StringTooLong,
+        );
+        assert_rejects(&rename("Legacy", " legacy "), Unit09Error::ValidationFailed);
+    }
+
+    #[test]
+    fn validates_set_repo_fee_override_args() {
+        let args = SetRepoFeeOverrideArgs {
+            fee_bps_override: Some(MAX_FEE_BPS),
+        };
+        assert!(args.validate().is_ok());
+
+        let args = SetRepoFeeOverrideArgs {
+            fee_bps_override: None,
+        };
+        assert!(args.validate().is_ok());
+
+        let args = SetRepoFeeOverrideArgs {
+            fee_bps_override: Some(MAX_FEE_BPS + 1),
+        };
+        assert_rejects(&args, Unit09Error::InvalidFeeBps);
+    }
+
+    #[test]
+    fn validates_admin_reassign_fork_args() {
+        let args = AdminReassignForkArgs {
+            new_owner: Pubkey::new_unique(),
+        };
+        assert!(args.validate().is_ok());
+
+        let args = AdminReassignForkArgs {
+            new_owner: Pubkey::default(),
+        };
+        assert_rejects(&args, Unit09Error::InvalidForkOwner);
+    }
+}
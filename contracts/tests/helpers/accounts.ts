@@ -21,7 +21,8 @@
  * ============================================================================
  */
 
-import { PublicKey } from "@solana/web3.js";
+import { createHash } from "crypto";
+import { AccountMeta, PublicKey } from "@solana/web3.js";
 import type { Unit09ProgramClient } from "./provider";
 
 // ============================================================================
@@ -88,35 +89,29 @@ export const SEED_GLOBAL_METADATA = "global_metadata";
  */
 export const SEED_AUTHORITY = "authority";
 
-// ============================================================================
-// Helper: version tuple to byte seeds
-// ============================================================================
-
 /**
- * Convert a semantic version triple (u16, u16, u16) into LE byte arrays
- * used as part of the module version PDA seeds.
- *
- * On-chain: each component is serialized via `to_le_bytes()`.
+ * Seed prefix for ObserverCredits accounts.
+ * On-chain: `b"observer_credits"`
  */
-export function versionToSeedBytes(
-  major: number,
-  minor: number,
-  patch: number
-): [Uint8Array, Uint8Array, Uint8Array] {
-  const bufMajor = new Uint8Array(2);
-  const bufMinor = new Uint8Array(2);
-  const bufPatch = new Uint8Array(2);
+export const SEED_OBSERVER_CREDITS = "observer_credits";
 
-  const dvMajor = new DataView(bufMajor.buffer);
-  const dvMinor = new DataView(bufMinor.buffer);
-  const dvPatch = new DataView(bufPatch.buffer);
+/**
+ * Seed prefix for OwnerForkIndex accounts.
+ * On-chain: `b"owner_fork_index"`
+ */
+export const SEED_OWNER_FORK_INDEX = "owner_fork_index";
 
-  dvMajor.setUint16(0, major, true);
-  dvMinor.setUint16(0, minor, true);
-  dvPatch.setUint16(0, patch, true);
+/**
+ * Seed prefix for MetricsSnapshot accounts.
+ * On-chain: `b"metrics_snapshot"`
+ */
+export const SEED_METRICS_SNAPSHOT = "metrics_snapshot";
 
-  return [bufMajor, bufMinor, bufPatch];
-}
+/**
+ * Seed prefix for RepoNameIndex accounts.
+ * On-chain: `b"repo_name_index"`
+ */
+export const SEED_REPO_NAME_INDEX = "repo_name_index";
 
 // ============================================================================
 // PDA derivation helpers
@@ -189,44 +184,34 @@ export function getModulePda(programId: PublicKey, moduleKey: PublicKey): Public
 }
 
 /**
- * Derive the ModuleVersion PDA.
+ * Derive the ModuleVersion PDA of the snapshot at `index`
+ * (`0..Module::version_count`, in creation order).
  * Seeds:
  *   [
  *     b"module_version",
- *     module_key,
- *     major.to_le_bytes(),
- *     minor.to_le_bytes(),
- *     patch.to_le_bytes(),
+ *     module,
+ *     index.to_le_bytes(), // u32
  *   ]
  */
 export function findModuleVersionPda(
   programId: PublicKey,
-  moduleKey: PublicKey,
-  major: number,
-  minor: number,
-  patch: number
+  module: PublicKey,
+  index: number
 ): [PublicKey, number] {
-  const [majorBytes, minorBytes, patchBytes] = versionToSeedBytes(major, minor, patch);
+  const indexBytes = Buffer.alloc(4);
+  indexBytes.writeUInt32LE(index, 0);
   return PublicKey.findProgramAddressSync(
-    [
-      Buffer.from(SEED_MODULE_VERSION),
-      moduleKey.toBuffer(),
-      Buffer.from(majorBytes),
-      Buffer.from(minorBytes),
-      Buffer.from(patchBytes),
-    ],
+    [Buffer.from(SEED_MODULE_VERSION), module.toBuffer(), indexBytes],
     programId
   );
 }
 
 export function getModuleVersionPda(
   programId: PublicKey,
-  moduleKey: PublicKey,
-  major: number,
-  minor: number,
-  patch: number
+  module: PublicKey,
+  index: number
 ): PublicKey {
-  return findModuleVersionPda(programId, moduleKey, major, minor, patch)[0];
+  return findModuleVersionPda(programId, module, index)[0];
 }
 
 /**
@@ -300,6 +285,99 @@ export function getAuthorityPda(programId: PublicKey, authority: PublicKey): Pub
   return findAuthorityPda(programId, authority)[0];
 }
 
+/**
+ * `remainingAccounts` for admin-quorum instructions (`setConfig`,
+ * `restoreMetrics`, `addCoAdmin`, ...): one `[signer, authorityPda]` pair
+ * per co-signer. The co-signers must also be passed to `.signers(...)`.
+ */
+export function buildCoSignerAccounts(
+  programId: PublicKey,
+  coSigners: PublicKey[]
+): AccountMeta[] {
+  return coSigners.flatMap((coSigner) => [
+    { pubkey: coSigner, isWritable: false, isSigner: true },
+    {
+      pubkey: getAuthorityPda(programId, coSigner),
+      isWritable: false,
+      isSigner: false,
+    },
+  ]);
+}
+
+/**
+ * Derive the ObserverCredits PDA.
+ * Seeds: `[b"observer_credits", observer_pubkey]`
+ */
+export function findObserverCreditsPda(
+  programId: PublicKey,
+  observer: PublicKey
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from(SEED_OBSERVER_CREDITS), observer.toBuffer()],
+    programId
+  );
+}
+
+export function getObserverCreditsPda(programId: PublicKey, observer: PublicKey): PublicKey {
+  return findObserverCreditsPda(programId, observer)[0];
+}
+
+/**
+ * Derive the OwnerForkIndex PDA.
+ * Seeds: `[b"owner_fork_index", owner_pubkey]`
+ */
+export function findOwnerForkIndexPda(
+  programId: PublicKey,
+  owner: PublicKey
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from(SEED_OWNER_FORK_INDEX), owner.toBuffer()],
+    programId
+  );
+}
+
+export function getOwnerForkIndexPda(programId: PublicKey, owner: PublicKey): PublicKey {
+  return findOwnerForkIndexPda(programId, owner)[0];
+}
+
+/**
+ * Derive the MetricsSnapshot PDA.
+ * Seeds: `[b"metrics_snapshot", index_le_u32]`
+ */
+export function findMetricsSnapshotPda(
+  programId: PublicKey,
+  index: number
+): [PublicKey, number] {
+  const indexBytes = Buffer.alloc(4);
+  indexBytes.writeUInt32LE(index, 0);
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from(SEED_METRICS_SNAPSHOT), indexBytes],
+    programId
+  );
+}
+
+export function getMetricsSnapshotPda(programId: PublicKey, index: number): PublicKey {
+  return findMetricsSnapshotPda(programId, index)[0];
+}
+
+/**
+ * Derive the RepoNameIndex PDA.
+ * Seeds: `[b"repo_name_index", sha256(name)]`
+ */
+export function findRepoNameIndexPda(
+  programId: PublicKey,
+  name: string
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from(SEED_REPO_NAME_INDEX), createHash("sha256").update(name).digest()],
+    programId
+  );
+}
+
+export function getRepoNameIndexPda(programId: PublicKey, name: string): PublicKey {
+  return findRepoNameIndexPda(programId, name)[0];
+}
+
 // ============================================================================
 // Bulk / convenience helpers
 // ============================================================================
@@ -308,7 +386,7 @@ export function getAuthorityPda(programId: PublicKey, authority: PublicKey): Pub
  * Derive all core PDAs in one call, for quick test setup.
  *
  * The repo/module/fork/moduleVersion/moduleRepoLink fields are optional and
- * only derived when their corresponding keys (and version tuple) are provided.
+ * only derived when their corresponding keys (and version index) are provided.
  */
 export interface CorePdasInput {
   programId: PublicKey;
@@ -316,9 +394,7 @@ export interface CorePdasInput {
   moduleKey?: PublicKey;
   forkKey?: PublicKey;
   moduleVersion?: {
-    major: number;
-    minor: number;
-    patch: number;
+    index: number; // u32, ModuleVersion::index
   };
   moduleRepoLink?: {
     moduleKey: PublicKey;
@@ -366,9 +442,8 @@ export function deriveAllCorePdas(input: CorePdasInput): CorePdasOutput {
     module = getModulePda(programId, input.moduleKey);
   }
 
-  if (input.moduleKey && input.moduleVersion) {
-    const { major, minor, patch } = input.moduleVersion;
-    moduleVersion = getModuleVersionPda(programId, input.moduleKey, major, minor, patch);
+  if (module && input.moduleVersion) {
+    moduleVersion = getModuleVersionPda(programId, module, input.moduleVersion.index);
   }
 
   if (input.forkKey) {
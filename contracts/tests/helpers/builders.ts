@@ -29,6 +29,7 @@ import type {
   UpdateForkStateArgs,
   RecordObservationArgs,
   RecordMetricsArgs,
+  SetConfigArgs,
   SemanticVersionTuple,
 } from "../../../idl/types";
 
@@ -38,8 +39,11 @@ import type {
 } from "./provider";
 
 import {
+  buildCoSignerAccounts,
   buildInitializePdaAccounts,
   deriveAllCorePdasFromProgram,
+  getAuthorityPda,
+  getForkPda,
 } from "./accounts";
 
 // ============================================================================
@@ -169,6 +173,7 @@ export interface BuildInitializeArgsOptions {
   maxModulesPerRepo?: number;
   policyRef?: Uint8Array;
   lifecycleNoteRef?: Uint8Array;
+  requireAll?: boolean;
 }
 
 /**
@@ -182,6 +187,7 @@ export function buildInitializeArgs(opts: BuildInitializeArgsOptions = {}): Init
     maxModulesPerRepo: opts.maxModulesPerRepo ?? 128,
     policyRef: opts.policyRef ?? defaultBytes,
     lifecycleNoteRef: opts.lifecycleNoteRef ?? defaultBytes,
+    requireAll: opts.requireAll ?? false,
   };
 }
 
@@ -194,6 +200,7 @@ export interface BuildRegisterRepoArgsOptions {
   name?: string;
   url?: string;
   tags?: string;
+  metadataUri?: string;
   allowObservation?: boolean;
 }
 
@@ -207,6 +214,7 @@ export function buildRegisterRepoArgs(
   const name = opts.name ?? buildRepoName();
   const url = opts.url ?? buildRepoUrl();
   const tags = opts.tags ?? buildTagLine("unit09,repo,example");
+  const metadataUri = opts.metadataUri ?? buildMetadataUri("repo");
   const allowObservation = opts.allowObservation ?? true;
 
   return {
@@ -214,6 +222,7 @@ export function buildRegisterRepoArgs(
     name,
     url,
     tags,
+    metadataUri,
     allowObservation,
   };
 }
@@ -222,8 +231,13 @@ export interface BuildUpdateRepoArgsOptions {
   name?: string | null;
   url?: string | null;
   tags?: string | null;
+  metadataUri?: string | null;
   isActive?: boolean | null;
   allowObservation?: boolean | null;
+  allowedObservers?: PublicKey[] | null;
+  tagBits?: bigint | null;
+  enforceUniqueModuleUri?: boolean | null;
+  requireAccessProof?: boolean | null;
 }
 
 /**
@@ -238,8 +252,15 @@ export function buildUpdateRepoArgs(
     name: opts.name ?? null,
     url: opts.url ?? null,
     tags: opts.tags ?? null,
+    metadataUri: opts.metadataUri ?? null,
     isActive: opts.isActive ?? null,
     allowObservation: opts.allowObservation ?? null,
+    allowedObservers: opts.allowedObservers
+      ? opts.allowedObservers.map((k) => k.toBase58())
+      : null,
+    tagBits: opts.tagBits ?? null,
+    enforceUniqueModuleUri: opts.enforceUniqueModuleUri ?? null,
+    requireAccessProof: opts.requireAccessProof ?? null,
   };
 }
 
@@ -258,6 +279,8 @@ export interface BuildRegisterModuleArgsOptions {
   changelogUri?: string;
   isStable?: boolean;
   createInitialVersionSnapshot?: boolean;
+  inheritRepoTags?: boolean;
+  immutableUntil?: bigint;
 }
 
 /**
@@ -280,6 +303,8 @@ export function buildRegisterModuleArgs(
     changelogUri: opts.changelogUri ?? buildChangelogUri(),
     isStable: opts.isStable ?? false,
     createInitialVersionSnapshot: opts.createInitialVersionSnapshot ?? true,
+    inheritRepoTags: opts.inheritRepoTags ?? false,
+    immutableUntil: opts.immutableUntil ?? BigInt(0),
   };
 }
 
@@ -288,6 +313,7 @@ export interface BuildUpdateModuleArgsOptions {
   metadataUri?: string | null;
   category?: string | null;
   tags?: string | null;
+  tagBits?: bigint | null;
   isActive?: boolean | null;
   createVersionSnapshot?: boolean;
   newVersion?: SemanticVersionTuple | null;
@@ -307,6 +333,7 @@ export function buildUpdateModuleArgs(
     metadataUri: opts.metadataUri ?? null,
     category: opts.category ?? null,
     tags: opts.tags ?? null,
+    tagBits: opts.tagBits ?? null,
     isActive: opts.isActive ?? null,
     createVersionSnapshot: opts.createVersionSnapshot ?? true,
     newVersion: opts.newVersion ?? null,
@@ -328,6 +355,7 @@ export interface BuildCreateForkArgsOptions {
   tags?: string;
   isRoot?: boolean;
   depth?: number | null;
+  deriveKey?: boolean;
 }
 
 /**
@@ -348,6 +376,7 @@ export function buildCreateForkArgs(
     tags: opts.tags ?? buildTagLine("unit09,fork,branch"),
     isRoot,
     depth,
+    deriveKey: opts.deriveKey ?? false,
   };
 }
 
@@ -382,6 +411,12 @@ export interface BuildRecordObservationArgsOptions {
   modulesTouched?: number;
   revision?: string;
   note?: string;
+  module?: PublicKey | null;
+  observedAtOverride?: bigint | null;
+  idempotencyKey?: Uint8Array | number[] | null;
+  sourceRegionHash?: Uint8Array | number[] | null;
+  accessProof?: Uint8Array | number[] | null;
+  kind?: number;
 }
 
 /**
@@ -396,6 +431,12 @@ export function buildRecordObservationArgs(
     modulesTouched: opts.modulesTouched ?? randInt(1, 12),
     revision: opts.revision ?? `rev-${randomSuffix(8)}`,
     note: opts.note ?? "Automated observation recorded during test.",
+    module: opts.module ? opts.module.toBase58() : null,
+    observedAtOverride: opts.observedAtOverride ?? null,
+    idempotencyKey: opts.idempotencyKey ? Array.from(opts.idempotencyKey) : null,
+    sourceRegionHash: opts.sourceRegionHash ? Array.from(opts.sourceRegionHash) : null,
+    accessProof: opts.accessProof ? Array.from(opts.accessProof) : null,
+    kind: opts.kind ?? 0,
   };
 }
 
@@ -425,6 +466,51 @@ export function buildRecordMetricsArgs(
   };
 }
 
+// ============================================================================
+// Config builders
+// ============================================================================
+
+export type BuildSetConfigArgsOptions = Partial<
+  Omit<SetConfigArgs, "feeRecipient" | "policyRef">
+> & {
+  feeRecipient?: PublicKey | null;
+  policyRef?: Uint8Array | number[] | null;
+};
+
+/**
+ * Build SetConfigArgs that change only the fields given in `overrides`.
+ * Every other field defaults to null, which indicates no change.
+ */
+export function buildSetConfigArgs(
+  overrides: BuildSetConfigArgsOptions = {}
+): SetConfigArgs {
+  const { feeRecipient, policyRef, ...rest } = overrides;
+  return {
+    feeBps: null,
+    maxModulesPerRepo: null,
+    isActive: null,
+    autoFreezeOnInconsistency: null,
+    enforceUrlKeyed: null,
+    maxObservationsPerWindow: null,
+    minLocPerObservation: null,
+    requiredAdminSigs: null,
+    quietWhenFrozen: null,
+    creditsPerObservation: null,
+    stalenessThresholdSecs: null,
+    maxNameLenOverride: null,
+    requireAttestor: null,
+    eventVerbosity: null,
+    reviewGated: null,
+    labelPrefixRequired: null,
+    cooldowns: null,
+    minObserverLamports: null,
+    enforceUniqueRepoName: null,
+    ...rest,
+    feeRecipient: feeRecipient ? feeRecipient.toBase58() : null,
+    policyRef: policyRef ? Uint8Array.from(policyRef) : null,
+  };
+}
+
 // ============================================================================
 // Higher-level builders (on-chain workflows used in tests)
 // ============================================================================
@@ -559,6 +645,8 @@ export async function createModuleOnChain(
 
 export interface CreateForkOnChainOptions extends BuildCreateForkArgsOptions {
   owner?: PublicKey;
+  /** Ancestor fork keys, nearest parent first, whose descendant counts to bump. */
+  ancestors?: PublicKey[];
 }
 
 /**
@@ -594,7 +682,107 @@ export async function createForkOnChain(
       payer: ctx.wallet.publicKey,
       systemProgram: SystemProgram.programId,
     })
+    .remainingAccounts(
+      (opts.ancestors ?? []).map((ancestor) => ({
+        pubkey: getForkPda(program.programId, ancestor),
+        isWritable: true,
+        isSigner: false,
+      }))
+    )
     .rpc();
 
   return { forkKey, tx };
 }
+
+// ---------------------------------------------------------------------------
+
+/**
+ * Register `coAdmin` as a co-admin via `addCoAdmin`, signed by the context
+ * wallet (the deployment admin) plus `coSigners`.
+ */
+export async function addCoAdminOnChain(
+  ctx: Unit09TestContext,
+  coAdmin: PublicKey,
+  coSigners: Keypair[] = []
+): Promise<string> {
+  const program = ctx.program;
+  const pda = deriveAllCorePdasFromProgram(program);
+
+  const builder = program.methods
+    .addCoAdmin({ coAdmin })
+    .accounts({
+      admin: ctx.wallet.publicKey,
+      config: pda.config,
+      metrics: pda.metrics,
+      authority: getAuthorityPda(program.programId, coAdmin),
+      systemProgram: SystemProgram.programId,
+    })
+    .remainingAccounts(
+      buildCoSignerAccounts(
+        program.programId,
+        coSigners.map((signer) => signer.publicKey)
+      )
+    );
+
+  return coSigners.length > 0 ? builder.signers(coSigners).rpc() : builder.rpc();
+}
+
+/**
+ * Remove the co-admin `coAdmin` via `removeCoAdmin`, signed by the context
+ * wallet (the deployment admin) plus `coSigners`.
+ */
+export async function removeCoAdminOnChain(
+  ctx: Unit09TestContext,
+  coAdmin: PublicKey,
+  coSigners: Keypair[] = []
+): Promise<string> {
+  const program = ctx.program;
+  const pda = deriveAllCorePdasFromProgram(program);
+
+  const builder = program.methods
+    .removeCoAdmin()
+    .accounts({
+      admin: ctx.wallet.publicKey,
+      config: pda.config,
+      metrics: pda.metrics,
+      authority: getAuthorityPda(program.programId, coAdmin),
+    })
+    .remainingAccounts(
+      buildCoSignerAccounts(
+        program.programId,
+        coSigners.map((signer) => signer.publicKey)
+      )
+    );
+
+  return coSigners.length > 0 ? builder.signers(coSigners).rpc() : builder.rpc();
+}
+
+/**
+ * Set `Config::required_admin_sigs` via `setConfig`, signed by the context
+ * wallet (the deployment admin) plus `coSigners`.
+ */
+export async function setRequiredAdminSigsOnChain(
+  ctx: Unit09TestContext,
+  requiredAdminSigs: number,
+  coSigners: Keypair[] = []
+): Promise<string> {
+  const program = ctx.program;
+  const pda = deriveAllCorePdasFromProgram(program);
+
+  const builder = program.methods
+    .setConfig(buildSetConfigArgs({ requiredAdminSigs }))
+    .accounts({
+      admin: ctx.wallet.publicKey,
+      config: pda.config,
+      metrics: pda.metrics,
+      systemProgram: SystemProgram.programId,
+    })
+    .remainingAccounts(
+      buildCoSignerAccounts(
+        program.programId,
+        coSigners.map((signer) => signer.publicKey)
+      )
+    );
+
+  return coSigners.length > 0 ? builder.signers(coSigners).rpc() : builder.rpc();
+}
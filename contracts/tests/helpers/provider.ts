@@ -8,6 +8,7 @@
  *   - Wallet / payer resolution
  *   - Program instance loading from IDL
  *   - Convenience helpers for airdrops and keypair creation
+ *   - Simulating read-only queries and decoding their return data
  *
  * It is used by all Jest/Mocha-style specs under:
  *   contracts/unit09-program/tests/
@@ -57,6 +58,8 @@ import {
   LAMPORTS_PER_SOL,
   PublicKey,
   SystemProgram,
+  Transaction,
+  TransactionError,
   clusterApiUrl,
 } from "@solana/web3.js";
 
@@ -294,6 +297,48 @@ export function createUnit09TestContext(): Unit09TestContext {
   };
 }
 
+// ============================================================================
+// Simulation helpers
+// ============================================================================
+
+/**
+ * Outcome of simulating a read-only query.
+ */
+export interface SimulatedReturnData {
+  err: TransactionError | null;
+  logs: string[];
+
+  /**
+   * Payload written with `set_return_data`, empty if none was set. The
+   * runtime trims trailing zero bytes, so a zero or `false` result may
+   * arrive shorter than its encoded width.
+   */
+  data: Buffer;
+}
+
+/**
+ * Simulate the transaction of a method builder, paid by the context wallet,
+ * and decode the program's return data.
+ *
+ *   const { data } = await simulateReturnData(
+ *     ctx,
+ *     ctx.program.methods.getVersionCount().accounts({ module })
+ *   );
+ */
+export async function simulateReturnData(
+  ctx: Unit09TestContext,
+  builder: { transaction(): Promise<Transaction> }
+): Promise<SimulatedReturnData> {
+  const tx = await builder.transaction();
+  tx.feePayer = ctx.wallet.publicKey;
+  const sim = await ctx.connection.simulateTransaction(tx);
+  return {
+    err: sim.value.err,
+    logs: sim.value.logs ?? [],
+    data: Buffer.from(sim.value.returnData?.data[0] ?? "", "base64"),
+  };
+}
+
 // ============================================================================
 // Convenience re-exports
 // ============================================================================
@@ -6,8 +6,16 @@
  * This file focuses on fork-related behavior:
  *   - Creating root and child forks
  *   - Preventing duplicate fork creation for the same fork key
+ *   - Deriving fork keys from owner and label (`deriveKey`)
+ *   - Enforcing `Config::labelPrefixRequired` on created, renamed and cloned
+ *     fork labels
+ *   - Applying `Config::maxNameLenOverride` to renamed and cloned labels
+ *   - Enforcing the fork creation cooldown (`Config::cooldowns`)
  *   - Updating fork state (label, metadata, tags, active flag)
+ *   - Rejecting edits to inactive forks except reactivation
  *   - Verifying lifecycle and metrics react to fork-level activity
+ *   - Admin reassignment of a fork owner (recovery path)
+ *   - Pruning inactive forks with `pruneForks`
  *
  * It relies on helpers from:
  *   - tests/helpers/provider.ts
@@ -19,13 +27,16 @@
  * ============================================================================
  */
 
-import { SystemProgram, PublicKey, Keypair } from "@solana/web3.js";
+import { createHash } from "crypto";
+
+import { SystemProgram, SYSVAR_CLOCK_PUBKEY, PublicKey, Keypair } from "@solana/web3.js";
 import { BN } from "@coral-xyz/anchor";
 
-import { createUnit09TestContext } from "./helpers/provider";
+import { createUnit09TestContext, simulateReturnData } from "./helpers/provider";
 import {
   deriveAllCorePdasFromProgram,
   getForkPda,
+  getOwnerForkIndexPda,
 } from "./helpers/accounts";
 import {
   BuildInitializeArgsOptions,
@@ -33,6 +44,7 @@ import {
   buildUpdateForkStateArgs,
   createForkOnChain,
   initializeUnit09OnChain,
+  buildSetConfigArgs,
 } from "./helpers/builders";
 import {
   assertFork,
@@ -267,6 +279,49 @@ describe("unit09_program – forks", () => {
     ).rejects.toThrow();
   });
 
+  it("derives the same fork key for the same owner and label", async () => {
+    const program = ctx.program;
+
+    const owner = ctx.wallet.publicKey;
+    const label = `unit09-derived-${Date.now()}`;
+    const derivedKey = new PublicKey(
+      createHash("sha256")
+        .update(Buffer.concat([owner.toBuffer(), Buffer.from(label)]))
+        .digest()
+    );
+    const pdas = deriveAllCorePdasFromProgram(program, { forkKey: derivedKey });
+
+    const createDerived = () =>
+      program.methods
+        .createFork(
+          buildCreateForkArgs({
+            // Ignored by the program when deriveKey is set.
+            forkKey: Keypair.generate().publicKey,
+            label,
+            isRoot: true,
+            depth: 0,
+            deriveKey: true,
+          })
+        )
+        .accounts({
+          config: pdas.config,
+          lifecycle: pdas.lifecycle,
+          fork: pdas.fork,
+          owner,
+          payer: ctx.wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+    await createDerived();
+
+    const forkAcc = await program.account.fork.fetch(pdas.fork);
+    expect(forkAcc.forkKey.toBase58()).toBe(derivedKey.toBase58());
+
+    // Same owner and label derive the same fork, which already exists.
+    await expect(createDerived()).rejects.toThrow();
+  });
+
   it("updates fork state metadata via updateForkState", async () => {
     const program = ctx.program;
 
@@ -389,6 +444,38 @@ describe("unit09_program – forks", () => {
     expect(forkAcc.tags).toContain("partial-updated");
   });
 
+  it("rejects edits to an inactive fork but allows reactivating it", async () => {
+    const program = ctx.program;
+    const pdas = deriveAllCorePdasFromProgram(program);
+    const { forkKey } = await createForkOnChain(ctx);
+    const forkPda = getForkPda(program.programId, forkKey);
+
+    const update = (opts: Parameters<typeof buildUpdateForkStateArgs>[0]) =>
+      program.methods
+        .updateForkState(buildUpdateForkStateArgs(opts))
+        .accounts({
+          config: pdas.config,
+          fork: forkPda,
+          owner: ctx.wallet.publicKey,
+        })
+        .rpc();
+
+    await update({ isActive: false });
+
+    await expect(update({ tags: "unit09,fork,inactive-edit" })).rejects.toThrow(
+      /ForkInactive/
+    );
+
+    await update({ isActive: true });
+
+    const forkAcc = await program.account.fork.fetch(forkPda);
+    expect(forkAcc.isActive).toBe(true);
+    expect(forkAcc.tags).not.toContain("inactive-edit");
+
+    // Active again, so ordinary edits go through.
+    await update({ tags: "unit09,fork,reactivated" });
+  });
+
   it("touches lifecycle timestamps when creating forks", async () => {
     const program = ctx.program;
 
@@ -480,6 +567,188 @@ describe("unit09_program – forks", () => {
     );
   });
 
+  it("checks fork lineage via checkForkDescendant", async () => {
+    const program = ctx.program;
+
+    const child = await createForkOnChain(ctx, {
+      parent: canonicalRootForkKey,
+      label: "unit09-lineage-child",
+    });
+    const grandchild = await createForkOnChain(ctx, {
+      parent: child.forkKey,
+      label: "unit09-lineage-grandchild",
+    });
+    const unrelated = await createForkOnChain(ctx, {
+      label: "unit09-lineage-unrelated",
+      isRoot: true,
+      depth: 0,
+    });
+
+    // Simulate the query and return the raw simulation result; the answer
+    // is a Borsh `bool` in the return data.
+    const check = async (ancestor: PublicKey, chain: PublicKey[]) => {
+      const { err, logs, data } = await simulateReturnData(
+        ctx,
+        program.methods
+          .checkForkDescendant({ ancestor })
+          .accounts({ candidate: getForkPda(program.programId, grandchild.forkKey) })
+          .remainingAccounts(
+            chain.map((forkKey) => ({
+              pubkey: getForkPda(program.programId, forkKey),
+              isWritable: false,
+              isSigner: false,
+            }))
+          )
+      );
+      // `false` may arrive as an empty buffer (trailing zeros are trimmed).
+      return { err, logs, result: data.length > 0 && data.readUInt8(0) === 1 };
+    };
+
+    // True: root is two levels up.
+    const found = await check(canonicalRootForkKey, [child.forkKey, canonicalRootForkKey]);
+    expect(found.err).toBeNull();
+    expect(found.result).toBe(true);
+
+    // False: walk reaches the root without meeting the unrelated fork.
+    const notFound = await check(unrelated.forkKey, [child.forkKey, canonicalRootForkKey]);
+    expect(notFound.err).toBeNull();
+    expect(notFound.result).toBe(false);
+
+    // Broken chain: the first entry is not the grandchild's parent.
+    const broken = await check(unrelated.forkKey, [canonicalRootForkKey]);
+    expect(broken.err).not.toBeNull();
+    expect(broken.logs.join("\n")).toMatch(/InvalidForkParent/);
+  });
+
+  it("counts descendants on ancestors passed to createFork", async () => {
+    const program = ctx.program;
+
+    const root = await createForkOnChain(ctx, {
+      label: "unit09-subtree-root",
+      isRoot: true,
+      depth: 0,
+    });
+    const child = await createForkOnChain(ctx, {
+      parent: root.forkKey,
+      label: "unit09-subtree-child",
+      ancestors: [root.forkKey],
+    });
+    await createForkOnChain(ctx, {
+      parent: child.forkKey,
+      label: "unit09-subtree-grandchild",
+      ancestors: [child.forkKey, root.forkKey],
+    });
+
+    const countOf = async (forkKey: PublicKey) => {
+      const { err, data } = await simulateReturnData(
+        ctx,
+        program.methods
+          .getDescendantCount()
+          .accounts({ fork: getForkPda(program.programId, forkKey) })
+      );
+      expect(err).toBeNull();
+      // Trailing zero bytes may be trimmed; pad back to a u64.
+      return Buffer.concat([data, Buffer.alloc(8)]).readBigUInt64LE(0);
+    };
+
+    expect(await countOf(root.forkKey)).toBe(BigInt(2));
+    expect(await countOf(child.forkKey)).toBe(BigInt(1));
+
+    // A mismatched ancestor entry is rejected.
+    await expect(
+      createForkOnChain(ctx, {
+        parent: child.forkKey,
+        label: "unit09-subtree-bad-chain",
+        ancestors: [root.forkKey],
+      })
+    ).rejects.toThrow(/InvalidForkParent/);
+  });
+
+  it("lists direct children on the parent fork up to the cap", async () => {
+    const program = ctx.program;
+    const MAX_CHILDREN_PER_FORK = 8;
+
+    const parent = await createForkOnChain(ctx, {
+      label: "unit09-children-root",
+      isRoot: true,
+      depth: 0,
+    });
+
+    const childKeys: PublicKey[] = [];
+    for (let i = 0; i < MAX_CHILDREN_PER_FORK; i++) {
+      const child = await createForkOnChain(ctx, {
+        parent: parent.forkKey,
+        label: `unit09-children-${i}`,
+        ancestors: [parent.forkKey],
+      });
+      childKeys.push(child.forkKey);
+    }
+
+    const parentAccount = await program.account.fork.fetch(
+      getForkPda(program.programId, parent.forkKey)
+    );
+    expect(parentAccount.children.map((key: PublicKey) => key.toBase58())).toEqual(
+      childKeys.map((key) => key.toBase58())
+    );
+
+    await expect(
+      createForkOnChain(ctx, {
+        parent: parent.forkKey,
+        label: "unit09-children-overflow",
+        ancestors: [parent.forkKey],
+      })
+    ).rejects.toThrow(/ForkLimitReached/);
+  });
+
+  it("clones a fork from a template, inheriting tags and linking to the source", async () => {
+    const program = ctx.program;
+
+    const source = await createForkOnChain(ctx, {
+      label: "unit09-template",
+      tags: "story,template",
+      isRoot: true,
+      depth: 0,
+    });
+    const sourceAccount = await program.account.fork.fetch(
+      getForkPda(program.programId, source.forkKey)
+    );
+
+    const cloneKey = Keypair.generate().publicKey;
+    const pda = deriveAllCorePdasFromProgram(program, { forkKey: cloneKey });
+
+    await program.methods
+      .cloneFork({ forkKey: cloneKey, labelSuffix: "eu" })
+      .accounts({
+        payer: ctx.wallet.publicKey,
+        owner: ctx.wallet.publicKey,
+        config: pda.config,
+        lifecycle: pda.lifecycle,
+        metrics: pda.metrics,
+        source: getForkPda(program.programId, source.forkKey),
+        fork: pda.fork,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    const clone = await program.account.fork.fetch(pda.fork);
+    expect(clone.label).toBe("unit09-template-eu");
+    expect(clone.tags).toBe(sourceAccount.tags);
+    expect(clone.metadataUri).toBe(sourceAccount.metadataUri);
+    expect(clone.parent.toBase58()).toBe(source.forkKey.toBase58());
+    expect(clone.depth).toBe(sourceAccount.depth + 1);
+    expect(clone.isRoot).toBe(false);
+
+    const sourceAfter = await program.account.fork.fetch(
+      getForkPda(program.programId, source.forkKey)
+    );
+    expect(sourceAfter.descendantCount.toNumber()).toBe(
+      sourceAccount.descendantCount.toNumber() + 1
+    );
+    expect(sourceAfter.children.map((key: PublicKey) => key.toBase58())).toContain(
+      cloneKey.toBase58()
+    );
+  });
+
   it("exposes the creation transaction for the canonical root fork", () => {
     expect(canonicalRootForkTx).toBeTruthy();
     if (canonicalRootForkTx) {
@@ -487,4 +756,489 @@ describe("unit09_program – forks", () => {
       console.log("Unit09 canonical root fork tx:", canonicalRootForkTx);
     }
   });
+
+  it("rejects a duplicate fork label for an owner only when the index is passed", async () => {
+    const program = ctx.program;
+    const pdas = deriveAllCorePdasFromProgram(program);
+    const owner = Keypair.generate();
+    const ownerForkIndex = getOwnerForkIndexPda(program.programId, owner.publicKey);
+    const label = "unit09-fork-same-label";
+
+    const create = (withIndex: boolean) => {
+      const forkKey = Keypair.generate().publicKey;
+      return program.methods
+        .createFork(
+          buildCreateForkArgs({
+            forkKey,
+            label,
+            isRoot: true,
+            depth: 0,
+          })
+        )
+        .accounts({
+          config: pdas.config,
+          lifecycle: pdas.lifecycle,
+          fork: deriveAllCorePdasFromProgram(program, { forkKey }).fork,
+          ownerForkIndex: withIndex ? ownerForkIndex : null,
+          owner: owner.publicKey,
+          payer: ctx.wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([owner])
+        .rpc();
+    };
+
+    // Without an index, duplicate labels are allowed.
+    await create(false);
+    await create(false);
+
+    await program.methods
+      .initOwnerForkIndex()
+      .accounts({
+        payer: ctx.wallet.publicKey,
+        owner: owner.publicKey,
+        config: pdas.config,
+        lifecycle: pdas.lifecycle,
+        ownerForkIndex,
+        systemProgram: SystemProgram.programId,
+        clock: SYSVAR_CLOCK_PUBKEY,
+      })
+      .signers([owner])
+      .rpc();
+
+    await create(true);
+    await expect(create(true)).rejects.toThrow(/ForkLabelTaken/);
+
+    const indexAcc = await program.account.ownerForkIndex.fetch(ownerForkIndex);
+    expect(indexAcc.owner.equals(owner.publicKey)).toBe(true);
+    expect(indexAcc.labelCount.toNumber()).toBe(1);
+  });
+
+  it("requires the configured label prefix on new forks", async () => {
+    const program = ctx.program;
+    const { config, metrics } = deriveAllCorePdasFromProgram(program);
+
+    const setLabelPrefix = (labelPrefixRequired: string) =>
+      program.methods
+        .setConfig(buildSetConfigArgs({ labelPrefixRequired }))
+        .accounts({
+          admin: ctx.wallet.publicKey,
+          config,
+          metrics,
+          systemProgram: SystemProgram.programId,
+          clock: SYSVAR_CLOCK_PUBKEY,
+        })
+        .rpc();
+
+    // A template created before the prefix was required.
+    const template = await createForkOnChain(ctx, { label: "unit09-unprefixed-template" });
+    const templatePda = getForkPda(program.programId, template.forkKey);
+
+    const cloneFrom = (source: PublicKey, labelSuffix: string) => {
+      const cloneKey = Keypair.generate().publicKey;
+      return program.methods
+        .cloneFork({ forkKey: cloneKey, labelSuffix })
+        .accounts({
+          payer: ctx.wallet.publicKey,
+          owner: ctx.wallet.publicKey,
+          config,
+          lifecycle: deriveAllCorePdasFromProgram(program).lifecycle,
+          metrics,
+          source,
+          fork: getForkPda(program.programId, cloneKey),
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+    };
+
+    await setLabelPrefix("storyline-1:");
+    try {
+      const configAcc = await program.account.config.fetch(config);
+      expect(configAcc.labelPrefixRequired).toBe("storyline-1:");
+
+      const { forkKey } = await createForkOnChain(ctx, {
+        label: "storyline-1:intro",
+      });
+      const forkAcc = await program.account.fork.fetch(
+        getForkPda(program.programId, forkKey)
+      );
+      expect(forkAcc.label).toBe("storyline-1:intro");
+
+      await expect(
+        createForkOnChain(ctx, { label: "storyline-2:intro" })
+      ).rejects.toThrow(/ValidationFailed/);
+
+      // Renames must keep the prefix.
+      const forkPda = getForkPda(program.programId, forkKey);
+      await expect(
+        program.methods
+          .updateForkState(buildUpdateForkStateArgs({ label: "storyline-2:intro" }))
+          .accounts({ config, fork: forkPda, owner: ctx.wallet.publicKey })
+          .rpc()
+      ).rejects.toThrow(/ValidationFailed/);
+      await program.methods
+        .updateForkState(buildUpdateForkStateArgs({ label: "storyline-1:prologue" }))
+        .accounts({ config, fork: forkPda, owner: ctx.wallet.publicKey })
+        .rpc();
+
+      // Clones inherit the source label, so only prefixed sources clone.
+      await cloneFrom(forkPda, "eu");
+      await expect(cloneFrom(templatePda, "eu")).rejects.toThrow(/ValidationFailed/);
+    } finally {
+      // An empty prefix removes the requirement for the other tests.
+      await setLabelPrefix("");
+    }
+
+    const configAcc = await program.account.config.fetch(config);
+    expect(configAcc.labelPrefixRequired).toBeNull();
+  });
+
+  it("applies the name length override to renamed and cloned fork labels", async () => {
+    const program = ctx.program;
+    const { config, metrics } = deriveAllCorePdasFromProgram(program);
+
+    const setMaxNameLenOverride = (maxNameLenOverride: number) =>
+      program.methods
+        .setConfig(buildSetConfigArgs({ maxNameLenOverride }))
+        .accounts({
+          admin: ctx.wallet.publicKey,
+          config,
+          metrics,
+          systemProgram: SystemProgram.programId,
+          clock: SYSVAR_CLOCK_PUBKEY,
+        })
+        .rpc();
+
+    const { forkKey } = await createForkOnChain(ctx, { label: "unit09-lbl" });
+    const forkPda = getForkPda(program.programId, forkKey);
+
+    await setMaxNameLenOverride(16);
+    try {
+      await expect(
+        program.methods
+          .updateForkState(buildUpdateForkStateArgs({ label: "unit09-label-too-long" }))
+          .accounts({ config, fork: forkPda, owner: ctx.wallet.publicKey })
+          .rpc()
+      ).rejects.toThrow(/StringTooLong/);
+
+      const cloneKey = Keypair.generate().publicKey;
+      await expect(
+        program.methods
+          // "unit09-lbl-long-suffix" is 22 bytes.
+          .cloneFork({ forkKey: cloneKey, labelSuffix: "long-suffix" })
+          .accounts({
+            payer: ctx.wallet.publicKey,
+            owner: ctx.wallet.publicKey,
+            config,
+            lifecycle: deriveAllCorePdasFromProgram(program).lifecycle,
+            metrics,
+            source: forkPda,
+            fork: getForkPda(program.programId, cloneKey),
+            systemProgram: SystemProgram.programId,
+          })
+          .rpc()
+      ).rejects.toThrow(/StringTooLong/);
+    } finally {
+      await setMaxNameLenOverride(64);
+    }
+
+    const forkAcc = await program.account.fork.fetch(forkPda);
+    expect(forkAcc.label).toBe("unit09-lbl");
+  });
+
+  it("enforces the fork creation cooldown", async () => {
+    const program = ctx.program;
+    const { config, metrics } = deriveAllCorePdasFromProgram(program);
+
+    const setCooldowns = (forkCreationSecs: number) =>
+      program.methods
+        .setConfig(
+          buildSetConfigArgs({
+            cooldowns: { observationSecs: 0, forkCreationSecs, moduleTouchSecs: 0 },
+          })
+        )
+        .accounts({
+          admin: ctx.wallet.publicKey,
+          config,
+          metrics,
+          systemProgram: SystemProgram.programId,
+          clock: SYSVAR_CLOCK_PUBKEY,
+        })
+        .rpc();
+
+    // Without a window, forks can be created back to back.
+    await createForkOnChain(ctx);
+    const metricsAcc = await program.account.metrics.fetch(metrics);
+    expect(metricsAcc.lastForkCreatedAt.toNumber()).toBeGreaterThan(0);
+
+    await setCooldowns(3600);
+    try {
+      const configAcc = await program.account.config.fetch(config);
+      expect(configAcc.cooldowns.forkCreationSecs).toBe(3600);
+      expect(configAcc.cooldowns.observationSecs).toBe(0);
+
+      await expect(createForkOnChain(ctx)).rejects.toThrow(/CooldownActive/);
+    } finally {
+      // A zero window disables the cooldown for the other tests.
+      await setCooldowns(0);
+    }
+
+    await createForkOnChain(ctx);
+  });
+
+  it("lets only the admin reassign a fork's owner", async () => {
+    const program = ctx.program;
+    const { forkKey } = await createForkOnChain(ctx, {
+      label: "unit09-fork-reassigned",
+      isRoot: true,
+      depth: 0,
+    });
+    const pdas = deriveAllCorePdasFromProgram(program, { forkKey });
+    const newOwner = Keypair.generate().publicKey;
+    const outsider = Keypair.generate();
+
+    const reassign = (owner: PublicKey, admin?: Keypair) => {
+      const builder = program.methods
+        .adminReassignFork({ newOwner: owner })
+        .accounts({
+          admin: admin ? admin.publicKey : ctx.wallet.publicKey,
+          config: pdas.config,
+          lifecycle: pdas.lifecycle,
+          metrics: pdas.metrics,
+          fork: pdas.fork,
+          clock: SYSVAR_CLOCK_PUBKEY,
+        });
+      return admin ? builder.signers([admin]).rpc() : builder.rpc();
+    };
+
+    await expect(reassign(newOwner, outsider)).rejects.toThrow(/InvalidAdmin/);
+    await expect(reassign(PublicKey.default)).rejects.toThrow(/InvalidForkOwner/);
+    await expect(reassign(ctx.wallet.publicKey)).rejects.toThrow(/NoChangeRequested/);
+
+    const events: any[] = [];
+    const listener = program.addEventListener("forkOwnerReassigned", (event: any) => {
+      events.push(event);
+    });
+    try {
+      await reassign(newOwner);
+      await new Promise((resolve) => setTimeout(resolve, 2_000));
+    } finally {
+      await program.removeEventListener(listener);
+    }
+
+    const forkAcc = await program.account.fork.fetch(pdas.fork);
+    expect(forkAcc.owner.equals(newOwner)).toBe(true);
+
+    expect(events).toHaveLength(1);
+    expect(events[0].admin.equals(ctx.wallet.publicKey)).toBe(true);
+    expect(events[0].oldOwner.equals(ctx.wallet.publicKey)).toBe(true);
+    expect(events[0].newOwner.equals(newOwner)).toBe(true);
+
+    // The previous owner no longer controls the fork.
+    await expect(
+      program.methods
+        .updateForkState(buildUpdateForkStateArgs({ isActive: false }))
+        .accounts({
+          config: pdas.config,
+          fork: pdas.fork,
+          owner: ctx.wallet.publicKey,
+        })
+        .rpc()
+    ).rejects.toThrow(/InvalidForkOwner/);
+  });
+
+  it("prunes inactive forks and refunds their rent", async () => {
+    const program = ctx.program;
+    const pdas = deriveAllCorePdasFromProgram(program);
+    const refundRecipient = Keypair.generate().publicKey;
+
+    const createInactiveFork = async (label: string): Promise<PublicKey> => {
+      const { forkKey } = await createForkOnChain(ctx, { label, isRoot: true, depth: 0 });
+      const forkPda = getForkPda(program.programId, forkKey);
+      await program.methods
+        .updateForkState(buildUpdateForkStateArgs({ isActive: false }))
+        .accounts({
+          config: pdas.config,
+          fork: forkPda,
+          owner: ctx.wallet.publicKey,
+        })
+        .rpc();
+      return forkPda;
+    };
+
+    const prune = (forks: PublicKey[], atomic: boolean) =>
+      program.methods
+        .pruneForks({ atomic, ancestorCount: 0 })
+        .accounts({
+          admin: ctx.wallet.publicKey,
+          config: pdas.config,
+          lifecycle: pdas.lifecycle,
+          metrics: pdas.metrics,
+          refundRecipient,
+          clock: SYSVAR_CLOCK_PUBKEY,
+        })
+        .remainingAccounts(
+          forks.map((pubkey) => ({ pubkey, isWritable: true, isSigner: false }))
+        )
+        .rpc();
+
+    const first = await createInactiveFork("unit09-fork-prune-a");
+    const second = await createInactiveFork("unit09-fork-prune-b");
+    const { forkKey: activeKey } = await createForkOnChain(ctx, {
+      label: "unit09-fork-prune-active",
+      isRoot: true,
+      depth: 0,
+    });
+    const active = getForkPda(program.programId, activeKey);
+
+    // An active fork fails an atomic batch.
+    await expect(prune([first, active], true)).rejects.toThrow(/ForkNotPrunable/);
+
+    const rent =
+      (await ctx.connection.getBalance(first)) + (await ctx.connection.getBalance(second));
+    const metricsBefore = await program.account.metrics.fetch(pdas.metrics);
+
+    await prune([first, second], true);
+
+    expect(await program.account.fork.fetchNullable(first)).toBeNull();
+    expect(await program.account.fork.fetchNullable(second)).toBeNull();
+    expect(await ctx.connection.getBalance(refundRecipient)).toBe(rent);
+
+    const metricsAfter = await program.account.metrics.fetch(pdas.metrics);
+    expect(metricsBefore.totalForks.sub(metricsAfter.totalForks).toNumber()).toBe(2);
+    expect(metricsAfter.activeForks.eq(metricsBefore.activeForks)).toBe(true);
+
+    // The active fork is untouched.
+    const activeAcc = await program.account.fork.fetch(active);
+    expect(activeAcc.isActive).toBe(true);
+  });
+
+  it("prunes a child only together with its parent fork", async () => {
+    const program = ctx.program;
+    const pdas = deriveAllCorePdasFromProgram(program);
+    const refundRecipient = Keypair.generate().publicKey;
+
+    const deactivate = (forkPda: PublicKey) =>
+      program.methods
+        .updateForkState(buildUpdateForkStateArgs({ isActive: false }))
+        .accounts({
+          config: pdas.config,
+          fork: forkPda,
+          owner: ctx.wallet.publicKey,
+        })
+        .rpc();
+
+    const prune = (forks: PublicKey[], atomic: boolean, ancestors: PublicKey[] = []) =>
+      program.methods
+        .pruneForks({ atomic, ancestorCount: ancestors.length })
+        .accounts({
+          admin: ctx.wallet.publicKey,
+          config: pdas.config,
+          lifecycle: pdas.lifecycle,
+          metrics: pdas.metrics,
+          refundRecipient,
+          clock: SYSVAR_CLOCK_PUBKEY,
+        })
+        .remainingAccounts(
+          [...forks, ...ancestors].map((pubkey) => ({
+            pubkey,
+            isWritable: true,
+            isSigner: false,
+          }))
+        )
+        .rpc();
+
+    const parent = await createForkOnChain(ctx, {
+      label: "unit09-prune-parent",
+      isRoot: true,
+      depth: 0,
+    });
+    const child = await createForkOnChain(ctx, {
+      parent: parent.forkKey,
+      label: "unit09-prune-child",
+      ancestors: [parent.forkKey],
+    });
+    const parentPda = getForkPda(program.programId, parent.forkKey);
+    const childPda = getForkPda(program.programId, child.forkKey);
+    await deactivate(childPda);
+
+    // Without its parent the child cannot be pruned, atomically or not.
+    await expect(prune([childPda], true)).rejects.toThrow(/MissingRequiredAccount/);
+    await prune([childPda], false);
+    expect(await program.account.fork.fetchNullable(childPda)).not.toBeNull();
+
+    // With its (still active) parent the child is pruned and detached.
+    await prune([childPda, parentPda], false);
+    expect(await program.account.fork.fetchNullable(childPda)).toBeNull();
+
+    const parentAcc = await program.account.fork.fetch(parentPda);
+    expect(parentAcc.isActive).toBe(true);
+    expect(parentAcc.children).toHaveLength(0);
+    expect(parentAcc.descendantCount.toNumber()).toBe(0);
+
+    // A parent that is not a fork is vouched for by its empty fork PDA.
+    const identity = Keypair.generate().publicKey;
+    const orphan = await createForkOnChain(ctx, {
+      parent: identity,
+      label: "unit09-prune-orphan",
+    });
+    const orphanPda = getForkPda(program.programId, orphan.forkKey);
+    await deactivate(orphanPda);
+
+    await expect(prune([orphanPda], true)).rejects.toThrow(/MissingRequiredAccount/);
+    await prune([orphanPda, getForkPda(program.programId, identity)], true);
+    expect(await program.account.fork.fetchNullable(orphanPda)).toBeNull();
+
+    // Ancestors passed after the forks are decremented up the whole chain.
+    const root = await createForkOnChain(ctx, {
+      label: "unit09-prune-root",
+      isRoot: true,
+      depth: 0,
+    });
+    const middle = await createForkOnChain(ctx, {
+      parent: root.forkKey,
+      label: "unit09-prune-middle",
+      ancestors: [root.forkKey],
+    });
+    const leaf = await createForkOnChain(ctx, {
+      parent: middle.forkKey,
+      depth: 2,
+      label: "unit09-prune-leaf",
+      ancestors: [middle.forkKey, root.forkKey],
+    });
+    const rootPda = getForkPda(program.programId, root.forkKey);
+    const middlePda = getForkPda(program.programId, middle.forkKey);
+    const leafPda = getForkPda(program.programId, leaf.forkKey);
+    expect((await program.account.fork.fetch(rootPda)).descendantCount.toNumber()).toBe(2);
+    await deactivate(leafPda);
+
+    await prune([leafPda], true, [middlePda, rootPda]);
+    expect(await program.account.fork.fetchNullable(leafPda)).toBeNull();
+
+    const middleAcc = await program.account.fork.fetch(middlePda);
+    expect(middleAcc.children).toHaveLength(0);
+    expect(middleAcc.descendantCount.toNumber()).toBe(0);
+    expect((await program.account.fork.fetch(rootPda)).descendantCount.toNumber()).toBe(1);
+  });
+
+  it("rejects migrating a fork that is already on the current schema", async () => {
+    const program = ctx.program;
+
+    const { forkKey } = await createForkOnChain(ctx, { label: "unit09-migrate" });
+    const pdas = deriveAllCorePdasFromProgram(program, { forkKey });
+
+    const migrate = (admin: Keypair | null) => {
+      const builder = program.methods.migrateFork().accounts({
+        admin: admin ? admin.publicKey : ctx.wallet.publicKey,
+        config: pdas.config,
+        metrics: pdas.metrics,
+        fork: pdas.fork,
+        systemProgram: SystemProgram.programId,
+      });
+      return admin ? builder.signers([admin]).rpc() : builder.rpc();
+    };
+
+    await expect(migrate(Keypair.generate())).rejects.toThrow(/InvalidAdmin/);
+    await expect(migrate(null)).rejects.toThrow(/MigrationAlreadyApplied/);
+  });
 });
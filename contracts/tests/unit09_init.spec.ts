@@ -23,14 +23,19 @@
  * ============================================================================
  */
 
-import { SystemProgram } from "@solana/web3.js";
+import { Keypair, PublicKey, SystemProgram, SYSVAR_CLOCK_PUBKEY } from "@solana/web3.js";
 import { BN } from "@coral-xyz/anchor";
 
-import { createUnit09TestContext } from "./helpers/provider";
-import { buildInitializePdaAccounts } from "./helpers/accounts";
+import { createUnit09TestContext, simulateReturnData } from "./helpers/provider";
+import { buildInitializePdaAccounts, getAuthorityPda, getRepoPda } from "./helpers/accounts";
 import {
+  addCoAdminOnChain,
   buildInitializeArgs,
   BuildInitializeArgsOptions,
+  createRepoOnChain,
+  removeCoAdminOnChain,
+  setRequiredAdminSigsOnChain,
+  buildSetConfigArgs,
 } from "./helpers/builders";
 import {
   assertConfig,
@@ -60,10 +65,43 @@ describe("unit09_program – initialize", () => {
     await ctx.ensurePayerHasFunds(2 * 1_000_000_000); // 2 SOL (on devnet/localnet)
   });
 
+  it("reverts every account when one init step fails", async () => {
+    // `requireAll` without the metadata account fails after the other
+    // accounts were already allocated by Anchor.
+    const initArgs = buildInitializeArgs({
+      ...customInitOptions,
+      admin: ctx.wallet.publicKey,
+      requireAll: true,
+    });
+
+    await expect(
+      ctx.program.methods
+        .initialize(initArgs)
+        .accounts({
+          config: pda.config,
+          metrics: pda.metrics,
+          lifecycle: pda.lifecycle,
+          globalMetadata: null,
+          admin: ctx.wallet.publicKey,
+          payer: ctx.wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc()
+    ).rejects.toThrow(/MissingRequiredAccount/);
+
+    expect(await ctx.program.account.config.fetchNullable(pda.config)).toBeNull();
+    expect(await ctx.program.account.metrics.fetchNullable(pda.metrics)).toBeNull();
+    expect(await ctx.program.account.lifecycle.fetchNullable(pda.lifecycle)).toBeNull();
+    expect(
+      await ctx.program.account.globalMetadata.fetchNullable(pda.globalMetadata)
+    ).toBeNull();
+  });
+
   it("initializes config, metrics, lifecycle, and global metadata", async () => {
     const initArgs = buildInitializeArgs({
       ...customInitOptions,
       admin: ctx.wallet.publicKey,
+      requireAll: true,
     });
 
     const tx = await ctx.program.methods
@@ -180,6 +218,284 @@ describe("unit09_program – initialize", () => {
     ).rejects.toThrow();
   });
 
+  it("rejects the default pubkey as fee recipient", async () => {
+    await expect(
+      ctx.program.methods
+        .setConfig(buildSetConfigArgs({ feeRecipient: PublicKey.default }))
+        .accounts({
+          admin: ctx.wallet.publicKey,
+          config: pda.config,
+          metrics: pda.metrics,
+          systemProgram: SystemProgram.programId,
+          clock: SYSVAR_CLOCK_PUBKEY,
+        })
+        .rpc()
+    ).rejects.toThrow(/InvalidFeeRecipient/);
+  });
+
+  it("keeps single-admin set_config working when one signature is required", async () => {
+    const setRequiredAdminSigs = (requiredAdminSigs: number) =>
+      ctx.program.methods
+        .setConfig(buildSetConfigArgs({ requiredAdminSigs }))
+        .accounts({
+          admin: ctx.wallet.publicKey,
+          config: pda.config,
+          metrics: pda.metrics,
+          systemProgram: SystemProgram.programId,
+          clock: SYSVAR_CLOCK_PUBKEY,
+        })
+        .rpc();
+
+    await expect(setRequiredAdminSigs(0)).rejects.toThrow(/ValueOutOfRange/);
+
+    await setRequiredAdminSigs(1);
+    const configAcc = await ctx.program.account.config.fetch(pda.config);
+    expect(configAcc.requiredAdminSigs).toEqual(1);
+  });
+
+  it("enforces a 2-of-N admin quorum once a co-admin is registered", async () => {
+    const coAdmin = Keypair.generate();
+    const outsider = Keypair.generate();
+    const coAdminAuthority = getAuthorityPda(ctx.program.programId, coAdmin.publicKey);
+    const fetchConfig = () => ctx.program.account.config.fetch(pda.config);
+
+    // Without co-admins a quorum of two can never be met.
+    await expect(setRequiredAdminSigsOnChain(ctx, 2)).rejects.toThrow(
+      /AdminQuorumUnreachable/
+    );
+
+    // The admin cannot register itself; any other key gets an admin entry.
+    await expect(addCoAdminOnChain(ctx, ctx.wallet.publicKey)).rejects.toThrow(
+      /ValidationFailed/
+    );
+    await addCoAdminOnChain(ctx, coAdmin.publicKey);
+    const authorityAcc = await ctx.program.account.authority.fetch(coAdminAuthority);
+    expect(authorityAcc.authority.equals(coAdmin.publicKey)).toBe(true);
+    expect(authorityAcc.isGlobal).toBe(true);
+    expect((await fetchConfig()).coAdminCount).toEqual(1);
+
+    // Raising the requirement is still checked against the current quorum (1).
+    await setRequiredAdminSigsOnChain(ctx, 2);
+    expect((await fetchConfig()).requiredAdminSigs).toEqual(2);
+
+    // From now on the admin alone, or with a signer that is not a
+    // co-admin, falls short.
+    await expect(setRequiredAdminSigsOnChain(ctx, 2)).rejects.toThrow(
+      /UnauthorizedAdminAction/
+    );
+    await expect(setRequiredAdminSigsOnChain(ctx, 2, [outsider])).rejects.toThrow(
+      /UnauthorizedAdminAction/
+    );
+
+    // Admin plus co-admin reach the quorum.
+    await setRequiredAdminSigsOnChain(ctx, 2, [coAdmin]);
+
+    // The co-admin cannot be removed while the quorum depends on it.
+    await expect(
+      removeCoAdminOnChain(ctx, coAdmin.publicKey, [coAdmin])
+    ).rejects.toThrow(/AdminQuorumUnreachable/);
+
+    // Back to single-admin, after which the co-admin can be removed.
+    await setRequiredAdminSigsOnChain(ctx, 1, [coAdmin]);
+    await removeCoAdminOnChain(ctx, coAdmin.publicKey);
+    expect(await ctx.program.account.authority.fetchNullable(coAdminAuthority)).toBeNull();
+    const configAcc = await fetchConfig();
+    expect(configAcc.coAdminCount).toEqual(0);
+    expect(configAcc.requiredAdminSigs).toEqual(1);
+  });
+
+  it("bumps policyVersion only when the policy ref changes", async () => {
+    const setPolicyRef = (policyRef: number[] | null) =>
+      ctx.program.methods
+        .setConfig(buildSetConfigArgs({ policyRef }))
+        .accounts({
+          admin: ctx.wallet.publicKey,
+          config: pda.config,
+          metrics: pda.metrics,
+          systemProgram: SystemProgram.programId,
+          clock: SYSVAR_CLOCK_PUBKEY,
+        })
+        .rpc();
+
+    const before = await ctx.program.account.config.fetch(pda.config);
+    const newRef = Array.from(before.policyRef as number[], (b) => (b + 1) % 256);
+
+    await setPolicyRef(newRef);
+    const changed = await ctx.program.account.config.fetch(pda.config);
+    expect(changed.policyVersion).toBe(before.policyVersion + 1);
+
+    // Same ref, and no ref at all: unchanged version.
+    await setPolicyRef(newRef);
+    await setPolicyRef(null);
+    const unchanged = await ctx.program.account.config.fetch(pda.config);
+    expect(unchanged.policyVersion).toBe(changed.policyVersion);
+  });
+
+  it("collects the computed fee into the configured fee recipient", async () => {
+    const recipient = Keypair.generate().publicKey;
+
+    await ctx.program.methods
+      .setConfig(buildSetConfigArgs({ feeRecipient: recipient }))
+      .accounts({
+        admin: ctx.wallet.publicKey,
+        config: pda.config,
+        metrics: pda.metrics,
+        systemProgram: SystemProgram.programId,
+        clock: SYSVAR_CLOCK_PUBKEY,
+      })
+      .rpc();
+
+    const configAcc = await ctx.program.account.config.fetch(pda.config);
+    expect(configAcc.feeRecipient.toBase58()).toEqual(recipient.toBase58());
+
+    // 0.1 SOL keeps the fee above the rent-exempt minimum for a new account.
+    const amount = 100_000_000;
+    const expectedFee = Math.floor((amount * configAcc.feeBps) / 10_000);
+
+    // The fee is charged for a repository owned by the payer.
+    const { repoKey } = await createRepoOnChain(ctx, {});
+    const repo = getRepoPda(ctx.program.programId, repoKey);
+
+    const collect = (payer: Keypair | null) => {
+      const builder = ctx.program.methods
+        .collectFee({ amount: new BN(amount) })
+        .accounts({
+          payer: payer ? payer.publicKey : ctx.wallet.publicKey,
+          config: pda.config,
+          feeRecipient: recipient,
+          repo,
+          systemProgram: SystemProgram.programId,
+        });
+      return payer ? builder.signers([payer]).rpc() : builder.rpc();
+    };
+
+    const before = await ctx.connection.getBalance(recipient);
+    await collect(null);
+    const after = await ctx.connection.getBalance(recipient);
+    expect(after - before).toEqual(expectedFee);
+
+    // Another payer cannot charge fees against this repository (and so
+    // cannot pick up its override).
+    const stranger = Keypair.generate();
+    await expect(collect(stranger)).rejects.toThrow(/InvalidAuthority/);
+  });
+
+  it("confirms cached singleton bumps via refreshBumps", async () => {
+    const tx = await ctx.program.methods
+      .refreshBumps()
+      .accounts({
+        admin: ctx.wallet.publicKey,
+        config: pda.config,
+        metrics: pda.metrics,
+        lifecycle: pda.lifecycle,
+        globalMetadata: null,
+      })
+      .rpc();
+
+    expect(tx).toBeTruthy();
+  });
+
+  it("rejects migrating a deployment that is already on the current schema", async () => {
+    const migrate = (admin: Keypair | null) => {
+      const builder = ctx.program.methods.migrateDeployment().accounts({
+        admin: admin ? admin.publicKey : ctx.wallet.publicKey,
+        config: pda.config,
+        metrics: pda.metrics,
+        systemProgram: SystemProgram.programId,
+      });
+      return admin ? builder.signers([admin]).rpc() : builder.rpc();
+    };
+
+    await expect(migrate(Keypair.generate())).rejects.toThrow(/InvalidAdmin/);
+    await expect(migrate(null)).rejects.toThrow(/MigrationAlreadyApplied/);
+  });
+
+  it("returns non-zero compute-unit estimates via getCuEstimates", async () => {
+    const { err, data } = await simulateReturnData(
+      ctx,
+      ctx.program.methods.getCuEstimates().accounts({})
+    );
+    expect(err).toBeNull();
+
+    // Borsh Vec<CuEstimate>: u32 count, then (u32 len + utf8 name, u32 units).
+    const count = data.readUInt32LE(0);
+    let offset = 4;
+    const estimates: { instruction: string; units: number }[] = [];
+    for (let i = 0; i < count; i++) {
+      const len = data.readUInt32LE(offset);
+      const instruction = data.toString("utf8", offset + 4, offset + 4 + len);
+      const units = data.readUInt32LE(offset + 4 + len);
+      estimates.push({ instruction, units });
+      offset += 8 + len;
+    }
+
+    expect(estimates.length).toBeGreaterThan(0);
+    expect(estimates.map((e) => e.instruction)).toContain("record_observation");
+    for (const estimate of estimates) {
+      expect(estimate.units).toBeGreaterThan(0);
+    }
+  });
+
+  it("reports fewer permissions via getPermissions while frozen", async () => {
+    // Bits from `permission_bits` in state/lifecycle.rs.
+    const WRITE_REPOS = 1 << 0;
+    const RECORD_OBSERVATIONS = 1 << 3;
+    const COLLECT_FEE = 1 << 6;
+
+    const getPermissions = async (): Promise<number> => {
+      const { err, data } = await simulateReturnData(
+        ctx,
+        ctx.program.methods
+          .getPermissions()
+          .accounts({ config: pda.config, lifecycle: pda.lifecycle })
+      );
+      expect(err).toBeNull();
+      // Trailing zero bytes may be trimmed; pad back to a u32.
+      return Buffer.concat([data, Buffer.alloc(4)]).readUInt32LE(0);
+    };
+
+    const setPhase = (phase: number) =>
+      ctx.program.methods
+        .setLifecycle({ phase, globalFreeze: null, freezeReason: "" })
+        .accounts({
+          admin: ctx.wallet.publicKey,
+          config: pda.config,
+          lifecycle: pda.lifecycle,
+          metrics: pda.metrics,
+          clock: SYSVAR_CLOCK_PUBKEY,
+        })
+        .rpc();
+
+    const lifecycleAcc = await ctx.program.account.lifecycle.fetch(pda.lifecycle);
+    const originalPhase = lifecycleAcc.phase;
+
+    const normal = await getPermissions();
+    expect(normal & WRITE_REPOS).toBe(WRITE_REPOS);
+    expect(normal & RECORD_OBSERVATIONS).toBe(RECORD_OBSERVATIONS);
+
+    await setPhase(3); // Frozen
+    try {
+      const frozen = await getPermissions();
+      expect(frozen & WRITE_REPOS).toBe(0);
+      expect(frozen & RECORD_OBSERVATIONS).toBe(0);
+      expect(frozen & COLLECT_FEE).toBe(COLLECT_FEE);
+      expect(frozen).not.toEqual(normal);
+    } finally {
+      await setPhase(originalPhase);
+    }
+  });
+
+  it("only maps Authority accounts to permissions via getAuthorityPermissions", async () => {
+    // The role-to-permission mapping itself is covered by the unit tests of
+    // `Authority::permissions`; any other account type is rejected.
+    const { err, logs } = await simulateReturnData(
+      ctx,
+      ctx.program.methods.getAuthorityPermissions().accounts({ authority: pda.config })
+    );
+    expect(err).not.toBeNull();
+    expect(JSON.stringify(logs)).toMatch(/AccountDiscriminatorMismatch/);
+  });
+
   it("exposes the initialization transaction signature for debugging", () => {
     // Not strictly needed for correctness, but useful for CI / explorers
     expect(initTxSignature).toBeTruthy();
@@ -22,12 +22,15 @@
  * ============================================================================
  */
 
-import { SystemProgram, Keypair, PublicKey } from "@solana/web3.js";
+import { SystemProgram, SYSVAR_CLOCK_PUBKEY, Keypair, PublicKey } from "@solana/web3.js";
 import { BN } from "@coral-xyz/anchor";
 
-import { createUnit09TestContext } from "./helpers/provider";
+import { createUnit09TestContext, simulateReturnData } from "./helpers/provider";
 import {
+  buildCoSignerAccounts,
   deriveAllCorePdasFromProgram,
+  getAuthorityPda,
+  getMetricsSnapshotPda,
 } from "./helpers/accounts";
 import {
   BuildInitializeArgsOptions,
@@ -36,10 +39,16 @@ import {
   buildRegisterModuleArgs,
   buildCreateForkArgs,
   buildRecordMetricsArgs,
+  buildRecordObservationArgs,
+  buildUpdateRepoArgs,
   createRepoOnChain,
   createModuleOnChain,
   createForkOnChain,
   initializeUnit09OnChain,
+  addCoAdminOnChain,
+  removeCoAdminOnChain,
+  setRequiredAdminSigsOnChain,
+  buildSetConfigArgs,
 } from "./helpers/builders";
 import {
   assertMetrics,
@@ -228,6 +237,26 @@ describe("unit09_program – metrics", () => {
     );
   });
 
+  it("rejects recordMetrics when every field is null", async () => {
+    const program = ctx.program;
+    const pdas = deriveAllCorePdasFromProgram(program);
+    const metricsBefore = await program.account.metrics.fetch(pdas.metrics);
+
+    await expect(
+      program.methods
+        .recordMetrics(buildRecordMetricsArgs())
+        .accounts({
+          config: pdas.config,
+          metrics: pdas.metrics,
+          admin: ctx.wallet.publicKey,
+        })
+        .rpc()
+    ).rejects.toThrow(/ValidationFailed/);
+
+    const metricsAfter = await program.account.metrics.fetch(pdas.metrics);
+    expect(metricsAfter.eventSeq.eq(metricsBefore.eventSeq)).toBe(true);
+  });
+
   it("ignores null fields when calling recordMetrics (partial update)", async () => {
     const program = ctx.program;
 
@@ -398,6 +427,200 @@ describe("unit09_program – metrics", () => {
     expect(metricsAfterSecond.totalModules.gte(metricsAfterFirst.totalModules)).toBe(true);
   });
 
+  it("decrements activeRepos when a repo is deactivated", async () => {
+    const program = ctx.program;
+
+    const { repoKey } = await createRepoOnChain(ctx, {
+      name: "unit09-metrics-active-repo",
+    });
+    const pdas = deriveAllCorePdasFromProgram(program, { repoKey });
+
+    const metricsBefore = await program.account.metrics.fetch(pdas.metrics);
+
+    await program.methods
+      .updateRepo(buildUpdateRepoArgs({ isActive: false }))
+      .accounts({
+        config: pdas.config,
+        metrics: pdas.metrics,
+        repo: pdas.repo,
+        authority: ctx.wallet.publicKey,
+      })
+      .rpc();
+
+    const metricsAfter = await program.account.metrics.fetch(pdas.metrics);
+
+    expectBnEquals(
+      metricsAfter.activeRepos as unknown as BN,
+      metricsBefore.activeRepos.toBigInt() - BigInt(1),
+      "metrics.activeRepos"
+    );
+    // Deactivation does not remove the repo from the total.
+    expectBnEquals(
+      metricsAfter.totalRepos as unknown as BN,
+      metricsBefore.totalRepos.toBigInt(),
+      "metrics.totalRepos"
+    );
+  });
+
+  it("emits strictly increasing eventSeq values across consecutive instructions", async () => {
+    const program = ctx.program;
+    const seqs: BN[] = [];
+
+    const listener = program.addEventListener("repoRegistered", (event: any) => {
+      seqs.push(event.eventSeq as BN);
+    });
+
+    try {
+      await createRepoOnChain(ctx, { name: "unit09-metrics-seq-a" });
+      await createRepoOnChain(ctx, { name: "unit09-metrics-seq-b" });
+
+      // Give the websocket subscription a moment to deliver both events.
+      await new Promise((resolve) => setTimeout(resolve, 2_000));
+    } finally {
+      await program.removeEventListener(listener);
+    }
+
+    expect(seqs.length).toBe(2);
+    expect(seqs[1].gt(seqs[0])).toBe(true);
+
+    const pdas = deriveAllCorePdasFromProgram(program);
+    const metricsAcc = await program.account.metrics.fetch(pdas.metrics);
+    expect(metricsAcc.eventSeq.gte(seqs[1])).toBe(true);
+  });
+
+  it("recomputes repository totals from Repo accounts across batches", async () => {
+    const program = ctx.program;
+
+    const repoKeys: PublicKey[] = [];
+    for (let i = 0; i < 3; i++) {
+      const { repoKey } = await createRepoOnChain(ctx, {
+        name: `unit09-recompute-repo-${i}`,
+      });
+      repoKeys.push(repoKey);
+    }
+    const repoPdas = repoKeys.map(
+      (repoKey) => deriveAllCorePdasFromProgram(program, { repoKey }).repo
+    );
+    const pdas = deriveAllCorePdasFromProgram(program);
+    const asRemaining = (pubkeys: PublicKey[]) =>
+      pubkeys.map((pubkey) => ({ pubkey, isWritable: false, isSigner: false }));
+
+    const recompute = (reset: boolean, finalize: boolean, repos: PublicKey[]) =>
+      program.methods
+        .recomputeMetrics({ reset, finalize })
+        .accounts({
+          admin: ctx.wallet.publicKey,
+          config: pdas.config,
+          lifecycle: pdas.lifecycle,
+          metrics: pdas.metrics,
+        })
+        .remainingAccounts(asRemaining(repos))
+        .rpc();
+
+    // Continuing without a reset is rejected.
+    await expect(recompute(false, true, repoPdas)).rejects.toThrow(
+      /MetricsRecomputeNotStarted/
+    );
+
+    // A repo passed twice would be counted twice.
+    await expect(recompute(true, true, [repoPdas[0], repoPdas[0]])).rejects.toThrow(
+      /ValidationFailed/
+    );
+
+    await recompute(true, false, repoPdas.slice(0, 2));
+    const midway = await program.account.metrics.fetch(pdas.metrics);
+    expect(midway.recomputeInProgress).toBe(true);
+
+    await recompute(false, true, repoPdas.slice(2));
+
+    const repos = await Promise.all(
+      repoPdas.map((pda) => program.account.repo.fetch(pda))
+    );
+    const sum = (pick: (repo: (typeof repos)[number]) => BN | number) =>
+      repos.reduce((acc, repo) => acc + BigInt(pick(repo).toString()), BigInt(0));
+
+    const metricsAfter = await program.account.metrics.fetch(pdas.metrics);
+    expect(metricsAfter.recomputeInProgress).toBe(false);
+    expectBnEquals(metricsAfter.totalRepos as unknown as BN, 3, "totalRepos");
+    expectBnEquals(
+      metricsAfter.activeRepos as unknown as BN,
+      repos.filter((repo) => repo.isActive).length,
+      "activeRepos"
+    );
+    expectBnEquals(
+      metricsAfter.totalModules as unknown as BN,
+      sum((repo) => repo.moduleCount),
+      "totalModules"
+    );
+    expectBnEquals(
+      metricsAfter.totalObservations as unknown as BN,
+      sum((repo) => repo.observationCount),
+      "totalObservations"
+    );
+    expectBnEquals(
+      metricsAfter.totalLinesOfCode as unknown as BN,
+      sum((repo) => repo.totalLinesOfCode),
+      "totalLinesOfCode"
+    );
+    expectBnEquals(
+      metricsAfter.totalFilesProcessed as unknown as BN,
+      sum((repo) => repo.totalFilesProcessed),
+      "totalFilesProcessed"
+    );
+  });
+
+  it("reports the drift corrected by recomputeMetrics", async () => {
+    const program = ctx.program;
+    const pdas = deriveAllCorePdasFromProgram(program);
+
+    const { repoKey } = await createRepoOnChain(ctx, {
+      name: "unit09-drift-repo",
+    });
+    const repoPda = deriveAllCorePdasFromProgram(program, { repoKey }).repo;
+
+    // Deliberately push the live observation total far above reality.
+    const inflated = BigInt(1_000_000);
+    await program.methods
+      .recordMetrics(buildRecordMetricsArgs({ totalObservations: inflated }))
+      .accounts({
+        config: pdas.config,
+        metrics: pdas.metrics,
+        authority: ctx.wallet.publicKey,
+      })
+      .rpc();
+
+    const drifts: any[] = [];
+    const listener = program.addEventListener("metricsDriftReported", (event: any) => {
+      drifts.push(event);
+    });
+
+    try {
+      await program.methods
+        .recomputeMetrics({ reset: true, finalize: true })
+        .accounts({
+          admin: ctx.wallet.publicKey,
+          config: pdas.config,
+          lifecycle: pdas.lifecycle,
+          metrics: pdas.metrics,
+        })
+        .remainingAccounts([{ pubkey: repoPda, isWritable: false, isSigner: false }])
+        .rpc();
+
+      await new Promise((resolve) => setTimeout(resolve, 2_000));
+    } finally {
+      await program.removeEventListener(listener);
+    }
+
+    const repo = await program.account.repo.fetch(repoPda);
+    const recomputed = BigInt(repo.observationCount.toString());
+
+    expect(drifts.length).toBe(1);
+    expect(BigInt(drifts[0].totalObservationsDelta.toString())).toBe(
+      recomputed - inflated
+    );
+    expect(BigInt(drifts[0].totalReposDelta.toString()) <= BigInt(0)).toBe(true);
+  });
+
   it("exposes structured metrics fields for external dashboards or analytics", async () => {
     const program = ctx.program;
 
@@ -423,4 +646,476 @@ describe("unit09_program – metrics", () => {
     expect(Number.isFinite(safeTotals.modules)).toBe(true);
     expect(Number.isFinite(safeTotals.forks)).toBe(true);
   });
+
+  it("suppresses optional metrics events in Sunset only when quietWhenFrozen is set", async () => {
+    const program = ctx.program;
+    const pdas = deriveAllCorePdasFromProgram(program);
+
+    const setQuietWhenFrozen = (quietWhenFrozen: boolean) =>
+      program.methods
+        .setConfig(buildSetConfigArgs({ quietWhenFrozen }))
+        .accounts({
+          admin: ctx.wallet.publicKey,
+          config: pdas.config,
+          metrics: pdas.metrics,
+          systemProgram: SystemProgram.programId,
+          clock: SYSVAR_CLOCK_PUBKEY,
+        })
+        .rpc();
+
+    const setPhase = (phase: number) =>
+      program.methods
+        .setLifecycle({ phase, globalFreeze: null, freezeReason: "" })
+        .accounts({
+          admin: ctx.wallet.publicKey,
+          config: pdas.config,
+          lifecycle: pdas.lifecycle,
+          metrics: pdas.metrics,
+          clock: SYSVAR_CLOCK_PUBKEY,
+        })
+        .rpc();
+
+    // Returns how many event sequence numbers one reconciliation consumed.
+    const reconcile = async (): Promise<number> => {
+      const before = await program.account.metrics.fetch(pdas.metrics);
+      await program.methods
+        .recordMetrics(
+          buildRecordMetricsArgs({ totalRepos: before.totalRepos.toBigInt() })
+        )
+        .accounts({
+          config: pdas.config,
+          metrics: pdas.metrics,
+          admin: ctx.wallet.publicKey,
+        })
+        .rpc();
+      const after = await program.account.metrics.fetch(pdas.metrics);
+      return after.eventSeq.sub(before.eventSeq).toNumber();
+    };
+
+    const lifecycleAcc = await program.account.lifecycle.fetch(pdas.lifecycle);
+    const originalPhase = lifecycleAcc.phase;
+
+    await setQuietWhenFrozen(true);
+    try {
+      // Not frozen: MetricsReconciled is emitted.
+      expect(await reconcile()).toBe(1);
+
+      await setPhase(5); // Sunset
+      expect(await reconcile()).toBe(0);
+
+      await setQuietWhenFrozen(false);
+      expect(await reconcile()).toBe(1);
+    } finally {
+      await setPhase(originalPhase);
+      await setQuietWhenFrozen(false);
+    }
+  });
+
+  it("gates debug events on eventVerbosity", async () => {
+    const program = ctx.program;
+    const pdas = deriveAllCorePdasFromProgram(program);
+
+    const { repoKey } = await createRepoOnChain(ctx, {
+      name: "unit09-verbosity-repo",
+    });
+    const repoPda = deriveAllCorePdasFromProgram(program, { repoKey }).repo;
+
+    const setEventVerbosity = (eventVerbosity: number) =>
+      program.methods
+        .setConfig(buildSetConfigArgs({ eventVerbosity }))
+        .accounts({
+          admin: ctx.wallet.publicKey,
+          config: pdas.config,
+          metrics: pdas.metrics,
+          systemProgram: SystemProgram.programId,
+          clock: SYSVAR_CLOCK_PUBKEY,
+        })
+        .rpc();
+
+    // Returns how many debug logs one recomputation emitted.
+    const recomputeLogs = async (): Promise<number> => {
+      const logs: any[] = [];
+      const listener = program.addEventListener("unit09Log", (event: any) => {
+        logs.push(event);
+      });
+      try {
+        await program.methods
+          .recomputeMetrics({ reset: true, finalize: true })
+          .accounts({
+            admin: ctx.wallet.publicKey,
+            config: pdas.config,
+            lifecycle: pdas.lifecycle,
+            metrics: pdas.metrics,
+          })
+          .remainingAccounts([{ pubkey: repoPda, isWritable: false, isSigner: false }])
+          .rpc();
+        await new Promise((resolve) => setTimeout(resolve, 2_000));
+      } finally {
+        await program.removeEventListener(listener);
+      }
+      return logs.length;
+    };
+
+    await expect(setEventVerbosity(3)).rejects.toThrow(/ValueOutOfRange/);
+
+    try {
+      await setEventVerbosity(0);
+      expect(await recomputeLogs()).toBe(0);
+
+      await setEventVerbosity(2);
+      expect(await recomputeLogs()).toBe(1);
+    } finally {
+      await setEventVerbosity(1);
+    }
+  });
+
+  it("compares two metrics snapshots on-chain", async () => {
+    const program = ctx.program;
+    const pdas = deriveAllCorePdasFromProgram(program);
+
+    const takeSnapshot = async (): Promise<PublicKey> => {
+      const { snapshotCount } = await program.account.metrics.fetch(pdas.metrics);
+      const snapshotPda = getMetricsSnapshotPda(program.programId, snapshotCount);
+      await program.methods
+        .takeMetricsSnapshot()
+        .accounts({
+          payer: ctx.wallet.publicKey,
+          admin: ctx.wallet.publicKey,
+          config: pdas.config,
+          lifecycle: pdas.lifecycle,
+          metrics: pdas.metrics,
+          metricsSnapshot: snapshotPda,
+          systemProgram: SystemProgram.programId,
+          clock: SYSVAR_CLOCK_PUBKEY,
+        })
+        .rpc();
+      return snapshotPda;
+    };
+
+    const compare = async (from: PublicKey, to: PublicKey): Promise<Buffer> => {
+      const { err, data } = await simulateReturnData(
+        ctx,
+        program.methods.compareSnapshots().accounts({ from, to })
+      );
+      expect(err).toBeNull();
+      expect(data.length).toBeGreaterThan(0);
+      return data;
+    };
+
+    // Borsh i128, little-endian.
+    const readI128 = (data: Buffer, offset: number): bigint =>
+      (data.readBigInt64LE(offset + 8) << BigInt(64)) + data.readBigUInt64LE(offset);
+
+    const fromPda = await takeSnapshot();
+    await createRepoOnChain(ctx, { name: "unit09-snapshot-repo-a" });
+    await createRepoOnChain(ctx, { name: "unit09-snapshot-repo-b" });
+    const toPda = await takeSnapshot();
+
+    const fromAcc = await program.account.metricsSnapshot.fetch(fromPda);
+    const toAcc = await program.account.metricsSnapshot.fetch(toPda);
+    expect(toAcc.index).toBe(fromAcc.index + 1);
+
+    const data = await compare(fromPda, toPda);
+    expect(data.readUInt32LE(0)).toBe(fromAcc.index);
+    expect(data.readUInt32LE(4)).toBe(toAcc.index);
+    expect(data.readBigInt64LE(8)).toBe(
+      BigInt(toAcc.takenAt.sub(fromAcc.takenAt).toString())
+    );
+    // Counters follow in declaration order: totalRepos first.
+    expect(readI128(data, 16)).toBe(BigInt(2));
+    expect(readI128(data, 16 + 16 * 6)).toBe(BigInt(2)); // activeRepos
+
+    // Reversed order yields negated deltas.
+    const reversed = await compare(toPda, fromPda);
+    expect(readI128(reversed, 16)).toBe(BigInt(-2));
+
+    // Non-snapshot accounts are rejected.
+    const { logs } = await simulateReturnData(
+      ctx,
+      program.methods.compareSnapshots().accounts({ from: pdas.config, to: toPda })
+    );
+    expect(JSON.stringify(logs)).toMatch(/InvalidAccountDiscriminator/);
+  });
+
+  it("restores metrics from a snapshot only with the admin quorum", async () => {
+    const program = ctx.program;
+    const pdas = deriveAllCorePdasFromProgram(program);
+
+    const takeSnapshot = async (): Promise<PublicKey> => {
+      const { snapshotCount } = await program.account.metrics.fetch(pdas.metrics);
+      const snapshotPda = getMetricsSnapshotPda(program.programId, snapshotCount);
+      await program.methods
+        .takeMetricsSnapshot()
+        .accounts({
+          payer: ctx.wallet.publicKey,
+          admin: ctx.wallet.publicKey,
+          config: pdas.config,
+          lifecycle: pdas.lifecycle,
+          metrics: pdas.metrics,
+          metricsSnapshot: snapshotPda,
+          systemProgram: SystemProgram.programId,
+          clock: SYSVAR_CLOCK_PUBKEY,
+        })
+        .rpc();
+      return snapshotPda;
+    };
+
+    const restore = (metricsSnapshot: PublicKey, coSigner?: PublicKey) =>
+      program.methods
+        .restoreMetrics()
+        .accounts({
+          admin: ctx.wallet.publicKey,
+          config: pdas.config,
+          lifecycle: pdas.lifecycle,
+          metrics: pdas.metrics,
+          metricsSnapshot,
+          clock: SYSVAR_CLOCK_PUBKEY,
+        })
+        .remainingAccounts(
+          coSigner
+            ? [
+                { pubkey: coSigner, isWritable: false, isSigner: false },
+                {
+                  pubkey: getAuthorityPda(program.programId, coSigner),
+                  isWritable: false,
+                  isSigner: false,
+                },
+              ]
+            : []
+        )
+        .rpc();
+
+    const before = await takeSnapshot();
+    await createRepoOnChain(ctx, { name: "unit09-restore-repo" });
+    const after = await takeSnapshot();
+    const beforeAcc = await program.account.metricsSnapshot.fetch(before);
+
+    // A co-signer that did not sign does not count towards the quorum.
+    await expect(restore(before, Keypair.generate().publicKey)).rejects.toThrow(
+      /UnauthorizedAdminAction/
+    );
+    const unchanged = await program.account.metrics.fetch(pdas.metrics);
+    expect(unchanged.totalRepos.eq(beforeAcc.totalRepos)).toBe(false);
+
+    // One admin signature is the full quorum at requiredAdminSigs = 1.
+    const { requiredAdminSigs } = await program.account.config.fetch(pdas.config);
+    expect(requiredAdminSigs).toEqual(1);
+    await restore(before);
+
+    const restored = await program.account.metrics.fetch(pdas.metrics);
+    expect(restored.totalRepos.eq(beforeAcc.totalRepos)).toBe(true);
+    expect(restored.activeRepos.eq(beforeAcc.activeRepos)).toBe(true);
+
+    // Roll forward again so later tests see the real totals.
+    await restore(after);
+  });
+
+  it("restores metrics only once a 2-of-N admin quorum signs", async () => {
+    const program = ctx.program;
+    const pdas = deriveAllCorePdasFromProgram(program);
+    const coAdmin = Keypair.generate();
+
+    const takeSnapshot = async (): Promise<PublicKey> => {
+      const { snapshotCount } = await program.account.metrics.fetch(pdas.metrics);
+      const snapshotPda = getMetricsSnapshotPda(program.programId, snapshotCount);
+      await program.methods
+        .takeMetricsSnapshot()
+        .accounts({
+          payer: ctx.wallet.publicKey,
+          admin: ctx.wallet.publicKey,
+          config: pdas.config,
+          lifecycle: pdas.lifecycle,
+          metrics: pdas.metrics,
+          metricsSnapshot: snapshotPda,
+          systemProgram: SystemProgram.programId,
+          clock: SYSVAR_CLOCK_PUBKEY,
+        })
+        .rpc();
+      return snapshotPda;
+    };
+
+    const restore = (metricsSnapshot: PublicKey, coSigners: Keypair[]) => {
+      const builder = program.methods
+        .restoreMetrics()
+        .accounts({
+          admin: ctx.wallet.publicKey,
+          config: pdas.config,
+          lifecycle: pdas.lifecycle,
+          metrics: pdas.metrics,
+          metricsSnapshot,
+          clock: SYSVAR_CLOCK_PUBKEY,
+        })
+        .remainingAccounts(
+          buildCoSignerAccounts(
+            program.programId,
+            coSigners.map((signer) => signer.publicKey)
+          )
+        );
+      return coSigners.length > 0 ? builder.signers(coSigners).rpc() : builder.rpc();
+    };
+
+    await addCoAdminOnChain(ctx, coAdmin.publicKey);
+    await setRequiredAdminSigsOnChain(ctx, 2);
+
+    try {
+      const before = await takeSnapshot();
+      await createRepoOnChain(ctx, { name: "unit09-quorum-restore-repo" });
+      const after = await takeSnapshot();
+      const beforeAcc = await program.account.metricsSnapshot.fetch(before);
+
+      // A valid admin signature alone is one short of the quorum.
+      await expect(restore(before, [])).rejects.toThrow(/UnauthorizedAdminAction/);
+      const unchanged = await program.account.metrics.fetch(pdas.metrics);
+      expect(unchanged.totalRepos.eq(beforeAcc.totalRepos)).toBe(false);
+
+      // Admin plus co-admin reach it.
+      await restore(before, [coAdmin]);
+      const restored = await program.account.metrics.fetch(pdas.metrics);
+      expect(restored.totalRepos.eq(beforeAcc.totalRepos)).toBe(true);
+
+      // Roll forward again so later tests see the real totals.
+      await restore(after, [coAdmin]);
+    } finally {
+      await setRequiredAdminSigsOnChain(ctx, 1, [coAdmin]);
+      await removeCoAdminOnChain(ctx, coAdmin.publicKey);
+    }
+  });
+
+  it("leaves global metrics untouched for a metadata-only observation", async () => {
+    const program = ctx.program;
+    const pdas = deriveAllCorePdasFromProgram(program);
+
+    const { repoKey } = await createRepoOnChain(ctx, {
+      name: "unit09-metadata-only-repo",
+    });
+    const repoPda = deriveAllCorePdasFromProgram(program, { repoKey }).repo;
+
+    const metricsBefore = await program.account.metrics.fetch(pdas.metrics);
+    const regionHash = [0x3c, 0x01, 0x00, 0x7a, 0x12, 0x09, 0x44, 0x02];
+    const accessProof = new Array(32).fill(0).map((_, i) => (i * 5 + 3) % 256);
+
+    await program.methods
+      .recordObservation(
+        buildRecordObservationArgs({
+          linesOfCode: BigInt(0),
+          filesProcessed: 0,
+          modulesTouched: 0,
+          revision: "rev-metadata",
+          note: "metadata-only refresh",
+          sourceRegionHash: regionHash,
+          accessProof,
+          kind: 1, // MetadataOnly
+        })
+      )
+      .accounts({
+        config: pdas.config,
+        metrics: pdas.metrics,
+        repo: repoPda,
+        lifecycle: pdas.lifecycle,
+        observer: ctx.wallet.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    const metricsAfter = await program.account.metrics.fetch(pdas.metrics);
+    expect(metricsAfter.totalObservations.eq(metricsBefore.totalObservations)).toBe(true);
+    expect(metricsAfter.totalLinesOfCode.eq(metricsBefore.totalLinesOfCode)).toBe(true);
+    expect(metricsAfter.totalFilesProcessed.eq(metricsBefore.totalFilesProcessed)).toBe(true);
+    expect(metricsAfter.eventSeq.eq(metricsBefore.eventSeq)).toBe(true);
+    expect(metricsAfter.updatedAt.eq(metricsBefore.updatedAt)).toBe(true);
+
+    const repoAcc = await program.account.repo.fetch(repoPda);
+    expect(repoAcc.lastRevision).toBe("rev-metadata");
+    expect(repoAcc.lastNote).toBe("metadata-only refresh");
+    expect(repoAcc.lastObservedAt.toNumber()).toBeGreaterThan(0);
+    expect(repoAcc.observationCount.toNumber()).toBe(0);
+    expect(Array.from(repoAcc.lastSourceRegionHash as number[])).toEqual(regionHash);
+    expect(Array.from(repoAcc.lastAccessProof as number[])).toEqual(accessProof);
+  });
+
+  it("stops observations gracefully when a counter is close to overflow", async () => {
+    const program = ctx.program;
+    const pdas = deriveAllCorePdasFromProgram(program);
+
+    const { repoKey } = await createRepoOnChain(ctx, {
+      name: "unit09-overflow-repo",
+    });
+    const repoPda = deriveAllCorePdasFromProgram(program, { repoKey }).repo;
+
+    const setAutoFreeze = (autoFreezeOnInconsistency: boolean) =>
+      program.methods
+        .setConfig(buildSetConfigArgs({ autoFreezeOnInconsistency }))
+        .accounts({
+          admin: ctx.wallet.publicKey,
+          config: pdas.config,
+          metrics: pdas.metrics,
+          systemProgram: SystemProgram.programId,
+          clock: SYSVAR_CLOCK_PUBKEY,
+        })
+        .rpc();
+
+    const setLinesOfCode = (totalLinesOfCode: bigint) =>
+      program.methods
+        .recordMetrics(buildRecordMetricsArgs({ totalLinesOfCode }))
+        .accounts({
+          config: pdas.config,
+          metrics: pdas.metrics,
+          admin: ctx.wallet.publicKey,
+        })
+        .rpc();
+
+    const observe = () =>
+      program.methods
+        .recordObservation(
+          buildRecordObservationArgs({
+            linesOfCode: BigInt(10),
+            filesProcessed: 1,
+            modulesTouched: 1,
+            revision: "rev-overflow",
+            note: "overflow headroom check",
+          })
+        )
+        .accounts({
+          config: pdas.config,
+          metrics: pdas.metrics,
+          repo: repoPda,
+          lifecycle: pdas.lifecycle,
+          observer: ctx.wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+    const metricsBefore = await program.account.metrics.fetch(pdas.metrics);
+    const { phase: originalPhase } = await program.account.lifecycle.fetch(pdas.lifecycle);
+
+    await setAutoFreeze(false);
+    await setLinesOfCode((BigInt(1) << BigInt(64)) - BigInt(2));
+    try {
+      await expect(observe()).rejects.toThrow(/MetricsInconsistent/);
+
+      await setAutoFreeze(true);
+      await observe();
+
+      const lifecycleAcc = await program.account.lifecycle.fetch(pdas.lifecycle);
+      expect(lifecycleAcc.phase).toBe(3); // Frozen
+      expect(lifecycleAcc.freezeReason).toMatch(/total_lines_of_code/);
+
+      // The observation itself was dropped.
+      const metricsAfter = await program.account.metrics.fetch(pdas.metrics);
+      expect(metricsAfter.totalObservations.eq(metricsBefore.totalObservations)).toBe(true);
+    } finally {
+      await setAutoFreeze(false);
+      await program.methods
+        .setLifecycle({ phase: originalPhase, globalFreeze: null, freezeReason: "" })
+        .accounts({
+          admin: ctx.wallet.publicKey,
+          config: pdas.config,
+          lifecycle: pdas.lifecycle,
+          metrics: pdas.metrics,
+          clock: SYSVAR_CLOCK_PUBKEY,
+        })
+        .rpc();
+      await setLinesOfCode(metricsBefore.totalLinesOfCode.toBigInt());
+    }
+  });
 });
@@ -9,6 +9,7 @@
  *   - Updating module metadata and version (with optional snapshot)
  *   - Preventing duplicate registration for the same module key
  *   - Verifying metrics counters react to module-level activity
+ *   - Keeping the module touch cooldown independent of observations
  *
  * It relies on helpers from:
  *   - tests/helpers/provider.ts
@@ -20,15 +21,23 @@
  * ============================================================================
  */
 
-import { SystemProgram, PublicKey, Keypair } from "@solana/web3.js";
+import {
+  SystemProgram,
+  PublicKey,
+  Keypair,
+  SYSVAR_CLOCK_PUBKEY,
+  SYSVAR_RENT_PUBKEY,
+} from "@solana/web3.js";
 import { BN } from "@coral-xyz/anchor";
 
-import { createUnit09TestContext } from "./helpers/provider";
+import { createUnit09TestContext, simulateReturnData } from "./helpers/provider";
 import {
+  SEED_MODULE,
   deriveAllCorePdasFromProgram,
   getRepoPda,
   getModulePda,
   getModuleVersionPda,
+  getModuleRepoLinkPda,
 } from "./helpers/accounts";
 import {
   BuildInitializeArgsOptions,
@@ -37,9 +46,12 @@ import {
   buildRegisterRepoArgs,
   buildRegisterModuleArgs,
   buildUpdateModuleArgs,
+  buildUpdateRepoArgs,
   createRepoOnChain,
   createModuleOnChain,
+  buildRecordObservationArgs,
   initializeUnit09OnChain,
+  buildSetConfigArgs,
 } from "./helpers/builders";
 import {
   assertModule,
@@ -131,11 +143,7 @@ describe("unit09_program – modules", () => {
     const pdas = deriveAllCorePdasFromProgram(program, {
       repoKey,
       moduleKey,
-      moduleVersion: {
-        major: initialVersion[0],
-        minor: initialVersion[1],
-        patch: initialVersion[2],
-      },
+      moduleVersion: { index: 0 },
     });
 
     const tx = await program.methods
@@ -215,11 +223,7 @@ describe("unit09_program – modules", () => {
     const pdas = deriveAllCorePdasFromProgram(program, {
       repoKey,
       moduleKey,
-      moduleVersion: {
-        major: firstArgs.version[0],
-        minor: firstArgs.version[1],
-        patch: firstArgs.version[2],
-      },
+      moduleVersion: { index: 0 },
     });
 
     // First registration should succeed
@@ -277,11 +281,7 @@ describe("unit09_program – modules", () => {
     const pdas = deriveAllCorePdasFromProgram(program, {
       repoKey,
       moduleKey,
-      moduleVersion: {
-        major: baseVersion[0],
-        minor: baseVersion[1],
-        patch: baseVersion[2],
-      },
+      moduleVersion: { index: 0 },
     });
 
     await program.methods
@@ -321,13 +321,7 @@ describe("unit09_program – modules", () => {
     expect(tx).toBeTruthy();
 
     const moduleAcc = await program.account.module.fetch(pdas.module);
-    const versionPda = getModuleVersionPda(
-      program.programId,
-      moduleKey,
-      baseVersion[0],
-      baseVersion[1],
-      baseVersion[2]
-    );
+    const versionPda = getModuleVersionPda(program.programId, pdas.module!, 0);
     const versionAcc = await program.account.moduleVersion.fetch(versionPda);
 
     // Module should reflect new metadata
@@ -366,11 +360,7 @@ describe("unit09_program – modules", () => {
     const pdasInitial = deriveAllCorePdasFromProgram(program, {
       repoKey,
       moduleKey,
-      moduleVersion: {
-        major: initialVersion[0],
-        minor: initialVersion[1],
-        patch: initialVersion[2],
-      },
+      moduleVersion: { index: 0 },
     });
 
     await program.methods
@@ -409,11 +399,7 @@ describe("unit09_program – modules", () => {
     const pdasNext = deriveAllCorePdasFromProgram(program, {
       repoKey,
       moduleKey,
-      moduleVersion: {
-        major: nextVersion[0],
-        minor: nextVersion[1],
-        patch: nextVersion[2],
-      },
+      moduleVersion: { index: 1 },
     });
 
     const newVersionAcc = await program.account.moduleVersion.fetch(pdasNext.moduleVersion!);
@@ -428,6 +414,206 @@ describe("unit09_program – modules", () => {
     );
   });
 
+  it("counts version snapshots so each one can be enumerated by index", async () => {
+    const program = ctx.program;
+
+    const repoKey = canonicalRepoKey;
+    const moduleKey = Keypair.generate().publicKey;
+
+    const versions = [
+      buildSemanticVersion({ major: 1, minor: 0, patch: 0 }),
+      buildSemanticVersion({ major: 1, minor: 1, patch: 0 }),
+      buildSemanticVersion({ major: 1, minor: 2, patch: 0 }),
+    ];
+
+    const pdas = deriveAllCorePdasFromProgram(program, {
+      repoKey,
+      moduleKey,
+      moduleVersion: { index: 0 },
+    });
+
+    await program.methods
+      .registerModule(
+        buildRegisterModuleArgs({
+          moduleKey,
+          name: "unit09-module-version-count",
+          version: versions[0],
+        })
+      )
+      .accounts({
+        config: pdas.config,
+        repo: pdas.repo,
+        module: pdas.module,
+        moduleVersion: pdas.moduleVersion,
+        authority: ctx.wallet.publicKey,
+        payer: ctx.wallet.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    for (const [offset, version] of versions.slice(1).entries()) {
+      await program.methods
+        .updateModule(
+          buildUpdateModuleArgs({
+            newVersion: version,
+            createVersionSnapshot: true,
+          })
+        )
+        .accounts({
+          config: pdas.config,
+          module: pdas.module,
+          authority: ctx.wallet.publicKey,
+          moduleVersion: getModuleVersionPda(program.programId, pdas.module!, offset + 1),
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+    }
+
+    const { err, data } = await simulateReturnData(
+      ctx,
+      program.methods.getVersionCount().accounts({ module: pdas.module })
+    );
+    expect(err).toBeNull();
+    // Trailing zero bytes may be trimmed; pad back to a u32.
+    const versionCount = Buffer.concat([data, Buffer.alloc(4)]).readUInt32LE(0);
+    expect(versionCount).toBe(versions.length);
+
+    const moduleAcc = await program.account.module.fetch(pdas.module);
+    expect(moduleAcc.versionCount).toBe(versions.length);
+
+    // Every snapshot is derived from its index alone, in creation order.
+    for (let index = 0; index < versionCount; index++) {
+      const versionPda = getModuleVersionPda(program.programId, pdas.module!, index);
+      const versionAcc = await program.account.moduleVersion.fetch(versionPda);
+      expect(versionAcc.index).toBe(index);
+      expect([
+        versionAcc.majorVersion,
+        versionAcc.minorVersion,
+        versionAcc.patchVersion,
+      ]).toEqual(versions[index]);
+    }
+  });
+
+  it("stores a validated module changelog URI on register and update", async () => {
+    const program = ctx.program;
+    const repoKey = canonicalRepoKey;
+
+    const register = (changelogUri: string) => {
+      const moduleKey = Keypair.generate().publicKey;
+      const pdas = deriveAllCorePdasFromProgram(program, { repoKey, moduleKey });
+      const builder = program.methods
+        .registerModule(
+          buildRegisterModuleArgs({
+            moduleKey,
+            name: "unit09-module-changelog",
+            category: "unit09-changelog",
+            changelogUri,
+            createInitialVersionSnapshot: false,
+          })
+        )
+        .accounts({
+          config: pdas.config,
+          repo: pdas.repo,
+          module: pdas.module,
+          authority: ctx.wallet.publicKey,
+          payer: ctx.wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        });
+      return { pdas, rpc: () => builder.rpc() };
+    };
+
+    await expect(register("ftp://unit09.org/changelog").rpc()).rejects.toThrow(/MetadataInvalid/);
+
+    const { pdas, rpc } = register("https://unit09.org/changelog/module-x");
+    await rpc();
+    let moduleAcc = await program.account.module.fetch(pdas.module);
+    expect(moduleAcc.changelogUri).toBe("https://unit09.org/changelog/module-x");
+
+    const update = (changelogUri: string) =>
+      program.methods
+        .updateModule(buildUpdateModuleArgs({ changelogUri, createVersionSnapshot: false }))
+        .accounts({
+          config: pdas.config,
+          module: pdas.module,
+          authority: ctx.wallet.publicKey,
+        })
+        .rpc();
+
+    await expect(update("not-a-uri")).rejects.toThrow(/MetadataInvalid/);
+
+    await update("ipfs://unit09-changelog");
+    moduleAcc = await program.account.module.fetch(pdas.module);
+    expect(moduleAcc.changelogUri).toBe("ipfs://unit09-changelog");
+
+    // An empty string clears the link.
+    await update("");
+    moduleAcc = await program.account.module.fetch(pdas.module);
+    expect(moduleAcc.changelogUri).toBe("");
+  });
+
+  it("rejects module edits until the immutability window has passed", async () => {
+    const program = ctx.program;
+    const repoKey = canonicalRepoKey;
+
+    const chainNow = async (): Promise<number> =>
+      (await ctx.connection.getBlockTime(await ctx.connection.getSlot())) ?? 0;
+
+    const register = async (immutableUntil: bigint) => {
+      const moduleKey = Keypair.generate().publicKey;
+      const pdas = deriveAllCorePdasFromProgram(program, { repoKey, moduleKey });
+      await program.methods
+        .registerModule(
+          buildRegisterModuleArgs({
+            moduleKey,
+            name: "unit09-module-immutable",
+            category: "unit09-immutable",
+            createInitialVersionSnapshot: false,
+            immutableUntil,
+          })
+        )
+        .accounts({
+          config: pdas.config,
+          repo: pdas.repo,
+          module: pdas.module,
+          authority: ctx.wallet.publicKey,
+          payer: ctx.wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+      return pdas;
+    };
+
+    // More than 90 days ahead is rejected.
+    const tooFar = BigInt((await chainNow()) + 91 * 86_400);
+    await expect(register(tooFar)).rejects.toThrow(/TimestampInFuture/);
+
+    const immutableUntil = BigInt((await chainNow()) + 3);
+    const pdas = await register(immutableUntil);
+    let moduleAcc = await program.account.module.fetch(pdas.module);
+    expect(moduleAcc.immutableUntil.toString()).toBe(immutableUntil.toString());
+
+    const rename = (name: string) =>
+      program.methods
+        .updateModule(buildUpdateModuleArgs({ name, createVersionSnapshot: false }))
+        .accounts({
+          config: pdas.config,
+          module: pdas.module,
+          authority: ctx.wallet.publicKey,
+        })
+        .rpc();
+
+    // During the window.
+    await expect(rename("unit09-module-too-early")).rejects.toThrow(/ModuleImmutable/);
+
+    // After the window.
+    while (BigInt(await chainNow()) < immutableUntil) {
+      await new Promise((resolve) => setTimeout(resolve, 1_000));
+    }
+    await rename("unit09-module-after-window");
+    moduleAcc = await program.account.module.fetch(pdas.module);
+    expect(moduleAcc.name).toBe("unit09-module-after-window");
+  });
+
   it("supports partial updates (null fields mean no change)", async () => {
     const program = ctx.program;
 
@@ -449,11 +635,7 @@ describe("unit09_program – modules", () => {
     const pdas = deriveAllCorePdasFromProgram(program, {
       repoKey,
       moduleKey,
-      moduleVersion: {
-        major: registerArgs.version[0],
-        minor: registerArgs.version[1],
-        patch: registerArgs.version[2],
-      },
+      moduleVersion: { index: 0 },
     });
 
     await program.methods
@@ -502,6 +684,83 @@ describe("unit09_program – modules", () => {
     expect(moduleAcc.tags).toContain("partial-updated");
   });
 
+  it("rejects a duplicate module metadata URI when the repo enforces uniqueness", async () => {
+    const program = ctx.program;
+
+    const { repoKey } = await createRepoOnChain(ctx, {
+      name: "unit09-unique-module-uri",
+    });
+    const pdas = deriveAllCorePdasFromProgram(program, { repoKey });
+
+    await program.methods
+      .updateRepo(buildUpdateRepoArgs({ enforceUniqueModuleUri: true }))
+      .accounts({
+        config: pdas.config,
+        metrics: pdas.metrics,
+        repo: pdas.repo,
+        authority: ctx.wallet.publicKey,
+      })
+      .rpc();
+
+    const metadataUri = "https://unit09.org/meta/module/shared.json";
+    await createModuleOnChain(ctx, { repoKey, metadataUri });
+
+    await expect(
+      createModuleOnChain(ctx, { repoKey, metadataUri })
+    ).rejects.toThrow(/MetadataInvalid/);
+
+    // A distinct URI is still accepted.
+    await createModuleOnChain(ctx, {
+      repoKey,
+      metadataUri: "https://unit09.org/meta/module/other.json",
+    });
+  });
+
+  it("sets, clears, and validates a repo's primary module", async () => {
+    const program = ctx.program;
+
+    const { repoKey } = await createRepoOnChain(ctx, {
+      name: "unit09-primary-module-repo",
+    });
+    const { moduleKey } = await createModuleOnChain(ctx, { repoKey });
+    const { repoKey: otherRepoKey } = await createRepoOnChain(ctx, {
+      name: "unit09-primary-module-other",
+    });
+    const { moduleKey: otherModuleKey } = await createModuleOnChain(ctx, {
+      repoKey: otherRepoKey,
+    });
+
+    const pdas = deriveAllCorePdasFromProgram(program, { repoKey, moduleKey });
+    const otherModulePda = deriveAllCorePdasFromProgram(program, {
+      repoKey: otherRepoKey,
+      moduleKey: otherModuleKey,
+    }).module;
+
+    const setPrimary = (module: PublicKey | null) =>
+      program.methods
+        .setPrimaryModule({ module })
+        .accounts({
+          authority: ctx.wallet.publicKey,
+          config: pdas.config,
+          lifecycle: pdas.lifecycle,
+          metrics: pdas.metrics,
+          repo: pdas.repo,
+          module,
+        })
+        .rpc();
+
+    await setPrimary(pdas.module);
+    let repoAcc = await program.account.repo.fetch(pdas.repo);
+    expect(repoAcc.primaryModule?.toBase58()).toBe(pdas.module.toBase58());
+
+    // A module from another repository is rejected.
+    await expect(setPrimary(otherModulePda)).rejects.toThrow(/ModuleRepoMismatch/);
+
+    await setPrimary(null);
+    repoAcc = await program.account.repo.fetch(pdas.repo);
+    expect(repoAcc.primaryModule).toBeNull();
+  });
+
   it("keeps metrics consistent with module registrations", async () => {
     const program = ctx.program;
 
@@ -520,11 +779,7 @@ describe("unit09_program – modules", () => {
       const pdas = deriveAllCorePdasFromProgram(program, {
         repoKey,
         moduleKey,
-        moduleVersion: {
-          major: args.version[0],
-          minor: args.version[1],
-          patch: args.version[2],
-        },
+        moduleVersion: { index: 0 },
       });
 
       await program.methods
@@ -570,4 +825,238 @@ describe("unit09_program – modules", () => {
       }
     );
   });
+
+  it("attributes observations to a module of the observed repo only", async () => {
+    const program = ctx.program;
+    const programId = program.programId;
+
+    const ownRepo = await createRepoOnChain(ctx, {});
+    const otherRepo = await createRepoOnChain(ctx, {});
+    const { moduleKey } = await createModuleOnChain(ctx, { repoKey: ownRepo.repoKey });
+
+    const ownRepoPda = getRepoPda(programId, ownRepo.repoKey);
+    const [modulePda] = PublicKey.findProgramAddressSync(
+      [Buffer.from(SEED_MODULE), ownRepoPda.toBuffer(), moduleKey.toBuffer()],
+      programId
+    );
+
+    const observe = (repoKey: PublicKey) => {
+      const pdas = deriveAllCorePdasFromProgram(program, { repoKey });
+      return program.methods
+        .recordObservation(
+          buildRecordObservationArgs({
+            revision: "rev-module-scope",
+            note: "module-scoped observation",
+            module: modulePda,
+          })
+        )
+        .accounts({
+          observer: ctx.wallet.publicKey,
+          config: pdas.config,
+          lifecycle: pdas.lifecycle,
+          metrics: pdas.metrics,
+          repo: pdas.repo,
+          module: modulePda,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+    };
+
+    await expect(observe(otherRepo.repoKey)).rejects.toThrow(/ModuleRepoMismatch/);
+
+    await observe(ownRepo.repoKey);
+    const moduleAcc = await program.account.module.fetch(modulePda);
+    expect(moduleAcc.observationCount.toNumber()).toBe(1);
+    expect(moduleAcc.lastObservedAt.toNumber()).toBeGreaterThan(0);
+  });
+
+  it("links modules across repos only when both repos share an authority or admin signs", async () => {
+    const program = ctx.program;
+    const programId = program.programId;
+
+    // A non-admin authority owning two repos, and an outsider owning a third.
+    const shared = Keypair.generate();
+    const outsider = Keypair.generate();
+
+    const registerRepoAs = async (signer: Keypair) => {
+      const repoKey = Keypair.generate().publicKey;
+      const pdas = deriveAllCorePdasFromProgram(program, { repoKey });
+      await program.methods
+        .registerRepo(buildRegisterRepoArgs({ repoKey }))
+        .accounts({
+          config: pdas.config,
+          repo: pdas.repo,
+          authority: signer.publicKey,
+          payer: ctx.wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([signer])
+        .rpc();
+      return repoKey;
+    };
+
+    const homeRepoKey = await registerRepoAs(shared);
+    const siblingRepoKey = await registerRepoAs(shared);
+    const foreignRepoKey = await registerRepoAs(outsider);
+
+    const moduleKey = Keypair.generate().publicKey;
+    const homeRepoPda = getRepoPda(programId, homeRepoKey);
+    const [modulePda] = PublicKey.findProgramAddressSync(
+      [Buffer.from(SEED_MODULE), homeRepoPda.toBuffer(), moduleKey.toBuffer()],
+      programId
+    );
+    await program.methods
+      .registerModule(buildRegisterModuleArgs({ moduleKey }))
+      .accounts({
+        config: deriveAllCorePdasFromProgram(program).config,
+        repo: homeRepoPda,
+        module: modulePda,
+        authority: shared.publicKey,
+        payer: ctx.wallet.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([shared])
+      .rpc();
+
+    const link = (repoKey: PublicKey, signer?: Keypair) => {
+      const pdas = deriveAllCorePdasFromProgram(program, { repoKey });
+      const builder = program.methods
+        .linkModuleToRepo({ isPrimary: false, notes: "cross-repo link" })
+        .accounts({
+          payer: ctx.wallet.publicKey,
+          authority: signer ? signer.publicKey : ctx.wallet.publicKey,
+          config: pdas.config,
+          lifecycle: pdas.lifecycle,
+          metrics: pdas.metrics,
+          repo: pdas.repo,
+          module: modulePda,
+          sourceRepo: homeRepoPda,
+          link: getModuleRepoLinkPda(programId, modulePda, pdas.repo),
+          systemProgram: SystemProgram.programId,
+          rent: SYSVAR_RENT_PUBKEY,
+          clock: SYSVAR_CLOCK_PUBKEY,
+        });
+      return signer ? builder.signers([signer]).rpc() : builder.rpc();
+    };
+
+    // Same (non-admin) authority on both repos.
+    await link(siblingRepoKey, shared);
+    const siblingPdas = deriveAllCorePdasFromProgram(program, { repoKey: siblingRepoKey });
+    const siblingLink = await program.account.moduleRepoLink.fetch(
+      getModuleRepoLinkPda(programId, modulePda, siblingPdas.repo)
+    );
+    expect(siblingLink.module.equals(modulePda)).toBe(true);
+
+    // Different authorities: neither the module authority nor the foreign
+    // repo authority can link the module into the foreign repo.
+    await expect(link(foreignRepoKey, shared)).rejects.toThrow(/InvalidAuthority/);
+    await expect(link(foreignRepoKey, outsider)).rejects.toThrow(/InvalidAuthority/);
+
+    // The admin (context wallet) may.
+    await link(foreignRepoKey);
+    const foreignPdas = deriveAllCorePdasFromProgram(program, { repoKey: foreignRepoKey });
+    const foreignLink = await program.account.moduleRepoLink.fetch(
+      getModuleRepoLinkPda(programId, modulePda, foreignPdas.repo)
+    );
+    expect(foreignLink.repo.equals(foreignPdas.repo)).toBe(true);
+  });
+
+  it("rejects migrating a module that is already on the current schema", async () => {
+    const program = ctx.program;
+
+    const { repoKey } = await createRepoOnChain(ctx, {});
+    const { moduleKey } = await createModuleOnChain(ctx, { repoKey });
+    const pdas = deriveAllCorePdasFromProgram(program, { repoKey, moduleKey });
+
+    const migrate = (admin: Keypair | null) => {
+      const builder = program.methods.migrateModule().accounts({
+        admin: admin ? admin.publicKey : ctx.wallet.publicKey,
+        config: pdas.config,
+        metrics: pdas.metrics,
+        module: pdas.module,
+        legacyVersion: null,
+        systemProgram: SystemProgram.programId,
+      });
+      return admin ? builder.signers([admin]).rpc() : builder.rpc();
+    };
+
+    await expect(migrate(Keypair.generate())).rejects.toThrow(/InvalidAdmin/);
+    await expect(migrate(null)).rejects.toThrow(/MigrationAlreadyApplied/);
+  });
+
+  it("keeps the observation and module touch cooldowns independent", async () => {
+    const program = ctx.program;
+
+    const { repoKey } = await createRepoOnChain(ctx, {});
+    const { moduleKey } = await createModuleOnChain(ctx, { repoKey });
+    const pdas = deriveAllCorePdasFromProgram(program, { repoKey, moduleKey });
+
+    const setCooldowns = (observationSecs: number, moduleTouchSecs: number) =>
+      program.methods
+        .setConfig(
+          buildSetConfigArgs({
+            cooldowns: { observationSecs, forkCreationSecs: 0, moduleTouchSecs },
+          })
+        )
+        .accounts({
+          admin: ctx.wallet.publicKey,
+          config: pdas.config,
+          metrics: pdas.metrics,
+          systemProgram: SystemProgram.programId,
+          clock: SYSVAR_CLOCK_PUBKEY,
+        })
+        .rpc();
+
+    const observe = (revision: string) =>
+      program.methods
+        .recordObservation(
+          buildRecordObservationArgs({
+            linesOfCode: BigInt(10),
+            filesProcessed: 1,
+            modulesTouched: 1,
+            revision,
+            note: "independent cooldowns",
+          })
+        )
+        .accounts({
+          config: pdas.config,
+          metrics: pdas.metrics,
+          repo: pdas.repo,
+          lifecycle: pdas.lifecycle,
+          observer: ctx.wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+    const touch = (metadataUri: string) =>
+      program.methods
+        .updateModule(buildUpdateModuleArgs({ metadataUri }))
+        .accounts({
+          config: pdas.config,
+          module: pdas.module,
+          authority: ctx.wallet.publicKey,
+        })
+        .rpc();
+
+    try {
+      // Observation cooldown only: the repository cools down, the module
+      // can still be updated back to back.
+      await setCooldowns(3600, 0);
+      await observe("rev-independent-1");
+      await expect(observe("rev-independent-2")).rejects.toThrow(/CooldownActive/);
+      await touch("https://unit09.org/meta/module/touch-1.json");
+      await touch("https://unit09.org/meta/module/touch-2.json");
+
+      // Module touch cooldown only: the freshly updated module cools down,
+      // the just-observed repository can be observed again.
+      await setCooldowns(0, 3600);
+      await expect(
+        touch("https://unit09.org/meta/module/touch-3.json")
+      ).rejects.toThrow(/CooldownActive/);
+      await observe("rev-independent-3");
+      await observe("rev-independent-4");
+    } finally {
+      await setCooldowns(0, 0);
+    }
+  });
 });
@@ -20,12 +20,16 @@
  * ============================================================================
  */
 
-import { SystemProgram, PublicKey, Keypair } from "@solana/web3.js";
+import { createHash } from "crypto";
+import { SystemProgram, SYSVAR_CLOCK_PUBKEY, PublicKey, Keypair } from "@solana/web3.js";
 import { BN } from "@coral-xyz/anchor";
 
-import { createUnit09TestContext } from "./helpers/provider";
+import { createUnit09TestContext, simulateReturnData } from "./helpers/provider";
 import {
   deriveAllCorePdasFromProgram,
+  getModulePda,
+  getObserverCreditsPda,
+  getRepoNameIndexPda,
   getRepoPda,
 } from "./helpers/accounts";
 import {
@@ -37,6 +41,8 @@ import {
   buildRecordObservationArgs,
   initializeUnit09OnChain,
   createRepoOnChain,
+  createModuleOnChain,
+  buildSetConfigArgs,
 } from "./helpers/builders";
 import {
   assertRepo,
@@ -202,6 +208,354 @@ describe("unit09_program – repos", () => {
     ).rejects.toThrow();
   });
 
+  it("requires repoKey to be the URL hash when URL keying is enforced", async () => {
+    const program = ctx.program;
+    const { config, metrics } = deriveAllCorePdasFromProgram(program);
+
+    const setEnforceUrlKeyed = async (enforceUrlKeyed: boolean) => {
+      await program.methods
+        .setConfig(buildSetConfigArgs({ enforceUrlKeyed }))
+        .accounts({
+          admin: ctx.wallet.publicKey,
+          config,
+          metrics,
+          systemProgram: SystemProgram.programId,
+          clock: SYSVAR_CLOCK_PUBKEY,
+        })
+        .rpc();
+    };
+
+    const url = `https://github.com/unit09-labs/url-keyed-${Date.now()}`;
+    const urlKey = new PublicKey(createHash("sha256").update(url).digest());
+
+    await setEnforceUrlKeyed(true);
+    try {
+      await expect(
+        createRepoOnChain(ctx, { repoKey: Keypair.generate().publicKey, url })
+      ).rejects.toThrow(/RepoKeyMismatch/);
+
+      const { repoKey } = await createRepoOnChain(ctx, { repoKey: urlKey, url });
+      expect(repoKey.equals(urlKey)).toBe(true);
+    } finally {
+      await setEnforceUrlKeyed(false);
+    }
+  });
+
+  it("rejects a duplicate repository name when unique names are enforced", async () => {
+    const program = ctx.program;
+    const { config, metrics } = deriveAllCorePdasFromProgram(program);
+
+    const setEnforceUniqueRepoName = async (enforceUniqueRepoName: boolean) => {
+      await program.methods
+        .setConfig(buildSetConfigArgs({ enforceUniqueRepoName }))
+        .accounts({
+          admin: ctx.wallet.publicKey,
+          config,
+          metrics,
+          systemProgram: SystemProgram.programId,
+          clock: SYSVAR_CLOCK_PUBKEY,
+        })
+        .rpc();
+    };
+
+    const name = `unit09-unique-${Date.now()}`;
+    const repoNameIndex = getRepoNameIndexPda(program.programId, name);
+
+    const registerNamed = (repoKey: PublicKey) =>
+      program.methods
+        .registerRepo(buildRegisterRepoArgs({ repoKey, name }))
+        .accounts({
+          config,
+          repo: getRepoPda(program.programId, repoKey),
+          repoNameIndex,
+          authority: ctx.wallet.publicKey,
+          payer: ctx.wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+    await setEnforceUniqueRepoName(true);
+    try {
+      const firstKey = Keypair.generate().publicKey;
+      await registerNamed(firstKey);
+
+      const index = await program.account.repoNameIndex.fetch(repoNameIndex);
+      expect(index.repo.equals(getRepoPda(program.programId, firstKey))).toBe(true);
+
+      await expect(registerNamed(Keypair.generate().publicKey)).rejects.toThrow(
+        /RepoNameTaken/
+      );
+    } finally {
+      await setEnforceUniqueRepoName(false);
+    }
+  });
+
+  it("claims names on finalizeRepo and keeps claimed names from being renamed", async () => {
+    const program = ctx.program;
+    const { config, lifecycle, metrics } = deriveAllCorePdasFromProgram(program);
+
+    const setEnforceUniqueRepoName = (enforceUniqueRepoName: boolean) =>
+      program.methods
+        .setConfig(buildSetConfigArgs({ enforceUniqueRepoName }))
+        .accounts({
+          admin: ctx.wallet.publicKey,
+          config,
+          metrics,
+          systemProgram: SystemProgram.programId,
+          clock: SYSVAR_CLOCK_PUBKEY,
+        })
+        .rpc();
+
+    const name = `unit09-finalized-${Date.now()}`;
+    const repoNameIndex = getRepoNameIndexPda(program.programId, name);
+
+    const reserveAndFinalize = async (repoKey: PublicKey) => {
+      const repo = getRepoPda(program.programId, repoKey);
+      await program.methods
+        .reserveRepo({ repoKey })
+        .accounts({
+          payer: ctx.wallet.publicKey,
+          authority: ctx.wallet.publicKey,
+          config,
+          lifecycle,
+          repo,
+          systemProgram: SystemProgram.programId,
+          clock: SYSVAR_CLOCK_PUBKEY,
+        })
+        .rpc();
+      await program.methods
+        .finalizeRepo({
+          name,
+          url: `https://github.com/unit09-labs/${repoKey.toBase58()}`,
+          tags: "",
+          metadataUri: "",
+          allowObservation: true,
+        })
+        .accounts({
+          authority: ctx.wallet.publicKey,
+          config,
+          lifecycle,
+          metrics,
+          repo,
+          repoNameIndex,
+          systemProgram: SystemProgram.programId,
+          clock: SYSVAR_CLOCK_PUBKEY,
+        })
+        .rpc();
+      return repo;
+    };
+
+    const rename = (repo: PublicKey, currentNameIndex: PublicKey | null) =>
+      program.methods
+        .updateRepo(buildUpdateRepoArgs({ name: `${name}-renamed` }))
+        .accounts({
+          config,
+          repo,
+          authority: ctx.wallet.publicKey,
+          currentNameIndex,
+        })
+        .rpc();
+
+    let claimed = PublicKey.default;
+    await setEnforceUniqueRepoName(true);
+    try {
+      claimed = await reserveAndFinalize(Keypair.generate().publicKey);
+
+      const index = await program.account.repoNameIndex.fetch(repoNameIndex);
+      expect(index.repo.equals(claimed)).toBe(true);
+
+      await expect(reserveAndFinalize(Keypair.generate().publicKey)).rejects.toThrow(
+        /RepoNameTaken/
+      );
+
+      // No repository can be renamed while names are enforced.
+      await expect(rename(claimed, repoNameIndex)).rejects.toThrow(/ValidationFailed/);
+    } finally {
+      await setEnforceUniqueRepoName(false);
+    }
+
+    // Without enforcement the holder of an index still keeps its name...
+    await expect(rename(claimed, null)).rejects.toThrow(/MissingRequiredAccount/);
+    await expect(rename(claimed, repoNameIndex)).rejects.toThrow(/ValidationFailed/);
+
+    // ...while a repository without one can be renamed.
+    const { repoKey } = await createRepoOnChain(ctx, { name });
+    const unclaimed = getRepoPda(program.programId, repoKey);
+    await rename(unclaimed, repoNameIndex);
+    const repoAcc = await program.account.repo.fetch(unclaimed);
+    expect(repoAcc.name).toBe(`${name}-renamed`);
+  });
+
+  it("rejects repositories on a blocked host until it is unblocked", async () => {
+    const program = ctx.program;
+    const { config, metrics } = deriveAllCorePdasFromProgram(program);
+
+    const host = `blocked-${Date.now()}.example.org`;
+    const url = `https://${host}/unit09-labs/blocked`;
+    const hostAccounts = {
+      admin: ctx.wallet.publicKey,
+      config,
+      metrics,
+      clock: SYSVAR_CLOCK_PUBKEY,
+    };
+
+    await program.methods.addBlockedHost({ host }).accounts(hostAccounts).rpc();
+    try {
+      // Any URL on the host matches, whatever the case or `www.` prefix.
+      await expect(
+        createRepoOnChain(ctx, { url: `https://WWW.${host.toUpperCase()}/other` })
+      ).rejects.toThrow(/RepoBlocked/);
+      await expect(createRepoOnChain(ctx, { url })).rejects.toThrow(/RepoBlocked/);
+    } finally {
+      await program.methods.removeBlockedHost({ host }).accounts(hostAccounts).rpc();
+    }
+
+    const { repoKey } = await createRepoOnChain(ctx, { url });
+    const repoAcc = await program.account.repo.fetch(getRepoPda(program.programId, repoKey));
+    expect(repoAcc.url).toBe(url);
+  });
+
+  it("applies a runtime name length override that can only tighten the limit", async () => {
+    const program = ctx.program;
+    const { config, metrics } = deriveAllCorePdasFromProgram(program);
+
+    const setMaxNameLenOverride = (maxNameLenOverride: number) =>
+      program.methods
+        .setConfig(buildSetConfigArgs({ maxNameLenOverride }))
+        .accounts({
+          admin: ctx.wallet.publicKey,
+          config,
+          metrics,
+          systemProgram: SystemProgram.programId,
+          clock: SYSVAR_CLOCK_PUBKEY,
+        })
+        .rpc();
+
+    // MAX_NAME_LEN is 64; the override can never loosen it.
+    await expect(setMaxNameLenOverride(65)).rejects.toThrow(/ValueOutOfRange/);
+
+    await setMaxNameLenOverride(16);
+    try {
+      await expect(
+        createRepoOnChain(ctx, { name: "unit09-name-over-16" })
+      ).rejects.toThrow(/StringTooLong/);
+      await createRepoOnChain(ctx, { name: "unit09-name-ok" });
+    } finally {
+      // MAX_NAME_LEN is the default limit and clears the override.
+      await setMaxNameLenOverride(64);
+    }
+
+    const configAcc = await program.account.config.fetch(config);
+    expect(configAcc.maxNameLenOverride).toBeNull();
+  });
+
+  it("registers and finalizes repos inactive until an admin activates them when reviewGated is set", async () => {
+    const program = ctx.program;
+    const { config, metrics } = deriveAllCorePdasFromProgram(program);
+
+    const setReviewGated = (reviewGated: boolean) =>
+      program.methods
+        .setConfig(buildSetConfigArgs({ reviewGated }))
+        .accounts({
+          admin: ctx.wallet.publicKey,
+          config,
+          metrics,
+          systemProgram: SystemProgram.programId,
+          clock: SYSVAR_CLOCK_PUBKEY,
+        })
+        .rpc();
+
+    await setReviewGated(true);
+    try {
+      const { repoKey } = await createRepoOnChain(ctx, {});
+      const pdas = deriveAllCorePdasFromProgram(program, { repoKey });
+
+      let repoAcc = await program.account.repo.fetch(pdas.repo);
+      expect(repoAcc.isActive).toBe(false);
+
+      const observe = () =>
+        program.methods
+          .recordObservation(buildRecordObservationArgs({ revision: "rev-gated" }))
+          .accounts({
+            config: pdas.config,
+            metrics: pdas.metrics,
+            repo: pdas.repo,
+            lifecycle: pdas.lifecycle,
+            observer: ctx.wallet.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .rpc();
+
+      await expect(observe()).rejects.toThrow(/RepoInactive/);
+
+      const activate = (admin: PublicKey | null) =>
+        program.methods
+          .updateRepo(buildUpdateRepoArgs({ isActive: true }))
+          .accounts({
+            config: pdas.config,
+            repo: pdas.repo,
+            authority: ctx.wallet.publicKey,
+            admin,
+          })
+          .rpc();
+
+      // The repository authority alone cannot activate it.
+      await expect(activate(null)).rejects.toThrow(/UnauthorizedAdminAction/);
+
+      // The wallet is also the deployment admin.
+      await activate(ctx.wallet.publicKey);
+      repoAcc = await program.account.repo.fetch(pdas.repo);
+      expect(repoAcc.isActive).toBe(true);
+
+      await observe();
+
+      // Finalizing a reserved repository follows the same gate.
+      const reservedKey = Keypair.generate().publicKey;
+      const reservedPdas = deriveAllCorePdasFromProgram(program, { repoKey: reservedKey });
+      await program.methods
+        .reserveRepo({ repoKey: reservedKey })
+        .accounts({
+          payer: ctx.wallet.publicKey,
+          authority: ctx.wallet.publicKey,
+          config: reservedPdas.config,
+          lifecycle: reservedPdas.lifecycle,
+          repo: reservedPdas.repo,
+          systemProgram: SystemProgram.programId,
+          clock: SYSVAR_CLOCK_PUBKEY,
+        })
+        .rpc();
+
+      const metricsBefore = await program.account.metrics.fetch(metrics);
+      await program.methods
+        .finalizeRepo({
+          name: "unit09-gated-reserved",
+          url: "https://github.com/unit09-labs/gated-reserved",
+          tags: "",
+          metadataUri: "",
+          allowObservation: true,
+        })
+        .accounts({
+          authority: ctx.wallet.publicKey,
+          config: reservedPdas.config,
+          lifecycle: reservedPdas.lifecycle,
+          metrics: reservedPdas.metrics,
+          repo: reservedPdas.repo,
+          clock: SYSVAR_CLOCK_PUBKEY,
+        })
+        .rpc();
+
+      const finalized = await program.account.repo.fetch(reservedPdas.repo);
+      expect(finalized.isReserved).toBe(false);
+      expect(finalized.isActive).toBe(false);
+
+      const metricsAfter = await program.account.metrics.fetch(metrics);
+      expect(metricsAfter.totalRepos.toNumber()).toBe(metricsBefore.totalRepos.toNumber() + 1);
+      expect(metricsAfter.activeRepos.toNumber()).toBe(metricsBefore.activeRepos.toNumber());
+    } finally {
+      await setReviewGated(false);
+    }
+  });
+
   it("updates repo metadata via updateRepo", async () => {
     const program = ctx.program;
 
@@ -241,6 +595,7 @@ describe("unit09_program – repos", () => {
         config: pdas.config,
         repo: pdas.repo,
         authority: ctx.wallet.publicKey,
+        currentNameIndex: getRepoNameIndexPda(program.programId, registerArgs.name),
       })
       .rpc();
 
@@ -259,6 +614,48 @@ describe("unit09_program – repos", () => {
     );
   });
 
+  it("rejects a repo metadata URI with an unsupported scheme", async () => {
+    const program = ctx.program;
+
+    const repoKey = Keypair.generate().publicKey;
+    const registerArgs = buildRegisterRepoArgs({
+      repoKey,
+      metadataUri: "ftp://unit09.org/meta/repo.json",
+    });
+
+    const pdas = deriveAllCorePdasFromProgram(program, { repoKey });
+
+    await expect(
+      program.methods
+        .registerRepo(registerArgs)
+        .accounts({
+          config: pdas.config,
+          repo: pdas.repo,
+          authority: ctx.wallet.publicKey,
+          payer: ctx.wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc()
+    ).rejects.toThrow(/MetadataInvalid/);
+
+    // A valid repo cannot be switched to an invalid scheme either.
+    const validRepo = await createRepoOnChain(ctx, {});
+    const validPdas = deriveAllCorePdasFromProgram(program, {
+      repoKey: validRepo.repoKey,
+    });
+
+    await expect(
+      program.methods
+        .updateRepo(buildUpdateRepoArgs({ metadataUri: "file:///etc/repo.json" }))
+        .accounts({
+          config: validPdas.config,
+          repo: validPdas.repo,
+          authority: ctx.wallet.publicKey,
+        })
+        .rpc()
+    ).rejects.toThrow(/MetadataInvalid/);
+  });
+
   it("allows partial updates with null fields (no change)", async () => {
     const program = ctx.program;
 
@@ -316,6 +713,172 @@ describe("unit09_program – repos", () => {
     );
   });
 
+  it("updates tags alone without touching name or url", async () => {
+    const program = ctx.program;
+
+    const initialName = "unit09-repo-tags-only";
+    const initialUrl = "https://github.com/unit09-labs/unit09-tags-only";
+    const { repoKey } = await createRepoOnChain(ctx, {
+      name: initialName,
+      url: initialUrl,
+      tags: "unit09,repo,before",
+    });
+    const pdas = deriveAllCorePdasFromProgram(program, { repoKey });
+
+    let changedFields: number | undefined;
+    const listener = program.addEventListener("repoUpdated", (event: any) => {
+      changedFields = event.changedFields;
+    });
+
+    try {
+      await program.methods
+        .updateRepo(buildUpdateRepoArgs({ tags: "unit09,repo,after" }))
+        .accounts({
+          config: pdas.config,
+          repo: pdas.repo,
+          authority: ctx.wallet.publicKey,
+        })
+        .rpc();
+
+      await new Promise((resolve) => setTimeout(resolve, 2_000));
+    } finally {
+      await program.removeEventListener(listener);
+    }
+
+    const repoAcc = await program.account.repo.fetch(pdas.repo);
+    assertRepo(
+      { pubkey: pdas.repo, data: repoAcc },
+      { name: initialName, url: initialUrl }
+    );
+    expect(repoAcc.tags).toBe("unit09,repo,after");
+
+    // Only the TAGS flag (1 << 2) should be reported.
+    expect(changedFields).toBe(1 << 2);
+  });
+
+  it("lets a maintainer update metadata but not authority-only fields", async () => {
+    const program = ctx.program;
+
+    const { repoKey } = await createRepoOnChain(ctx, {
+      name: "unit09-repo-maintained",
+    });
+    const pdas = deriveAllCorePdasFromProgram(program, { repoKey });
+
+    const maintainer = Keypair.generate();
+    const outsider = Keypair.generate();
+
+    await program.methods
+      .addMaintainer({ maintainer: maintainer.publicKey })
+      .accounts({
+        authority: ctx.wallet.publicKey,
+        config: pdas.config,
+        lifecycle: pdas.lifecycle,
+        metrics: pdas.metrics,
+        repo: pdas.repo,
+        clock: SYSVAR_CLOCK_PUBKEY,
+      })
+      .rpc();
+
+    const updateAs = (
+      signer: Keypair,
+      args: Parameters<typeof buildUpdateRepoArgs>[0],
+      currentNameIndex: PublicKey | null = null
+    ) =>
+      program.methods
+        .updateRepo(buildUpdateRepoArgs(args))
+        .accounts({
+          config: pdas.config,
+          repo: pdas.repo,
+          authority: signer.publicKey,
+          currentNameIndex,
+        })
+        .signers([signer])
+        .rpc();
+
+    await updateAs(
+      maintainer,
+      { name: "unit09-repo-renamed-by-maintainer" },
+      getRepoNameIndexPda(program.programId, "unit09-repo-maintained")
+    );
+    const repoAcc = await program.account.repo.fetch(pdas.repo);
+    expect(repoAcc.name).toBe("unit09-repo-renamed-by-maintainer");
+
+    // Maintainers cannot touch activation or observation settings.
+    await expect(updateAs(maintainer, { isActive: false })).rejects.toThrow(
+      /InvalidAuthority/
+    );
+
+    // Non-maintainers cannot update at all.
+    await expect(updateAs(outsider, { name: "unit09-repo-hijacked" })).rejects.toThrow(
+      /InvalidAuthority/
+    );
+
+    // Once removed, the former maintainer is rejected too.
+    await program.methods
+      .removeMaintainer({ maintainer: maintainer.publicKey })
+      .accounts({
+        authority: ctx.wallet.publicKey,
+        config: pdas.config,
+        lifecycle: pdas.lifecycle,
+        metrics: pdas.metrics,
+        repo: pdas.repo,
+        clock: SYSVAR_CLOCK_PUBKEY,
+      })
+      .rpc();
+    await expect(updateAs(maintainer, { name: "unit09-repo-too-late" })).rejects.toThrow(
+      /InvalidAuthority/
+    );
+  });
+
+  it("renames a tag across a batch of repos, skipping repos without it", async () => {
+    const program = ctx.program;
+
+    const oldTag = `legacy-${Date.now()}`;
+    const { repoKey: taggedKey } = await createRepoOnChain(ctx, {
+      tags: `unit09,${oldTag},repo`,
+    });
+    const { repoKey: untaggedKey } = await createRepoOnChain(ctx, {
+      tags: "unit09,repo",
+    });
+    const pdas = deriveAllCorePdasFromProgram(program);
+    const taggedRepo = getRepoPda(program.programId, taggedKey);
+    const untaggedRepo = getRepoPda(program.programId, untaggedKey);
+
+    const renamed: string[] = [];
+    const listener = program.addEventListener("tagRenamed", (event: any) => {
+      renamed.push(event.repo.toBase58());
+    });
+
+    try {
+      await program.methods
+        .renameTag({ old: oldTag.toUpperCase(), new: "archived" })
+        .accounts({
+          admin: ctx.wallet.publicKey,
+          config: pdas.config,
+          lifecycle: pdas.lifecycle,
+          metrics: pdas.metrics,
+          clock: SYSVAR_CLOCK_PUBKEY,
+        })
+        .remainingAccounts([
+          { pubkey: taggedRepo, isWritable: true, isSigner: false },
+          { pubkey: untaggedRepo, isWritable: true, isSigner: false },
+        ])
+        .rpc();
+
+      await new Promise((resolve) => setTimeout(resolve, 2_000));
+    } finally {
+      await program.removeEventListener(listener);
+    }
+
+    const taggedAcc = await program.account.repo.fetch(taggedRepo);
+    const untaggedAcc = await program.account.repo.fetch(untaggedRepo);
+    expect(taggedAcc.tags).toBe("unit09,archived,repo");
+    expect(untaggedAcc.tags).toBe("unit09,repo");
+
+    // One event, for the repo that actually carried the tag.
+    expect(renamed).toEqual([taggedRepo.toBase58()]);
+  });
+
   it("records repo observations and touches metrics counters", async () => {
     const program = ctx.program;
     const programId = program.programId;
@@ -356,48 +919,1235 @@ describe("unit09_program – repos", () => {
     expect(metricsAfter.totalFilesProcessed.gt(metricsBefore.totalFilesProcessed)).toBe(true);
   });
 
-  it("handles multiple repos without cross-contamination", async () => {
+  it("sets firstObservedAt once and keeps it stable across observations", async () => {
     const program = ctx.program;
 
-    // Repo A
-    const repoAKey = Keypair.generate().publicKey;
-    const repoAArgs = buildRegisterRepoArgs({
-      repoKey: repoAKey,
-      name: "unit09-repo-A",
-      tags: "unit09,repo,A",
-    });
-    const pdaA = deriveAllCorePdasFromProgram(program, { repoKey: repoAKey });
+    const pdas = deriveAllCorePdasFromProgram(program, { repoKey: primaryRepoKey });
+
+    const observe = async (revision: string) => {
+      await program.methods
+        .recordObservation(
+          buildRecordObservationArgs({
+            linesOfCode: BigInt(10),
+            filesProcessed: 1,
+            modulesTouched: 1,
+            revision,
+            note: "first-observed-at check",
+          })
+        )
+        .accounts({
+          config: pdas.config,
+          metrics: pdas.metrics,
+          repo: pdas.repo,
+          lifecycle: pdas.lifecycle,
+          observer: ctx.wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+    };
 
-    await program.methods
-      .registerRepo(repoAArgs)
-      .accounts({
-        config: pdaA.config,
-        repo: pdaA.repo,
-        authority: ctx.wallet.publicKey,
-        payer: ctx.wallet.publicKey,
-        systemProgram: SystemProgram.programId,
-      })
-      .rpc();
+    await observe("rev-first-1");
+    const afterFirst = await program.account.repo.fetch(pdas.repo);
+    expect(afterFirst.firstObservedAt.toNumber()).toBeGreaterThan(0);
 
-    // Repo B
-    const repoBKey = Keypair.generate().publicKey;
-    const repoBArgs = buildRegisterRepoArgs({
-      repoKey: repoBKey,
-      name: "unit09-repo-B",
-      tags: "unit09,repo,B",
-    });
-    const pdaB = deriveAllCorePdasFromProgram(program, { repoKey: repoBKey });
+    await observe("rev-first-2");
+    const afterSecond = await program.account.repo.fetch(pdas.repo);
 
-    await program.methods
-      .registerRepo(repoBArgs)
-      .accounts({
-        config: pdaB.config,
-        repo: pdaB.repo,
-        authority: ctx.wallet.publicKey,
-        payer: ctx.wallet.publicKey,
-        systemProgram: SystemProgram.programId,
-      })
-      .rpc();
+    expect(afterSecond.firstObservedAt.eq(afterFirst.firstObservedAt)).toBe(true);
+    expect(afterSecond.lastObservedAt.gte(afterSecond.firstObservedAt)).toBe(true);
+  });
+
+  it("records an optional attestor and requires one when requireAttestor is set", async () => {
+    const program = ctx.program;
+
+    const { repoKey } = await createRepoOnChain(ctx, {});
+    const pdas = deriveAllCorePdasFromProgram(program, { repoKey });
+    const attestor = Keypair.generate();
+
+    const observe = (revision: string, withAttestor: boolean) => {
+      const builder = program.methods
+        .recordObservation(
+          buildRecordObservationArgs({
+            linesOfCode: BigInt(10),
+            filesProcessed: 1,
+            modulesTouched: 1,
+            revision,
+            note: "attestor check",
+          })
+        )
+        .accounts({
+          observer: ctx.wallet.publicKey,
+          attestor: withAttestor ? attestor.publicKey : null,
+          config: pdas.config,
+          lifecycle: pdas.lifecycle,
+          metrics: pdas.metrics,
+          repo: pdas.repo,
+          systemProgram: SystemProgram.programId,
+        });
+      return withAttestor ? builder.signers([attestor]).rpc() : builder.rpc();
+    };
+
+    const setRequireAttestor = (requireAttestor: boolean) =>
+      program.methods
+        .setConfig(buildSetConfigArgs({ requireAttestor }))
+        .accounts({
+          admin: ctx.wallet.publicKey,
+          config: pdas.config,
+          metrics: pdas.metrics,
+          systemProgram: SystemProgram.programId,
+          clock: SYSVAR_CLOCK_PUBKEY,
+        })
+        .rpc();
+
+    // Optional by default.
+    await observe("rev-attest-1", false);
+    let repoAcc = await program.account.repo.fetch(pdas.repo);
+    expect(repoAcc.lastAttestor).toBeNull();
+
+    await observe("rev-attest-2", true);
+    repoAcc = await program.account.repo.fetch(pdas.repo);
+    expect(repoAcc.lastAttestor?.equals(attestor.publicKey)).toBe(true);
+
+    await setRequireAttestor(true);
+    try {
+      await expect(observe("rev-attest-3", false)).rejects.toThrow(/MissingAuthority/);
+      await observe("rev-attest-4", true);
+    } finally {
+      await setRequireAttestor(false);
+    }
+
+    repoAcc = await program.account.repo.fetch(pdas.repo);
+    expect(repoAcc.lastRevision).toBe("rev-attest-4");
+  });
+
+  it("applies an observation retried with the same idempotency key only once", async () => {
+    const program = ctx.program;
+
+    const { repoKey } = await createRepoOnChain(ctx, {});
+    const pdas = deriveAllCorePdasFromProgram(program, { repoKey });
+
+    const idempotencyKey = Array.from({ length: 16 }, (_, i) => i + 1);
+    // Distinct notes keep the two transactions from being deduplicated by
+    // the cluster itself; only the key should make the retry a no-op.
+    const observe = (note: string) =>
+      program.methods
+        .recordObservation(
+          buildRecordObservationArgs({
+            linesOfCode: BigInt(10),
+            filesProcessed: 1,
+            modulesTouched: 1,
+            revision: "rev-idempotent",
+            note,
+            idempotencyKey,
+          })
+        )
+        .accounts({
+          config: pdas.config,
+          metrics: pdas.metrics,
+          repo: pdas.repo,
+          lifecycle: pdas.lifecycle,
+          observer: ctx.wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+    await observe("first attempt");
+    const metricsAfterFirst = await program.account.metrics.fetch(pdas.metrics);
+
+    await observe("retry");
+    const repoAcc = await program.account.repo.fetch(pdas.repo);
+    const metricsAfterRetry = await program.account.metrics.fetch(pdas.metrics);
+
+    expect(repoAcc.observationCount.toNumber()).toBe(1);
+    expect(repoAcc.totalLinesOfCode.toNumber()).toBe(10);
+    expect(metricsAfterRetry.totalObservations.eq(metricsAfterFirst.totalObservations)).toBe(true);
+  });
+
+  it("treats a keyed retry inside the observation cooldown as a no-op", async () => {
+    const program = ctx.program;
+
+    const { repoKey } = await createRepoOnChain(ctx, {});
+    const pdas = deriveAllCorePdasFromProgram(program, { repoKey });
+
+    const setObservationCooldown = (observationSecs: number) =>
+      program.methods
+        .setConfig(
+          buildSetConfigArgs({
+            cooldowns: { observationSecs, forkCreationSecs: 0, moduleTouchSecs: 0 },
+          })
+        )
+        .accounts({
+          admin: ctx.wallet.publicKey,
+          config: pdas.config,
+          metrics: pdas.metrics,
+          systemProgram: SystemProgram.programId,
+          clock: SYSVAR_CLOCK_PUBKEY,
+        })
+        .rpc();
+
+    const idempotencyKey = Array.from({ length: 16 }, (_, i) => 0xc0 + i);
+    const observe = (note: string) =>
+      program.methods
+        .recordObservation(
+          buildRecordObservationArgs({
+            linesOfCode: BigInt(10),
+            filesProcessed: 1,
+            modulesTouched: 1,
+            revision: "rev-idempotent-cooldown",
+            note,
+            idempotencyKey,
+          })
+        )
+        .accounts({
+          config: pdas.config,
+          metrics: pdas.metrics,
+          repo: pdas.repo,
+          lifecycle: pdas.lifecycle,
+          observer: ctx.wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+    await setObservationCooldown(3600);
+    try {
+      await observe("first attempt");
+      const repoAfterFirst = await program.account.repo.fetch(pdas.repo);
+      const metricsAfterFirst = await program.account.metrics.fetch(pdas.metrics);
+
+      // The retry lands inside the cooldown window; the replay check runs
+      // first, so it succeeds without applying anything.
+      await observe("retry");
+      const repoAfterRetry = await program.account.repo.fetch(pdas.repo);
+      const metricsAfterRetry = await program.account.metrics.fetch(pdas.metrics);
+
+      expect(repoAfterRetry.observationCount.toNumber()).toBe(1);
+      expect(repoAfterRetry.totalLinesOfCode.toNumber()).toBe(10);
+      expect(repoAfterRetry.lastObservedAt.eq(repoAfterFirst.lastObservedAt)).toBe(true);
+      expect(metricsAfterRetry.totalObservations.eq(metricsAfterFirst.totalObservations)).toBe(
+        true
+      );
+    } finally {
+      await setObservationCooldown(0);
+    }
+  });
+
+  it("stores a non-zero source region hash and rejects an all-zero one", async () => {
+    const program = ctx.program;
+
+    const { repoKey } = await createRepoOnChain(ctx, {});
+    const pdas = deriveAllCorePdasFromProgram(program, { repoKey });
+
+    const observe = (sourceRegionHash: number[]) =>
+      program.methods
+        .recordObservation(
+          buildRecordObservationArgs({ revision: "rev-region", sourceRegionHash })
+        )
+        .accounts({
+          config: pdas.config,
+          metrics: pdas.metrics,
+          repo: pdas.repo,
+          lifecycle: pdas.lifecycle,
+          observer: ctx.wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+    await expect(observe(new Array(8).fill(0))).rejects.toThrow(/MetadataInvalid/);
+
+    const regionHash = [0x5e, 0x11, 0x0a, 0x42, 0x00, 0x09, 0x7f, 0x01];
+    await observe(regionHash);
+    const repoAcc = await program.account.repo.fetch(pdas.repo);
+    expect(Array.from(repoAcc.lastSourceRegionHash as number[])).toEqual(regionHash);
+  });
+
+  it("requires an access proof on observations only when the repo asks for one", async () => {
+    const program = ctx.program;
+
+    const { repoKey } = await createRepoOnChain(ctx, {});
+    const pdas = deriveAllCorePdasFromProgram(program, { repoKey });
+
+    const observe = (accessProof: number[] | null) =>
+      program.methods
+        .recordObservation(
+          buildRecordObservationArgs({ revision: "rev-private", accessProof })
+        )
+        .accounts({
+          config: pdas.config,
+          metrics: pdas.metrics,
+          repo: pdas.repo,
+          lifecycle: pdas.lifecycle,
+          observer: ctx.wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+    // Not required: observations without a proof are accepted.
+    await observe(null);
+
+    await program.methods
+      .updateRepo(buildUpdateRepoArgs({ requireAccessProof: true }))
+      .accounts({
+        config: pdas.config,
+        metrics: pdas.metrics,
+        repo: pdas.repo,
+        authority: ctx.wallet.publicKey,
+      })
+      .rpc();
+
+    // Required but missing.
+    await expect(observe(null)).rejects.toThrow(/ObservationNotAllowed/);
+
+    // Required and present: only the latest proof hash is kept.
+    const accessProof = new Array(32).fill(0).map((_, i) => (i * 7 + 1) % 256);
+    await observe(accessProof);
+
+    const repoAcc = await program.account.repo.fetch(pdas.repo);
+    expect(repoAcc.requireAccessProof).toBe(true);
+    expect(repoAcc.observationCount.toNumber()).toBe(2);
+    expect(Array.from(repoAcc.lastAccessProof as number[])).toEqual(accessProof);
+  });
+
+  it("charges prepaid credits per observation when metering is enabled", async () => {
+    const program = ctx.program;
+
+    const { repoKey } = await createRepoOnChain(ctx, {});
+    const pdas = deriveAllCorePdasFromProgram(program, { repoKey });
+    const observerCredits = getObserverCreditsPda(program.programId, ctx.wallet.publicKey);
+    const feeRecipient = Keypair.generate().publicKey;
+
+    // Credits are lamports; amounts stay above the rent-exempt minimum so
+    // the first transfer can create the fresh fee recipient.
+    const cost = 5_000_000;
+
+    const setMetering = (creditsPerObservation: number, recipient: PublicKey | null) =>
+      program.methods
+        .setConfig(
+          buildSetConfigArgs({
+            feeRecipient: recipient,
+            creditsPerObservation: BigInt(creditsPerObservation),
+          })
+        )
+        .accounts({
+          admin: ctx.wallet.publicKey,
+          config: pdas.config,
+          metrics: pdas.metrics,
+          systemProgram: SystemProgram.programId,
+          clock: SYSVAR_CLOCK_PUBKEY,
+        })
+        .rpc();
+
+    const topUp = (amount: number) =>
+      program.methods
+        .topUpCredits({ observer: ctx.wallet.publicKey, amount: new BN(amount) })
+        .accounts({
+          payer: ctx.wallet.publicKey,
+          config: pdas.config,
+          lifecycle: pdas.lifecycle,
+          metrics: pdas.metrics,
+          feeRecipient,
+          observerCredits,
+          systemProgram: SystemProgram.programId,
+          clock: SYSVAR_CLOCK_PUBKEY,
+        })
+        .rpc();
+
+    const observe = (note: string, withCredits: boolean) =>
+      program.methods
+        .recordObservation(
+          buildRecordObservationArgs({
+            linesOfCode: BigInt(10),
+            filesProcessed: 1,
+            modulesTouched: 1,
+            revision: "rev-metered",
+            note,
+          })
+        )
+        .accounts({
+          config: pdas.config,
+          metrics: pdas.metrics,
+          repo: pdas.repo,
+          lifecycle: pdas.lifecycle,
+          observer: ctx.wallet.publicKey,
+          observerCredits: withCredits ? observerCredits : null,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+    await setMetering(cost, feeRecipient);
+    try {
+      // Metering on and no credits account supplied.
+      await expect(observe("unpaid", false)).rejects.toThrow(/InsufficientCredits/);
+
+      await topUp(2 * cost + 1_000_000);
+      let creditsAcc = await program.account.observerCredits.fetch(observerCredits);
+      const startBalance = creditsAcc.balance.toNumber();
+      expect(await ctx.connection.getBalance(feeRecipient)).toEqual(2 * cost + 1_000_000);
+
+      // Spend the balance down below one observation.
+      const affordable = Math.floor(startBalance / cost);
+      for (let i = 0; i < affordable; i++) {
+        await observe(`paid ${i}`, true);
+      }
+      creditsAcc = await program.account.observerCredits.fetch(observerCredits);
+      const remaining = startBalance - affordable * cost;
+      expect(creditsAcc.balance.toNumber()).toBe(remaining);
+
+      // Exhausted.
+      await expect(observe("exhausted", true)).rejects.toThrow(/InsufficientCredits/);
+
+      // A top-up makes observations possible again.
+      await topUp(cost);
+      await observe("after top-up", true);
+      creditsAcc = await program.account.observerCredits.fetch(observerCredits);
+      expect(creditsAcc.balance.toNumber()).toBe(remaining);
+    } finally {
+      await setMetering(0, null);
+    }
+
+    // Metering disabled: no credits account needed.
+    await observe("free", false);
+  });
+
+  it("enforces the per-repo observer allow-list", async () => {
+    const program = ctx.program;
+
+    const { repoKey } = await createRepoOnChain(ctx, {});
+    const pdas = deriveAllCorePdasFromProgram(program, { repoKey });
+
+    const setAllowedObservers = async (allowedObservers: PublicKey[]) => {
+      await program.methods
+        .updateRepo(buildUpdateRepoArgs({ allowedObservers }))
+        .accounts({
+          config: pdas.config,
+          repo: pdas.repo,
+          authority: ctx.wallet.publicKey,
+        })
+        .rpc();
+    };
+
+    const observe = () =>
+      program.methods
+        .recordObservation(
+          buildRecordObservationArgs({
+            linesOfCode: BigInt(10),
+            filesProcessed: 1,
+            modulesTouched: 1,
+            revision: "rev-allow-list",
+            note: "allow-list check",
+          })
+        )
+        .accounts({
+          config: pdas.config,
+          metrics: pdas.metrics,
+          repo: pdas.repo,
+          lifecycle: pdas.lifecycle,
+          observer: ctx.wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+    // Wallet not in the list: rejected.
+    await setAllowedObservers([Keypair.generate().publicKey]);
+    await expect(observe()).rejects.toThrow(/ObservationNotAllowed/);
+
+    // Wallet in the list: accepted.
+    await setAllowedObservers([ctx.wallet.publicKey]);
+    await observe();
+
+    // Empty list: anyone may observe again.
+    await setAllowedObservers([]);
+    await observe();
+
+    const repoAcc = await program.account.repo.fetch(pdas.repo);
+    expect(repoAcc.allowedObservers).toHaveLength(0);
+    expect(repoAcc.observationCount.toNumber()).toBe(2);
+  });
+
+  it("requires the observer wallet to hold minObserverLamports", async () => {
+    const program = ctx.program;
+
+    const { repoKey } = await createRepoOnChain(ctx, {});
+    const pdas = deriveAllCorePdasFromProgram(program, { repoKey });
+
+    // A separate observer so transaction fees, paid by the provider wallet,
+    // do not move its balance.
+    const observer = await ctx.createFundedKeypair(1);
+    const balance = await ctx.connection.getBalance(observer.publicKey);
+
+    const setMinObserverLamports = (minObserverLamports: number) =>
+      program.methods
+        .setConfig(buildSetConfigArgs({ minObserverLamports: BigInt(minObserverLamports) }))
+        .accounts({
+          admin: ctx.wallet.publicKey,
+          config: pdas.config,
+          metrics: pdas.metrics,
+          systemProgram: SystemProgram.programId,
+          clock: SYSVAR_CLOCK_PUBKEY,
+        })
+        .rpc();
+
+    const observe = (revision: string) =>
+      program.methods
+        .recordObservation(
+          buildRecordObservationArgs({
+            linesOfCode: BigInt(10),
+            filesProcessed: 1,
+            modulesTouched: 1,
+            revision,
+            note: "observer balance check",
+          })
+        )
+        .accounts({
+          config: pdas.config,
+          metrics: pdas.metrics,
+          repo: pdas.repo,
+          lifecycle: pdas.lifecycle,
+          observer: observer.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([observer])
+        .rpc();
+
+    try {
+      // One lamport short: rejected.
+      await setMinObserverLamports(balance + 1);
+      await expect(observe("rev-balance-below")).rejects.toThrow(
+        /InsufficientObserverBalance/
+      );
+
+      // Exactly at the threshold: accepted.
+      await setMinObserverLamports(balance);
+      await observe("rev-balance-at");
+    } finally {
+      // 0 disables the check for the other tests.
+      await setMinObserverLamports(0);
+    }
+
+    const repoAcc = await program.account.repo.fetch(pdas.repo);
+    expect(repoAcc.lastRevision).toBe("rev-balance-at");
+    expect(repoAcc.observationCount.toNumber()).toBe(1);
+  });
+
+  it("logs the retry time when the observation cooldown blocks", async () => {
+    const program = ctx.program;
+
+    const { repoKey } = await createRepoOnChain(ctx, {});
+    const pdas = deriveAllCorePdasFromProgram(program, { repoKey });
+    const windowSecs = 3600;
+
+    const setObservationCooldown = (observationSecs: number) =>
+      program.methods
+        .setConfig(
+          buildSetConfigArgs({
+            cooldowns: { observationSecs, forkCreationSecs: 0, moduleTouchSecs: 0 },
+          })
+        )
+        .accounts({
+          admin: ctx.wallet.publicKey,
+          config: pdas.config,
+          metrics: pdas.metrics,
+          systemProgram: SystemProgram.programId,
+          clock: SYSVAR_CLOCK_PUBKEY,
+        })
+        .rpc();
+
+    const observe = (revision: string) =>
+      program.methods
+        .recordObservation(
+          buildRecordObservationArgs({
+            linesOfCode: BigInt(10),
+            filesProcessed: 1,
+            modulesTouched: 1,
+            revision,
+            note: "cooldown retry hint",
+          })
+        )
+        .accounts({
+          config: pdas.config,
+          metrics: pdas.metrics,
+          repo: pdas.repo,
+          lifecycle: pdas.lifecycle,
+          observer: ctx.wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        });
+
+    await setObservationCooldown(windowSecs);
+    try {
+      // First observation of a fresh repo: nothing to cool down from.
+      await observe("rev-cooldown-1").rpc();
+      const repoAcc = await program.account.repo.fetch(pdas.repo);
+      const retryAt = repoAcc.lastObservedAt.toNumber() + windowSecs;
+
+      const sim = await simulateReturnData(ctx, observe("rev-cooldown-2"));
+      expect(sim.err).not.toBeNull();
+
+      const logs = sim.logs.join("\n");
+      expect(logs).toMatch(/CooldownActive/);
+      expect(logs).toContain(`retry_at=${retryAt}`);
+    } finally {
+      await setObservationCooldown(0);
+    }
+  });
+
+  it("lets only the admin backfill an observation timestamp", async () => {
+    const program = ctx.program;
+
+    const { repoKey } = await createRepoOnChain(ctx, {});
+    const pdas = deriveAllCorePdasFromProgram(program, { repoKey });
+
+    const observe = (observer: Keypair | null, observedAtOverride: bigint) => {
+      const builder = program.methods
+        .recordObservation(
+          buildRecordObservationArgs({
+            linesOfCode: BigInt(10),
+            filesProcessed: 1,
+            modulesTouched: 1,
+            revision: "rev-backfill",
+            note: "backfill check",
+            observedAtOverride,
+          })
+        )
+        .accounts({
+          config: pdas.config,
+          metrics: pdas.metrics,
+          repo: pdas.repo,
+          lifecycle: pdas.lifecycle,
+          observer: observer ? observer.publicKey : ctx.wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        });
+      return (observer ? builder.signers([observer]) : builder).rpc();
+    };
+
+    // Non-admin observers may not set the override.
+    await expect(observe(Keypair.generate(), BigInt(1_600_000_000))).rejects.toThrow(
+      /UnauthorizedAdminAction/
+    );
+
+    // Admin backfill in the past is stored as-is.
+    await observe(null, BigInt(1_600_000_000));
+    let repoAcc = await program.account.repo.fetch(pdas.repo);
+    expect(repoAcc.lastObservedAt.toNumber()).toBe(1_600_000_000);
+
+    // A future-dated override is clamped to the current time.
+    const farFuture = BigInt(Math.floor(Date.now() / 1000) + 365 * 24 * 3600);
+    await observe(null, farFuture);
+    repoAcc = await program.account.repo.fetch(pdas.repo);
+    expect(repoAcc.lastObservedAt.toNumber()).toBeLessThan(Number(farFuture));
+    expect(repoAcc.firstObservedAt.toNumber()).toBe(1_600_000_000);
+  });
+
+  it("reports repos whose last observation is older than the staleness threshold", async () => {
+    const program = ctx.program;
+
+    const { repoKey: freshKey } = await createRepoOnChain(ctx, {});
+    const { repoKey: staleKey } = await createRepoOnChain(ctx, {});
+    const pdas = deriveAllCorePdasFromProgram(program);
+    const freshRepo = getRepoPda(program.programId, freshKey);
+    const staleRepo = getRepoPda(program.programId, staleKey);
+
+    const setThreshold = (stalenessThresholdSecs: number) =>
+      program.methods
+        .setConfig(buildSetConfigArgs({ stalenessThresholdSecs: BigInt(stalenessThresholdSecs) }))
+        .accounts({
+          admin: ctx.wallet.publicKey,
+          config: pdas.config,
+          metrics: pdas.metrics,
+          systemProgram: SystemProgram.programId,
+          clock: SYSVAR_CLOCK_PUBKEY,
+        })
+        .rpc();
+
+    const observe = (repo: PublicKey, observedAtOverride: bigint | null) =>
+      program.methods
+        .recordObservation(
+          buildRecordObservationArgs({
+            linesOfCode: BigInt(10),
+            filesProcessed: 1,
+            modulesTouched: 1,
+            revision: "rev-staleness",
+            note: "staleness check",
+            observedAtOverride,
+          })
+        )
+        .accounts({
+          config: pdas.config,
+          metrics: pdas.metrics,
+          repo,
+          lifecycle: pdas.lifecycle,
+          observer: ctx.wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+    const getStaleRepos = async (): Promise<string[]> => {
+      const { err, data } = await simulateReturnData(
+        ctx,
+        program.methods
+          .getStaleRepos()
+          .accounts({ config: pdas.config, clock: SYSVAR_CLOCK_PUBKEY })
+          .remainingAccounts([
+            { pubkey: freshRepo, isWritable: false, isSigner: false },
+            { pubkey: staleRepo, isWritable: false, isSigner: false },
+          ])
+      );
+      expect(err).toBeNull();
+      expect(data.length).toBeGreaterThan(0);
+      // Borsh Vec<Pubkey>: u32 length, then 32-byte keys.
+      const keys: string[] = [];
+      for (let i = 0; i < data.readUInt32LE(0); i++) {
+        keys.push(new PublicKey(data.subarray(4 + 32 * i, 36 + 32 * i)).toBase58());
+      }
+      return keys;
+    };
+
+    // Fresh: observed now. Stale: admin backfill two hours ago.
+    await observe(freshRepo, null);
+    await observe(staleRepo, BigInt(Math.floor(Date.now() / 1000) - 2 * 3600));
+
+    await setThreshold(3600);
+    try {
+      expect(await getStaleRepos()).toEqual([staleRepo.toBase58()]);
+    } finally {
+      await setThreshold(0);
+    }
+
+    // Disabled threshold: nothing is stale.
+    expect(await getStaleRepos()).toEqual([]);
+  });
+
+  it("rejects migrating a repo that is already on the current schema", async () => {
+    const program = ctx.program;
+
+    const { repoKey } = await createRepoOnChain(ctx, {});
+    const pdas = deriveAllCorePdasFromProgram(program, { repoKey });
+
+    const outsider = Keypair.generate();
+    await expect(
+      program.methods
+        .migrateRepo()
+        .accounts({
+          admin: outsider.publicKey,
+          config: pdas.config,
+          metrics: pdas.metrics,
+          repo: pdas.repo,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([outsider])
+        .rpc()
+    ).rejects.toThrow(/InvalidAdmin/);
+
+    await expect(
+      program.methods
+        .migrateRepo()
+        .accounts({
+          admin: ctx.wallet.publicKey,
+          config: pdas.config,
+          metrics: pdas.metrics,
+          repo: pdas.repo,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc()
+    ).rejects.toThrow(/MigrationAlreadyApplied/);
+  });
+
+  it("pauses and resumes observation via setRepoObservable", async () => {
+    const program = ctx.program;
+
+    const { repoKey } = await createRepoOnChain(ctx, {});
+    const pdas = deriveAllCorePdasFromProgram(program, { repoKey });
+
+    const setObservable = async (allowObservation: boolean) => {
+      await program.methods
+        .setRepoObservable({ allowObservation })
+        .accounts({
+          authority: ctx.wallet.publicKey,
+          config: pdas.config,
+          lifecycle: pdas.lifecycle,
+          metrics: pdas.metrics,
+          repo: pdas.repo,
+        })
+        .rpc();
+    };
+
+    const observe = () =>
+      program.methods
+        .recordObservation(
+          buildRecordObservationArgs({
+            linesOfCode: BigInt(10),
+            filesProcessed: 1,
+            modulesTouched: 1,
+            revision: "rev-observable",
+            note: "observable toggle check",
+          })
+        )
+        .accounts({
+          config: pdas.config,
+          metrics: pdas.metrics,
+          repo: pdas.repo,
+          lifecycle: pdas.lifecycle,
+          observer: ctx.wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+    const before = await program.account.repo.fetch(pdas.repo);
+
+    await setObservable(false);
+    await expect(observe()).rejects.toThrow(/ObservationNotAllowed/);
+
+    await setObservable(true);
+    await observe();
+
+    // Nothing but the flag (and timestamps) should have changed.
+    const after = await program.account.repo.fetch(pdas.repo);
+    expect(after.allowObservation).toBe(true);
+    expect(after.name).toBe(before.name);
+    expect(after.url).toBe(before.url);
+    expect(after.tags).toBe(before.tags);
+    expect(after.isActive).toBe(before.isActive);
+  });
+
+  it("keeps a reserved repo inactive until finalizeRepo activates it when not review-gated", async () => {
+    const program = ctx.program;
+
+    const repoKey = Keypair.generate().publicKey;
+    const pdas = deriveAllCorePdasFromProgram(program, { repoKey });
+
+    await program.methods
+      .reserveRepo({ repoKey })
+      .accounts({
+        payer: ctx.wallet.publicKey,
+        authority: ctx.wallet.publicKey,
+        config: pdas.config,
+        lifecycle: pdas.lifecycle,
+        repo: pdas.repo,
+        systemProgram: SystemProgram.programId,
+        clock: SYSVAR_CLOCK_PUBKEY,
+      })
+      .rpc();
+
+    const reserved = await program.account.repo.fetch(pdas.repo);
+    expect(reserved.isReserved).toBe(true);
+    expect(reserved.isActive).toBe(false);
+    expect(reserved.name).toBe("");
+
+    const observe = () =>
+      program.methods
+        .recordObservation(
+          buildRecordObservationArgs({
+            linesOfCode: BigInt(10),
+            filesProcessed: 1,
+            modulesTouched: 1,
+            revision: "rev-reserved",
+            note: "reserved repo check",
+          })
+        )
+        .accounts({
+          config: pdas.config,
+          metrics: pdas.metrics,
+          repo: pdas.repo,
+          lifecycle: pdas.lifecycle,
+          observer: ctx.wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+    await expect(observe()).rejects.toThrow(/RepoInactive/);
+
+    const metricsBefore = await program.account.metrics.fetch(pdas.metrics);
+
+    await program.methods
+      .finalizeRepo({
+        name: "unit09-reserved",
+        url: "https://github.com/unit09-labs/reserved",
+        tags: "solana",
+        metadataUri: "",
+        allowObservation: true,
+      })
+      .accounts({
+        authority: ctx.wallet.publicKey,
+        config: pdas.config,
+        lifecycle: pdas.lifecycle,
+        metrics: pdas.metrics,
+        repo: pdas.repo,
+        clock: SYSVAR_CLOCK_PUBKEY,
+      })
+      .rpc();
+
+    const finalized = await program.account.repo.fetch(pdas.repo);
+    expect(finalized.isReserved).toBe(false);
+    expect(finalized.isActive).toBe(true);
+    expect(finalized.name).toBe("unit09-reserved");
+
+    const metricsAfter = await program.account.metrics.fetch(pdas.metrics);
+    expect(metricsAfter.totalRepos.toNumber()).toBe(metricsBefore.totalRepos.toNumber() + 1);
+
+    await observe();
+  });
+
+  it("reverts a multi-repo observation whose splits do not sum to the totals", async () => {
+    const program = ctx.program;
+
+    const secondRepo = await createRepoOnChain(ctx, {});
+    const pdasA = deriveAllCorePdasFromProgram(program, { repoKey: primaryRepoKey });
+    const pdasB = deriveAllCorePdasFromProgram(program, { repoKey: secondRepo.repoKey });
+
+    await expect(
+      program.methods
+        .recordObservationMulti({
+          linesOfCode: new BN(1_000),
+          filesProcessed: 10,
+          modulesTouched: 2,
+          splits: [
+            {
+              repoIndex: 0,
+              repoKey: primaryRepoKey,
+              linesOfCode: new BN(600),
+              filesProcessed: 6,
+              modulesTouched: 1,
+            },
+            // 600 + 300 != 1_000
+            {
+              repoIndex: 1,
+              repoKey: secondRepo.repoKey,
+              linesOfCode: new BN(300),
+              filesProcessed: 4,
+              modulesTouched: 1,
+            },
+          ],
+          revision: "monorepo@split-check",
+          note: "submodule scan",
+          atomic: true,
+        })
+        .accounts({
+          observer: ctx.wallet.publicKey,
+          config: pdasA.config,
+          lifecycle: pdasA.lifecycle,
+          metrics: pdasA.metrics,
+          clock: SYSVAR_CLOCK_PUBKEY,
+        })
+        .remainingAccounts([
+          { pubkey: pdasA.repo, isWritable: true, isSigner: false },
+          { pubkey: pdasB.repo, isWritable: true, isSigner: false },
+        ])
+        .rpc()
+    ).rejects.toThrow(/MetricsInconsistent/);
+  });
+
+  it("rejects a multi-repo observation with misordered repo accounts", async () => {
+    const program = ctx.program;
+
+    const secondRepo = await createRepoOnChain(ctx, {});
+    const pdasA = deriveAllCorePdasFromProgram(program, { repoKey: primaryRepoKey });
+    const pdasB = deriveAllCorePdasFromProgram(program, { repoKey: secondRepo.repoKey });
+    const before = await program.account.repo.fetch(pdasA.repo);
+
+    await expect(
+      program.methods
+        .recordObservationMulti({
+          linesOfCode: new BN(900),
+          filesProcessed: 10,
+          modulesTouched: 2,
+          splits: [
+            {
+              repoIndex: 0,
+              repoKey: primaryRepoKey,
+              linesOfCode: new BN(600),
+              filesProcessed: 6,
+              modulesTouched: 1,
+            },
+            {
+              repoIndex: 1,
+              repoKey: secondRepo.repoKey,
+              linesOfCode: new BN(300),
+              filesProcessed: 4,
+              modulesTouched: 1,
+            },
+          ],
+          revision: "monorepo@misordered",
+          note: "submodule scan",
+          atomic: true,
+        })
+        .accounts({
+          observer: ctx.wallet.publicKey,
+          config: pdasA.config,
+          lifecycle: pdasA.lifecycle,
+          metrics: pdasA.metrics,
+          clock: SYSVAR_CLOCK_PUBKEY,
+        })
+        // Accounts swapped relative to the splits.
+        .remainingAccounts([
+          { pubkey: pdasB.repo, isWritable: true, isSigner: false },
+          { pubkey: pdasA.repo, isWritable: true, isSigner: false },
+        ])
+        .rpc()
+    ).rejects.toThrow(/RepoKeyMismatch/);
+
+    const after = await program.account.repo.fetch(pdasA.repo);
+    expect(after.observationCount.eq(before.observationCount)).toBe(true);
+  });
+
+  it("rejects a multi-repo observation passing the same repo account twice", async () => {
+    const program = ctx.program;
+
+    const pdas = deriveAllCorePdasFromProgram(program, { repoKey: primaryRepoKey });
+    const before = await program.account.repo.fetch(pdas.repo);
+
+    await expect(
+      program.methods
+        .recordObservationMulti({
+          linesOfCode: new BN(900),
+          filesProcessed: 10,
+          modulesTouched: 2,
+          splits: [
+            {
+              repoIndex: 0,
+              repoKey: primaryRepoKey,
+              linesOfCode: new BN(600),
+              filesProcessed: 6,
+              modulesTouched: 1,
+            },
+            {
+              repoIndex: 1,
+              repoKey: primaryRepoKey,
+              linesOfCode: new BN(300),
+              filesProcessed: 4,
+              modulesTouched: 1,
+            },
+          ],
+          revision: "monorepo@duplicate",
+          note: "submodule scan",
+          atomic: true,
+        })
+        .accounts({
+          observer: ctx.wallet.publicKey,
+          config: pdas.config,
+          lifecycle: pdas.lifecycle,
+          metrics: pdas.metrics,
+          clock: SYSVAR_CLOCK_PUBKEY,
+        })
+        // Same repo at two indices; both splits must use one index instead.
+        .remainingAccounts([
+          { pubkey: pdas.repo, isWritable: true, isSigner: false },
+          { pubkey: pdas.repo, isWritable: true, isSigner: false },
+        ])
+        .rpc()
+    ).rejects.toThrow(/ValidationFailed/);
+
+    const after = await program.account.repo.fetch(pdas.repo);
+    expect(after.observationCount.eq(before.observationCount)).toBe(true);
+    expect(after.totalLinesOfCode.eq(before.totalLinesOfCode)).toBe(true);
+  });
+
+  it("skips a failing split only when the multi-repo observation is non-atomic", async () => {
+    const program = ctx.program;
+
+    const openRepo = await createRepoOnChain(ctx, {});
+    const pausedRepo = await createRepoOnChain(ctx, {});
+    const pdasOpen = deriveAllCorePdasFromProgram(program, { repoKey: openRepo.repoKey });
+    const pdasPaused = deriveAllCorePdasFromProgram(program, { repoKey: pausedRepo.repoKey });
+
+    // The invalid item: a repo that does not allow observation.
+    await program.methods
+      .setRepoObservable({ allowObservation: false })
+      .accounts({
+        authority: ctx.wallet.publicKey,
+        config: pdasPaused.config,
+        lifecycle: pdasPaused.lifecycle,
+        metrics: pdasPaused.metrics,
+        repo: pdasPaused.repo,
+      })
+      .rpc();
+
+    const observeBoth = (atomic: boolean) =>
+      program.methods
+        .recordObservationMulti({
+          linesOfCode: new BN(900),
+          filesProcessed: 10,
+          modulesTouched: 2,
+          splits: [
+            {
+              repoIndex: 0,
+              repoKey: openRepo.repoKey,
+              linesOfCode: new BN(600),
+              filesProcessed: 6,
+              modulesTouched: 1,
+            },
+            {
+              repoIndex: 1,
+              repoKey: pausedRepo.repoKey,
+              linesOfCode: new BN(300),
+              filesProcessed: 4,
+              modulesTouched: 1,
+            },
+          ],
+          revision: `monorepo@atomic-${atomic}`,
+          note: "submodule scan",
+          atomic,
+        })
+        .accounts({
+          observer: ctx.wallet.publicKey,
+          config: pdasOpen.config,
+          lifecycle: pdasOpen.lifecycle,
+          metrics: pdasOpen.metrics,
+          clock: SYSVAR_CLOCK_PUBKEY,
+        })
+        .remainingAccounts([
+          { pubkey: pdasOpen.repo, isWritable: true, isSigner: false },
+          { pubkey: pdasPaused.repo, isWritable: true, isSigner: false },
+        ])
+        .rpc();
+
+    // Atomic: the paused repo reverts everything.
+    await expect(observeBoth(true)).rejects.toThrow(/ObservationNotAllowed/);
+    let openAcc = await program.account.repo.fetch(pdasOpen.repo);
+    expect(openAcc.observationCount.toNumber()).toBe(0);
+
+    // Best-effort: the open repo is applied, the paused one skipped.
+    let report: { applied: number; skipped: number } | undefined;
+    const listener = program.addEventListener("batchPartiallyApplied", (event: any) => {
+      report = { applied: event.applied, skipped: event.skipped };
+    });
+    const metricsBefore = await program.account.metrics.fetch(pdasOpen.metrics);
+    try {
+      await observeBoth(false);
+      await new Promise((resolve) => setTimeout(resolve, 2_000));
+    } finally {
+      await program.removeEventListener(listener);
+    }
+
+    openAcc = await program.account.repo.fetch(pdasOpen.repo);
+    const pausedAcc = await program.account.repo.fetch(pdasPaused.repo);
+    const metricsAfter = await program.account.metrics.fetch(pdasOpen.metrics);
+    expect(openAcc.observationCount.toNumber()).toBe(1);
+    expect(pausedAcc.observationCount.toNumber()).toBe(0);
+    expect(
+      metricsAfter.totalLinesOfCode.sub(metricsBefore.totalLinesOfCode).toNumber()
+    ).toBe(600);
+    expect(report).toEqual({ applied: 1, skipped: 1 });
+  });
+
+  it("does not meter a non-atomic multi-repo observation that applies nothing", async () => {
+    const program = ctx.program;
+
+    const { repoKey } = await createRepoOnChain(ctx, {});
+    const pdas = deriveAllCorePdasFromProgram(program, { repoKey });
+
+    await program.methods
+      .setRepoObservable({ allowObservation: false })
+      .accounts({
+        authority: ctx.wallet.publicKey,
+        config: pdas.config,
+        lifecycle: pdas.lifecycle,
+        metrics: pdas.metrics,
+        repo: pdas.repo,
+      })
+      .rpc();
+
+    const setMetering = (creditsPerObservation: number) =>
+      program.methods
+        .setConfig(
+          buildSetConfigArgs({ creditsPerObservation: BigInt(creditsPerObservation) })
+        )
+        .accounts({
+          admin: ctx.wallet.publicKey,
+          config: pdas.config,
+          metrics: pdas.metrics,
+          systemProgram: SystemProgram.programId,
+          clock: SYSVAR_CLOCK_PUBKEY,
+        })
+        .rpc();
+
+    const observe = (atomic: boolean) =>
+      program.methods
+        .recordObservationMulti({
+          linesOfCode: new BN(300),
+          filesProcessed: 4,
+          modulesTouched: 1,
+          splits: [
+            {
+              repoIndex: 0,
+              repoKey,
+              linesOfCode: new BN(300),
+              filesProcessed: 4,
+              modulesTouched: 1,
+            },
+          ],
+          revision: `monorepo@unmetered-${atomic}`,
+          note: "all splits skipped",
+          atomic,
+        })
+        .accounts({
+          observer: ctx.wallet.publicKey,
+          config: pdas.config,
+          lifecycle: pdas.lifecycle,
+          metrics: pdas.metrics,
+          observerCredits: null,
+          clock: SYSVAR_CLOCK_PUBKEY,
+        })
+        .remainingAccounts([{ pubkey: pdas.repo, isWritable: true, isSigner: false }])
+        .rpc();
+
+    // Metering is on and no credits account is supplied: a call that
+    // records nothing is not charged, so it does not fail on credits.
+    await setMetering(5_000_000);
+    try {
+      const metricsBefore = await program.account.metrics.fetch(pdas.metrics);
+      await observe(false);
+      const metricsAfter = await program.account.metrics.fetch(pdas.metrics);
+      expect(metricsAfter.totalObservations.eq(metricsBefore.totalObservations)).toBe(true);
+      expect(
+        metricsAfter.observationsThisWindow.eq(metricsBefore.observationsThisWindow)
+      ).toBe(true);
+
+      await expect(observe(true)).rejects.toThrow(/ObservationNotAllowed/);
+    } finally {
+      await setMetering(0);
+    }
+  });
+
+  it("handles multiple repos without cross-contamination", async () => {
+    const program = ctx.program;
+
+    // Repo A
+    const repoAKey = Keypair.generate().publicKey;
+    const repoAArgs = buildRegisterRepoArgs({
+      repoKey: repoAKey,
+      name: "unit09-repo-A",
+      tags: "unit09,repo,A",
+    });
+    const pdaA = deriveAllCorePdasFromProgram(program, { repoKey: repoAKey });
+
+    await program.methods
+      .registerRepo(repoAArgs)
+      .accounts({
+        config: pdaA.config,
+        repo: pdaA.repo,
+        authority: ctx.wallet.publicKey,
+        payer: ctx.wallet.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    // Repo B
+    const repoBKey = Keypair.generate().publicKey;
+    const repoBArgs = buildRegisterRepoArgs({
+      repoKey: repoBKey,
+      name: "unit09-repo-B",
+      tags: "unit09,repo,B",
+    });
+    const pdaB = deriveAllCorePdasFromProgram(program, { repoKey: repoBKey });
+
+    await program.methods
+      .registerRepo(repoBArgs)
+      .accounts({
+        config: pdaB.config,
+        repo: pdaB.repo,
+        authority: ctx.wallet.publicKey,
+        payer: ctx.wallet.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
 
     const repoAAcc = await program.account.repo.fetch(pdaA.repo);
     const repoBAcc = await program.account.repo.fetch(pdaB.repo);
@@ -421,6 +2171,296 @@ describe("unit09_program – repos", () => {
     expect(repoAAcc.createdAt).not.toEqual(repoBAcc.createdAt);
   });
 
+  it("lets only the admin set a bounded per-repo fee override", async () => {
+    const program = ctx.program;
+
+    const { repoKey } = await createRepoOnChain(ctx, {});
+    const pdas = deriveAllCorePdasFromProgram(program, { repoKey });
+    const stranger = Keypair.generate();
+
+    const setOverride = (feeBpsOverride: number | null, admin: Keypair | null) => {
+      const builder = program.methods
+        .setRepoFeeOverride({ feeBpsOverride })
+        .accounts({
+          admin: admin ? admin.publicKey : ctx.wallet.publicKey,
+          config: pdas.config,
+          lifecycle: pdas.lifecycle,
+          metrics: pdas.metrics,
+          repo: pdas.repo,
+          clock: SYSVAR_CLOCK_PUBKEY,
+        });
+      return admin ? builder.signers([admin]).rpc() : builder.rpc();
+    };
+
+    // A non-admin signer.
+    await expect(setOverride(250, stranger)).rejects.toThrow(/InvalidAdmin/);
+
+    // Admin (the test wallet) with an out-of-range value.
+    await expect(setOverride(5_001, null)).rejects.toThrow(/InvalidFeeBps/);
+
+    await setOverride(250, null);
+    let repoAcc = await program.account.repo.fetch(pdas.repo);
+    expect(repoAcc.feeBpsOverride).toBe(250);
+
+    await setOverride(null, null);
+    repoAcc = await program.account.repo.fetch(pdas.repo);
+    expect(repoAcc.feeBpsOverride).toBeNull();
+  });
+
+  it("increments the repo score by the weighted observation amount", async () => {
+    const program = ctx.program;
+
+    const { repoKey } = await createRepoOnChain(ctx, {});
+    const pdas = deriveAllCorePdasFromProgram(program, { repoKey });
+
+    await program.methods
+      .recordObservation(
+        buildRecordObservationArgs({
+          linesOfCode: BigInt(12_345),
+          filesProcessed: 7,
+          modulesTouched: 3,
+          revision: "rev-score",
+          note: "score check",
+        })
+      )
+      .accounts({
+        config: pdas.config,
+        metrics: pdas.metrics,
+        repo: pdas.repo,
+        lifecycle: pdas.lifecycle,
+        observer: ctx.wallet.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    // 12_345 / 1_000 + 7 files + 3 modules * 10
+    const expected = 12 + 7 + 30;
+    const repoAcc = await program.account.repo.fetch(pdas.repo);
+    expect(repoAcc.score.toNumber()).toBe(expected);
+
+    const { err, data } = await simulateReturnData(
+      ctx,
+      program.methods.getRepoScore().accounts({ repo: pdas.repo })
+    );
+    expect(err).toBeNull();
+    // Trailing zero bytes may be trimmed; pad back to a u64.
+    expect(Buffer.concat([data, Buffer.alloc(8)]).readBigUInt64LE(0)).toBe(BigInt(expected));
+  });
+
+  it("returns a versioned RepoView matching the live repo via getRepo", async () => {
+    const program = ctx.program;
+
+    const { repoKey } = await createRepoOnChain(ctx, {
+      name: "unit09-view-repo",
+      tags: "solana,anchor",
+    });
+    const pdas = deriveAllCorePdasFromProgram(program, { repoKey });
+
+    const { err, data } = await simulateReturnData(
+      ctx,
+      program.methods.getRepo().accounts({ repo: pdas.repo })
+    );
+    expect(err).toBeNull();
+
+    // Borsh RepoView, decoded field by field.
+    let offset = 0;
+    const u8 = () => data.readUInt8(offset++);
+    const bool = () => u8() === 1;
+    const u16 = () => {
+      const value = data.readUInt16LE(offset);
+      offset += 2;
+      return value;
+    };
+    const u32 = () => {
+      const value = data.readUInt32LE(offset);
+      offset += 4;
+      return value;
+    };
+    const u64 = () => {
+      const value = data.readBigUInt64LE(offset);
+      offset += 8;
+      return value;
+    };
+    const i64 = () => {
+      const value = data.readBigInt64LE(offset);
+      offset += 8;
+      return value;
+    };
+    const pubkey = () => {
+      const value = new PublicKey(data.subarray(offset, offset + 32));
+      offset += 32;
+      return value;
+    };
+    const str = () => {
+      const len = u32();
+      const value = data.toString("utf8", offset, offset + len);
+      offset += len;
+      return value;
+    };
+    const option = <T>(read: () => T): T | null => (u8() === 1 ? read() : null);
+
+    const view = {
+      schemaVersion: u8(),
+      repoKey: pubkey(),
+      authority: pubkey(),
+      name: str(),
+      url: str(),
+      host: u8(),
+      tags: str(),
+      tagBits: u64(),
+      metadataUri: str(),
+      isActive: bool(),
+      allowObservation: bool(),
+      isReserved: bool(),
+      requireAccessProof: bool(),
+      maintainerCount: u8(),
+      allowedObserverCount: u8(),
+      moduleCount: u32(),
+      primaryModule: option(pubkey),
+      feeBpsOverride: option(u16),
+      observationCount: u64(),
+      totalLinesOfCode: u64(),
+      totalFilesProcessed: u64(),
+      score: u64(),
+      lastObserver: pubkey(),
+      firstObservedAt: i64(),
+      lastObservedAt: i64(),
+      lastRevision: str(),
+      createdAt: i64(),
+      updatedAt: i64(),
+    };
+    expect(offset).toBe(data.length);
+
+    const repoAcc = await program.account.repo.fetch(pdas.repo);
+    expect(view.schemaVersion).toBe(1);
+    expect(view.repoKey.equals(repoKey)).toBe(true);
+    expect(view.authority.equals(repoAcc.authority)).toBe(true);
+    expect(view.name).toBe(repoAcc.name);
+    expect(view.url).toBe(repoAcc.url);
+    expect(view.host).toBe(repoAcc.host);
+    expect(view.tags).toBe(repoAcc.tags);
+    expect(view.tagBits.toString()).toBe(repoAcc.tagBits.toString());
+    expect(view.metadataUri).toBe(repoAcc.metadataUri);
+    expect(view.isActive).toBe(repoAcc.isActive);
+    expect(view.allowObservation).toBe(repoAcc.allowObservation);
+    expect(view.isReserved).toBe(repoAcc.isReserved);
+    expect(view.maintainerCount).toBe(repoAcc.maintainers.length);
+    expect(view.allowedObserverCount).toBe(repoAcc.allowedObservers.length);
+    expect(view.moduleCount).toBe(repoAcc.moduleCount);
+    expect(view.primaryModule).toBeNull();
+    expect(view.feeBpsOverride).toBeNull();
+    expect(view.observationCount.toString()).toBe(repoAcc.observationCount.toString());
+    expect(view.score.toString()).toBe(repoAcc.score.toString());
+    expect(view.lastRevision).toBe(repoAcc.lastRevision);
+    expect(view.createdAt.toString()).toBe(repoAcc.createdAt.toString());
+    expect(view.updatedAt.toString()).toBe(repoAcc.updatedAt.toString());
+  });
+
+  it("ranks the tag shared by all modules first in summarizeRepoTags", async () => {
+    const program = ctx.program;
+
+    const { repoKey } = await createRepoOnChain(ctx, {});
+    const pdas = deriveAllCorePdasFromProgram(program, { repoKey });
+
+    const moduleTags = ["anchor,shared", "Shared,cli", "sdk, shared ,cli"];
+    const modulePdas: PublicKey[] = [];
+    for (const tags of moduleTags) {
+      const { moduleKey } = await createModuleOnChain(ctx, { repoKey, tags });
+      modulePdas.push(getModulePda(program.programId, moduleKey));
+    }
+
+    const { err, data } = await simulateReturnData(
+      ctx,
+      program.methods
+        .summarizeRepoTags()
+        .accounts({ repo: pdas.repo })
+        .remainingAccounts(
+          modulePdas.map((pubkey) => ({ pubkey, isWritable: false, isSigner: false }))
+        )
+    );
+    expect(err).toBeNull();
+    expect(data.length).toBeGreaterThan(0);
+
+    // Borsh Vec<TagCount>: u32 count, then (u32 len + utf8 tag, u32 count).
+    const summary: { tag: string; count: number }[] = [];
+    let offset = 4;
+    for (let i = 0; i < data.readUInt32LE(0); i++) {
+      const len = data.readUInt32LE(offset);
+      const tag = data.toString("utf8", offset + 4, offset + 4 + len);
+      summary.push({ tag, count: data.readUInt32LE(offset + 4 + len) });
+      offset += 8 + len;
+    }
+
+    expect(summary[0]).toEqual({ tag: "shared", count: 3 });
+    expect(summary[1]).toEqual({ tag: "cli", count: 2 });
+  });
+
+  it("breaks summarizeRepoTags ties by tag whatever the module order", async () => {
+    const program = ctx.program;
+
+    const { repoKey } = await createRepoOnChain(ctx, {});
+    const pdas = deriveAllCorePdasFromProgram(program, { repoKey });
+
+    // Every tag is carried by exactly one module.
+    const moduleTags = ["zeta", "mid", "alpha"];
+    const modulePdas: PublicKey[] = [];
+    for (const tags of moduleTags) {
+      const { moduleKey } = await createModuleOnChain(ctx, { repoKey, tags });
+      modulePdas.push(getModulePda(program.programId, moduleKey));
+    }
+
+    const summarize = async (modules: PublicKey[]): Promise<string[]> => {
+      const { err, data } = await simulateReturnData(
+        ctx,
+        program.methods
+          .summarizeRepoTags()
+          .accounts({ repo: pdas.repo })
+          .remainingAccounts(
+            modules.map((pubkey) => ({ pubkey, isWritable: false, isSigner: false }))
+          )
+      );
+      expect(err).toBeNull();
+
+      // Borsh Vec<TagCount>: u32 count, then (u32 len + utf8 tag, u32 count).
+      const tags: string[] = [];
+      let offset = 4;
+      for (let i = 0; i < data.readUInt32LE(0); i++) {
+        const len = data.readUInt32LE(offset);
+        tags.push(data.toString("utf8", offset + 4, offset + 4 + len));
+        offset += 8 + len;
+      }
+      return tags;
+    };
+
+    expect(await summarize(modulePdas)).toEqual(["alpha", "mid", "zeta"]);
+    expect(await summarize([...modulePdas].reverse())).toEqual(["alpha", "mid", "zeta"]);
+  });
+
+  it("matches whole tags only in repoHasTag", async () => {
+    const program = ctx.program;
+
+    const { repoKey } = await createRepoOnChain(ctx, { tags: "solana-x,Anchor,unit09" });
+    const pdas = deriveAllCorePdasFromProgram(program, { repoKey });
+
+    const hasTag = async (tag: string): Promise<boolean> => {
+      const { err, data } = await simulateReturnData(
+        ctx,
+        program.methods.repoHasTag({ tag }).accounts({ repo: pdas.repo })
+      );
+      expect(err).toBeNull();
+      // Trailing zero bytes may be trimmed from return data, so `false`
+      // can arrive as an empty buffer.
+      return data.length > 0 && data.readUInt8(0) === 1;
+    };
+
+    // Present, case-insensitively.
+    expect(await hasTag("anchor")).toBe(true);
+    // Absent.
+    expect(await hasTag("indexer")).toBe(false);
+    // A prefix of another tag is not a tag.
+    expect(await hasTag("solana")).toBe(false);
+  });
+
   it("exposes the registration transaction for the canonical repo", () => {
     expect(primaryRepoTx).toBeTruthy();
     if (primaryRepoTx) {
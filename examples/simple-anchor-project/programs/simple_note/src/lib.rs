@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::set_return_data;
 
 declare_id!("SmplNote1111111111111111111111111111111111");
 
@@ -10,14 +11,19 @@ pub mod simple_note {
         ctx: Context<CreateNote>,
         title: String,
         body: String,
+        tags: String,
     ) -> Result<()> {
         require!(title.len() <= 64, SimpleNoteError::TitleTooLong);
         require!(body.len() <= 512, SimpleNoteError::BodyTooLong);
+        require!(tags.len() <= Note::MAX_TAGS_LEN, SimpleNoteError::TagsTooLong);
 
         let note = &mut ctx.accounts.note;
         note.owner = ctx.accounts.authority.key();
         note.title = title;
         note.body = body;
+        note.tags = tags;
+        note.update_count = 0;
+        note.version = 0;
         note.bump = *ctx.bumps.get("note").unwrap();
 
         emit!(NoteCreated {
@@ -28,10 +34,34 @@ pub mod simple_note {
         Ok(())
     }
 
+    /// Counts how many of the notes passed in `remaining_accounts` belong to
+    /// `authority`. The result is written as a little-endian `u32` via
+    /// `set_return_data`.
+    pub fn count_notes<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CountNotes<'info>>,
+    ) -> Result<()> {
+        let authority = ctx.accounts.authority.key();
+        let mut owned: u32 = 0;
+
+        for account_info in ctx.remaining_accounts.iter() {
+            // `try_from` checks the account owner and discriminator, so only
+            // real `Note` accounts of this program are accepted.
+            let note: Account<Note> = Account::try_from(account_info)?;
+            if note.owner == authority {
+                owned += 1;
+            }
+        }
+
+        set_return_data(&owned.to_le_bytes());
+
+        Ok(())
+    }
+
     pub fn update_note(
         ctx: Context<UpdateNote>,
         new_title: Option<String>,
         new_body: Option<String>,
+        new_tags: Option<String>,
     ) -> Result<()> {
         let note = &mut ctx.accounts.note;
 
@@ -47,11 +77,52 @@ pub mod simple_note {
             note.body = b;
         }
 
+        if let Some(tags) = new_tags {
+            require!(tags.len() <= Note::MAX_TAGS_LEN, SimpleNoteError::TagsTooLong);
+            note.tags = tags;
+        }
+
+        note.update_count = note
+            .update_count
+            .checked_add(1)
+            .ok_or(SimpleNoteError::Overflow)?;
+        note.version = note
+            .version
+            .checked_add(1)
+            .ok_or(SimpleNoteError::Overflow)?;
+
         emit!(NoteUpdated {
             owner: note.owner,
             title: note.title.clone(),
         });
 
+        emit!(NoteVersionBumped {
+            owner: note.owner,
+            title: note.title.clone(),
+            version: note.version,
+        });
+
+        Ok(())
+    }
+
+    /// Hands a note over to `new_owner`. Both the current owner and the new
+    /// owner must sign.
+    pub fn transfer_note(ctx: Context<TransferNote>) -> Result<()> {
+        let note = &mut ctx.accounts.note;
+        let new_owner = ctx.accounts.new_owner.key();
+
+        require_keys_eq!(note.owner, ctx.accounts.authority.key(), SimpleNoteError::Unauthorized);
+        require_keys_neq!(note.owner, new_owner, SimpleNoteError::SameOwner);
+
+        let previous_owner = note.owner;
+        note.owner = new_owner;
+
+        emit!(NoteTransferred {
+            previous_owner,
+            new_owner,
+            title: note.title.clone(),
+        });
+
         Ok(())
     }
 
@@ -86,6 +157,11 @@ pub struct CreateNote<'info> {
     pub system_program: Program<'info, System>,
 }
 
+#[derive(Accounts)]
+pub struct CountNotes<'info> {
+    pub authority: Signer<'info>,
+}
+
 #[derive(Accounts)]
 pub struct UpdateNote<'info> {
     #[account(mut)]
@@ -94,6 +170,16 @@ pub struct UpdateNote<'info> {
     pub authority: Signer<'info>,
 }
 
+#[derive(Accounts)]
+pub struct TransferNote<'info> {
+    #[account(mut)]
+    pub note: Account<'info, Note>,
+
+    pub authority: Signer<'info>,
+
+    pub new_owner: Signer<'info>,
+}
+
 #[derive(Accounts)]
 pub struct CloseNote<'info> {
     #[account(
@@ -111,14 +197,22 @@ pub struct Note {
     pub owner: Pubkey,
     pub title: String,
     pub body: String,
+    pub tags: String,
+    pub update_count: u64,
+    pub version: u32,
     pub bump: u8,
 }
 
 impl Note {
+    pub const MAX_TAGS_LEN: usize = 64;
+
     pub const MAX_SIZE: usize =
         32 + // owner
         4 + 64 + // title (len + data)
         4 + 512 + // body
+        4 + Self::MAX_TAGS_LEN + // tags
+        8 + // update_count
+        4 + // version
         1; // bump
 }
 
@@ -134,6 +228,20 @@ pub struct NoteUpdated {
     pub title: String,
 }
 
+#[event]
+pub struct NoteVersionBumped {
+    pub owner: Pubkey,
+    pub title: String,
+    pub version: u32,
+}
+
+#[event]
+pub struct NoteTransferred {
+    pub previous_owner: Pubkey,
+    pub new_owner: Pubkey,
+    pub title: String,
+}
+
 #[event]
 pub struct NoteClosed {
     pub owner: Pubkey,
@@ -148,4 +256,10 @@ pub enum SimpleNoteError {
     BodyTooLong,
     #[msg("Unauthorized")]
     Unauthorized,
+    #[msg("Counter overflow")]
+    Overflow,
+    #[msg("New owner must differ from the current owner")]
+    SameOwner,
+    #[msg("Tags are too long")]
+    TagsTooLong,
 }
@@ -1,6 +1,6 @@
 import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
-import { PublicKey } from "@solana/web3.js";
+import { Keypair, PublicKey } from "@solana/web3.js";
 import { assert } from "chai";
 
 describe("simple_note", () => {
@@ -11,6 +11,157 @@ describe("simple_note", () => {
 
   const authority = provider.wallet.publicKey;
 
+  const findNotePda = (title: string) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("note"), authority.toBuffer(), Buffer.from(title)],
+      program.programId
+    )[0];
+
+  it("counts notes owned by the authority via remaining accounts", async () => {
+    const titles = ["count-a", "count-b"];
+    const notePdas = titles.map(findNotePda);
+
+    for (let i = 0; i < titles.length; i++) {
+      await program.methods
+        .createNote(titles[i], "body", "")
+        .accounts({
+          note: notePdas[i],
+          authority,
+        })
+        .rpc();
+    }
+
+    const simulation = await program.methods
+      .countNotes()
+      .accounts({ authority })
+      .remainingAccounts(
+        notePdas.map((pubkey) => ({ pubkey, isWritable: false, isSigner: false }))
+      )
+      .simulate();
+
+    const prefix = `Program return: ${program.programId.toBase58()} `;
+    const returnLog = simulation.raw.find((line) => line.startsWith(prefix));
+    assert.ok(returnLog, "expected return data in logs");
+
+    const returned = Buffer.from(returnLog!.slice(prefix.length), "base64");
+    assert.equal(returned.readUInt32LE(0), 2);
+  });
+
+  it("bumps the note version on each update", async () => {
+    const title = "versioned";
+    const notePda = findNotePda(title);
+
+    await program.methods
+      .createNote(title, "v0", "")
+      .accounts({ note: notePda, authority })
+      .rpc();
+
+    const bumpedVersions: number[] = [];
+    const listener = program.addEventListener("noteVersionBumped", (event) => {
+      bumpedVersions.push(event.version);
+    });
+
+    for (const body of ["v1", "v2"]) {
+      await program.methods
+        .updateNote(null, body, null)
+        .accounts({ note: notePda, authority })
+        .rpc();
+    }
+
+    await new Promise((resolve) => setTimeout(resolve, 2000));
+    await program.removeEventListener(listener);
+
+    const noteAccount = await program.account.note.fetch(notePda);
+    assert.equal(noteAccount.version, 2);
+    assert.equal(noteAccount.updateCount.toNumber(), 2);
+    assert.include(bumpedVersions, 2);
+  });
+
+  it("rejects a transfer not signed by the current owner", async () => {
+    const title = "transfer-unauthorized";
+    const notePda = findNotePda(title);
+
+    await program.methods
+      .createNote(title, "body", "")
+      .accounts({ note: notePda, authority })
+      .rpc();
+
+    const intruder = Keypair.generate();
+    const newOwner = Keypair.generate();
+
+    try {
+      await program.methods
+        .transferNote()
+        .accounts({
+          note: notePda,
+          authority: intruder.publicKey,
+          newOwner: newOwner.publicKey,
+        })
+        .signers([intruder, newOwner])
+        .rpc();
+      assert.fail("transfer by a non-owner should fail");
+    } catch (err) {
+      assert.include(String(err), "Unauthorized");
+    }
+  });
+
+  it("rejects a transfer to the same owner", async () => {
+    const title = "transfer-same-owner";
+    const notePda = findNotePda(title);
+
+    await program.methods
+      .createNote(title, "body", "")
+      .accounts({ note: notePda, authority })
+      .rpc();
+
+    try {
+      await program.methods
+        .transferNote()
+        .accounts({
+          note: notePda,
+          authority,
+          newOwner: authority,
+        })
+        .rpc();
+      assert.fail("transfer to the same owner should fail");
+    } catch (err) {
+      assert.include(String(err), "SameOwner");
+    }
+  });
+
+  it("stores a tag string within the length limit", async () => {
+    const title = "tagged";
+    const notePda = findNotePda(title);
+
+    await program.methods
+      .createNote(title, "body", "solana,anchor")
+      .accounts({ note: notePda, authority })
+      .rpc();
+
+    await program.methods
+      .updateNote(null, null, "solana,anchor,example")
+      .accounts({ note: notePda, authority })
+      .rpc();
+
+    const noteAccount = await program.account.note.fetch(notePda);
+    assert.equal(noteAccount.tags, "solana,anchor,example");
+  });
+
+  it("rejects an over-long tag string", async () => {
+    const title = "tagged-too-long";
+    const notePda = findNotePda(title);
+
+    try {
+      await program.methods
+        .createNote(title, "body", "t".repeat(65))
+        .accounts({ note: notePda, authority })
+        .rpc();
+      assert.fail("over-long tags should fail");
+    } catch (err) {
+      assert.include(String(err), "TagsTooLong");
+    }
+  });
+
   it("creates, updates, and closes a note", async () => {
     const title = "hello";
     const [notePda] = PublicKey.findProgramAddressSync(
@@ -19,7 +170,7 @@ describe("simple_note", () => {
     );
 
     await program.methods
-      .createNote(title, "world")
+      .createNote(title, "world", "")
       .accounts({
         note: notePda,
         authority,
@@ -31,7 +182,7 @@ describe("simple_note", () => {
     assert.equal(noteAccount.body, "world");
 
     await program.methods
-      .updateNote(null, "updated body")
+      .updateNote(null, "updated body", null)
       .accounts({
         note: notePda,
         authority,